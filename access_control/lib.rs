@@ -3,9 +3,13 @@
 //! # Access Control Library for ink! Smart Contracts
 //!
 //! This library provides secure access control patterns for smart contracts:
-//! - **Ownable**: Single owner with transfer/renounce capabilities
+//! - **Ownable**: Single owner with two-step transfer and renounce capabilities
 //! - **AccessControl**: Role-based permissions (admin, minter, pauser, etc.)
+//!   with enumerable role members, optional expiring grants, and optional
+//!   default-admin rules (single admin, delayed two-step transfer)
 //! - **Pausable**: Emergency stop functionality
+//! - **Timelock**: Delayed execution of scheduled operations
+//! - **Guardian**: Pause-only emergency key alongside the owner
 //!
 //! ## Usage
 //!
@@ -68,6 +72,26 @@ pub enum AccessError {
     Paused,
     /// Contract is not paused
     NotPaused,
+    /// Caller is not the pending owner
+    NotPendingOwner,
+    /// Requested delay is below the timelock minimum
+    DelayTooShort,
+    /// Operation id is already scheduled or executed
+    OperationExists,
+    /// Operation is not scheduled (or already executed/cancelled)
+    UnknownOperation,
+    /// Operation delay has not elapsed yet
+    OperationNotReady,
+    /// Expiry is in the past or does not extend the current grant
+    InvalidExpiry,
+    /// Caller is neither the guardian nor the owner
+    NotGuardian,
+    /// DEFAULT_ADMIN_ROLE can only change through the default-admin transfer
+    AdminRulesEnforced,
+    /// Caller is not the pending default admin
+    NotPendingAdmin,
+    /// Default-admin transfer delay has not elapsed yet
+    AdminTransferNotReady,
 }
 
 pub type Result<T> = core::result::Result<T, AccessError>;
@@ -88,6 +112,7 @@ pub mod ownable {
     )]
     pub struct OwnableData {
         owner: Option<AccountId>,
+        pending_owner: Option<AccountId>,
     }
 
     /// Events for Ownable
@@ -99,10 +124,29 @@ pub mod ownable {
         new_owner: Option<AccountId>,
     }
 
+    #[ink::event]
+    pub struct OwnershipTransferStarted {
+        #[ink(topic)]
+        previous_owner: Option<AccountId>,
+        #[ink(topic)]
+        new_owner: AccountId,
+    }
+
+    #[ink::event]
+    pub struct OwnershipTransferCancelled {
+        #[ink(topic)]
+        owner: Option<AccountId>,
+        #[ink(topic)]
+        pending_owner: AccountId,
+    }
+
     impl OwnableData {
         /// Initialize Ownable with initial owner
         pub fn new(owner: AccountId) -> Self {
-            Self { owner: Some(owner) }
+            Self {
+                owner: Some(owner),
+                pending_owner: None,
+            }
         }
 
         /// Get current owner
@@ -110,6 +154,11 @@ pub mod ownable {
             self.owner
         }
 
+        /// Get the account that may accept ownership, if a transfer is in progress
+        pub fn pending_owner(&self) -> Option<AccountId> {
+            self.pending_owner
+        }
+
         /// Ensure caller is owner (reverts if not)
         pub fn ensure_owner(&self, caller: AccountId) -> Result<()> {
             match self.owner {
@@ -126,12 +175,93 @@ pub mod ownable {
             }
         }
 
-        /// Transfer ownership to new owner
+        /// Start a two-step ownership transfer
+        ///
+        /// Ownership only moves once `new_owner` calls `accept_ownership`.
+        /// Starting a new transfer replaces any pending one.
         ///
         /// Requirements:
         /// - Caller must be current owner
         /// - New owner cannot be zero address
         pub fn transfer_ownership<E: ink::env::Environment>(
+            &mut self,
+            caller: AccountId,
+            new_owner: AccountId,
+            emit_event: impl FnOnce(OwnershipTransferStarted),
+        ) -> Result<()> {
+            self.ensure_owner(caller)?;
+
+            if new_owner == AccountId::from([0u8; 32]) {
+                return Err(AccessError::ZeroAddress);
+            }
+
+            self.pending_owner = Some(new_owner);
+
+            emit_event(OwnershipTransferStarted {
+                previous_owner: self.owner,
+                new_owner,
+            });
+
+            Ok(())
+        }
+
+        /// Complete a two-step ownership transfer
+        ///
+        /// Requirements:
+        /// - Caller must be the pending owner
+        pub fn accept_ownership(
+            &mut self,
+            caller: AccountId,
+            emit_event: impl FnOnce(OwnershipTransferred),
+        ) -> Result<()> {
+            if self.pending_owner != Some(caller) {
+                return Err(AccessError::NotPendingOwner);
+            }
+
+            let previous_owner = self.owner;
+            self.owner = Some(caller);
+            self.pending_owner = None;
+
+            emit_event(OwnershipTransferred {
+                previous_owner,
+                new_owner: Some(caller),
+            });
+
+            Ok(())
+        }
+
+        /// Abort a pending ownership transfer
+        ///
+        /// Emits only if a transfer was actually pending.
+        ///
+        /// Requirements:
+        /// - Caller must be current owner
+        pub fn cancel_transfer(
+            &mut self,
+            caller: AccountId,
+            emit_event: impl FnOnce(OwnershipTransferCancelled),
+        ) -> Result<()> {
+            self.ensure_owner(caller)?;
+
+            if let Some(pending_owner) = self.pending_owner.take() {
+                emit_event(OwnershipTransferCancelled {
+                    owner: self.owner,
+                    pending_owner,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Transfer ownership immediately, without acceptance by the new owner
+        ///
+        /// A mistyped address permanently orphans the contract; prefer
+        /// `transfer_ownership` unless the new owner cannot sign.
+        ///
+        /// Requirements:
+        /// - Caller must be current owner
+        /// - New owner cannot be zero address
+        pub fn force_transfer_ownership<E: ink::env::Environment>(
             &mut self,
             caller: AccountId,
             new_owner: AccountId,
@@ -145,6 +275,7 @@ pub mod ownable {
 
             let previous_owner = self.owner;
             self.owner = Some(new_owner);
+            self.pending_owner = None;
 
             emit_event(OwnershipTransferred {
                 previous_owner,
@@ -167,6 +298,7 @@ pub mod ownable {
 
             let previous_owner = self.owner;
             self.owner = None;
+            self.pending_owner = None;
 
             emit_event(OwnershipTransferred {
                 previous_owner,
@@ -184,6 +316,7 @@ pub mod ownable {
 
 pub mod access_control {
     use super::*;
+    use ink::prelude::vec::Vec;
     use ink::primitives::AccountId;
     use ink::storage::Mapping;
 
@@ -195,16 +328,23 @@ pub mod access_control {
     pub const UPGRADER_ROLE: RoleType = 4;
 
     /// Storage for AccessControl pattern
+    #[ink::storage_item]
     #[derive(Debug, Default)]
-    #[cfg_attr(
-        feature = "std",
-        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
-    )]
     pub struct AccessControlData {
         /// Role assignments: (role, account) => has_role
         roles: Mapping<(RoleType, AccountId), ()>,
         /// Role admins: role => admin_role
         role_admins: Mapping<RoleType, RoleType>,
+        /// Role members: (role, index) => account
+        role_members: Mapping<(RoleType, u32), AccountId>,
+        /// Reverse index: (role, account) => index in role_members
+        role_member_index: Mapping<(RoleType, AccountId), u32>,
+        /// Number of members per role
+        role_member_count: Mapping<RoleType, u32>,
+        /// Grant expiry: (role, account) => timestamp after which the grant lapses
+        role_expiry: Mapping<(RoleType, AccountId), u64>,
+        /// Default-admin rules, when enabled
+        admin_rules: Option<default_admin_rules::DefaultAdminRules>,
     }
 
     /// Events for AccessControl
@@ -240,17 +380,43 @@ pub mod access_control {
         /// Initialize AccessControl with default admin
         pub fn new(admin: AccountId) -> Self {
             let mut data = Self::default();
-            data.roles.insert((DEFAULT_ADMIN_ROLE, admin), &());
+            data.add_member(DEFAULT_ADMIN_ROLE, admin);
             data.role_admins
                 .insert(DEFAULT_ADMIN_ROLE, &DEFAULT_ADMIN_ROLE);
             data
         }
 
         /// Check if account has role
+        ///
+        /// Ignores grant expiry; use `has_role_at` for roles that may be
+        /// granted with `grant_role_with_expiry`.
         pub fn has_role(&self, role: RoleType, account: AccountId) -> bool {
             self.roles.contains((role, account))
         }
 
+        /// Check if account has an unexpired grant of role at time `now`
+        pub fn has_role_at(&self, role: RoleType, account: AccountId, now: u64) -> bool {
+            self.has_role(role, account)
+                && self
+                    .role_expiry
+                    .get((role, account))
+                    .is_none_or(|expires_at| now < expires_at)
+        }
+
+        /// Ensure caller has an unexpired grant of role at time `now`
+        pub fn ensure_role_at(&self, caller: AccountId, role: RoleType, now: u64) -> Result<()> {
+            if self.has_role_at(role, caller, now) {
+                Ok(())
+            } else {
+                Err(AccessError::MissingRole)
+            }
+        }
+
+        /// Get expiry of a grant (None for permanent grants or non-members)
+        pub fn role_expiry(&self, role: RoleType, account: AccountId) -> Option<u64> {
+            self.role_expiry.get((role, account))
+        }
+
         /// Ensure caller has role (reverts if not)
         pub fn ensure_role(&self, caller: AccountId, role: RoleType) -> Result<()> {
             if self.has_role(role, caller) {
@@ -267,47 +433,119 @@ pub mod access_control {
 
         /// Grant role to account
         ///
+        /// Re-granting renews a lapsed grant as a permanent one; an unexpired
+        /// grant keeps its expiry (use `extend_role` to push it back).
+        ///
         /// Requirements:
-        /// - Caller must have admin role for the target role
+        /// - Caller must hold an unexpired grant of the admin role at `now`
         pub fn grant_role(
             &mut self,
             caller: AccountId,
             role: RoleType,
             account: AccountId,
+            now: u64,
             emit_event: impl FnOnce(RoleGranted),
         ) -> Result<()> {
-            let admin_role = self.get_role_admin(role);
-            self.ensure_role(caller, admin_role)?;
+            self.ensure_role_admin(caller, role, now)?;
 
             if !self.has_role(role, account) {
-                self.roles.insert((role, account), &());
+                self.add_member(role, account);
 
                 emit_event(RoleGranted {
                     role,
                     account,
                     sender: caller,
                 });
+            } else if !self.has_role_at(role, account, now) {
+                self.role_expiry.remove((role, account));
+                emit_event(RoleGranted {
+                    role,
+                    account,
+                    sender: caller,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Grant role to account until `expires_at`
+        ///
+        /// Re-granting replaces any previous expiry, including after it lapsed.
+        ///
+        /// Requirements:
+        /// - Caller must hold an unexpired grant of the admin role at `now`
+        /// - `expires_at` must be after `now`
+        pub fn grant_role_with_expiry(
+            &mut self,
+            caller: AccountId,
+            role: RoleType,
+            account: AccountId,
+            expires_at: u64,
+            now: u64,
+            emit_event: impl FnOnce(RoleGranted),
+        ) -> Result<()> {
+            self.ensure_role_admin(caller, role, now)?;
+
+            if expires_at <= now {
+                return Err(AccessError::InvalidExpiry);
+            }
+
+            if !self.has_role(role, account) {
+                self.add_member(role, account);
             }
+            self.role_expiry.insert((role, account), &expires_at);
+
+            emit_event(RoleGranted {
+                role,
+                account,
+                sender: caller,
+            });
 
             Ok(())
         }
 
+        /// Push back the expiry of an existing expiring grant
+        ///
+        /// Requirements:
+        /// - Caller must hold an unexpired grant of the admin role at `now`
+        /// - Account must hold an expiring grant of role
+        /// - `expires_at` must be later than the current expiry
+        pub fn extend_role(
+            &mut self,
+            caller: AccountId,
+            role: RoleType,
+            account: AccountId,
+            expires_at: u64,
+            now: u64,
+        ) -> Result<()> {
+            self.ensure_role_admin(caller, role, now)?;
+
+            match self.role_expiry.get((role, account)) {
+                Some(current) if expires_at > current => {
+                    self.role_expiry.insert((role, account), &expires_at);
+                    Ok(())
+                }
+                Some(_) => Err(AccessError::InvalidExpiry),
+                None => Err(AccessError::MissingRole),
+            }
+        }
+
         /// Revoke role from account
         ///
         /// Requirements:
-        /// - Caller must have admin role for the target role
+        /// - Caller must hold an unexpired grant of the admin role at `now`
         pub fn revoke_role(
             &mut self,
             caller: AccountId,
             role: RoleType,
             account: AccountId,
+            now: u64,
             emit_event: impl FnOnce(RoleRevoked),
         ) -> Result<()> {
-            let admin_role = self.get_role_admin(role);
-            self.ensure_role(caller, admin_role)?;
+            self.ensure_role_admin(caller, role, now)?;
 
             if self.has_role(role, account) {
-                self.roles.remove((role, account));
+                self.remove_member(role, account);
 
                 emit_event(RoleRevoked {
                     role,
@@ -319,17 +557,143 @@ pub mod access_control {
             Ok(())
         }
 
+        /// Grant several roles at once
+        ///
+        /// All grants are authorized before any is applied, so an unauthorized
+        /// entry rejects the whole batch. `emit_event` runs once per new grant.
+        ///
+        /// Requirements:
+        /// - Caller must hold an unexpired grant of the admin role of every
+        ///   target role at `now`
+        pub fn grant_roles(
+            &mut self,
+            caller: AccountId,
+            grants: Vec<(RoleType, AccountId)>,
+            now: u64,
+            mut emit_event: impl FnMut(RoleGranted),
+        ) -> Result<()> {
+            for (role, _) in &grants {
+                self.ensure_role_admin(caller, *role, now)?;
+            }
+
+            for (role, account) in grants {
+                self.grant_role(caller, role, account, now, &mut emit_event)?;
+            }
+
+            Ok(())
+        }
+
+        /// Revoke several roles at once
+        ///
+        /// All revocations are authorized before any is applied, so an
+        /// unauthorized entry rejects the whole batch. `emit_event` runs once
+        /// per removed grant.
+        ///
+        /// Requirements:
+        /// - Caller must hold an unexpired grant of the admin role of every
+        ///   target role at `now`
+        pub fn revoke_roles(
+            &mut self,
+            caller: AccountId,
+            revocations: Vec<(RoleType, AccountId)>,
+            now: u64,
+            mut emit_event: impl FnMut(RoleRevoked),
+        ) -> Result<()> {
+            for (role, _) in &revocations {
+                self.ensure_role_admin(caller, *role, now)?;
+            }
+
+            // Authorization is checked up front: revoking the caller's own
+            // admin role mid-batch must not fail later entries.
+            for (role, account) in revocations {
+                if self.has_role(role, account) {
+                    self.remove_member(role, account);
+
+                    emit_event(RoleRevoked {
+                        role,
+                        account,
+                        sender: caller,
+                    });
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Move a role from `old` to `new` in one call (key rotation)
+        ///
+        /// An expiring grant keeps its expiry on the new holder.
+        /// If `new` already holds the role, its own grant is kept.
+        ///
+        /// Requirements:
+        /// - Caller must hold an unexpired grant of the admin role at `now`
+        /// - `old` must hold the role
+        /// - `new` cannot be zero address
+        pub fn replace_role_holder(
+            &mut self,
+            caller: AccountId,
+            role: RoleType,
+            old: AccountId,
+            new: AccountId,
+            now: u64,
+            emit_revoked: impl FnOnce(RoleRevoked),
+            emit_granted: impl FnOnce(RoleGranted),
+        ) -> Result<()> {
+            self.ensure_role_admin(caller, role, now)?;
+
+            if !self.has_role(role, old) {
+                return Err(AccessError::MissingRole);
+            }
+
+            if new == AccountId::from([0u8; 32]) {
+                return Err(AccessError::ZeroAddress);
+            }
+
+            if old == new {
+                return Ok(());
+            }
+
+            let expiry = self.role_expiry.get((role, old));
+            self.remove_member(role, old);
+            emit_revoked(RoleRevoked {
+                role,
+                account: old,
+                sender: caller,
+            });
+
+            // An existing grant held by `new` is left as is
+            if !self.has_role(role, new) {
+                self.add_member(role, new);
+                if let Some(expires_at) = expiry {
+                    self.role_expiry.insert((role, new), &expires_at);
+                }
+
+                emit_granted(RoleGranted {
+                    role,
+                    account: new,
+                    sender: caller,
+                });
+            }
+
+            Ok(())
+        }
+
         /// Renounce role for caller
         ///
-        /// Allows account to give up their own role
+        /// Allows account to give up their own role. With default-admin rules
+        /// enabled, the default admin must use `renounce_default_admin`.
         pub fn renounce_role(
             &mut self,
             caller: AccountId,
             role: RoleType,
             emit_event: impl FnOnce(RoleRevoked),
         ) -> Result<()> {
+            if role == DEFAULT_ADMIN_ROLE && self.admin_rules.is_some() {
+                return Err(AccessError::AdminRulesEnforced);
+            }
+
             if self.has_role(role, caller) {
-                self.roles.remove((role, caller));
+                self.remove_member(role, caller);
 
                 emit_event(RoleRevoked {
                     role,
@@ -341,18 +705,46 @@ pub mod access_control {
             Ok(())
         }
 
+        /// Number of accounts holding a role
+        pub fn get_role_member_count(&self, role: RoleType) -> u32 {
+            self.role_member_count.get(role).unwrap_or(0)
+        }
+
+        /// Account at `index` among the holders of a role
+        ///
+        /// Order is not stable: removals move the last member into the gap.
+        pub fn get_role_member(&self, role: RoleType, index: u32) -> Option<AccountId> {
+            self.role_members.get((role, index))
+        }
+
+        /// Up to `limit` holders of a role starting at `offset`
+        pub fn get_role_members(&self, role: RoleType, offset: u32, limit: u32) -> Vec<AccountId> {
+            let end = offset
+                .saturating_add(limit)
+                .min(self.get_role_member_count(role));
+
+            (offset..end)
+                .filter_map(|index| self.role_members.get((role, index)))
+                .collect()
+        }
+
         /// Set admin role for a role
         ///
         /// Requirements:
-        /// - Caller must have DEFAULT_ADMIN_ROLE
+        /// - Caller must hold an unexpired grant of DEFAULT_ADMIN_ROLE at `now`
         pub fn set_role_admin(
             &mut self,
             caller: AccountId,
             role: RoleType,
             admin_role: RoleType,
+            now: u64,
             emit_event: impl FnOnce(RoleAdminChanged),
         ) -> Result<()> {
-            self.ensure_role(caller, DEFAULT_ADMIN_ROLE)?;
+            self.ensure_role_at(caller, DEFAULT_ADMIN_ROLE, now)?;
+
+            if role == DEFAULT_ADMIN_ROLE && self.admin_rules.is_some() {
+                return Err(AccessError::AdminRulesEnforced);
+            }
 
             let previous_admin_role = self.get_role_admin(role);
             self.role_admins.insert(role, &admin_role);
@@ -365,6 +757,268 @@ pub mod access_control {
 
             Ok(())
         }
+
+        /// Ensure caller may grant or revoke `role` at time `now`
+        fn ensure_role_admin(&self, caller: AccountId, role: RoleType, now: u64) -> Result<()> {
+            if role == DEFAULT_ADMIN_ROLE && self.admin_rules.is_some() {
+                return Err(AccessError::AdminRulesEnforced);
+            }
+
+            self.ensure_role_at(caller, self.get_role_admin(role), now)
+        }
+
+        /// Record `account` as a holder of `role` (caller checks it is not one yet)
+        fn add_member(&mut self, role: RoleType, account: AccountId) {
+            let count = self.get_role_member_count(role);
+
+            self.roles.insert((role, account), &());
+            self.role_members.insert((role, count), &account);
+            self.role_member_index.insert((role, account), &count);
+            self.role_member_count
+                .insert(role, &count.saturating_add(1));
+        }
+
+        /// Remove `account` from the holders of `role` (swap-and-pop)
+        fn remove_member(&mut self, role: RoleType, account: AccountId) {
+            let Some(index) = self.role_member_index.take((role, account)) else {
+                return;
+            };
+            let last = self.get_role_member_count(role).saturating_sub(1);
+
+            if index != last {
+                if let Some(moved) = self.role_members.get((role, last)) {
+                    self.role_members.insert((role, index), &moved);
+                    self.role_member_index.insert((role, moved), &index);
+                }
+            }
+
+            self.roles.remove((role, account));
+            self.role_expiry.remove((role, account));
+            self.role_members.remove((role, last));
+            self.role_member_count.insert(role, &last);
+        }
+    }
+
+    /// Default-admin rules (OpenZeppelin's AccessControlDefaultAdminRules)
+    ///
+    /// When enabled through `AccessControlData::with_default_admin_rules`:
+    /// - DEFAULT_ADMIN_ROLE always has at most one holder
+    /// - it cannot be granted, revoked, or renounced directly
+    /// - it moves through `begin_default_admin_transfer` and
+    ///   `accept_default_admin_transfer`, separated by `delay`
+    /// - renouncing requires a transfer to the zero address scheduled
+    ///   `delay` in advance, then `renounce_default_admin`
+    pub mod default_admin_rules {
+        use super::*;
+
+        /// Storage for the default-admin rules
+        #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+        #[cfg_attr(
+            feature = "std",
+            derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+        )]
+        pub struct DefaultAdminRules {
+            delay: u64,
+            /// Pending admin; the zero address schedules a renounce
+            pending_admin: Option<AccountId>,
+            /// Timestamp from which the pending transfer may complete
+            pending_schedule: u64,
+        }
+
+        /// Events for default-admin rules
+        #[ink::event]
+        pub struct DefaultAdminTransferScheduled {
+            #[ink(topic)]
+            new_admin: AccountId,
+            accept_schedule: u64,
+        }
+
+        #[ink::event]
+        pub struct DefaultAdminTransferCanceled {
+            #[ink(topic)]
+            pending_admin: AccountId,
+        }
+
+        impl AccessControlData {
+            /// Initialize AccessControl with default-admin rules enforced
+            ///
+            /// `delay` uses the same unit as the `now` arguments below.
+            pub fn with_default_admin_rules(admin: AccountId, delay: u64) -> Self {
+                let mut data = Self::new(admin);
+                data.admin_rules = Some(DefaultAdminRules {
+                    delay,
+                    pending_admin: None,
+                    pending_schedule: 0,
+                });
+                data
+            }
+
+            /// Check if default-admin rules are enforced
+            pub fn has_default_admin_rules(&self) -> bool {
+                self.admin_rules.is_some()
+            }
+
+            /// Get the single default admin (rules enabled only)
+            pub fn default_admin(&self) -> Option<AccountId> {
+                self.admin_rules.as_ref()?;
+                self.get_role_member(DEFAULT_ADMIN_ROLE, 0)
+            }
+
+            /// Get the pending default admin and the time it may accept
+            pub fn pending_default_admin(&self) -> Option<(AccountId, u64)> {
+                let rules = self.admin_rules.as_ref()?;
+                rules
+                    .pending_admin
+                    .map(|pending| (pending, rules.pending_schedule))
+            }
+
+            /// Get the default-admin transfer delay
+            pub fn default_admin_delay(&self) -> Option<u64> {
+                self.admin_rules.as_ref().map(|rules| rules.delay)
+            }
+
+            /// Schedule a default-admin transfer (zero address schedules a renounce)
+            ///
+            /// Replaces any pending transfer.
+            ///
+            /// Requirements:
+            /// - Rules must be enabled
+            /// - Caller must be the default admin
+            pub fn begin_default_admin_transfer(
+                &mut self,
+                caller: AccountId,
+                new_admin: AccountId,
+                now: u64,
+                emit_event: impl FnOnce(DefaultAdminTransferScheduled),
+            ) -> Result<()> {
+                self.ensure_role_at(caller, DEFAULT_ADMIN_ROLE, now)?;
+                let rules = self
+                    .admin_rules
+                    .as_mut()
+                    .ok_or(AccessError::AdminRulesEnforced)?;
+
+                let accept_schedule = now.saturating_add(rules.delay);
+                rules.pending_admin = Some(new_admin);
+                rules.pending_schedule = accept_schedule;
+
+                emit_event(DefaultAdminTransferScheduled {
+                    new_admin,
+                    accept_schedule,
+                });
+
+                Ok(())
+            }
+
+            /// Cancel a pending default-admin transfer or renounce
+            ///
+            /// Requirements:
+            /// - Rules must be enabled
+            /// - Caller must be the default admin
+            pub fn cancel_default_admin_transfer(
+                &mut self,
+                caller: AccountId,
+                now: u64,
+                emit_event: impl FnOnce(DefaultAdminTransferCanceled),
+            ) -> Result<()> {
+                self.ensure_role_at(caller, DEFAULT_ADMIN_ROLE, now)?;
+                let rules = self
+                    .admin_rules
+                    .as_mut()
+                    .ok_or(AccessError::AdminRulesEnforced)?;
+
+                if let Some(pending_admin) = rules.pending_admin.take() {
+                    rules.pending_schedule = 0;
+                    emit_event(DefaultAdminTransferCanceled { pending_admin });
+                }
+
+                Ok(())
+            }
+
+            /// Complete a default-admin transfer
+            ///
+            /// Requirements:
+            /// - Caller must be the pending admin
+            /// - The transfer delay must have elapsed
+            pub fn accept_default_admin_transfer(
+                &mut self,
+                caller: AccountId,
+                now: u64,
+                emit_revoked: impl FnOnce(RoleRevoked),
+                emit_granted: impl FnOnce(RoleGranted),
+            ) -> Result<()> {
+                let previous = self.take_ready_transfer(caller, now)?;
+
+                if let Some(previous) = previous {
+                    self.remove_member(DEFAULT_ADMIN_ROLE, previous);
+                    emit_revoked(RoleRevoked {
+                        role: DEFAULT_ADMIN_ROLE,
+                        account: previous,
+                        sender: caller,
+                    });
+                }
+
+                self.add_member(DEFAULT_ADMIN_ROLE, caller);
+                emit_granted(RoleGranted {
+                    role: DEFAULT_ADMIN_ROLE,
+                    account: caller,
+                    sender: caller,
+                });
+
+                Ok(())
+            }
+
+            /// Give up the default admin role, leaving the contract without one
+            ///
+            /// Requirements:
+            /// - Caller must be the default admin
+            /// - A renounce (transfer to the zero address) must be scheduled
+            ///   and its delay elapsed
+            pub fn renounce_default_admin(
+                &mut self,
+                caller: AccountId,
+                now: u64,
+                emit_event: impl FnOnce(RoleRevoked),
+            ) -> Result<()> {
+                self.ensure_role_at(caller, DEFAULT_ADMIN_ROLE, now)?;
+                self.take_ready_transfer(AccountId::from([0u8; 32]), now)?;
+
+                self.remove_member(DEFAULT_ADMIN_ROLE, caller);
+                emit_event(RoleRevoked {
+                    role: DEFAULT_ADMIN_ROLE,
+                    account: caller,
+                    sender: caller,
+                });
+
+                Ok(())
+            }
+
+            /// Clear a pending transfer to `pending` once it is ready,
+            /// returning the current default admin
+            fn take_ready_transfer(
+                &mut self,
+                pending: AccountId,
+                now: u64,
+            ) -> Result<Option<AccountId>> {
+                let current = self.get_role_member(DEFAULT_ADMIN_ROLE, 0);
+                let rules = self
+                    .admin_rules
+                    .as_mut()
+                    .ok_or(AccessError::AdminRulesEnforced)?;
+
+                if rules.pending_admin != Some(pending) {
+                    return Err(AccessError::NotPendingAdmin);
+                }
+
+                if now < rules.pending_schedule {
+                    return Err(AccessError::AdminTransferNotReady);
+                }
+
+                rules.pending_admin = None;
+                rules.pending_schedule = 0;
+
+                Ok(current)
+            }
+        }
     }
 }
 
@@ -460,6 +1114,344 @@ pub mod pausable {
     }
 }
 
+// ============================================================================
+// Timelock Module
+// ============================================================================
+
+pub mod timelock {
+    use super::access_control::AccessControlData;
+    use super::*;
+    use ink::primitives::AccountId;
+    use ink::storage::Mapping;
+
+    /// Timelock role constants
+    pub const PROPOSER_ROLE: RoleType = 10;
+    pub const EXECUTOR_ROLE: RoleType = 11;
+    pub const CANCELLER_ROLE: RoleType = 12;
+
+    /// Operation identifier, chosen by the proposer (usually a hash of the call)
+    pub type OperationId = [u8; 32];
+
+    /// A scheduled call
+    ///
+    /// The timelock only tracks state; the embedding contract performs the
+    /// actual `build_call` against `target`/`selector` once `execute_ready`
+    /// succeeds, after checking its arguments hash to `args_hash`.
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct OperationState {
+        pub target: AccountId,
+        pub selector: [u8; 4],
+        pub args_hash: [u8; 32],
+        /// Timestamp from which the operation may execute
+        pub ready_at: u64,
+        pub executed: bool,
+    }
+
+    /// Storage for Timelock pattern
+    #[ink::storage_item]
+    #[derive(Debug, Default)]
+    pub struct TimelockData {
+        min_delay: u64,
+        operations: Mapping<OperationId, OperationState>,
+    }
+
+    /// Events for Timelock
+    #[ink::event]
+    pub struct CallScheduled {
+        #[ink(topic)]
+        id: OperationId,
+        #[ink(topic)]
+        target: AccountId,
+        selector: [u8; 4],
+        ready_at: u64,
+    }
+
+    #[ink::event]
+    pub struct CallExecuted {
+        #[ink(topic)]
+        id: OperationId,
+    }
+
+    #[ink::event]
+    pub struct Cancelled {
+        #[ink(topic)]
+        id: OperationId,
+    }
+
+    impl TimelockData {
+        /// Initialize Timelock with a minimum delay (same unit as `now`)
+        pub fn new(min_delay: u64) -> Self {
+            Self {
+                min_delay,
+                operations: Mapping::default(),
+            }
+        }
+
+        /// Get minimum delay
+        pub fn min_delay(&self) -> u64 {
+            self.min_delay
+        }
+
+        /// Get a scheduled or executed operation
+        pub fn get_operation(&self, id: OperationId) -> Option<OperationState> {
+            self.operations.get(id)
+        }
+
+        /// Check if operation is scheduled and not yet executed
+        pub fn is_operation_pending(&self, id: OperationId) -> bool {
+            matches!(self.operations.get(id), Some(op) if !op.executed)
+        }
+
+        /// Check if operation is pending and its delay has elapsed
+        pub fn is_operation_ready(&self, id: OperationId, now: u64) -> bool {
+            matches!(self.operations.get(id), Some(op) if !op.executed && now >= op.ready_at)
+        }
+
+        /// Check if operation has been executed
+        pub fn is_operation_done(&self, id: OperationId) -> bool {
+            matches!(self.operations.get(id), Some(op) if op.executed)
+        }
+
+        /// Schedule an operation
+        ///
+        /// Requirements:
+        /// - Caller must hold an unexpired grant of PROPOSER_ROLE at `now`
+        /// - `delay` must be at least the minimum delay
+        /// - `id` must not be scheduled or executed already
+        #[allow(clippy::too_many_arguments)]
+        pub fn schedule(
+            &mut self,
+            roles: &AccessControlData,
+            caller: AccountId,
+            id: OperationId,
+            target: AccountId,
+            selector: [u8; 4],
+            args_hash: [u8; 32],
+            delay: u64,
+            now: u64,
+            emit_event: impl FnOnce(CallScheduled),
+        ) -> Result<()> {
+            roles.ensure_role_at(caller, PROPOSER_ROLE, now)?;
+
+            if delay < self.min_delay {
+                return Err(AccessError::DelayTooShort);
+            }
+
+            if self.operations.contains(id) {
+                return Err(AccessError::OperationExists);
+            }
+
+            let ready_at = now.saturating_add(delay);
+            self.operations.insert(
+                id,
+                &OperationState {
+                    target,
+                    selector,
+                    args_hash,
+                    ready_at,
+                    executed: false,
+                },
+            );
+
+            emit_event(CallScheduled {
+                id,
+                target,
+                selector,
+                ready_at,
+            });
+
+            Ok(())
+        }
+
+        /// Mark a ready operation as executed and return it for dispatch
+        ///
+        /// The operation is marked done before the caller dispatches it, so a
+        /// re-entrant call cannot execute it twice.
+        ///
+        /// Requirements:
+        /// - Caller must hold an unexpired grant of EXECUTOR_ROLE at `now`
+        /// - Operation must be pending and its delay elapsed
+        pub fn execute_ready(
+            &mut self,
+            roles: &AccessControlData,
+            caller: AccountId,
+            id: OperationId,
+            now: u64,
+            emit_event: impl FnOnce(CallExecuted),
+        ) -> Result<OperationState> {
+            roles.ensure_role_at(caller, EXECUTOR_ROLE, now)?;
+
+            let mut op = match self.operations.get(id) {
+                Some(op) if !op.executed => op,
+                _ => return Err(AccessError::UnknownOperation),
+            };
+
+            if now < op.ready_at {
+                return Err(AccessError::OperationNotReady);
+            }
+
+            op.executed = true;
+            self.operations.insert(id, &op);
+
+            emit_event(CallExecuted { id });
+
+            Ok(op)
+        }
+
+        /// Cancel a pending operation
+        ///
+        /// Requirements:
+        /// - Caller must hold an unexpired grant of CANCELLER_ROLE at `now`
+        /// - Operation must be pending
+        pub fn cancel(
+            &mut self,
+            roles: &AccessControlData,
+            caller: AccountId,
+            id: OperationId,
+            now: u64,
+            emit_event: impl FnOnce(Cancelled),
+        ) -> Result<()> {
+            roles.ensure_role_at(caller, CANCELLER_ROLE, now)?;
+
+            if !self.is_operation_pending(id) {
+                return Err(AccessError::UnknownOperation);
+            }
+
+            self.operations.remove(id);
+
+            emit_event(Cancelled { id });
+
+            Ok(())
+        }
+    }
+}
+
+// ============================================================================
+// Guardian Module
+// ============================================================================
+
+pub mod guardian {
+    use super::ownable::OwnableData;
+    use super::pausable::{PausableData, Paused, Unpaused};
+    use super::*;
+    use ink::primitives::AccountId;
+
+    /// Storage for Guardian pattern
+    ///
+    /// The guardian is a low-security hot key that can only pause. Unpausing
+    /// and changing the guardian stay with the owner.
+    #[derive(Debug, Default, Encode, Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct GuardianData {
+        pausable: PausableData,
+        guardian: Option<AccountId>,
+    }
+
+    /// Events for Guardian
+    #[ink::event]
+    pub struct GuardianChanged {
+        #[ink(topic)]
+        previous_guardian: Option<AccountId>,
+        #[ink(topic)]
+        new_guardian: Option<AccountId>,
+    }
+
+    impl GuardianData {
+        /// Initialize Guardian (starts unpaused, optionally with a guardian)
+        pub fn new(guardian: Option<AccountId>) -> Self {
+            Self {
+                pausable: PausableData::new(),
+                guardian,
+            }
+        }
+
+        /// Get current guardian
+        pub fn guardian(&self) -> Option<AccountId> {
+            self.guardian
+        }
+
+        /// Check if account is the guardian
+        pub fn is_guardian(&self, account: AccountId) -> bool {
+            self.guardian == Some(account)
+        }
+
+        /// Check if contract is paused
+        pub fn is_paused(&self) -> bool {
+            self.pausable.is_paused()
+        }
+
+        /// Ensure contract is not paused (reverts if paused)
+        pub fn ensure_not_paused(&self) -> Result<()> {
+            self.pausable.ensure_not_paused()
+        }
+
+        /// Set or clear the guardian
+        ///
+        /// Requirements:
+        /// - Caller must be owner
+        pub fn set_guardian(
+            &mut self,
+            ownable: &OwnableData,
+            caller: AccountId,
+            guardian: Option<AccountId>,
+            emit_event: impl FnOnce(GuardianChanged),
+        ) -> Result<()> {
+            ownable.ensure_owner(caller)?;
+
+            let previous_guardian = self.guardian;
+            self.guardian = guardian;
+
+            emit_event(GuardianChanged {
+                previous_guardian,
+                new_guardian: guardian,
+            });
+
+            Ok(())
+        }
+
+        /// Pause contract
+        ///
+        /// Requirements:
+        /// - Caller must be guardian or owner
+        /// - Contract must not be paused
+        pub fn guardian_pause(
+            &mut self,
+            ownable: &OwnableData,
+            caller: AccountId,
+            emit_event: impl FnOnce(Paused),
+        ) -> Result<()> {
+            if !self.is_guardian(caller) && !ownable.is_owner(caller) {
+                return Err(AccessError::NotGuardian);
+            }
+
+            self.pausable.pause(caller, emit_event)
+        }
+
+        /// Unpause contract
+        ///
+        /// Requirements:
+        /// - Caller must be owner (the guardian cannot unpause)
+        /// - Contract must be paused
+        pub fn unpause(
+            &mut self,
+            ownable: &OwnableData,
+            caller: AccountId,
+            emit_event: impl FnOnce(Unpaused),
+        ) -> Result<()> {
+            ownable.ensure_owner(caller)?;
+
+            self.pausable.unpause(caller, emit_event)
+        }
+    }
+}
+
 // ============================================================================
 // Example Contract Using Access Control
 // ============================================================================
@@ -468,15 +1460,15 @@ pub mod pausable {
 #[ink::contract]
 mod example_contract {
     use super::access_control::*;
+    use super::guardian::*;
     use super::ownable::*;
-    use super::pausable::*;
     use super::*;
 
     #[ink(storage)]
     pub struct ExampleContract {
         ownable: OwnableData,
         access_control: AccessControlData,
-        pausable: PausableData,
+        guardian: GuardianData,
         value: u128,
     }
 
@@ -487,7 +1479,7 @@ mod example_contract {
             Self {
                 ownable: OwnableData::new(caller),
                 access_control: AccessControlData::new(caller),
-                pausable: PausableData::new(),
+                guardian: GuardianData::new(None),
                 value: 0,
             }
         }
@@ -513,37 +1505,53 @@ mod example_contract {
         /// Pausable function (reverts when paused)
         #[ink(message)]
         pub fn pausable_function(&mut self) -> Result<()> {
-            self.pausable.ensure_not_paused()?;
+            self.guardian.ensure_not_paused()?;
             self.value = self.value.saturating_add(1);
             Ok(())
         }
 
-        /// Pause contract (owner only)
+        /// Pause contract (guardian or owner)
         #[ink(message)]
         pub fn pause(&mut self) -> Result<()> {
             let caller = self.env().caller();
-            self.ownable.ensure_owner(caller)?;
-            self.pausable.pause(caller, |event| {
-                self.env().emit_event(event);
-            })
+            self.guardian
+                .guardian_pause(&self.ownable, caller, |event| {
+                    Self::env().emit_event(event);
+                })
         }
 
         /// Unpause contract (owner only)
         #[ink(message)]
         pub fn unpause(&mut self) -> Result<()> {
             let caller = self.env().caller();
-            self.ownable.ensure_owner(caller)?;
-            self.pausable.unpause(caller, |event| {
-                self.env().emit_event(event);
+            self.guardian.unpause(&self.ownable, caller, |event| {
+                Self::env().emit_event(event);
             })
         }
 
+        /// Set or clear the guardian (owner only)
+        #[ink(message)]
+        pub fn set_guardian(&mut self, guardian: Option<AccountId>) -> Result<()> {
+            let caller = self.env().caller();
+            self.guardian
+                .set_guardian(&self.ownable, caller, guardian, |event| {
+                    Self::env().emit_event(event);
+                })
+        }
+
+        /// Get current guardian
+        #[ink(message)]
+        pub fn guardian(&self) -> Option<AccountId> {
+            self.guardian.guardian()
+        }
+
         /// Grant role (admin only)
         #[ink(message)]
         pub fn grant_role(&mut self, role: RoleType, account: AccountId) -> Result<()> {
             let caller = self.env().caller();
+            let now = self.env().block_timestamp();
             self.access_control
-                .grant_role(caller, role, account, |event| {
+                .grant_role(caller, role, account, now, |event| {
                     self.env().emit_event(event);
                 })
         }
@@ -552,8 +1560,9 @@ mod example_contract {
         #[ink(message)]
         pub fn revoke_role(&mut self, role: RoleType, account: AccountId) -> Result<()> {
             let caller = self.env().caller();
+            let now = self.env().block_timestamp();
             self.access_control
-                .revoke_role(caller, role, account, |event| {
+                .revoke_role(caller, role, account, now, |event| {
                     self.env().emit_event(event);
                 })
         }
@@ -626,5 +1635,840 @@ mod example_contract {
             // Function works again
             assert!(contract.pausable_function().is_ok());
         }
+
+        #[ink::test]
+        fn guardian_works() {
+            let mut contract = ExampleContract::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            contract.set_guardian(Some(accounts.bob)).unwrap();
+            assert_eq!(contract.guardian(), Some(accounts.bob));
+
+            // Guardian can pause but not unpause or replace itself
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            contract.pause().unwrap();
+            assert_eq!(contract.pausable_function(), Err(AccessError::Paused));
+            assert_eq!(contract.unpause(), Err(AccessError::NotOwner));
+            assert_eq!(
+                contract.set_guardian(Some(accounts.charlie)),
+                Err(AccessError::NotOwner)
+            );
+
+            // Owner unpauses
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.unpause().unwrap();
+            assert!(contract.pausable_function().is_ok());
+        }
+    }
+}
+
+// ============================================================================
+// Library Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::access_control::default_admin_rules::*;
+    use super::access_control::*;
+    use super::guardian::*;
+    use super::ownable::*;
+    use super::timelock::*;
+    use super::AccessError;
+    use ink::env::DefaultEnvironment;
+    use ink::primitives::AccountId;
+
+    fn accounts() -> ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment> {
+        ink::env::test::default_accounts::<ink::env::DefaultEnvironment>()
+    }
+
+    fn members(data: &AccessControlData, role: RoleType) -> Vec<AccountId> {
+        data.get_role_members(role, 0, u32::MAX)
+    }
+
+    #[ink::test]
+    fn role_members_enumerate() {
+        let accounts = accounts();
+        let admin = accounts.alice;
+        let mut data = AccessControlData::new(admin);
+
+        assert_eq!(data.get_role_member_count(DEFAULT_ADMIN_ROLE), 1);
+        assert_eq!(data.get_role_member(DEFAULT_ADMIN_ROLE, 0), Some(admin));
+
+        for account in [accounts.bob, accounts.charlie, accounts.django] {
+            data.grant_role(admin, MINTER_ROLE, account, 0, |_| {})
+                .unwrap();
+        }
+        // Granting twice does not duplicate the member
+        data.grant_role(admin, MINTER_ROLE, accounts.bob, 0, |_| {})
+            .unwrap();
+
+        assert_eq!(data.get_role_member_count(MINTER_ROLE), 3);
+        assert_eq!(
+            members(&data, MINTER_ROLE),
+            vec![accounts.bob, accounts.charlie, accounts.django]
+        );
+        assert_eq!(
+            data.get_role_members(MINTER_ROLE, 1, 1),
+            vec![accounts.charlie]
+        );
+        assert_eq!(
+            data.get_role_members(MINTER_ROLE, 2, 10),
+            vec![accounts.django]
+        );
+        assert!(data.get_role_members(MINTER_ROLE, 5, 10).is_empty());
+        assert_eq!(data.get_role_member(MINTER_ROLE, 3), None);
+    }
+
+    #[ink::test]
+    fn role_members_survive_revoke_and_regrant() {
+        let accounts = accounts();
+        let admin = accounts.alice;
+        let mut data = AccessControlData::new(admin);
+
+        for account in [accounts.bob, accounts.charlie, accounts.django] {
+            data.grant_role(admin, MINTER_ROLE, account, 0, |_| {})
+                .unwrap();
+        }
+
+        // Removing the first member moves the last one into its slot
+        data.revoke_role(admin, MINTER_ROLE, accounts.bob, 0, |_| {})
+            .unwrap();
+        assert_eq!(
+            members(&data, MINTER_ROLE),
+            vec![accounts.django, accounts.charlie]
+        );
+
+        // Renouncing the last member pops it
+        data.renounce_role(accounts.charlie, MINTER_ROLE, |_| {})
+            .unwrap();
+        assert_eq!(members(&data, MINTER_ROLE), vec![accounts.django]);
+
+        // Revoking a non-member is a no-op
+        data.revoke_role(admin, MINTER_ROLE, accounts.bob, 0, |_| {})
+            .unwrap();
+        assert_eq!(data.get_role_member_count(MINTER_ROLE), 1);
+
+        data.grant_role(admin, MINTER_ROLE, accounts.bob, 0, |_| {})
+            .unwrap();
+        data.grant_role(admin, MINTER_ROLE, accounts.charlie, 0, |_| {})
+            .unwrap();
+        assert_eq!(
+            members(&data, MINTER_ROLE),
+            vec![accounts.django, accounts.bob, accounts.charlie]
+        );
+
+        data.revoke_role(admin, MINTER_ROLE, accounts.django, 0, |_| {})
+            .unwrap();
+        data.revoke_role(admin, MINTER_ROLE, accounts.charlie, 0, |_| {})
+            .unwrap();
+        data.revoke_role(admin, MINTER_ROLE, accounts.bob, 0, |_| {})
+            .unwrap();
+        assert_eq!(data.get_role_member_count(MINTER_ROLE), 0);
+        assert!(members(&data, MINTER_ROLE).is_empty());
+        assert!(!data.has_role(MINTER_ROLE, accounts.bob));
+    }
+
+    #[ink::test]
+    fn two_step_transfer_works() {
+        let accounts = accounts();
+        let mut ownable = OwnableData::new(accounts.alice);
+        let mut transferred = 0;
+
+        ownable
+            .transfer_ownership::<DefaultEnvironment>(accounts.alice, accounts.bob, |_| {})
+            .unwrap();
+        assert_eq!(ownable.pending_owner(), Some(accounts.bob));
+        assert_eq!(ownable.owner(), Some(accounts.alice));
+
+        ownable
+            .accept_ownership(accounts.bob, |_| transferred += 1)
+            .unwrap();
+        assert_eq!(ownable.owner(), Some(accounts.bob));
+        assert_eq!(ownable.pending_owner(), None);
+        assert_eq!(transferred, 1);
+    }
+
+    #[ink::test]
+    fn two_step_transfer_rejects_wrong_accounts() {
+        let accounts = accounts();
+        let mut ownable = OwnableData::new(accounts.alice);
+
+        assert_eq!(
+            ownable.transfer_ownership::<DefaultEnvironment>(accounts.bob, accounts.bob, |_| {}),
+            Err(AccessError::NotOwner)
+        );
+        assert_eq!(
+            ownable.transfer_ownership::<DefaultEnvironment>(
+                accounts.alice,
+                AccountId::from([0u8; 32]),
+                |_| {}
+            ),
+            Err(AccessError::ZeroAddress)
+        );
+
+        // Nothing pending yet
+        assert_eq!(
+            ownable.accept_ownership(accounts.bob, |_| {}),
+            Err(AccessError::NotPendingOwner)
+        );
+
+        ownable
+            .transfer_ownership::<DefaultEnvironment>(accounts.alice, accounts.bob, |_| {})
+            .unwrap();
+        assert_eq!(
+            ownable.accept_ownership(accounts.charlie, |_| {}),
+            Err(AccessError::NotPendingOwner)
+        );
+        assert_eq!(ownable.owner(), Some(accounts.alice));
+
+        // A new transfer replaces the pending one
+        ownable
+            .transfer_ownership::<DefaultEnvironment>(accounts.alice, accounts.charlie, |_| {})
+            .unwrap();
+        assert_eq!(
+            ownable.accept_ownership(accounts.bob, |_| {}),
+            Err(AccessError::NotPendingOwner)
+        );
+        assert!(ownable.accept_ownership(accounts.charlie, |_| {}).is_ok());
+    }
+
+    #[ink::test]
+    fn cancel_transfer_clears_pending_owner() {
+        let accounts = accounts();
+        let mut ownable = OwnableData::new(accounts.alice);
+
+        ownable
+            .transfer_ownership::<DefaultEnvironment>(accounts.alice, accounts.bob, |_| {})
+            .unwrap();
+        assert_eq!(
+            ownable.cancel_transfer(accounts.bob, |_| {}),
+            Err(AccessError::NotOwner)
+        );
+
+        let mut cancelled = false;
+        ownable
+            .cancel_transfer(accounts.alice, |_| cancelled = true)
+            .unwrap();
+        assert!(cancelled);
+        assert_eq!(ownable.pending_owner(), None);
+
+        // Nothing left to cancel, nothing emitted
+        ownable
+            .cancel_transfer(accounts.alice, |_| panic!("no transfer was pending"))
+            .unwrap();
+        assert_eq!(
+            ownable.accept_ownership(accounts.bob, |_| {}),
+            Err(AccessError::NotPendingOwner)
+        );
+    }
+
+    #[ink::test]
+    fn force_transfer_and_renounce() {
+        let accounts = accounts();
+        let mut ownable = OwnableData::new(accounts.alice);
+
+        ownable
+            .transfer_ownership::<DefaultEnvironment>(accounts.alice, accounts.bob, |_| {})
+            .unwrap();
+        ownable
+            .force_transfer_ownership::<DefaultEnvironment>(
+                accounts.alice,
+                accounts.charlie,
+                |_| {},
+            )
+            .unwrap();
+        assert_eq!(ownable.owner(), Some(accounts.charlie));
+        assert!(ownable.is_owner(accounts.charlie));
+
+        // The superseded pending transfer can no longer complete
+        assert_eq!(
+            ownable.accept_ownership(accounts.bob, |_| {}),
+            Err(AccessError::NotPendingOwner)
+        );
+
+        ownable
+            .transfer_ownership::<DefaultEnvironment>(accounts.charlie, accounts.bob, |_| {})
+            .unwrap();
+        ownable
+            .renounce_ownership::<DefaultEnvironment>(accounts.charlie, |_| {})
+            .unwrap();
+        assert_eq!(ownable.owner(), None);
+        assert_eq!(
+            ownable.accept_ownership(accounts.bob, |_| {}),
+            Err(AccessError::NotPendingOwner)
+        );
+    }
+
+    const OP: OperationId = [1u8; 32];
+
+    /// Timelock with a 100-unit minimum delay; alice proposes, bob executes, charlie cancels
+    fn timelock_setup() -> (AccessControlData, TimelockData) {
+        let accounts = accounts();
+        let mut roles = AccessControlData::new(accounts.alice);
+        for (role, account) in [
+            (PROPOSER_ROLE, accounts.alice),
+            (EXECUTOR_ROLE, accounts.bob),
+            (CANCELLER_ROLE, accounts.charlie),
+        ] {
+            roles
+                .grant_role(accounts.alice, role, account, 0, |_| {})
+                .unwrap();
+        }
+
+        (roles, TimelockData::new(100))
+    }
+
+    fn schedule(
+        timelock: &mut TimelockData,
+        roles: &AccessControlData,
+        caller: AccountId,
+        delay: u64,
+        now: u64,
+    ) -> super::Result<()> {
+        timelock.schedule(
+            roles,
+            caller,
+            OP,
+            accounts().django,
+            [0xde, 0xad, 0xbe, 0xef],
+            [2u8; 32],
+            delay,
+            now,
+            |_| {},
+        )
+    }
+
+    #[ink::test]
+    fn timelock_schedule_rules() {
+        let accounts = accounts();
+        let (roles, mut timelock) = timelock_setup();
+
+        assert_eq!(
+            schedule(&mut timelock, &roles, accounts.bob, 100, 0),
+            Err(AccessError::MissingRole)
+        );
+        assert_eq!(
+            schedule(&mut timelock, &roles, accounts.alice, 99, 0),
+            Err(AccessError::DelayTooShort)
+        );
+        assert!(!timelock.is_operation_pending(OP));
+
+        schedule(&mut timelock, &roles, accounts.alice, 150, 10).unwrap();
+        assert!(timelock.is_operation_pending(OP));
+        assert_eq!(timelock.get_operation(OP).unwrap().ready_at, 160);
+        assert_eq!(
+            schedule(&mut timelock, &roles, accounts.alice, 150, 10),
+            Err(AccessError::OperationExists)
+        );
+    }
+
+    #[ink::test]
+    fn timelock_execute_after_delay() {
+        let accounts = accounts();
+        let (roles, mut timelock) = timelock_setup();
+        schedule(&mut timelock, &roles, accounts.alice, 100, 0).unwrap();
+
+        assert!(!timelock.is_operation_ready(OP, 99));
+        assert_eq!(
+            timelock.execute_ready(&roles, accounts.bob, OP, 99, |_| {}),
+            Err(AccessError::OperationNotReady)
+        );
+        assert_eq!(
+            timelock.execute_ready(&roles, accounts.alice, OP, 100, |_| {}),
+            Err(AccessError::MissingRole)
+        );
+
+        assert!(timelock.is_operation_ready(OP, 100));
+        let op = timelock
+            .execute_ready(&roles, accounts.bob, OP, 100, |_| {})
+            .unwrap();
+        assert_eq!(op.target, accounts.django);
+        assert_eq!(op.selector, [0xde, 0xad, 0xbe, 0xef]);
+        assert!(timelock.is_operation_done(OP));
+        assert!(!timelock.is_operation_pending(OP));
+
+        // No double execution, no rescheduling of a done id
+        assert_eq!(
+            timelock.execute_ready(&roles, accounts.bob, OP, 200, |_| {}),
+            Err(AccessError::UnknownOperation)
+        );
+        assert_eq!(
+            schedule(&mut timelock, &roles, accounts.alice, 100, 200),
+            Err(AccessError::OperationExists)
+        );
+    }
+
+    #[ink::test]
+    fn timelock_cancel_clears_state() {
+        let accounts = accounts();
+        let (roles, mut timelock) = timelock_setup();
+        schedule(&mut timelock, &roles, accounts.alice, 100, 0).unwrap();
+
+        assert_eq!(
+            timelock.cancel(&roles, accounts.alice, OP, 0, |_| {}),
+            Err(AccessError::MissingRole)
+        );
+        timelock
+            .cancel(&roles, accounts.charlie, OP, 0, |_| {})
+            .unwrap();
+
+        assert!(!timelock.is_operation_pending(OP));
+        assert!(!timelock.is_operation_done(OP));
+        assert_eq!(timelock.get_operation(OP), None);
+        assert_eq!(
+            timelock.execute_ready(&roles, accounts.bob, OP, 100, |_| {}),
+            Err(AccessError::UnknownOperation)
+        );
+        assert_eq!(
+            timelock.cancel(&roles, accounts.charlie, OP, 0, |_| {}),
+            Err(AccessError::UnknownOperation)
+        );
+
+        // The id can be scheduled again after cancellation
+        assert!(schedule(&mut timelock, &roles, accounts.alice, 100, 0).is_ok());
+    }
+
+    #[ink::test]
+    fn timelock_rejects_expired_roles() {
+        let accounts = accounts();
+        let mut roles = AccessControlData::new(accounts.alice);
+        for (role, account) in [
+            (PROPOSER_ROLE, accounts.bob),
+            (EXECUTOR_ROLE, accounts.charlie),
+            (CANCELLER_ROLE, accounts.eve),
+        ] {
+            roles
+                .grant_role_with_expiry(accounts.alice, role, account, 100, 0, |_| {})
+                .unwrap();
+        }
+        let mut timelock = TimelockData::new(10);
+
+        assert_eq!(
+            schedule(&mut timelock, &roles, accounts.bob, 10, 100),
+            Err(AccessError::MissingRole)
+        );
+        schedule(&mut timelock, &roles, accounts.bob, 10, 50).unwrap();
+
+        assert_eq!(
+            timelock.execute_ready(&roles, accounts.charlie, OP, 100, |_| {}),
+            Err(AccessError::MissingRole)
+        );
+        assert_eq!(
+            timelock.cancel(&roles, accounts.eve, OP, 100, |_| {}),
+            Err(AccessError::MissingRole)
+        );
+        assert!(timelock.is_operation_pending(OP));
+
+        // Before expiry the same grants still work
+        timelock
+            .cancel(&roles, accounts.eve, OP, 99, |_| {})
+            .unwrap();
+    }
+
+    #[ink::test]
+    fn expiring_role_lapses() {
+        let accounts = accounts();
+        let admin = accounts.alice;
+        let mut data = AccessControlData::new(admin);
+
+        assert_eq!(
+            data.grant_role_with_expiry(admin, PAUSER_ROLE, accounts.bob, 100, 100, |_| {}),
+            Err(AccessError::InvalidExpiry)
+        );
+        data.grant_role_with_expiry(admin, PAUSER_ROLE, accounts.bob, 100, 0, |_| {})
+            .unwrap();
+        assert_eq!(data.role_expiry(PAUSER_ROLE, accounts.bob), Some(100));
+
+        // Valid before expiry
+        assert!(data.has_role_at(PAUSER_ROLE, accounts.bob, 99));
+        assert!(data.ensure_role_at(accounts.bob, PAUSER_ROLE, 99).is_ok());
+
+        // Lapsed at and after expiry
+        assert!(!data.has_role_at(PAUSER_ROLE, accounts.bob, 100));
+        assert_eq!(
+            data.ensure_role_at(accounts.bob, PAUSER_ROLE, 150),
+            Err(AccessError::MissingRole)
+        );
+
+        // Permanent grants never lapse
+        assert!(data.has_role_at(DEFAULT_ADMIN_ROLE, admin, u64::MAX));
+    }
+
+    #[ink::test]
+    fn expiring_role_regrant_and_extend() {
+        let accounts = accounts();
+        let admin = accounts.alice;
+        let mut data = AccessControlData::new(admin);
+
+        data.grant_role_with_expiry(admin, PAUSER_ROLE, accounts.bob, 100, 0, |_| {})
+            .unwrap();
+
+        // Re-grant after expiry
+        data.grant_role_with_expiry(admin, PAUSER_ROLE, accounts.bob, 300, 200, |_| {})
+            .unwrap();
+        assert!(data.has_role_at(PAUSER_ROLE, accounts.bob, 250));
+        assert_eq!(data.get_role_member_count(PAUSER_ROLE), 1);
+
+        // Extend only forward, only by an admin
+        assert_eq!(
+            data.extend_role(admin, PAUSER_ROLE, accounts.bob, 300, 0),
+            Err(AccessError::InvalidExpiry)
+        );
+        assert_eq!(
+            data.extend_role(accounts.bob, PAUSER_ROLE, accounts.bob, 400, 0),
+            Err(AccessError::MissingRole)
+        );
+        data.extend_role(admin, PAUSER_ROLE, accounts.bob, 400, 0)
+            .unwrap();
+        assert!(data.has_role_at(PAUSER_ROLE, accounts.bob, 350));
+
+        // Revoking clears the expiry record
+        data.revoke_role(admin, PAUSER_ROLE, accounts.bob, 0, |_| {})
+            .unwrap();
+        assert_eq!(data.role_expiry(PAUSER_ROLE, accounts.bob), None);
+        assert_eq!(
+            data.extend_role(admin, PAUSER_ROLE, accounts.bob, 500, 0),
+            Err(AccessError::MissingRole)
+        );
+
+        // A plain grant keeps an unexpired expiry
+        data.grant_role_with_expiry(admin, PAUSER_ROLE, accounts.bob, 600, 500, |_| {})
+            .unwrap();
+        data.grant_role(admin, PAUSER_ROLE, accounts.bob, 550, |_| {})
+            .unwrap();
+        assert_eq!(data.role_expiry(PAUSER_ROLE, accounts.bob), Some(600));
+
+        // ...and renews a lapsed one as permanent
+        data.grant_role(admin, PAUSER_ROLE, accounts.bob, 700, |_| {})
+            .unwrap();
+        assert_eq!(data.role_expiry(PAUSER_ROLE, accounts.bob), None);
+        assert!(data.has_role_at(PAUSER_ROLE, accounts.bob, 1_000));
+    }
+
+    #[ink::test]
+    fn expired_admin_cannot_manage_roles() {
+        let accounts = accounts();
+        let admin = accounts.alice;
+        let mut data = AccessControlData::new(admin);
+        data.grant_role_with_expiry(admin, DEFAULT_ADMIN_ROLE, accounts.bob, 100, 0, |_| {})
+            .unwrap();
+        data.grant_role(admin, MINTER_ROLE, accounts.charlie, 0, |_| {})
+            .unwrap();
+
+        // Bob administers roles until his grant lapses
+        data.grant_role(accounts.bob, MINTER_ROLE, accounts.django, 99, |_| {})
+            .unwrap();
+
+        let bob = accounts.bob;
+        assert_eq!(
+            data.grant_role(bob, MINTER_ROLE, accounts.eve, 100, |_| {}),
+            Err(AccessError::MissingRole)
+        );
+        assert_eq!(
+            data.revoke_role(bob, MINTER_ROLE, accounts.charlie, 100, |_| {}),
+            Err(AccessError::MissingRole)
+        );
+        assert_eq!(
+            data.grant_roles(bob, vec![(MINTER_ROLE, accounts.eve)], 100, |_| {}),
+            Err(AccessError::MissingRole)
+        );
+        assert_eq!(
+            data.set_role_admin(bob, MINTER_ROLE, PAUSER_ROLE, 100, |_| {}),
+            Err(AccessError::MissingRole)
+        );
+
+        // Nor can he make his own grant permanent
+        assert_eq!(
+            data.grant_role(bob, DEFAULT_ADMIN_ROLE, bob, 150, |_| {}),
+            Err(AccessError::MissingRole)
+        );
+        assert_eq!(
+            data.grant_role_with_expiry(bob, DEFAULT_ADMIN_ROLE, bob, 1_000, 150, |_| {}),
+            Err(AccessError::MissingRole)
+        );
+        assert_eq!(data.role_expiry(DEFAULT_ADMIN_ROLE, bob), Some(100));
+        assert!(data.has_role(MINTER_ROLE, accounts.charlie));
+    }
+
+    #[ink::test]
+    fn grant_roles_is_all_or_nothing() {
+        let accounts = accounts();
+        let admin = accounts.alice;
+        let mut data = AccessControlData::new(admin);
+        let mut granted = 0;
+
+        // Bob administers MINTER only
+        data.grant_role(admin, DEFAULT_ADMIN_ROLE, accounts.django, 0, |_| {})
+            .unwrap();
+        data.set_role_admin(admin, MINTER_ROLE, PAUSER_ROLE, 0, |_| {})
+            .unwrap();
+        data.grant_role(admin, PAUSER_ROLE, accounts.bob, 0, |_| {})
+            .unwrap();
+
+        let mixed = vec![
+            (MINTER_ROLE, accounts.charlie),
+            (BURNER_ROLE, accounts.charlie),
+        ];
+        assert_eq!(
+            data.grant_roles(accounts.bob, mixed.clone(), 0, |_| granted += 1),
+            Err(AccessError::MissingRole)
+        );
+        assert!(!data.has_role(MINTER_ROLE, accounts.charlie));
+        assert!(!data.has_role(BURNER_ROLE, accounts.charlie));
+
+        data.grant_roles(admin, vec![(BURNER_ROLE, accounts.charlie)], 0, |_| {
+            granted += 1
+        })
+        .unwrap();
+        assert_eq!(
+            data.revoke_roles(accounts.bob, mixed, 0, |_| {}),
+            Err(AccessError::MissingRole)
+        );
+        assert!(data.has_role(BURNER_ROLE, accounts.charlie));
+
+        data.grant_roles(
+            accounts.bob,
+            vec![(MINTER_ROLE, accounts.charlie), (MINTER_ROLE, accounts.eve)],
+            0,
+            |_| granted += 1,
+        )
+        .unwrap();
+        assert_eq!(granted, 3);
+        assert_eq!(data.get_role_member_count(MINTER_ROLE), 2);
+
+        let mut revoked = 0;
+        data.revoke_roles(
+            admin,
+            vec![
+                (BURNER_ROLE, accounts.charlie),
+                (DEFAULT_ADMIN_ROLE, admin),
+                (DEFAULT_ADMIN_ROLE, accounts.django),
+            ],
+            0,
+            |_| revoked += 1,
+        )
+        .unwrap();
+        assert_eq!(revoked, 3);
+        assert_eq!(data.get_role_member_count(DEFAULT_ADMIN_ROLE), 0);
+    }
+
+    #[ink::test]
+    fn replace_role_holder_rotates_key() {
+        let accounts = accounts();
+        let admin = accounts.alice;
+        let mut data = AccessControlData::new(admin);
+        data.grant_role_with_expiry(admin, PAUSER_ROLE, accounts.bob, 100, 0, |_| {})
+            .unwrap();
+
+        assert_eq!(
+            data.replace_role_holder(
+                accounts.bob,
+                PAUSER_ROLE,
+                accounts.bob,
+                accounts.charlie,
+                0,
+                |_| {},
+                |_| {}
+            ),
+            Err(AccessError::MissingRole)
+        );
+        assert_eq!(
+            data.replace_role_holder(
+                admin,
+                PAUSER_ROLE,
+                accounts.django,
+                accounts.charlie,
+                0,
+                |_| {},
+                |_| {}
+            ),
+            Err(AccessError::MissingRole)
+        );
+
+        data.replace_role_holder(
+            admin,
+            PAUSER_ROLE,
+            accounts.bob,
+            accounts.charlie,
+            0,
+            |_| {},
+            |_| {},
+        )
+        .unwrap();
+        assert!(!data.has_role(PAUSER_ROLE, accounts.bob));
+        assert!(data.has_role(PAUSER_ROLE, accounts.charlie));
+        assert_eq!(data.role_expiry(PAUSER_ROLE, accounts.charlie), Some(100));
+        assert_eq!(
+            data.get_role_members(PAUSER_ROLE, 0, 10),
+            vec![accounts.charlie]
+        );
+    }
+
+    #[ink::test]
+    fn guardian_pauses_but_cannot_unpause() {
+        let accounts = accounts();
+        let ownable = OwnableData::new(accounts.alice);
+        let mut guardian = GuardianData::new(Some(accounts.bob));
+
+        assert_eq!(
+            guardian.guardian_pause(&ownable, accounts.charlie, |_| {}),
+            Err(AccessError::NotGuardian)
+        );
+
+        guardian
+            .guardian_pause(&ownable, accounts.bob, |_| {})
+            .unwrap();
+        assert!(guardian.is_paused());
+        assert_eq!(guardian.ensure_not_paused(), Err(AccessError::Paused));
+
+        assert_eq!(
+            guardian.unpause(&ownable, accounts.bob, |_| {}),
+            Err(AccessError::NotOwner)
+        );
+        guardian.unpause(&ownable, accounts.alice, |_| {}).unwrap();
+        assert!(!guardian.is_paused());
+
+        // The owner can pause without being the guardian
+        guardian
+            .guardian_pause(&ownable, accounts.alice, |_| {})
+            .unwrap();
+        assert!(guardian.is_paused());
+    }
+
+    #[ink::test]
+    fn guardian_cannot_change_guardian() {
+        let accounts = accounts();
+        let ownable = OwnableData::new(accounts.alice);
+        let mut guardian = GuardianData::new(Some(accounts.bob));
+
+        assert_eq!(
+            guardian.set_guardian(&ownable, accounts.bob, Some(accounts.charlie), |_| {}),
+            Err(AccessError::NotOwner)
+        );
+        assert!(guardian.is_guardian(accounts.bob));
+
+        guardian
+            .set_guardian(&ownable, accounts.alice, Some(accounts.charlie), |_| {})
+            .unwrap();
+        assert!(!guardian.is_guardian(accounts.bob));
+        assert_eq!(
+            guardian.guardian_pause(&ownable, accounts.bob, |_| {}),
+            Err(AccessError::NotGuardian)
+        );
+
+        guardian
+            .set_guardian(&ownable, accounts.alice, None, |_| {})
+            .unwrap();
+        assert_eq!(guardian.guardian(), None);
+    }
+
+    #[ink::test]
+    fn default_admin_transfer_respects_delay() {
+        let accounts = accounts();
+        let mut data = AccessControlData::with_default_admin_rules(accounts.alice, 100);
+        assert_eq!(data.default_admin(), Some(accounts.alice));
+        assert_eq!(data.default_admin_delay(), Some(100));
+
+        assert_eq!(
+            data.begin_default_admin_transfer(accounts.bob, accounts.bob, 0, |_| {}),
+            Err(AccessError::MissingRole)
+        );
+        data.begin_default_admin_transfer(accounts.alice, accounts.bob, 10, |_| {})
+            .unwrap();
+        assert_eq!(data.pending_default_admin(), Some((accounts.bob, 110)));
+
+        assert_eq!(
+            data.accept_default_admin_transfer(accounts.bob, 109, |_| {}, |_| {}),
+            Err(AccessError::AdminTransferNotReady)
+        );
+        assert_eq!(
+            data.accept_default_admin_transfer(accounts.charlie, 110, |_| {}, |_| {}),
+            Err(AccessError::NotPendingAdmin)
+        );
+
+        data.accept_default_admin_transfer(accounts.bob, 110, |_| {}, |_| {})
+            .unwrap();
+        assert_eq!(data.default_admin(), Some(accounts.bob));
+        assert!(!data.has_role(DEFAULT_ADMIN_ROLE, accounts.alice));
+        assert_eq!(data.get_role_member_count(DEFAULT_ADMIN_ROLE), 1);
+        assert_eq!(data.pending_default_admin(), None);
+    }
+
+    #[ink::test]
+    fn default_admin_transfer_cancel() {
+        let accounts = accounts();
+        let mut data = AccessControlData::with_default_admin_rules(accounts.alice, 100);
+
+        data.begin_default_admin_transfer(accounts.alice, accounts.bob, 0, |_| {})
+            .unwrap();
+        assert_eq!(
+            data.cancel_default_admin_transfer(accounts.bob, 0, |_| {}),
+            Err(AccessError::MissingRole)
+        );
+        data.cancel_default_admin_transfer(accounts.alice, 0, |_| {})
+            .unwrap();
+
+        assert_eq!(data.pending_default_admin(), None);
+        assert_eq!(
+            data.accept_default_admin_transfer(accounts.bob, 1_000, |_| {}, |_| {}),
+            Err(AccessError::NotPendingAdmin)
+        );
+        assert_eq!(data.default_admin(), Some(accounts.alice));
+    }
+
+    #[ink::test]
+    fn default_admin_single_holder_invariant() {
+        let accounts = accounts();
+        let admin = accounts.alice;
+        let mut data = AccessControlData::with_default_admin_rules(admin, 100);
+
+        assert_eq!(
+            data.grant_role(admin, DEFAULT_ADMIN_ROLE, accounts.bob, 0, |_| {}),
+            Err(AccessError::AdminRulesEnforced)
+        );
+        assert_eq!(
+            data.grant_roles(admin, vec![(DEFAULT_ADMIN_ROLE, accounts.bob)], 0, |_| {}),
+            Err(AccessError::AdminRulesEnforced)
+        );
+        assert_eq!(
+            data.revoke_role(admin, DEFAULT_ADMIN_ROLE, admin, 0, |_| {}),
+            Err(AccessError::AdminRulesEnforced)
+        );
+        assert_eq!(
+            data.renounce_role(admin, DEFAULT_ADMIN_ROLE, |_| {}),
+            Err(AccessError::AdminRulesEnforced)
+        );
+        assert_eq!(
+            data.set_role_admin(admin, DEFAULT_ADMIN_ROLE, MINTER_ROLE, 0, |_| {}),
+            Err(AccessError::AdminRulesEnforced)
+        );
+        assert_eq!(data.get_role_member_count(DEFAULT_ADMIN_ROLE), 1);
+
+        // Other roles are unaffected
+        assert!(data
+            .grant_role(admin, MINTER_ROLE, accounts.bob, 0, |_| {})
+            .is_ok());
+
+        // Renounce needs a scheduled transfer to the zero address
+        assert_eq!(
+            data.renounce_default_admin(admin, 1_000, |_| {}),
+            Err(AccessError::NotPendingAdmin)
+        );
+        data.begin_default_admin_transfer(admin, AccountId::from([0u8; 32]), 0, |_| {})
+            .unwrap();
+        assert_eq!(
+            data.renounce_default_admin(admin, 50, |_| {}),
+            Err(AccessError::AdminTransferNotReady)
+        );
+        data.renounce_default_admin(admin, 100, |_| {}).unwrap();
+        assert_eq!(data.default_admin(), None);
+        assert_eq!(data.get_role_member_count(DEFAULT_ADMIN_ROLE), 0);
+
+        // Without rules the classic API is untouched
+        let mut classic = AccessControlData::new(admin);
+        assert!(!classic.has_default_admin_rules());
+        assert!(classic
+            .grant_role(admin, DEFAULT_ADMIN_ROLE, accounts.bob, 0, |_| {})
+            .is_ok());
     }
 }
@@ -7,10 +7,24 @@
 ///
 /// ## Features
 /// - PSP34 standard compliance (mint, transfer, burn)
-/// - Metadata support (token URI)
+/// - Metadata support (token URI), with per-token or collection-wide freezing
 /// - Collection management
 /// - Enumeration support
 /// - Approval system
+/// - Payable public sale with supply and per-wallet limits
+/// - Creator royalties (default and per-token)
+/// - Batch minting
+/// - Base URI with delayed reveal
+/// - Pausable transfers and per-token locks (e.g. land titles under dispute)
+/// - Role-based access (admin, minter, registrar)
+/// - On-chain key/value attributes per token
+/// - Soulbound (non-transferable) tokens for identity documents
+/// - Lazy minting through ECDSA-signed vouchers
+/// - Optional hard cap on the number of tokens ever minted
+/// - Two-party transfer handshake (offer/accept) for land titles
+/// - Separate burn approvals for operators
+/// - Safe transfers that notify contract recipients through `PSP37Receiver`
+/// - Standard `PSP34::` messages (with `Id::U32` ids) for wallets and marketplaces
 ///
 /// ## Use Cases
 /// - Digital art collections
@@ -19,14 +33,42 @@
 /// - Gaming assets
 /// - Membership tokens
 
+#[path = "../psp37_multi_token/psp37_trait.rs"]
+pub mod psp37_trait;
+
+pub mod psp34_trait;
+
 #[ink::contract]
-mod beli_nft {
-    use ink::prelude::string::String;
+pub mod beli_nft {
+    use crate::psp34_trait::{self, Id, PSP34Error, PSP34};
+    use crate::psp37_trait::PSP37Receiver;
+    use access_control::access_control::{AccessControlData, DEFAULT_ADMIN_ROLE, MINTER_ROLE};
+    use access_control::pausable::PausableData;
+    use access_control::{AccessError, RoleType};
+    use ink::codegen::TraitCallBuilder;
+    use ink::env::hash::Blake2x256;
+    use ink::prelude::string::{String, ToString};
+    use ink::prelude::{vec, vec::Vec};
     use ink::storage::Mapping;
 
     /// Token ID type
     pub type TokenId = u32;
 
+    /// Basis points denominator (10000 = 100%)
+    const BPS_DENOMINATOR: u16 = 10_000;
+
+    /// Maximum royalty a creator can charge (1000 = 10%)
+    pub const MAX_ROYALTY_BPS: u16 = 1_000;
+
+    /// Maximum tokens minted in a single batch call (keeps weight bounded)
+    pub const MAX_BATCH_SIZE: u32 = 50;
+
+    /// Role allowed to lock and unlock tokens (e.g. the land registrar)
+    pub const REGISTRAR_ROLE: RoleType = 5;
+
+    /// Maximum number of attribute keys stored per token
+    pub const MAX_ATTRIBUTES: u32 = 32;
+
     /// The BeliNFT error types
     #[derive(Debug, PartialEq, Eq)]
     #[ink::scale_derive(Encode, Decode, TypeInfo)]
@@ -39,15 +81,181 @@ mod beli_nft {
         InvalidRecipient,
         /// Token already exists (double mint)
         TokenExists,
-        /// Minting only allowed by contract owner
+        /// Caller is not the contract admin
         NotOwner,
         /// Approval to current owner
         SelfApproval,
+        /// Public sale is not active
+        SaleNotActive,
+        /// Transferred value does not cover the mint price
+        InsufficientPayment,
+        /// Public sale supply exhausted
+        SoldOut,
+        /// Caller reached the per-wallet mint limit
+        WalletLimitReached,
+        /// Native transfer (refund or withdrawal) failed
+        TransferFailed,
+        /// Royalty exceeds MAX_ROYALTY_BPS
+        RoyaltyTooHigh,
+        /// Batch is empty or exceeds MAX_BATCH_SIZE
+        InvalidBatchSize,
+        /// Input vectors have different lengths
+        LengthMismatch,
+        /// Transfers and burns are paused
+        Paused,
+        /// Collection is not paused
+        NotPaused,
+        /// Token is locked by the registrar
+        TokenLocked,
+        /// Caller lacks the role required for this operation
+        MissingRole,
+        /// The contract owner cannot give up the admin role
+        AdminLockout,
+        /// Token already holds MAX_ATTRIBUTES keys
+        TooManyAttributes,
+        /// Token is soulbound and cannot be transferred or approved
+        Soulbound,
+        /// Voucher signature is malformed or not from a minter
+        InvalidSignature,
+        /// Voucher nonce has already been redeemed
+        VoucherUsed,
+        /// Voucher deadline has passed
+        VoucherExpired,
+        /// Minting would exceed the collection's max supply
+        MaxSupplyReached,
+        /// Max supply can only be lowered, and not below the minted count
+        InvalidMaxSupply,
+        /// Token has a pending transfer offer
+        TransferPending,
+        /// Token has no pending transfer offer
+        NoPendingTransfer,
+        /// Receiving contract rejected a safe transfer
+        TransferRejected,
+        /// Token URI is frozen and can no longer change
+        MetadataFrozen,
+    }
+
+    impl From<AccessError> for Error {
+        fn from(error: AccessError) -> Self {
+            match error {
+                AccessError::NotOwner => Error::NotOwner,
+                AccessError::MissingRole => Error::MissingRole,
+                AccessError::ZeroAddress => Error::InvalidRecipient,
+                AccessError::Paused => Error::Paused,
+                AccessError::NotPaused => Error::NotPaused,
+                AccessError::NotPendingOwner
+                | AccessError::DelayTooShort
+                | AccessError::OperationExists
+                | AccessError::UnknownOperation
+                | AccessError::OperationNotReady
+                | AccessError::InvalidExpiry
+                | AccessError::NotGuardian
+                | AccessError::AdminRulesEnforced
+                | AccessError::NotPendingAdmin
+                | AccessError::AdminTransferNotReady => Error::NotAuthorized,
+            }
+        }
+    }
+
+    impl From<Error> for PSP34Error {
+        fn from(error: Error) -> Self {
+            match error {
+                Error::TokenNotFound => PSP34Error::TokenNotExists,
+                Error::NotAuthorized => PSP34Error::NotApproved,
+                Error::InvalidRecipient => PSP34Error::Custom(String::from("InvalidRecipient")),
+                Error::TokenExists => PSP34Error::TokenExists,
+                Error::NotOwner => PSP34Error::Custom(String::from("NotOwner")),
+                Error::SelfApproval => PSP34Error::SelfApprove,
+                Error::SaleNotActive => PSP34Error::Custom(String::from("SaleNotActive")),
+                Error::InsufficientPayment => {
+                    PSP34Error::Custom(String::from("InsufficientPayment"))
+                }
+                Error::SoldOut => PSP34Error::Custom(String::from("SoldOut")),
+                Error::WalletLimitReached => PSP34Error::Custom(String::from("WalletLimitReached")),
+                Error::TransferFailed => PSP34Error::Custom(String::from("TransferFailed")),
+                Error::RoyaltyTooHigh => PSP34Error::Custom(String::from("RoyaltyTooHigh")),
+                Error::InvalidBatchSize => PSP34Error::Custom(String::from("InvalidBatchSize")),
+                Error::LengthMismatch => PSP34Error::Custom(String::from("LengthMismatch")),
+                Error::Paused => PSP34Error::Custom(String::from("Paused")),
+                Error::NotPaused => PSP34Error::Custom(String::from("NotPaused")),
+                Error::TokenLocked => PSP34Error::Custom(String::from("TokenLocked")),
+                Error::MissingRole => PSP34Error::Custom(String::from("MissingRole")),
+                Error::AdminLockout => PSP34Error::Custom(String::from("AdminLockout")),
+                Error::TooManyAttributes => PSP34Error::Custom(String::from("TooManyAttributes")),
+                Error::Soulbound => PSP34Error::Custom(String::from("Soulbound")),
+                Error::InvalidSignature => PSP34Error::Custom(String::from("InvalidSignature")),
+                Error::VoucherUsed => PSP34Error::Custom(String::from("VoucherUsed")),
+                Error::VoucherExpired => PSP34Error::Custom(String::from("VoucherExpired")),
+                Error::MaxSupplyReached => PSP34Error::Custom(String::from("MaxSupplyReached")),
+                Error::InvalidMaxSupply => PSP34Error::Custom(String::from("InvalidMaxSupply")),
+                Error::TransferPending => PSP34Error::Custom(String::from("TransferPending")),
+                Error::NoPendingTransfer => PSP34Error::Custom(String::from("NoPendingTransfer")),
+                Error::TransferRejected => {
+                    PSP34Error::SafeTransferCheckFailed(String::from("TransferRejected"))
+                }
+                Error::MetadataFrozen => PSP34Error::Custom(String::from("MetadataFrozen")),
+            }
+        }
     }
 
     /// Result type for BeliNFT operations
     pub type Result<T> = core::result::Result<T, Error>;
 
+    /// Public sale parameters
+    #[derive(Debug, Default, PartialEq, Eq, Clone)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct SaleConfig {
+        /// Price per token in native balance
+        pub price: Balance,
+        /// Maximum number of tokens that can exist once minted through the sale
+        pub max_supply: u32,
+        /// Maximum number of tokens a single account can public-mint
+        pub max_per_wallet: u32,
+        /// Whether `public_mint` is open
+        pub active: bool,
+    }
+
+    /// Royalty receiver and rate
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Royalty {
+        /// Account receiving royalty payments
+        pub receiver: AccountId,
+        /// Royalty rate in basis points
+        pub bps: u16,
+    }
+
+    /// A token count recorded at the end of a block
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Checkpoint {
+        /// Block the count was written in
+        pub block: u32,
+        /// Tokens held as of that block
+        pub value: u32,
+    }
+
+    /// Off-chain mint authorization signed by a minter
+    ///
+    /// The signature covers `blake2_256(SCALE((contract, voucher)))`, see `voucher_hash`.
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub struct MintVoucher {
+        /// Recipient of the minted token
+        pub to: AccountId,
+        /// Metadata URI of the minted token
+        pub uri: String,
+        /// Price the redeemer must pay
+        pub price: Balance,
+        /// Unique voucher number; each nonce can be redeemed once
+        pub nonce: u64,
+        /// Last block timestamp (ms) at which the voucher is valid
+        pub deadline: Timestamp,
+    }
+
     /// The BeliNFT storage
     #[ink(storage)]
     pub struct BeliNft {
@@ -65,12 +273,60 @@ mod beli_nft {
         total_supply: u32,
         /// Next token ID to mint
         next_token_id: TokenId,
-        /// Contract owner (can mint)
+        /// Primary admin; always holds DEFAULT_ADMIN_ROLE
         owner: AccountId,
         /// Collection name
         name: String,
         /// Collection symbol
         symbol: String,
+        /// Public sale configuration
+        sale: SaleConfig,
+        /// Mapping from account to tokens minted through the public sale
+        public_minted: Mapping<AccountId, u32>,
+        /// Sale proceeds claimable by the contract owner
+        proceeds: Balance,
+        /// Collection-wide royalty (applies when no per-token entry exists)
+        default_royalty: Option<Royalty>,
+        /// Mapping from token ID to royalty override
+        token_royalties: Mapping<TokenId, Royalty>,
+        /// Base URI; token URIs resolve to `base_uri + id` once revealed
+        base_uri: Option<String>,
+        /// Placeholder URI returned for every token before reveal
+        unrevealed_uri: Option<String>,
+        /// Whether the collection has been revealed
+        revealed: bool,
+        /// Global pause of transfers and burns
+        pausable: PausableData,
+        /// Tokens frozen by the registrar
+        locked_tokens: Mapping<TokenId, ()>,
+        /// Role assignments (admin, minter, registrar)
+        access_control: AccessControlData,
+        /// Mapping from (token ID, key) to attribute value
+        attributes: Mapping<(TokenId, String), String>,
+        /// Mapping from token ID to its attribute keys (for enumeration)
+        attribute_keys: Mapping<TokenId, Vec<String>>,
+        /// Non-transferable tokens
+        soulbound: Mapping<TokenId, ()>,
+        /// Redeemed voucher nonces
+        used_vouchers: Mapping<u64, ()>,
+        /// Mapping from voucher signer to withdrawable proceeds
+        voucher_proceeds: Mapping<AccountId, Balance>,
+        /// Hard cap on tokens ever minted (burns do not free capacity)
+        max_supply: Option<u32>,
+        /// Mapping from token ID to the recipient of a pending transfer offer
+        pending_transfers: Mapping<TokenId, AccountId>,
+        /// Mapping from (owner, operator) to burn approval
+        burn_approvals: Mapping<(AccountId, AccountId), ()>,
+        /// Whether transfer approvals also authorize burning (pre-burn-approval behavior)
+        legacy_burn: bool,
+        /// Tokens whose URI can no longer change
+        frozen_uris: Mapping<TokenId, ()>,
+        /// Whether every token URI (and the base/placeholder URIs) is frozen
+        all_uris_frozen: bool,
+        /// Token count history: (account, index) => checkpoint
+        checkpoints: Mapping<(AccountId, u32), Checkpoint>,
+        /// Number of token count checkpoints per account
+        checkpoint_counts: Mapping<AccountId, u32>,
     }
 
     /// Event emitted when a token is transferred
@@ -105,11 +361,195 @@ mod beli_nft {
         approved: bool,
     }
 
+    /// Event emitted when a token is bought through the public sale
+    #[ink(event)]
+    pub struct PublicMint {
+        #[ink(topic)]
+        minter: AccountId,
+        #[ink(topic)]
+        id: TokenId,
+        price: Balance,
+    }
+
+    /// Event emitted when the sale parameters change
+    #[ink(event)]
+    pub struct SaleUpdated {
+        price: Balance,
+        max_supply: u32,
+        max_per_wallet: u32,
+        active: bool,
+    }
+
+    /// Event emitted when the owner withdraws sale proceeds
+    #[ink(event)]
+    pub struct ProceedsWithdrawn {
+        #[ink(topic)]
+        to: AccountId,
+        amount: Balance,
+    }
+
+    /// Event emitted when a royalty is set or cleared
+    ///
+    /// `id` is `None` for the collection default; `receiver` is `None` when cleared.
+    #[ink(event)]
+    pub struct RoyaltyChanged {
+        #[ink(topic)]
+        id: Option<TokenId>,
+        #[ink(topic)]
+        receiver: Option<AccountId>,
+        bps: u16,
+    }
+
+    /// Event emitted when the base URI changes
+    #[ink(event)]
+    pub struct BaseUriSet {
+        base_uri: String,
+    }
+
+    /// Event emitted when the collection metadata is revealed
+    #[ink(event)]
+    pub struct Revealed {
+        base_uri: Option<String>,
+    }
+
+    /// Event emitted when a token's URI is set or cleared (`None`)
+    #[ink(event)]
+    pub struct TokenUriUpdated {
+        #[ink(topic)]
+        id: TokenId,
+        old_uri: Option<String>,
+        new_uri: Option<String>,
+    }
+
+    /// Event emitted when a token's URI is frozen
+    #[ink(event)]
+    pub struct TokenUriFrozen {
+        #[ink(topic)]
+        id: TokenId,
+    }
+
+    /// Event emitted when every token URI is frozen
+    #[ink(event)]
+    pub struct AllTokenUrisFrozen {}
+
+    /// Event emitted when a token is locked
+    #[ink(event)]
+    pub struct TokenLocked {
+        #[ink(topic)]
+        id: TokenId,
+    }
+
+    /// Event emitted when a token is unlocked
+    #[ink(event)]
+    pub struct TokenUnlocked {
+        #[ink(topic)]
+        id: TokenId,
+    }
+
+    /// Event emitted when a soulbound token is issued
+    #[ink(event)]
+    pub struct SoulboundIssued {
+        #[ink(topic)]
+        issuer: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+        #[ink(topic)]
+        id: TokenId,
+    }
+
+    /// Event emitted when an operator's burn approval changes
+    #[ink(event)]
+    pub struct BurnApproval {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        operator: AccountId,
+        approved: bool,
+    }
+
+    /// Event emitted when a transfer is offered
+    #[ink(event)]
+    pub struct TransferOffered {
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+        #[ink(topic)]
+        id: TokenId,
+    }
+
+    /// Event emitted when a transfer offer is accepted
+    #[ink(event)]
+    pub struct TransferAccepted {
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+        #[ink(topic)]
+        id: TokenId,
+    }
+
+    /// Event emitted when a transfer offer is cancelled
+    #[ink(event)]
+    pub struct TransferOfferCancelled {
+        #[ink(topic)]
+        id: TokenId,
+    }
+
+    /// Event emitted when the max supply is set or lowered
+    #[ink(event)]
+    pub struct MaxSupplySet {
+        max_supply: u32,
+    }
+
+    /// Event emitted when a voucher is redeemed
+    #[ink(event)]
+    pub struct VoucherRedeemed {
+        #[ink(topic)]
+        signer: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+        #[ink(topic)]
+        id: TokenId,
+        nonce: u64,
+        price: Balance,
+    }
+
+    /// Event emitted when an attribute is set or removed
+    ///
+    /// `value` is `None` when the attribute was removed.
+    #[ink(event)]
+    pub struct AttributeSet {
+        #[ink(topic)]
+        id: TokenId,
+        key: String,
+        value: Option<String>,
+    }
+
     impl BeliNft {
         /// Creates a new BeliNFT collection
+        ///
+        /// `max_supply` caps the number of tokens that can ever be minted.
+        /// `legacy_burn` lets transfer approvals authorize burning, as before
+        /// `set_burn_approval` existed.
         #[ink(constructor)]
-        pub fn new(name: String, symbol: String) -> Self {
+        pub fn new(
+            name: String,
+            symbol: String,
+            max_supply: Option<u32>,
+            legacy_burn: bool,
+        ) -> Self {
             let caller = Self::env().caller();
+            let now = Self::env().block_timestamp();
+
+            // The deployer starts with every role; the grants cannot fail
+            // because the deployer already holds DEFAULT_ADMIN_ROLE.
+            let mut access_control = AccessControlData::new(caller);
+            for role in [MINTER_ROLE, REGISTRAR_ROLE] {
+                let _ = access_control.grant_role(caller, role, caller, now, |event| {
+                    Self::env().emit_event(event);
+                });
+            }
 
             Self {
                 token_owner: Mapping::default(),
@@ -122,6 +562,30 @@ mod beli_nft {
                 owner: caller,
                 name,
                 symbol,
+                sale: SaleConfig::default(),
+                public_minted: Mapping::default(),
+                proceeds: 0,
+                default_royalty: None,
+                token_royalties: Mapping::default(),
+                base_uri: None,
+                unrevealed_uri: None,
+                revealed: false,
+                pausable: PausableData::new(),
+                locked_tokens: Mapping::default(),
+                access_control,
+                attributes: Mapping::default(),
+                attribute_keys: Mapping::default(),
+                soulbound: Mapping::default(),
+                used_vouchers: Mapping::default(),
+                voucher_proceeds: Mapping::default(),
+                max_supply,
+                pending_transfers: Mapping::default(),
+                burn_approvals: Mapping::default(),
+                legacy_burn,
+                frozen_uris: Mapping::default(),
+                all_uris_frozen: false,
+                checkpoints: Mapping::default(),
+                checkpoint_counts: Mapping::default(),
             }
         }
 
@@ -155,9 +619,40 @@ mod beli_nft {
             self.owned_tokens_count.get(owner).unwrap_or(0)
         }
 
-        /// Returns the approved address for a token
+        /// Returns the number of tokens an account held at the end of `block`
+        ///
+        /// Binary search over the account's checkpoints; accounts with no
+        /// history before `block` report zero.
+        #[ink(message)]
+        pub fn balance_of_at(&self, owner: AccountId, block: u32) -> u32 {
+            let count = self.checkpoint_counts.get(owner).unwrap_or(0);
+
+            // Find the first checkpoint written after `block`
+            let (mut low, mut high) = (0u32, count);
+            while low < high {
+                let mid = low + (high - low) / 2;
+                match self.checkpoints.get((owner, mid)) {
+                    Some(checkpoint) if checkpoint.block > block => high = mid,
+                    _ => low = mid.saturating_add(1),
+                }
+            }
+
+            low.checked_sub(1)
+                .and_then(|index| self.checkpoints.get((owner, index)))
+                .map(|checkpoint| checkpoint.value)
+                .unwrap_or(0)
+        }
+
+        /// Returns the number of token count checkpoints recorded for an account
+        #[ink(message)]
+        pub fn num_checkpoints(&self, owner: AccountId) -> u32 {
+            self.checkpoint_counts.get(owner).unwrap_or(0)
+        }
+
+        /// Returns the approved address for a token (None if it does not exist)
         #[ink(message)]
         pub fn get_approved(&self, id: TokenId) -> Option<AccountId> {
+            self.owner_of(id)?;
             self.token_approvals.get(id)
         }
 
@@ -168,26 +663,139 @@ mod beli_nft {
         }
 
         /// Returns the metadata URI for a token
+        ///
+        /// Resolution order: explicit per-token URI, then `base_uri + id` once
+        /// revealed (or when no placeholder is set), then the unrevealed placeholder.
         #[ink(message)]
         pub fn token_uri(&self, id: TokenId) -> Option<String> {
-            self.token_uri.get(id)
+            if !self.token_owner.contains(id) {
+                return None;
+            }
+
+            if let Some(uri) = self.token_uri.get(id) {
+                return Some(uri);
+            }
+
+            let base_active = self.revealed || self.unrevealed_uri.is_none();
+            match (&self.base_uri, base_active) {
+                (Some(base), true) => {
+                    let mut uri = base.clone();
+                    uri.push_str(&id.to_string());
+                    Some(uri)
+                }
+                _ => self.unrevealed_uri.clone(),
+            }
+        }
+
+        /// Returns the base URI
+        #[ink(message)]
+        pub fn base_uri(&self) -> Option<String> {
+            self.base_uri.clone()
+        }
+
+        /// Returns whether the collection has been revealed
+        #[ink(message)]
+        pub fn is_revealed(&self) -> bool {
+            self.revealed
+        }
+
+        /// Returns whether transfers and burns are paused
+        #[ink(message)]
+        pub fn is_paused(&self) -> bool {
+            self.pausable.is_paused()
+        }
+
+        /// Returns the collection's max supply, if capped
+        #[ink(message)]
+        pub fn max_supply(&self) -> Option<u32> {
+            self.max_supply
+        }
+
+        /// Returns whether a token is soulbound
+        #[ink(message)]
+        pub fn is_soulbound(&self, id: TokenId) -> bool {
+            self.soulbound.contains(id)
+        }
+
+        /// Returns whether a token is locked
+        #[ink(message)]
+        pub fn is_locked(&self, id: TokenId) -> bool {
+            self.locked_tokens.contains(id)
         }
 
-        /// Mints a new token (owner only)
+        /// Mints a new token (minter only)
         #[ink(message)]
         pub fn mint(&mut self, to: AccountId, uri: String) -> Result<TokenId> {
             let caller = self.env().caller();
-            if caller != self.owner {
-                return Err(Error::NotOwner);
-            }
+            self.access_control.ensure_role(caller, MINTER_ROLE)?;
 
-            let token_id = self.next_token_id;
-            self.mint_token(to, token_id, uri)?;
-            self.next_token_id = self.next_token_id.saturating_add(1);
+            self.mint_next(to, uri)
+        }
+
+        /// Mints a non-transferable token (minter only)
+        ///
+        /// The holder or any minter can still burn it, e.g. to revoke a credential.
+        #[ink(message)]
+        pub fn mint_soulbound(&mut self, to: AccountId, uri: String) -> Result<TokenId> {
+            let caller = self.env().caller();
+            self.access_control.ensure_role(caller, MINTER_ROLE)?;
+
+            let token_id = self.mint_next(to, uri)?;
+            self.soulbound.insert(token_id, &());
+
+            self.env().emit_event(SoulboundIssued {
+                issuer: caller,
+                to,
+                id: token_id,
+            });
 
             Ok(token_id)
         }
 
+        /// Mints one token per URI to a single recipient (minter only)
+        ///
+        /// Token ids are assigned sequentially in the order of `uris`.
+        #[ink(message)]
+        pub fn batch_mint(&mut self, to: AccountId, uris: Vec<String>) -> Result<Vec<TokenId>> {
+            let caller = self.env().caller();
+            self.access_control.ensure_role(caller, MINTER_ROLE)?;
+
+            Self::ensure_batch_size(uris.len())?;
+            self.ensure_capacity(uris.len())?;
+
+            let mut ids = Vec::with_capacity(uris.len());
+            for uri in uris {
+                ids.push(self.mint_next(to, uri)?);
+            }
+
+            Ok(ids)
+        }
+
+        /// Mints `uris[i]` to `recipients[i]` for every index (minter only)
+        #[ink(message)]
+        pub fn batch_mint_to(
+            &mut self,
+            recipients: Vec<AccountId>,
+            uris: Vec<String>,
+        ) -> Result<Vec<TokenId>> {
+            let caller = self.env().caller();
+            self.access_control.ensure_role(caller, MINTER_ROLE)?;
+
+            if recipients.len() != uris.len() {
+                return Err(Error::LengthMismatch);
+            }
+
+            Self::ensure_batch_size(uris.len())?;
+            self.ensure_capacity(uris.len())?;
+
+            let mut ids = Vec::with_capacity(uris.len());
+            for (to, uri) in recipients.into_iter().zip(uris) {
+                ids.push(self.mint_next(to, uri)?);
+            }
+
+            Ok(ids)
+        }
+
         /// Transfers a token
         #[ink(message)]
         pub fn transfer(&mut self, to: AccountId, id: TokenId) -> Result<()> {
@@ -198,6 +806,7 @@ mod beli_nft {
                 return Err(Error::NotAuthorized);
             }
 
+            self.ensure_no_pending_transfer(id)?;
             self.transfer_token_from(owner, to, id)?;
             Ok(())
         }
@@ -216,10 +825,36 @@ mod beli_nft {
                 return Err(Error::NotAuthorized);
             }
 
+            self.ensure_no_pending_transfer(id)?;
             self.transfer_token_from(from, to, id)?;
             Ok(())
         }
 
+        /// Transfers a token and, if `to` is a contract, notifies it through
+        /// `PSP37Receiver::before_received` with the id and a value of 1
+        ///
+        /// This is the PSP37 (multi-token) receiver hook, not a PSP34 one:
+        /// BeliNFT has no PSP34 receiver interface, so receiving contracts
+        /// must implement `PSP37Receiver`. The transfer reverts if the
+        /// receiver returns an error or does not implement the hook.
+        #[ink(message)]
+        pub fn safe_transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            id: TokenId,
+            data: Vec<u8>,
+        ) -> Result<()> {
+            self.transfer_from(from, to, id)?;
+
+            if self.env().is_contract(&to) {
+                let operator = self.env().caller();
+                self.notify_receiver(operator, from, to, id, data)?;
+            }
+
+            Ok(())
+        }
+
         /// Approves an address to transfer a specific token
         #[ink(message)]
         pub fn approve(&mut self, to: AccountId, id: TokenId) -> Result<()> {
@@ -234,7 +869,18 @@ mod beli_nft {
                 return Err(Error::SelfApproval);
             }
 
-            self.token_approvals.insert(id, &to);
+            if self.soulbound.contains(id) {
+                return Err(Error::Soulbound);
+            }
+
+            self.ensure_not_locked(id)?;
+
+            // Approving the zero address clears the approval
+            if to == AccountId::from([0u8; 32]) {
+                self.token_approvals.remove(id);
+            } else {
+                self.token_approvals.insert(id, &to);
+            }
 
             self.env().emit_event(Approval {
                 owner,
@@ -269,359 +915,3135 @@ mod beli_nft {
             Ok(())
         }
 
-        /// Burns a token
+        /// Allows or disallows `operator` to burn the caller's tokens
+        ///
+        /// Independent of `set_approval_for_all`: transfer approvals never
+        /// authorize burning unless the collection was deployed with `legacy_burn`.
         #[ink(message)]
-        pub fn burn(&mut self, id: TokenId) -> Result<()> {
+        pub fn set_burn_approval(&mut self, operator: AccountId, approved: bool) -> Result<()> {
             let caller = self.env().caller();
-            let owner = self.owner_of(id).ok_or(Error::TokenNotFound)?;
 
-            if caller != owner && !self.is_approved_or_owner(caller, id) {
-                return Err(Error::NotAuthorized);
+            if caller == operator {
+                return Err(Error::SelfApproval);
             }
 
-            self.burn_token(id)?;
+            if approved {
+                self.burn_approvals.insert((caller, operator), &());
+            } else {
+                self.burn_approvals.remove((caller, operator));
+            }
+
+            self.env().emit_event(BurnApproval {
+                owner: caller,
+                operator,
+                approved,
+            });
+
             Ok(())
         }
 
-        /// Updates the URI of a token (owner only)
+        /// Returns whether an operator may burn all tokens of an owner
         #[ink(message)]
-        pub fn set_token_uri(&mut self, id: TokenId, uri: String) -> Result<()> {
+        pub fn is_burn_approved(&self, owner: AccountId, operator: AccountId) -> bool {
+            self.burn_approvals.contains((owner, operator))
+        }
+
+        /// Returns whether transfer approvals also authorize burning
+        #[ink(message)]
+        pub fn legacy_burn(&self) -> bool {
+            self.legacy_burn
+        }
+
+        /// Offers a token to `to`; ownership moves only once `to` accepts
+        ///
+        /// While the offer is pending the token cannot be transferred or burned.
+        #[ink(message)]
+        pub fn offer_transfer(&mut self, to: AccountId, id: TokenId) -> Result<()> {
             let caller = self.env().caller();
-            if caller != self.owner {
-                return Err(Error::NotOwner);
+            let owner = self.owner_of(id).ok_or(Error::TokenNotFound)?;
+
+            if !self.is_approved_or_owner(caller, id) {
+                return Err(Error::NotAuthorized);
             }
 
-            if self.owner_of(id).is_none() {
-                return Err(Error::TokenNotFound);
+            if to == owner || to == AccountId::from([0u8; 32]) {
+                return Err(Error::InvalidRecipient);
+            }
+
+            if self.soulbound.contains(id) {
+                return Err(Error::Soulbound);
             }
 
-            self.token_uri.insert(id, &uri);
+            self.ensure_no_pending_transfer(id)?;
+
+            self.pending_transfers.insert(id, &to);
+
+            self.env().emit_event(TransferOffered {
+                from: owner,
+                to,
+                id,
+            });
+
             Ok(())
         }
 
-        /// Transfers ownership of the contract
+        /// Accepts a pending transfer offer (offered recipient only)
         #[ink(message)]
-        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<()> {
+        pub fn accept_transfer(&mut self, id: TokenId) -> Result<()> {
             let caller = self.env().caller();
-            if caller != self.owner {
-                return Err(Error::NotOwner);
+            let owner = self.owner_of(id).ok_or(Error::TokenNotFound)?;
+
+            match self.pending_transfers.get(id) {
+                Some(to) if to == caller => {}
+                Some(_) => return Err(Error::NotAuthorized),
+                None => return Err(Error::NoPendingTransfer),
             }
 
-            self.owner = new_owner;
+            self.transfer_token_from(owner, caller, id)?;
+            self.pending_transfers.remove(id);
+
+            self.env().emit_event(TransferAccepted {
+                from: owner,
+                to: caller,
+                id,
+            });
+
             Ok(())
         }
 
-        /// Returns the contract owner
+        /// Cancels a pending transfer offer (token owner only)
         #[ink(message)]
-        pub fn contract_owner(&self) -> AccountId {
-            self.owner
-        }
-
+        pub fn cancel_offer(&mut self, id: TokenId) -> Result<()> {
+            let caller = self.env().caller();
+            let owner = self.owner_of(id).ok_or(Error::TokenNotFound)?;
+
+            if caller != owner {
+                return Err(Error::NotAuthorized);
+            }
+
+            if self.pending_transfers.take(id).is_none() {
+                return Err(Error::NoPendingTransfer);
+            }
+
+            self.env().emit_event(TransferOfferCancelled { id });
+
+            Ok(())
+        }
+
+        /// Returns the recipient of a pending transfer offer
+        #[ink(message)]
+        pub fn pending_transfer(&self, id: TokenId) -> Option<AccountId> {
+            self.pending_transfers.get(id)
+        }
+
+        /// Burns a token
+        #[ink(message)]
+        pub fn burn(&mut self, id: TokenId) -> Result<()> {
+            let caller = self.env().caller();
+            let owner = self.owner_of(id).ok_or(Error::TokenNotFound)?;
+
+            // Issuers may revoke soulbound tokens
+            let revocation =
+                self.soulbound.contains(id) && self.access_control.has_role(MINTER_ROLE, caller);
+
+            let approved = if self.legacy_burn {
+                self.is_approved_or_owner(caller, id)
+            } else {
+                self.is_burn_approved(owner, caller)
+            };
+
+            if caller != owner && !revocation && !approved {
+                return Err(Error::NotAuthorized);
+            }
+
+            self.ensure_no_pending_transfer(id)?;
+            self.burn_token(id)?;
+            Ok(())
+        }
+
+        /// Updates the URI of a token (minter only)
+        #[ink(message)]
+        pub fn set_token_uri(&mut self, id: TokenId, uri: String) -> Result<()> {
+            let caller = self.env().caller();
+            self.access_control.ensure_role(caller, MINTER_ROLE)?;
+
+            if self.owner_of(id).is_none() {
+                return Err(Error::TokenNotFound);
+            }
+            if self.is_uri_frozen(id) {
+                return Err(Error::MetadataFrozen);
+            }
+
+            // An empty URI falls back to base/placeholder resolution
+            let old_uri = self.token_uri.get(id);
+            let new_uri = if uri.is_empty() {
+                self.token_uri.remove(id);
+                None
+            } else {
+                self.token_uri.insert(id, &uri);
+                Some(uri)
+            };
+
+            self.env().emit_event(TokenUriUpdated {
+                id,
+                old_uri,
+                new_uri,
+            });
+            Ok(())
+        }
+
+        /// Permanently freezes a token's URI (registrar only)
+        ///
+        /// The URI the token currently resolves to is pinned, so later base
+        /// URI changes cannot alter it either. There is no unfreeze; the URI
+        /// is only dropped if the token is burned.
+        #[ink(message)]
+        pub fn freeze_token_uri(&mut self, id: TokenId) -> Result<()> {
+            let caller = self.env().caller();
+            self.access_control.ensure_role(caller, REGISTRAR_ROLE)?;
+
+            if self.owner_of(id).is_none() {
+                return Err(Error::TokenNotFound);
+            }
+
+            if !self.frozen_uris.contains(id) {
+                if !self.token_uri.contains(id) {
+                    if let Some(uri) = self.token_uri(id) {
+                        self.token_uri.insert(id, &uri);
+                    }
+                }
+                self.frozen_uris.insert(id, &());
+                self.env().emit_event(TokenUriFrozen { id });
+            }
+
+            Ok(())
+        }
+
+        /// Permanently freezes every token URI, plus the base and placeholder
+        /// URIs and the reveal state (admin only)
+        ///
+        /// Minting continues; new tokens keep the URI they are minted with.
+        #[ink(message)]
+        pub fn freeze_all_token_uris(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            self.ensure_admin(caller)?;
+
+            if !self.all_uris_frozen {
+                self.all_uris_frozen = true;
+                self.env().emit_event(AllTokenUrisFrozen {});
+            }
+
+            Ok(())
+        }
+
+        /// Returns whether a token's URI is frozen, individually or collection-wide
+        #[ink(message)]
+        pub fn is_uri_frozen(&self, id: TokenId) -> bool {
+            self.all_uris_frozen || self.frozen_uris.contains(id)
+        }
+
+        /// Configures the public sale (admin only)
+        #[ink(message)]
+        pub fn set_sale(
+            &mut self,
+            price: Balance,
+            max_supply: u32,
+            max_per_wallet: u32,
+            active: bool,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            self.ensure_admin(caller)?;
+
+            self.sale = SaleConfig {
+                price,
+                max_supply,
+                max_per_wallet,
+                active,
+            };
+
+            self.env().emit_event(SaleUpdated {
+                price,
+                max_supply,
+                max_per_wallet,
+                active,
+            });
+
+            Ok(())
+        }
+
+        /// Mints a token to the caller in exchange for the sale price
+        ///
+        /// Any value transferred above the price is refunded to the caller.
+        #[ink(message, payable)]
+        pub fn public_mint(&mut self, uri: String) -> Result<TokenId> {
+            let caller = self.env().caller();
+            let paid = self.env().transferred_value();
+            let sale = self.sale.clone();
+
+            if !sale.active {
+                return Err(Error::SaleNotActive);
+            }
+
+            if paid < sale.price {
+                return Err(Error::InsufficientPayment);
+            }
+
+            if self.minted_count() >= sale.max_supply {
+                return Err(Error::SoldOut);
+            }
+
+            let minted_by_caller = self.public_minted.get(caller).unwrap_or(0);
+            if minted_by_caller >= sale.max_per_wallet {
+                return Err(Error::WalletLimitReached);
+            }
+
+            let token_id = self.mint_next(caller, uri)?;
+
+            self.public_minted
+                .insert(caller, &minted_by_caller.saturating_add(1));
+            self.proceeds = self.proceeds.saturating_add(sale.price);
+
+            let refund = paid.saturating_sub(sale.price);
+            if refund > 0 && self.env().transfer(caller, refund).is_err() {
+                return Err(Error::TransferFailed);
+            }
+
+            self.env().emit_event(PublicMint {
+                minter: caller,
+                id: token_id,
+                price: sale.price,
+            });
+
+            Ok(token_id)
+        }
+
+        /// Sends accumulated sale proceeds to the contract owner (admin only)
+        #[ink(message)]
+        pub fn withdraw_proceeds(&mut self) -> Result<Balance> {
+            let caller = self.env().caller();
+            self.ensure_admin(caller)?;
+
+            let amount = self.proceeds;
+            if amount > 0 {
+                self.proceeds = 0;
+                if self.env().transfer(caller, amount).is_err() {
+                    return Err(Error::TransferFailed);
+                }
+
+                self.env()
+                    .emit_event(ProceedsWithdrawn { to: caller, amount });
+            }
+
+            Ok(amount)
+        }
+
+        /// Returns the public sale configuration
+        #[ink(message)]
+        pub fn sale_config(&self) -> SaleConfig {
+            self.sale.clone()
+        }
+
+        /// Returns how many tokens an account has minted through the public sale
+        #[ink(message)]
+        pub fn public_minted_of(&self, account: AccountId) -> u32 {
+            self.public_minted.get(account).unwrap_or(0)
+        }
+
+        /// Returns the sale proceeds waiting to be withdrawn
+        #[ink(message)]
+        pub fn proceeds(&self) -> Balance {
+            self.proceeds
+        }
+
+        /// Mints a token authorized by a minter's signed voucher
+        ///
+        /// The redeemer pays `voucher.price`, which is credited to the signer;
+        /// any excess is refunded. `signature` is a 65-byte recoverable ECDSA
+        /// signature over `voucher_hash(voucher)`.
+        #[ink(message, payable)]
+        pub fn redeem_voucher(
+            &mut self,
+            voucher: MintVoucher,
+            signature: Vec<u8>,
+        ) -> Result<TokenId> {
+            let caller = self.env().caller();
+            let paid = self.env().transferred_value();
+
+            if self.env().block_timestamp() > voucher.deadline {
+                return Err(Error::VoucherExpired);
+            }
+
+            if self.used_vouchers.contains(voucher.nonce) {
+                return Err(Error::VoucherUsed);
+            }
+
+            let signer = self.recover_signer(&voucher, &signature)?;
+            if !self.access_control.has_role(MINTER_ROLE, signer) {
+                return Err(Error::InvalidSignature);
+            }
+
+            if paid < voucher.price {
+                return Err(Error::InsufficientPayment);
+            }
+
+            let token_id = self.mint_next(voucher.to, voucher.uri)?;
+
+            self.used_vouchers.insert(voucher.nonce, &());
+            let credited = self.voucher_proceeds.get(signer).unwrap_or(0);
+            self.voucher_proceeds
+                .insert(signer, &credited.saturating_add(voucher.price));
+
+            let refund = paid.saturating_sub(voucher.price);
+            if refund > 0 && self.env().transfer(caller, refund).is_err() {
+                return Err(Error::TransferFailed);
+            }
+
+            self.env().emit_event(VoucherRedeemed {
+                signer,
+                to: voucher.to,
+                id: token_id,
+                nonce: voucher.nonce,
+                price: voucher.price,
+            });
+
+            Ok(token_id)
+        }
+
+        /// Sends the caller's voucher proceeds to the caller
+        #[ink(message)]
+        pub fn withdraw_voucher_proceeds(&mut self) -> Result<Balance> {
+            let caller = self.env().caller();
+
+            let amount = self.voucher_proceeds.get(caller).unwrap_or(0);
+            if amount > 0 {
+                self.voucher_proceeds.remove(caller);
+                if self.env().transfer(caller, amount).is_err() {
+                    return Err(Error::TransferFailed);
+                }
+
+                self.env()
+                    .emit_event(ProceedsWithdrawn { to: caller, amount });
+            }
+
+            Ok(amount)
+        }
+
+        /// Returns whether a voucher nonce has been redeemed
+        #[ink(message)]
+        pub fn is_voucher_used(&self, nonce: u64) -> bool {
+            self.used_vouchers.contains(nonce)
+        }
+
+        /// Returns the voucher proceeds waiting to be withdrawn by a signer
+        #[ink(message)]
+        pub fn voucher_proceeds_of(&self, signer: AccountId) -> Balance {
+            self.voucher_proceeds.get(signer).unwrap_or(0)
+        }
+
+        /// Returns the digest a minter must sign to authorize `voucher`
+        ///
+        /// The contract address is included so vouchers cannot be replayed on
+        /// another collection.
+        #[ink(message)]
+        pub fn voucher_hash(&self, voucher: MintVoucher) -> [u8; 32] {
+            let mut hash = [0u8; 32];
+            ink::env::hash_encoded::<Blake2x256, _>(&(self.env().account_id(), voucher), &mut hash);
+            hash
+        }
+
+        /// Sets the collection-wide royalty (admin only)
+        #[ink(message)]
+        pub fn set_default_royalty(&mut self, receiver: AccountId, bps: u16) -> Result<()> {
+            self.ensure_royalty_admin(bps)?;
+
+            self.default_royalty = Some(Royalty { receiver, bps });
+
+            self.env().emit_event(RoyaltyChanged {
+                id: None,
+                receiver: Some(receiver),
+                bps,
+            });
+
+            Ok(())
+        }
+
+        /// Removes the collection-wide royalty (admin only)
+        #[ink(message)]
+        pub fn clear_default_royalty(&mut self) -> Result<()> {
+            self.ensure_royalty_admin(0)?;
+
+            self.default_royalty = None;
+
+            self.env().emit_event(RoyaltyChanged {
+                id: None,
+                receiver: None,
+                bps: 0,
+            });
+
+            Ok(())
+        }
+
+        /// Sets a royalty override for a single token (admin only)
+        #[ink(message)]
+        pub fn set_token_royalty(
+            &mut self,
+            id: TokenId,
+            receiver: AccountId,
+            bps: u16,
+        ) -> Result<()> {
+            self.ensure_royalty_admin(bps)?;
+
+            if self.owner_of(id).is_none() {
+                return Err(Error::TokenNotFound);
+            }
+
+            self.token_royalties.insert(id, &Royalty { receiver, bps });
+
+            self.env().emit_event(RoyaltyChanged {
+                id: Some(id),
+                receiver: Some(receiver),
+                bps,
+            });
+
+            Ok(())
+        }
+
+        /// Removes a token's royalty override, restoring the default (admin only)
+        #[ink(message)]
+        pub fn clear_token_royalty(&mut self, id: TokenId) -> Result<()> {
+            self.ensure_royalty_admin(0)?;
+
+            self.token_royalties.remove(id);
+
+            self.env().emit_event(RoyaltyChanged {
+                id: Some(id),
+                receiver: None,
+                bps: 0,
+            });
+
+            Ok(())
+        }
+
+        /// Returns the royalty receiver and amount owed for a sale
+        ///
+        /// Per-token overrides take precedence over the collection default.
+        /// Amounts round down. Without any royalty configured, returns the
+        /// zero address and 0.
+        #[ink(message)]
+        pub fn royalty_info(&self, id: TokenId, sale_price: Balance) -> (AccountId, Balance) {
+            match self.token_royalties.get(id).or(self.default_royalty) {
+                Some(royalty) => (
+                    royalty.receiver,
+                    Self::royalty_amount(sale_price, royalty.bps),
+                ),
+                None => (AccountId::from([0u8; 32]), 0),
+            }
+        }
+
+        /// Returns the collection-wide royalty
+        #[ink(message)]
+        pub fn default_royalty(&self) -> Option<Royalty> {
+            self.default_royalty
+        }
+
+        /// Sets the base URI used for tokens without an explicit URI (admin only)
+        #[ink(message)]
+        pub fn set_base_uri(&mut self, base: String) -> Result<()> {
+            let caller = self.env().caller();
+            self.ensure_admin(caller)?;
+
+            if self.all_uris_frozen {
+                return Err(Error::MetadataFrozen);
+            }
+
+            self.base_uri = Some(base.clone());
+
+            self.env().emit_event(BaseUriSet { base_uri: base });
+
+            Ok(())
+        }
+
+        /// Sets the placeholder URI shown before reveal (admin only)
+        #[ink(message)]
+        pub fn set_unrevealed_uri(&mut self, uri: String) -> Result<()> {
+            let caller = self.env().caller();
+            self.ensure_admin(caller)?;
+
+            if self.all_uris_frozen {
+                return Err(Error::MetadataFrozen);
+            }
+
+            self.unrevealed_uri = Some(uri);
+            Ok(())
+        }
+
+        /// Reveals the collection so tokens resolve to `base_uri + id` (admin only)
+        #[ink(message)]
+        pub fn reveal(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            self.ensure_admin(caller)?;
+
+            // Revealing changes what every placeholder token resolves to
+            if self.all_uris_frozen {
+                return Err(Error::MetadataFrozen);
+            }
+
+            self.revealed = true;
+
+            self.env().emit_event(Revealed {
+                base_uri: self.base_uri.clone(),
+            });
+
+            Ok(())
+        }
+
+        /// Pauses all transfers and burns (admin only)
+        #[ink(message)]
+        pub fn pause(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            self.ensure_admin(caller)?;
+
+            self.pausable.pause(caller, |event| {
+                Self::env().emit_event(event);
+            })?;
+
+            Ok(())
+        }
+
+        /// Resumes transfers and burns (admin only)
+        #[ink(message)]
+        pub fn unpause(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            self.ensure_admin(caller)?;
+
+            self.pausable.unpause(caller, |event| {
+                Self::env().emit_event(event);
+            })?;
+
+            Ok(())
+        }
+
+        /// Freezes a single token: blocks transfer, approve, and burn (registrar only)
+        #[ink(message)]
+        pub fn lock_token(&mut self, id: TokenId) -> Result<()> {
+            let caller = self.env().caller();
+            self.access_control.ensure_role(caller, REGISTRAR_ROLE)?;
+
+            if self.owner_of(id).is_none() {
+                return Err(Error::TokenNotFound);
+            }
+
+            if !self.locked_tokens.contains(id) {
+                self.locked_tokens.insert(id, &());
+                self.env().emit_event(TokenLocked { id });
+            }
+
+            Ok(())
+        }
+
+        /// Lifts a token lock (registrar only)
+        #[ink(message)]
+        pub fn unlock_token(&mut self, id: TokenId) -> Result<()> {
+            let caller = self.env().caller();
+            self.access_control.ensure_role(caller, REGISTRAR_ROLE)?;
+
+            if self.locked_tokens.contains(id) {
+                self.locked_tokens.remove(id);
+                self.env().emit_event(TokenUnlocked { id });
+            }
+
+            Ok(())
+        }
+
+        /// Sets or lowers the max supply (admin only)
+        ///
+        /// The cap can never be raised once set, nor lowered below the number
+        /// of tokens already minted.
+        #[ink(message)]
+        pub fn set_max_supply(&mut self, max_supply: u32) -> Result<()> {
+            let caller = self.env().caller();
+            self.ensure_admin(caller)?;
+
+            if max_supply < self.minted_count() {
+                return Err(Error::InvalidMaxSupply);
+            }
+
+            if let Some(current) = self.max_supply {
+                if max_supply > current {
+                    return Err(Error::InvalidMaxSupply);
+                }
+            }
+
+            self.max_supply = Some(max_supply);
+
+            self.env().emit_event(MaxSupplySet { max_supply });
+
+            Ok(())
+        }
+
+        /// Transfers ownership of the contract
+        ///
+        /// Every role held by the current owner (admin, minter, registrar)
+        /// moves to `new_owner`.
+        #[ink(message)]
+        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            let now = self.env().block_timestamp();
+            if caller != self.owner {
+                return Err(Error::NotOwner);
+            }
+
+            if new_owner == caller {
+                return Ok(());
+            }
+
+            // DEFAULT_ADMIN_ROLE goes last so the caller can still revoke the others
+            for role in [MINTER_ROLE, REGISTRAR_ROLE, DEFAULT_ADMIN_ROLE] {
+                if self.access_control.has_role(role, caller) {
+                    self.access_control
+                        .grant_role(caller, role, new_owner, now, |event| {
+                            Self::env().emit_event(event);
+                        })?;
+                    self.access_control
+                        .revoke_role(caller, role, caller, now, |event| {
+                            Self::env().emit_event(event);
+                        })?;
+                }
+            }
+
+            self.owner = new_owner;
+            Ok(())
+        }
+
+        /// Returns the contract owner (primary admin)
+        #[ink(message)]
+        pub fn contract_owner(&self) -> AccountId {
+            self.owner
+        }
+
+        /// Grants `role` to `account` (role admin only)
+        #[ink(message)]
+        pub fn grant_role(&mut self, role: RoleType, account: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            let now = self.env().block_timestamp();
+            self.access_control
+                .grant_role(caller, role, account, now, |event| {
+                    Self::env().emit_event(event);
+                })?;
+
+            Ok(())
+        }
+
+        /// Revokes `role` from `account` (role admin only)
+        ///
+        /// The owner's admin role can only move through `transfer_ownership`.
+        #[ink(message)]
+        pub fn revoke_role(&mut self, role: RoleType, account: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            let now = self.env().block_timestamp();
+            if role == DEFAULT_ADMIN_ROLE && account == self.owner {
+                return Err(Error::AdminLockout);
+            }
+
+            self.access_control
+                .revoke_role(caller, role, account, now, |event| {
+                    Self::env().emit_event(event);
+                })?;
+
+            Ok(())
+        }
+
+        /// Gives up one of the caller's roles
+        #[ink(message)]
+        pub fn renounce_role(&mut self, role: RoleType) -> Result<()> {
+            let caller = self.env().caller();
+            if role == DEFAULT_ADMIN_ROLE && caller == self.owner {
+                return Err(Error::AdminLockout);
+            }
+
+            self.access_control.renounce_role(caller, role, |event| {
+                Self::env().emit_event(event);
+            })?;
+
+            Ok(())
+        }
+
+        /// Returns whether `account` holds `role`
+        #[ink(message)]
+        pub fn has_role(&self, role: RoleType, account: AccountId) -> bool {
+            self.access_control.has_role(role, account)
+        }
+
+        /// Sets an attribute on a token, overwriting any previous value (minter only)
+        #[ink(message)]
+        pub fn set_attribute(&mut self, id: TokenId, key: String, value: String) -> Result<()> {
+            let caller = self.env().caller();
+            self.access_control.ensure_role(caller, MINTER_ROLE)?;
+
+            if self.owner_of(id).is_none() {
+                return Err(Error::TokenNotFound);
+            }
+
+            let mut keys = self.attribute_keys.get(id).unwrap_or_default();
+            if !keys.contains(&key) {
+                if keys.len() >= MAX_ATTRIBUTES as usize {
+                    return Err(Error::TooManyAttributes);
+                }
+                keys.push(key.clone());
+                self.attribute_keys.insert(id, &keys);
+            }
+
+            self.attributes.insert((id, key.clone()), &value);
+
+            self.env().emit_event(AttributeSet {
+                id,
+                key,
+                value: Some(value),
+            });
+
+            Ok(())
+        }
+
+        /// Removes an attribute from a token (minter only)
+        #[ink(message)]
+        pub fn remove_attribute(&mut self, id: TokenId, key: String) -> Result<()> {
+            let caller = self.env().caller();
+            self.access_control.ensure_role(caller, MINTER_ROLE)?;
+
+            if self.owner_of(id).is_none() {
+                return Err(Error::TokenNotFound);
+            }
+
+            let mut keys = self.attribute_keys.get(id).unwrap_or_default();
+            if let Some(pos) = keys.iter().position(|k| *k == key) {
+                keys.swap_remove(pos);
+                if keys.is_empty() {
+                    self.attribute_keys.remove(id);
+                } else {
+                    self.attribute_keys.insert(id, &keys);
+                }
+                self.attributes.remove((id, key.clone()));
+
+                self.env().emit_event(AttributeSet {
+                    id,
+                    key,
+                    value: None,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Returns the value of a token attribute
+        #[ink(message)]
+        pub fn get_attribute(&self, id: TokenId, key: String) -> Option<String> {
+            self.attributes.get((id, key))
+        }
+
+        /// Returns all attribute keys set on a token
+        #[ink(message)]
+        pub fn attribute_keys(&self, id: TokenId) -> Vec<String> {
+            self.attribute_keys.get(id).unwrap_or_default()
+        }
+
         // ========== Internal Functions ==========
 
-        /// Internal mint function
-        fn mint_token(&mut self, to: AccountId, id: TokenId, uri: String) -> Result<()> {
-            if self.token_owner.contains(id) {
-                return Err(Error::TokenExists);
+        /// Maps a PSP34 `Id` to the internal `TokenId`
+        ///
+        /// BeliNFT ids are exposed as `Id::U32`; any other variant names a
+        /// token that cannot exist in this collection.
+        fn token_id_of(id: &Id) -> Option<TokenId> {
+            match id {
+                Id::U32(token_id) => Some(*token_id),
+                _ => None,
+            }
+        }
+
+        /// Mints `next_token_id` to `to` and advances the counter
+        fn mint_next(&mut self, to: AccountId, uri: String) -> Result<TokenId> {
+            let token_id = self.next_token_id;
+            self.mint_token(to, token_id, uri)?;
+            self.next_token_id = self.next_token_id.saturating_add(1);
+
+            Ok(token_id)
+        }
+
+        /// Ensures `caller` holds DEFAULT_ADMIN_ROLE
+        fn ensure_admin(&self, caller: AccountId) -> Result<()> {
+            if !self.access_control.has_role(DEFAULT_ADMIN_ROLE, caller) {
+                return Err(Error::NotOwner);
+            }
+
+            Ok(())
+        }
+
+        /// Ensures `count` more tokens fit under the max supply
+        fn ensure_capacity(&self, count: usize) -> Result<()> {
+            if let Some(cap) = self.max_supply {
+                let remaining = cap.saturating_sub(self.minted_count());
+                if count > remaining as usize {
+                    return Err(Error::MaxSupplyReached);
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Ensures a batch is non-empty and within MAX_BATCH_SIZE
+        fn ensure_batch_size(len: usize) -> Result<()> {
+            if len == 0 || len > MAX_BATCH_SIZE as usize {
+                return Err(Error::InvalidBatchSize);
+            }
+
+            Ok(())
+        }
+
+        /// Ensures the caller is an admin and the royalty rate is within the cap
+        fn ensure_royalty_admin(&self, bps: u16) -> Result<()> {
+            self.ensure_admin(self.env().caller())?;
+
+            if bps > MAX_ROYALTY_BPS {
+                return Err(Error::RoyaltyTooHigh);
+            }
+
+            Ok(())
+        }
+
+        /// Computes `sale_price * bps / 10000` without overflowing
+        ///
+        /// The price is split into quotient and remainder of the denominator so
+        /// neither product can exceed `Balance::MAX` for `bps <= 10000`.
+        fn royalty_amount(sale_price: Balance, bps: u16) -> Balance {
+            let denominator = Balance::from(BPS_DENOMINATOR);
+            let bps = Balance::from(bps);
+
+            let whole = (sale_price / denominator).saturating_mul(bps);
+            let remainder = (sale_price % denominator)
+                .checked_mul(bps)
+                .map(|v| v / denominator)
+                .unwrap_or(0);
+
+            whole.saturating_add(remainder)
+        }
+
+        /// Number of tokens ever minted (burned tokens included)
+        fn minted_count(&self) -> u32 {
+            self.next_token_id.saturating_sub(1)
+        }
+
+        /// Writes an account's token count and records it in its history
+        fn set_balance(&mut self, owner: AccountId, count: u32) {
+            self.owned_tokens_count.insert(owner, &count);
+
+            let block = self.env().block_number();
+            let checkpoints = self.checkpoint_counts.get(owner).unwrap_or(0);
+            let last = checkpoints
+                .checked_sub(1)
+                .and_then(|index| self.checkpoints.get((owner, index)));
+            let checkpoint = Checkpoint {
+                block,
+                value: count,
+            };
+
+            match last {
+                // Several changes within one block share a checkpoint
+                Some(last) if last.block == block => {
+                    self.checkpoints
+                        .insert((owner, checkpoints.saturating_sub(1)), &checkpoint);
+                }
+                _ => {
+                    self.checkpoints.insert((owner, checkpoints), &checkpoint);
+                    self.checkpoint_counts
+                        .insert(owner, &checkpoints.saturating_add(1));
+                }
+            }
+        }
+
+        /// Internal mint function
+        fn mint_token(&mut self, to: AccountId, id: TokenId, uri: String) -> Result<()> {
+            if self.token_owner.contains(id) {
+                return Err(Error::TokenExists);
+            }
+
+            self.ensure_capacity(1)?;
+
+            // Never inherit an approval left over from a previous holder of this id
+            self.token_approvals.remove(id);
+
+            let count = self.balance_of(to);
+            self.set_balance(to, count.saturating_add(1));
+            self.token_owner.insert(id, &to);
+            if !uri.is_empty() {
+                self.token_uri.insert(id, &uri);
+            }
+            self.total_supply = self.total_supply.saturating_add(1);
+
+            self.env().emit_event(Transfer {
+                from: None,
+                to: Some(to),
+                id,
+            });
+
+            Ok(())
+        }
+
+        /// Internal transfer function
+        fn transfer_token_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            id: TokenId,
+        ) -> Result<()> {
+            if self.soulbound.contains(id) {
+                return Err(Error::Soulbound);
+            }
+
+            self.pausable.ensure_not_paused()?;
+            self.ensure_not_locked(id)?;
+
+            // Clear approvals
+            self.token_approvals.remove(id);
+
+            // Update balances
+            let from_count = self.balance_of(from);
+            self.set_balance(from, from_count.saturating_sub(1));
+
+            let to_count = self.balance_of(to);
+            self.set_balance(to, to_count.saturating_add(1));
+
+            // Update ownership
+            self.token_owner.insert(id, &to);
+
+            self.env().emit_event(Transfer {
+                from: Some(from),
+                to: Some(to),
+                id,
+            });
+
+            Ok(())
+        }
+
+        /// Calls `PSP37Receiver::before_received` on a receiving contract
+        fn notify_receiver(
+            &self,
+            operator: AccountId,
+            from: AccountId,
+            to: AccountId,
+            id: TokenId,
+            data: Vec<u8>,
+        ) -> Result<()> {
+            let mut receiver: ink::contract_ref!(PSP37Receiver) = to.into();
+
+            let result = receiver
+                .call_mut()
+                .before_received(operator, from, vec![u128::from(id)], vec![1], data)
+                .try_invoke();
+
+            match result {
+                Ok(Ok(Ok(()))) => Ok(()),
+                _ => Err(Error::TransferRejected),
+            }
+        }
+
+        /// Internal burn function
+        fn burn_token(&mut self, id: TokenId) -> Result<()> {
+            let owner = self.owner_of(id).ok_or(Error::TokenNotFound)?;
+
+            self.pausable.ensure_not_paused()?;
+            self.ensure_not_locked(id)?;
+
+            // Clear approvals
+            self.token_approvals.remove(id);
+
+            // Update balance
+            let count = self.balance_of(owner);
+            self.set_balance(owner, count.saturating_sub(1));
+
+            // Remove token
+            self.token_owner.remove(id);
+            self.token_uri.remove(id);
+            self.frozen_uris.remove(id);
+            self.token_royalties.remove(id);
+            self.clear_attributes(id);
+            self.soulbound.remove(id);
+            self.total_supply = self.total_supply.saturating_sub(1);
+
+            self.env().emit_event(Transfer {
+                from: Some(owner),
+                to: None,
+                id,
+            });
+
+            Ok(())
+        }
+
+        /// Removes every attribute of a token
+        fn clear_attributes(&mut self, id: TokenId) {
+            if let Some(keys) = self.attribute_keys.take(id) {
+                for key in keys {
+                    self.attributes.remove((id, key));
+                }
+            }
+        }
+
+        /// Recovers the account that signed a voucher
+        ///
+        /// The account id is `blake2_256` of the compressed public key, matching
+        /// Substrate's ECDSA account derivation.
+        fn recover_signer(&self, voucher: &MintVoucher, signature: &[u8]) -> Result<AccountId> {
+            let signature: [u8; 65] = signature.try_into().map_err(|_| Error::InvalidSignature)?;
+            let hash = self.voucher_hash(voucher.clone());
+
+            let mut public_key = [0u8; 33];
+            self.env()
+                .ecdsa_recover(&signature, &hash, &mut public_key)
+                .map_err(|_| Error::InvalidSignature)?;
+
+            let mut account = [0u8; 32];
+            ink::env::hash_bytes::<Blake2x256>(&public_key, &mut account);
+
+            Ok(AccountId::from(account))
+        }
+
+        /// Ensures a token has no pending transfer offer
+        fn ensure_no_pending_transfer(&self, id: TokenId) -> Result<()> {
+            if self.pending_transfers.contains(id) {
+                return Err(Error::TransferPending);
+            }
+
+            Ok(())
+        }
+
+        /// Ensures a token is not locked by the registrar
+        fn ensure_not_locked(&self, id: TokenId) -> Result<()> {
+            if self.locked_tokens.contains(id) {
+                return Err(Error::TokenLocked);
+            }
+
+            Ok(())
+        }
+
+        /// Checks if an address is approved or owner
+        fn is_approved_or_owner(&self, spender: AccountId, id: TokenId) -> bool {
+            let owner = match self.owner_of(id) {
+                Some(o) => o,
+                None => return false,
+            };
+
+            spender == owner
+                || self.get_approved(id) == Some(spender)
+                || self.is_approved_for_all(owner, spender)
+        }
+    }
+
+    /// PSP34 compatibility layer over the BeliNFT API
+    ///
+    /// Standard selectors for wallets and marketplaces; each message
+    /// delegates to its inherent counterpart, so the same checks (pause,
+    /// locks, soulbound, pending offers) apply.
+    impl PSP34 for BeliNft {
+        #[ink(message)]
+        fn collection_id(&self) -> Id {
+            Id::Bytes(self.env().account_id().as_ref().to_vec())
+        }
+
+        #[ink(message)]
+        fn balance_of(&self, owner: AccountId) -> u32 {
+            self.owned_tokens_count.get(owner).unwrap_or(0)
+        }
+
+        #[ink(message)]
+        fn owner_of(&self, id: Id) -> Option<AccountId> {
+            self.token_owner.get(Self::token_id_of(&id)?)
+        }
+
+        /// Stored approvals only; the owner is not its own operator
+        #[ink(message)]
+        fn allowance(&self, owner: AccountId, operator: AccountId, id: Option<Id>) -> bool {
+            if self.is_approved_for_all(owner, operator) {
+                return true;
+            }
+            let Some(id) = id else {
+                return false;
+            };
+
+            match Self::token_id_of(&id) {
+                Some(token_id) if self.token_owner.get(token_id) == Some(owner) => {
+                    self.get_approved(token_id) == Some(operator)
+                }
+                _ => false,
+            }
+        }
+
+        #[ink(message)]
+        fn approve(
+            &mut self,
+            operator: AccountId,
+            id: Option<Id>,
+            approved: bool,
+        ) -> psp34_trait::Result<()> {
+            let Some(id) = id else {
+                return Ok(self.set_approval_for_all(operator, approved)?);
+            };
+
+            let token_id = Self::token_id_of(&id).ok_or(PSP34Error::TokenNotExists)?;
+            if approved {
+                Ok(BeliNft::approve(self, operator, token_id)?)
+            } else if self.get_approved(token_id) == Some(operator) {
+                // Approving the zero address clears the approval
+                Ok(BeliNft::approve(
+                    self,
+                    AccountId::from([0u8; 32]),
+                    token_id,
+                )?)
+            } else if self.token_owner.contains(token_id) {
+                // Nothing to revoke
+                Ok(())
+            } else {
+                Err(PSP34Error::TokenNotExists)
+            }
+        }
+
+        /// `data` is not forwarded; use `safe_transfer_from` to notify
+        /// contract recipients through the PSP37 receiver hook
+        #[ink(message)]
+        fn transfer(&mut self, to: AccountId, id: Id, _data: Vec<u8>) -> psp34_trait::Result<()> {
+            let token_id = Self::token_id_of(&id).ok_or(PSP34Error::TokenNotExists)?;
+            Ok(BeliNft::transfer(self, to, token_id)?)
+        }
+
+        #[ink(message)]
+        fn total_supply(&self) -> u128 {
+            u128::from(self.total_supply)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn default_accounts() -> ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment> {
+            ink::env::test::default_accounts::<ink::env::DefaultEnvironment>()
+        }
+
+        fn set_caller(account: AccountId) {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(account);
+        }
+
+        #[ink::test]
+        fn new_works() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let nft = BeliNft::new(
+                String::from("Belize NFT Collection"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+
+            assert_eq!(nft.collection_name(), String::from("Belize NFT Collection"));
+            assert_eq!(nft.collection_symbol(), String::from("BNFT"));
+            assert_eq!(nft.total_supply(), 0);
+            assert_eq!(nft.contract_owner(), accounts.alice);
+        }
+
+        #[ink::test]
+        fn mint_works() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+
+            let uri = String::from("ipfs://QmTestHash");
+            let result = nft.mint(accounts.bob, uri.clone());
+            assert!(result.is_ok());
+
+            let token_id = result.unwrap();
+            assert_eq!(token_id, 1);
+            assert_eq!(nft.owner_of(token_id), Some(accounts.bob));
+            assert_eq!(nft.balance_of(accounts.bob), 1);
+            assert_eq!(nft.total_supply(), 1);
+            assert_eq!(nft.token_uri(token_id), Some(uri));
+        }
+
+        #[ink::test]
+        fn balance_of_at_works() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+            let set_block = ink::env::test::set_block_number::<ink::env::DefaultEnvironment>;
+
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+
+            // Block 1: two mints in the same block share one checkpoint
+            set_block(1);
+            let first = nft.mint(accounts.bob, String::new()).unwrap();
+            nft.mint(accounts.bob, String::new()).unwrap();
+            assert_eq!(nft.num_checkpoints(accounts.bob), 1);
+
+            // Block 3: Bob passes one on
+            set_block(3);
+            set_caller(accounts.bob);
+            nft.transfer(accounts.charlie, first).unwrap();
+
+            assert_eq!(nft.balance_of_at(accounts.bob, 0), 0);
+            assert_eq!(nft.balance_of_at(accounts.bob, 2), 2);
+            assert_eq!(nft.balance_of_at(accounts.bob, 3), 1);
+            assert_eq!(nft.balance_of_at(accounts.charlie, 2), 0);
+            assert_eq!(nft.balance_of_at(accounts.charlie, 5), 1);
+            assert_eq!(nft.num_checkpoints(accounts.bob), 2);
+        }
+
+        #[ink::test]
+        fn mint_fails_not_owner() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+
+            // Try to mint as non-owner
+            set_caller(accounts.bob);
+            let uri = String::from("ipfs://QmTestHash");
+            let result = nft.mint(accounts.charlie, uri);
+            assert_eq!(result, Err(Error::MissingRole));
+        }
+
+        #[ink::test]
+        fn transfer_works() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+
+            // Mint token to Bob
+            let uri = String::from("ipfs://QmTestHash");
+            let token_id = nft.mint(accounts.bob, uri).unwrap();
+
+            // Transfer from Bob to Charlie
+            set_caller(accounts.bob);
+            let result = nft.transfer(accounts.charlie, token_id);
+            assert!(result.is_ok());
+
+            assert_eq!(nft.owner_of(token_id), Some(accounts.charlie));
+            assert_eq!(nft.balance_of(accounts.bob), 0);
+            assert_eq!(nft.balance_of(accounts.charlie), 1);
+        }
+
+        #[ink::test]
+        fn transfer_fails_not_owner() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+
+            let uri = String::from("ipfs://QmTestHash");
+            let token_id = nft.mint(accounts.bob, uri).unwrap();
+
+            // Try to transfer as non-owner without approval
+            set_caller(accounts.charlie);
+            let result = nft.transfer(accounts.charlie, token_id);
+            assert_eq!(result, Err(Error::NotAuthorized));
+        }
+
+        #[ink::test]
+        fn approve_works() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+
+            let uri = String::from("ipfs://QmTestHash");
+            let token_id = nft.mint(accounts.bob, uri).unwrap();
+
+            // Approve Charlie to transfer Bob's token
+            set_caller(accounts.bob);
+            let result = nft.approve(accounts.charlie, token_id);
+            assert!(result.is_ok());
+
+            assert_eq!(nft.get_approved(token_id), Some(accounts.charlie));
+
+            // Charlie can now transfer
+            set_caller(accounts.charlie);
+            let result = nft.transfer(accounts.charlie, token_id);
+            assert!(result.is_ok());
+            assert_eq!(nft.owner_of(token_id), Some(accounts.charlie));
+        }
+
+        #[ink::test]
+        fn approval_for_all_works() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+
+            // Mint two tokens to Bob
+            let uri1 = String::from("ipfs://QmHash1");
+            let uri2 = String::from("ipfs://QmHash2");
+            let token_id_1 = nft.mint(accounts.bob, uri1).unwrap();
+            let token_id_2 = nft.mint(accounts.bob, uri2).unwrap();
+
+            // Bob approves Charlie as operator
+            set_caller(accounts.bob);
+            let result = nft.set_approval_for_all(accounts.charlie, true);
+            assert!(result.is_ok());
+            assert!(nft.is_approved_for_all(accounts.bob, accounts.charlie));
+
+            // Charlie can transfer both tokens
+            set_caller(accounts.charlie);
+            assert!(nft.transfer(accounts.charlie, token_id_1).is_ok());
+            assert!(nft.transfer(accounts.charlie, token_id_2).is_ok());
+            assert_eq!(nft.balance_of(accounts.charlie), 2);
+        }
+
+        #[ink::test]
+        fn burn_works() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+
+            let uri = String::from("ipfs://QmTestHash");
+            let token_id = nft.mint(accounts.bob, uri).unwrap();
+            assert_eq!(nft.total_supply(), 1);
+
+            // Bob burns his token
+            set_caller(accounts.bob);
+            let result = nft.burn(token_id);
+            assert!(result.is_ok());
+
+            assert_eq!(nft.owner_of(token_id), None);
+            assert_eq!(nft.balance_of(accounts.bob), 0);
+            assert_eq!(nft.total_supply(), 0);
+        }
+
+        #[ink::test]
+        fn transfer_ownership_works() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+
+            assert_eq!(nft.contract_owner(), accounts.alice);
+
+            let result = nft.transfer_ownership(accounts.bob);
+            assert!(result.is_ok());
+            assert_eq!(nft.contract_owner(), accounts.bob);
+
+            // Alice can no longer mint
+            let uri = String::from("ipfs://QmTestHash");
+            set_caller(accounts.alice);
+            let result = nft.mint(accounts.charlie, uri.clone());
+            assert_eq!(result, Err(Error::MissingRole));
+
+            // Bob can mint
+            set_caller(accounts.bob);
+            let result = nft.mint(accounts.charlie, uri);
+            assert!(result.is_ok());
+        }
+
+        #[ink::test]
+        fn multiple_mints_increment_ids() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+
+            let uri1 = String::from("ipfs://QmHash1");
+            let uri2 = String::from("ipfs://QmHash2");
+            let uri3 = String::from("ipfs://QmHash3");
+
+            let token_1 = nft.mint(accounts.bob, uri1).unwrap();
+            let token_2 = nft.mint(accounts.bob, uri2).unwrap();
+            let token_3 = nft.mint(accounts.bob, uri3).unwrap();
+
+            assert_eq!(token_1, 1);
+            assert_eq!(token_2, 2);
+            assert_eq!(token_3, 3);
+            assert_eq!(nft.balance_of(accounts.bob), 3);
+            assert_eq!(nft.total_supply(), 3);
+        }
+
+        fn set_value(value: Balance) {
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(value);
+        }
+
+        fn set_balance(account: AccountId, balance: Balance) {
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(account, balance);
+        }
+
+        fn get_balance(account: AccountId) -> Balance {
+            ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(account)
+                .expect("account balance")
+        }
+
+        fn contract_id() -> AccountId {
+            ink::env::test::callee::<ink::env::DefaultEnvironment>()
+        }
+
+        fn sale_collection(price: Balance, max_supply: u32, max_per_wallet: u32) -> BeliNft {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+            nft.set_sale(price, max_supply, max_per_wallet, true)
+                .unwrap();
+            set_balance(contract_id(), 10_000);
+            nft
+        }
+
+        #[ink::test]
+        fn public_mint_works() {
+            let accounts = default_accounts();
+            let mut nft = sale_collection(100, 10, 2);
+
+            set_caller(accounts.bob);
+            set_value(100);
+            let token_id = nft.public_mint(String::from("ipfs://QmSale1")).unwrap();
+
+            assert_eq!(nft.owner_of(token_id), Some(accounts.bob));
+            assert_eq!(nft.public_minted_of(accounts.bob), 1);
+            assert_eq!(nft.proceeds(), 100);
+        }
+
+        #[ink::test]
+        fn public_mint_fails_when_inactive() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+
+            set_caller(accounts.bob);
+            set_value(100);
+            assert_eq!(
+                nft.public_mint(String::from("ipfs://QmSale1")),
+                Err(Error::SaleNotActive)
+            );
+        }
+
+        #[ink::test]
+        fn public_mint_fails_underpayment() {
+            let accounts = default_accounts();
+            let mut nft = sale_collection(100, 10, 2);
+
+            set_caller(accounts.bob);
+            set_value(99);
+            assert_eq!(
+                nft.public_mint(String::from("ipfs://QmSale1")),
+                Err(Error::InsufficientPayment)
+            );
+            assert_eq!(nft.total_supply(), 0);
+        }
+
+        #[ink::test]
+        fn public_mint_refunds_overpayment() {
+            let accounts = default_accounts();
+            let mut nft = sale_collection(100, 10, 2);
+            set_balance(accounts.bob, 1_000);
+
+            set_caller(accounts.bob);
+            set_value(250);
+            nft.public_mint(String::from("ipfs://QmSale1")).unwrap();
+
+            assert_eq!(get_balance(accounts.bob), 1_150);
+            assert_eq!(nft.proceeds(), 100);
+        }
+
+        #[ink::test]
+        fn public_mint_fails_sold_out() {
+            let accounts = default_accounts();
+            let mut nft = sale_collection(100, 2, 5);
+
+            set_caller(accounts.bob);
+            set_value(100);
+            nft.public_mint(String::from("ipfs://QmSale1")).unwrap();
+            nft.public_mint(String::from("ipfs://QmSale2")).unwrap();
+
+            set_caller(accounts.charlie);
+            assert_eq!(
+                nft.public_mint(String::from("ipfs://QmSale3")),
+                Err(Error::SoldOut)
+            );
+        }
+
+        #[ink::test]
+        fn public_mint_fails_wallet_limit() {
+            let accounts = default_accounts();
+            let mut nft = sale_collection(100, 10, 1);
+
+            set_caller(accounts.bob);
+            set_value(100);
+            nft.public_mint(String::from("ipfs://QmSale1")).unwrap();
+            assert_eq!(
+                nft.public_mint(String::from("ipfs://QmSale2")),
+                Err(Error::WalletLimitReached)
+            );
+
+            // Other wallets are unaffected
+            set_caller(accounts.charlie);
+            assert!(nft.public_mint(String::from("ipfs://QmSale2")).is_ok());
+        }
+
+        #[ink::test]
+        fn withdraw_proceeds_works() {
+            let accounts = default_accounts();
+            let mut nft = sale_collection(100, 10, 5);
+
+            set_caller(accounts.bob);
+            set_value(100);
+            nft.public_mint(String::from("ipfs://QmSale1")).unwrap();
+            nft.public_mint(String::from("ipfs://QmSale2")).unwrap();
+            set_value(0);
+
+            // Only the owner can withdraw
+            assert_eq!(nft.withdraw_proceeds(), Err(Error::NotOwner));
+
+            set_caller(accounts.alice);
+            set_balance(accounts.alice, 0);
+            assert_eq!(nft.withdraw_proceeds(), Ok(200));
+            assert_eq!(get_balance(accounts.alice), 200);
+            assert_eq!(nft.proceeds(), 0);
+
+            // Nothing left to withdraw
+            assert_eq!(nft.withdraw_proceeds(), Ok(0));
+        }
+
+        #[ink::test]
+        fn default_royalty_applies() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+            let token_id = nft
+                .mint(accounts.bob, String::from("ipfs://QmHash"))
+                .unwrap();
+
+            // No royalty configured
+            assert_eq!(
+                nft.royalty_info(token_id, 10_000),
+                (AccountId::from([0u8; 32]), 0)
+            );
+
+            nft.set_default_royalty(accounts.django, 500).unwrap();
+            assert_eq!(nft.royalty_info(token_id, 10_000), (accounts.django, 500));
+        }
+
+        #[ink::test]
+        fn token_royalty_overrides_default() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+            let token_1 = nft
+                .mint(accounts.bob, String::from("ipfs://QmHash1"))
+                .unwrap();
+            let token_2 = nft
+                .mint(accounts.bob, String::from("ipfs://QmHash2"))
+                .unwrap();
+
+            nft.set_default_royalty(accounts.django, 500).unwrap();
+            nft.set_token_royalty(token_1, accounts.eve, 250).unwrap();
+
+            assert_eq!(nft.royalty_info(token_1, 10_000), (accounts.eve, 250));
+            assert_eq!(nft.royalty_info(token_2, 10_000), (accounts.django, 500));
+
+            // Clearing the override restores the default
+            nft.clear_token_royalty(token_1).unwrap();
+            assert_eq!(nft.royalty_info(token_1, 10_000), (accounts.django, 500));
+
+            nft.clear_default_royalty().unwrap();
+            assert_eq!(nft.royalty_info(token_1, 10_000).1, 0);
+        }
+
+        #[ink::test]
+        fn royalty_rounds_down_without_overflow() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+            let token_id = nft
+                .mint(accounts.bob, String::from("ipfs://QmHash"))
+                .unwrap();
+            nft.set_default_royalty(accounts.django, 333).unwrap();
+
+            // 999 * 333 / 10000 = 33.2667
+            assert_eq!(nft.royalty_info(token_id, 999).1, 33);
+            assert_eq!(nft.royalty_info(token_id, 1).1, 0);
+
+            // Would overflow a naive price * bps
+            let expected = (u128::MAX / 10_000) * 333 + (u128::MAX % 10_000) * 333 / 10_000;
+            assert_eq!(nft.royalty_info(token_id, u128::MAX).1, expected);
+        }
+
+        #[ink::test]
+        fn royalty_cap_and_owner_enforced() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+            let token_id = nft
+                .mint(accounts.bob, String::from("ipfs://QmHash"))
+                .unwrap();
+
+            assert_eq!(
+                nft.set_default_royalty(accounts.django, MAX_ROYALTY_BPS + 1),
+                Err(Error::RoyaltyTooHigh)
+            );
+            assert_eq!(
+                nft.set_token_royalty(token_id, accounts.django, MAX_ROYALTY_BPS + 1),
+                Err(Error::RoyaltyTooHigh)
+            );
+            assert!(nft
+                .set_default_royalty(accounts.django, MAX_ROYALTY_BPS)
+                .is_ok());
+
+            set_caller(accounts.bob);
+            assert_eq!(
+                nft.set_token_royalty(token_id, accounts.bob, 100),
+                Err(Error::NotOwner)
+            );
+        }
+
+        fn uris(count: usize) -> Vec<String> {
+            (0..count).map(|i| format!("ipfs://QmBatch{}", i)).collect()
+        }
+
+        #[ink::test]
+        fn batch_mint_works() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+            nft.mint(accounts.bob, String::from("ipfs://QmFirst"))
+                .unwrap();
+
+            let ids = nft.batch_mint(accounts.charlie, uris(3)).unwrap();
+            assert_eq!(ids, vec![2, 3, 4]);
+            assert_eq!(nft.balance_of(accounts.charlie), 3);
+            assert_eq!(nft.total_supply(), 4);
+            assert_eq!(nft.token_uri(3), Some(String::from("ipfs://QmBatch1")));
+
+            // One Transfer event per token (plus the single mint)
+            assert_eq!(ink::env::test::recorded_events().count(), 4);
+
+            // Next single mint continues the sequence
+            assert_eq!(nft.mint(accounts.bob, String::from("ipfs://QmNext")), Ok(5));
+        }
+
+        #[ink::test]
+        fn batch_mint_to_works() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+
+            let recipients = vec![accounts.bob, accounts.charlie, accounts.bob];
+            let ids = nft.batch_mint_to(recipients, uris(3)).unwrap();
+
+            assert_eq!(ids, vec![1, 2, 3]);
+            assert_eq!(nft.owner_of(1), Some(accounts.bob));
+            assert_eq!(nft.owner_of(2), Some(accounts.charlie));
+            assert_eq!(nft.owner_of(3), Some(accounts.bob));
+            assert_eq!(nft.balance_of(accounts.bob), 2);
+            assert_eq!(nft.balance_of(accounts.charlie), 1);
+        }
+
+        #[ink::test]
+        fn batch_mint_rejects_invalid_input() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+
+            assert_eq!(
+                nft.batch_mint_to(vec![accounts.bob], uris(2)),
+                Err(Error::LengthMismatch)
+            );
+            assert_eq!(
+                nft.batch_mint(accounts.bob, Vec::new()),
+                Err(Error::InvalidBatchSize)
+            );
+            assert_eq!(
+                nft.batch_mint(accounts.bob, uris(MAX_BATCH_SIZE as usize + 1)),
+                Err(Error::InvalidBatchSize)
+            );
+            assert_eq!(nft.total_supply(), 0);
+
+            set_caller(accounts.bob);
+            assert_eq!(
+                nft.batch_mint(accounts.bob, uris(1)),
+                Err(Error::MissingRole)
+            );
+        }
+
+        #[ink::test]
+        fn base_uri_and_reveal_precedence() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+            let explicit = nft
+                .mint(accounts.bob, String::from("ipfs://QmExplicit"))
+                .unwrap();
+            let implicit = nft.mint(accounts.bob, String::new()).unwrap();
+
+            // No base, no placeholder
+            assert_eq!(nft.token_uri(implicit), None);
+
+            nft.set_base_uri(String::from("ipfs://QmBase/")).unwrap();
+            nft.set_unrevealed_uri(String::from("ipfs://QmHidden"))
+                .unwrap();
+
+            // Unrevealed: placeholder for tokens without explicit URI
+            assert!(!nft.is_revealed());
+            assert_eq!(
+                nft.token_uri(explicit),
+                Some(String::from("ipfs://QmExplicit"))
+            );
+            assert_eq!(
+                nft.token_uri(implicit),
+                Some(String::from("ipfs://QmHidden"))
+            );
+
+            // Revealed: base + id
+            nft.reveal().unwrap();
+            assert!(nft.is_revealed());
+            assert_eq!(
+                nft.token_uri(implicit),
+                Some(String::from("ipfs://QmBase/2"))
+            );
+            assert_eq!(
+                nft.token_uri(explicit),
+                Some(String::from("ipfs://QmExplicit"))
+            );
+
+            // Clearing the explicit URI falls back to base + id
+            nft.set_token_uri(explicit, String::new()).unwrap();
+            assert_eq!(
+                nft.token_uri(explicit),
+                Some(String::from("ipfs://QmBase/1"))
+            );
+
+            // Nonexistent tokens have no URI
+            assert_eq!(nft.token_uri(99), None);
+        }
+
+        #[ink::test]
+        fn base_uri_without_placeholder_applies_immediately() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+            let token_id = nft.mint(accounts.bob, String::new()).unwrap();
+            nft.set_base_uri(String::from("https://meta.belizechain.org/"))
+                .unwrap();
+
+            assert_eq!(
+                nft.token_uri(token_id),
+                Some(String::from("https://meta.belizechain.org/1"))
+            );
+
+            set_caller(accounts.bob);
+            assert_eq!(nft.reveal(), Err(Error::NotOwner));
+            assert_eq!(nft.set_base_uri(String::new()), Err(Error::NotOwner));
+        }
+
+        #[ink::test]
+        fn reveal_blocked_after_freeze_all() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+            let token_id = nft.mint(accounts.bob, String::new()).unwrap();
+            nft.set_base_uri(String::from("ipfs://QmBase/")).unwrap();
+            nft.set_unrevealed_uri(String::from("ipfs://QmHidden"))
+                .unwrap();
+            nft.freeze_all_token_uris().unwrap();
+
+            assert_eq!(nft.reveal(), Err(Error::MetadataFrozen));
+            assert!(!nft.is_revealed());
+            assert_eq!(
+                nft.token_uri(token_id),
+                Some(String::from("ipfs://QmHidden"))
+            );
+        }
+
+        /// Land Ledger with Bob as minter and registrar
+        fn land_ledger() -> BeliNft {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Land Ledger"),
+                String::from("LAND"),
+                None,
+                false,
+            );
+            nft.grant_role(MINTER_ROLE, accounts.bob).unwrap();
+            nft.grant_role(REGISTRAR_ROLE, accounts.bob).unwrap();
+            nft
+        }
+
+        #[ink::test]
+        fn set_token_uri_emits_update() {
+            let accounts = default_accounts();
+            let mut nft = land_ledger();
+
+            set_caller(accounts.bob);
+            let parcel = nft
+                .mint(accounts.charlie, String::from("ipfs://QmParcel1"))
+                .unwrap();
+            nft.set_token_uri(parcel, String::from("ipfs://QmParcel1v2"))
+                .unwrap();
+
+            let decode_last = || {
+                let event = ink::env::test::recorded_events()
+                    .last()
+                    .expect("no event recorded");
+                <TokenUriUpdated as ink::scale::Decode>::decode(&mut &event.data[..])
+                    .expect("TokenUriUpdated decode failed")
+            };
+
+            let event = decode_last();
+            assert_eq!(event.id, parcel);
+            assert_eq!(event.old_uri, Some(String::from("ipfs://QmParcel1")));
+            assert_eq!(event.new_uri, Some(String::from("ipfs://QmParcel1v2")));
+
+            // Clearing reports no new URI
+            nft.set_token_uri(parcel, String::new()).unwrap();
+            let event = decode_last();
+            assert_eq!(event.old_uri, Some(String::from("ipfs://QmParcel1v2")));
+            assert_eq!(event.new_uri, None);
+        }
+
+        #[ink::test]
+        fn frozen_token_uri_cannot_change() {
+            let accounts = default_accounts();
+            let mut nft = land_ledger();
+
+            // Mint, verify, freeze
+            set_caller(accounts.bob);
+            let parcel = nft
+                .mint(accounts.charlie, String::from("ipfs://QmParcel1"))
+                .unwrap();
+            let other = nft
+                .mint(accounts.charlie, String::from("ipfs://QmParcel2"))
+                .unwrap();
+            assert!(!nft.is_uri_frozen(parcel));
+            assert!(nft.freeze_token_uri(parcel).is_ok());
+            assert!(nft.is_uri_frozen(parcel));
+            assert!(!nft.is_uri_frozen(other));
+
+            assert_eq!(
+                nft.set_token_uri(parcel, String::from("ipfs://QmForged")),
+                Err(Error::MetadataFrozen)
+            );
+            assert_eq!(
+                nft.set_token_uri(parcel, String::new()),
+                Err(Error::MetadataFrozen)
+            );
+            assert_eq!(
+                nft.token_uri(parcel),
+                Some(String::from("ipfs://QmParcel1"))
+            );
+            assert!(nft
+                .set_token_uri(other, String::from("ipfs://QmParcel2v2"))
+                .is_ok());
+
+            // Freezing again is a no-op; there is no way back
+            assert!(nft.freeze_token_uri(parcel).is_ok());
+            assert!(nft.is_uri_frozen(parcel));
+            assert_eq!(nft.freeze_token_uri(99), Err(Error::TokenNotFound));
+
+            // Only registrars freeze tokens
+            set_caller(accounts.charlie);
+            assert_eq!(nft.freeze_token_uri(other), Err(Error::MissingRole));
+        }
+
+        #[ink::test]
+        fn freezing_pins_the_resolved_uri() {
+            let accounts = default_accounts();
+            let mut nft = land_ledger();
+
+            nft.set_base_uri(String::from("ipfs://QmBase/")).unwrap();
+            let parcel = nft.mint(accounts.bob, String::new()).unwrap();
+            assert!(nft.freeze_token_uri(parcel).is_ok());
+
+            // A new base URI no longer reaches the frozen token
+            nft.set_base_uri(String::from("ipfs://QmOther/")).unwrap();
+            assert_eq!(nft.token_uri(parcel), Some(String::from("ipfs://QmBase/1")));
+        }
+
+        #[ink::test]
+        fn freeze_all_token_uris_still_allows_minting() {
+            let accounts = default_accounts();
+            let mut nft = land_ledger();
+
+            let parcel = nft
+                .mint(accounts.charlie, String::from("ipfs://QmParcel1"))
+                .unwrap();
+
+            // Only the admin freezes the whole collection
+            set_caller(accounts.bob);
+            assert_eq!(nft.freeze_all_token_uris(), Err(Error::NotOwner));
+            set_caller(accounts.alice);
+            assert!(nft.freeze_all_token_uris().is_ok());
+            assert!(nft.is_uri_frozen(parcel));
+
+            assert_eq!(
+                nft.set_token_uri(parcel, String::from("ipfs://QmForged")),
+                Err(Error::MetadataFrozen)
+            );
+            assert_eq!(
+                nft.set_base_uri(String::from("ipfs://QmBase/")),
+                Err(Error::MetadataFrozen)
+            );
+            assert_eq!(
+                nft.set_unrevealed_uri(String::from("ipfs://QmHidden")),
+                Err(Error::MetadataFrozen)
+            );
+
+            // New titles can still be minted and keep their URI for good
+            let next = nft
+                .mint(accounts.charlie, String::from("ipfs://QmParcel2"))
+                .unwrap();
+            assert_eq!(nft.token_uri(next), Some(String::from("ipfs://QmParcel2")));
+            assert!(nft.is_uri_frozen(next));
+            assert_eq!(
+                nft.set_token_uri(next, String::from("ipfs://QmForged")),
+                Err(Error::MetadataFrozen)
+            );
+        }
+
+        #[ink::test]
+        fn locked_token_blocked_while_unpaused() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Land Ledger"),
+                String::from("LAND"),
+                None,
+                false,
+            );
+            let parcel = nft
+                .mint(accounts.bob, String::from("ipfs://QmParcel1"))
+                .unwrap();
+            let other = nft
+                .mint(accounts.bob, String::from("ipfs://QmParcel2"))
+                .unwrap();
+
+            nft.lock_token(parcel).unwrap();
+            assert!(nft.is_locked(parcel));
+            assert!(!nft.is_paused());
+
+            set_caller(accounts.bob);
+            assert_eq!(
+                nft.transfer(accounts.charlie, parcel),
+                Err(Error::TokenLocked)
+            );
+            assert_eq!(
+                nft.approve(accounts.charlie, parcel),
+                Err(Error::TokenLocked)
+            );
+            assert_eq!(nft.burn(parcel), Err(Error::TokenLocked));
+
+            // Views keep working and other tokens are unaffected
+            assert_eq!(nft.owner_of(parcel), Some(accounts.bob));
+            assert!(nft.transfer(accounts.charlie, other).is_ok());
+
+            // Only the owner can unlock
+            assert_eq!(nft.unlock_token(parcel), Err(Error::MissingRole));
+            set_caller(accounts.alice);
+            nft.unlock_token(parcel).unwrap();
+
+            set_caller(accounts.bob);
+            assert!(nft.transfer(accounts.charlie, parcel).is_ok());
+        }
+
+        #[ink::test]
+        fn pause_blocks_unlocked_tokens() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Land Ledger"),
+                String::from("LAND"),
+                None,
+                false,
+            );
+            let parcel = nft
+                .mint(accounts.bob, String::from("ipfs://QmParcel1"))
+                .unwrap();
+
+            nft.pause().unwrap();
+            assert!(nft.is_paused());
+            assert_eq!(nft.pause(), Err(Error::Paused));
+
+            set_caller(accounts.bob);
+            assert!(!nft.is_locked(parcel));
+            assert_eq!(nft.transfer(accounts.charlie, parcel), Err(Error::Paused));
+            assert_eq!(nft.burn(parcel), Err(Error::Paused));
+            assert_eq!(nft.unpause(), Err(Error::NotOwner));
+
+            set_caller(accounts.alice);
+            nft.unpause().unwrap();
+            assert_eq!(nft.unpause(), Err(Error::NotPaused));
+
+            set_caller(accounts.bob);
+            assert!(nft.transfer(accounts.charlie, parcel).is_ok());
+        }
+
+        #[ink::test]
+        fn minter_mints_but_cannot_grant_roles() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Land Ledger"),
+                String::from("LAND"),
+                None,
+                false,
+            );
+            nft.grant_role(MINTER_ROLE, accounts.bob).unwrap();
+            nft.grant_role(REGISTRAR_ROLE, accounts.bob).unwrap();
+            assert!(nft.has_role(MINTER_ROLE, accounts.bob));
+            assert!(!nft.has_role(DEFAULT_ADMIN_ROLE, accounts.bob));
+
+            set_caller(accounts.bob);
+            let parcel = nft
+                .mint(accounts.charlie, String::from("ipfs://QmParcel1"))
+                .unwrap();
+            assert!(nft
+                .set_token_uri(parcel, String::from("ipfs://QmParcel1v2"))
+                .is_ok());
+            assert!(nft.lock_token(parcel).is_ok());
+
+            // Minting does not confer admin rights
+            assert_eq!(
+                nft.grant_role(MINTER_ROLE, accounts.django),
+                Err(Error::MissingRole)
+            );
+            assert_eq!(nft.set_sale(1, 1, 1, true), Err(Error::NotOwner));
+            assert_eq!(nft.contract_owner(), accounts.alice);
+
+            // Once revoked, the minter can no longer mint
+            set_caller(accounts.alice);
+            nft.revoke_role(MINTER_ROLE, accounts.bob).unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(
+                nft.mint(accounts.charlie, String::from("ipfs://QmParcel2")),
+                Err(Error::MissingRole)
+            );
+        }
+
+        #[ink::test]
+        fn owner_cannot_lose_admin_role() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut nft = BeliNft::new(
+                String::from("Land Ledger"),
+                String::from("LAND"),
+                None,
+                false,
+            );
+            nft.grant_role(DEFAULT_ADMIN_ROLE, accounts.bob).unwrap();
+
+            assert_eq!(
+                nft.renounce_role(DEFAULT_ADMIN_ROLE),
+                Err(Error::AdminLockout)
+            );
+            set_caller(accounts.bob);
+            assert_eq!(
+                nft.revoke_role(DEFAULT_ADMIN_ROLE, accounts.alice),
+                Err(Error::AdminLockout)
+            );
+            assert!(nft.has_role(DEFAULT_ADMIN_ROLE, accounts.alice));
+
+            // A secondary admin can step down
+            assert!(nft.renounce_role(DEFAULT_ADMIN_ROLE).is_ok());
+            assert!(!nft.has_role(DEFAULT_ADMIN_ROLE, accounts.bob));
+
+            // Transferring ownership moves every role to the new owner
+            set_caller(accounts.alice);
+            nft.transfer_ownership(accounts.charlie).unwrap();
+            for role in [DEFAULT_ADMIN_ROLE, MINTER_ROLE, REGISTRAR_ROLE] {
+                assert!(nft.has_role(role, accounts.charlie));
+                assert!(!nft.has_role(role, accounts.alice));
             }
+            assert_eq!(nft.contract_owner(), accounts.charlie);
+        }
+
+        #[ink::test]
+        fn attributes_overwrite_and_enumerate() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
 
-            let count = self.balance_of(to);
-            self.owned_tokens_count
-                .insert(to, &(count.saturating_add(1)));
-            self.token_owner.insert(id, &to);
-            self.token_uri.insert(id, &uri);
-            self.total_supply = self.total_supply.saturating_add(1);
+            let mut nft = BeliNft::new(
+                String::from("Land Ledger"),
+                String::from("LAND"),
+                None,
+                false,
+            );
+            let parcel = nft
+                .mint(accounts.bob, String::from("ipfs://QmParcel1"))
+                .unwrap();
+
+            let key = |k: &str| String::from(k);
+            nft.set_attribute(parcel, key("parcel_id"), key("BZ-001"))
+                .unwrap();
+            nft.set_attribute(parcel, key("district"), key("Cayo"))
+                .unwrap();
+            nft.set_attribute(parcel, key("area"), key("120ha"))
+                .unwrap();
+
+            // Overwriting keeps a single key entry
+            nft.set_attribute(parcel, key("district"), key("Toledo"))
+                .unwrap();
+            assert_eq!(
+                nft.get_attribute(parcel, key("district")),
+                Some(key("Toledo"))
+            );
+            assert_eq!(nft.attribute_keys(parcel).len(), 3);
+
+            nft.remove_attribute(parcel, key("parcel_id")).unwrap();
+            let keys = nft.attribute_keys(parcel);
+            assert_eq!(keys.len(), 2);
+            assert!(keys.contains(&key("district")));
+            assert!(keys.contains(&key("area")));
+            assert_eq!(nft.get_attribute(parcel, key("parcel_id")), None);
+
+            // Only minters write attributes
+            set_caller(accounts.bob);
+            assert_eq!(
+                nft.set_attribute(parcel, key("area"), key("1ha")),
+                Err(Error::MissingRole)
+            );
+        }
 
-            self.env().emit_event(Transfer {
-                from: None,
-                to: Some(to),
-                id,
-            });
+        #[ink::test]
+        fn burn_clears_attributes() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
 
-            Ok(())
+            let mut nft = BeliNft::new(
+                String::from("Land Ledger"),
+                String::from("LAND"),
+                None,
+                false,
+            );
+            let parcel = nft
+                .mint(accounts.alice, String::from("ipfs://QmParcel1"))
+                .unwrap();
+            nft.set_attribute(parcel, String::from("district"), String::from("Cayo"))
+                .unwrap();
+
+            nft.burn(parcel).unwrap();
+
+            assert!(nft.attribute_keys(parcel).is_empty());
+            assert_eq!(nft.get_attribute(parcel, String::from("district")), None);
+            assert_eq!(
+                nft.set_attribute(parcel, String::from("district"), String::from("Cayo")),
+                Err(Error::TokenNotFound)
+            );
         }
 
-        /// Internal transfer function
-        fn transfer_token_from(
-            &mut self,
-            from: AccountId,
-            to: AccountId,
-            id: TokenId,
-        ) -> Result<()> {
-            // Clear approvals
-            self.token_approvals.remove(id);
+        #[ink::test]
+        fn approve_zero_address_clears() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
 
-            // Update balances
-            let from_count = self.balance_of(from);
-            self.owned_tokens_count
-                .insert(from, &(from_count.saturating_sub(1)));
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+            let token_id = nft
+                .mint(accounts.bob, String::from("ipfs://QmTestHash"))
+                .unwrap();
 
-            let to_count = self.balance_of(to);
-            self.owned_tokens_count
-                .insert(to, &(to_count.saturating_add(1)));
+            set_caller(accounts.bob);
+            nft.approve(accounts.charlie, token_id).unwrap();
+            nft.approve(AccountId::from([0u8; 32]), token_id).unwrap();
+            assert_eq!(nft.get_approved(token_id), None);
 
-            // Update ownership
-            self.token_owner.insert(id, &to);
+            set_caller(accounts.charlie);
+            assert_eq!(
+                nft.transfer(accounts.charlie, token_id),
+                Err(Error::NotAuthorized)
+            );
+        }
 
-            self.env().emit_event(Transfer {
-                from: Some(from),
-                to: Some(to),
-                id,
-            });
+        #[ink::test]
+        fn approve_overwrite_emits_event() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
 
-            Ok(())
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+            let token_id = nft
+                .mint(accounts.bob, String::from("ipfs://QmTestHash"))
+                .unwrap();
+
+            set_caller(accounts.bob);
+            nft.approve(accounts.charlie, token_id).unwrap();
+            let before = ink::env::test::recorded_events().count();
+            nft.approve(accounts.django, token_id).unwrap();
+
+            assert_eq!(ink::env::test::recorded_events().count(), before + 1);
+            assert_eq!(nft.get_approved(token_id), Some(accounts.django));
         }
 
-        /// Internal burn function
-        fn burn_token(&mut self, id: TokenId) -> Result<()> {
-            let owner = self.owner_of(id).ok_or(Error::TokenNotFound)?;
+        #[ink::test]
+        fn get_approved_none_for_missing_tokens() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
 
-            // Clear approvals
-            self.token_approvals.remove(id);
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+            let token_id = nft
+                .mint(accounts.alice, String::from("ipfs://QmTestHash"))
+                .unwrap();
+            nft.approve(accounts.charlie, token_id).unwrap();
+
+            assert_eq!(nft.get_approved(token_id + 1), None);
+
+            nft.burn(token_id).unwrap();
+            assert_eq!(nft.get_approved(token_id), None);
+            assert_eq!(
+                nft.approve(accounts.charlie, token_id),
+                Err(Error::TokenNotFound)
+            );
+        }
 
-            // Update balance
-            let count = self.balance_of(owner);
-            self.owned_tokens_count
-                .insert(owner, &(count.saturating_sub(1)));
+        #[ink::test]
+        fn soulbound_tokens_cannot_move() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
 
-            // Remove token
-            self.token_owner.remove(id);
-            self.token_uri.remove(id);
-            self.total_supply = self.total_supply.saturating_sub(1);
+            let mut nft = BeliNft::new(String::from("Diplomas"), String::from("DIP"), None, false);
+            let diploma = nft
+                .mint_soulbound(accounts.bob, String::from("ipfs://QmDiploma"))
+                .unwrap();
+            let regular = nft
+                .mint(accounts.bob, String::from("ipfs://QmArt"))
+                .unwrap();
+            assert!(nft.is_soulbound(diploma));
+            assert!(!nft.is_soulbound(regular));
 
-            self.env().emit_event(Transfer {
-                from: Some(owner),
-                to: None,
-                id,
-            });
+            set_caller(accounts.bob);
+            nft.set_approval_for_all(accounts.charlie, true).unwrap();
+            assert_eq!(
+                nft.transfer(accounts.charlie, diploma),
+                Err(Error::Soulbound)
+            );
+            assert_eq!(
+                nft.approve(accounts.charlie, diploma),
+                Err(Error::Soulbound)
+            );
 
-            Ok(())
+            set_caller(accounts.charlie);
+            assert_eq!(
+                nft.transfer_from(accounts.bob, accounts.charlie, diploma),
+                Err(Error::Soulbound)
+            );
+            assert_eq!(nft.owner_of(diploma), Some(accounts.bob));
+
+            // Regular tokens are unaffected
+            assert!(nft
+                .transfer_from(accounts.bob, accounts.charlie, regular)
+                .is_ok());
         }
 
-        /// Checks if an address is approved or owner
-        fn is_approved_or_owner(&self, spender: AccountId, id: TokenId) -> bool {
-            let owner = match self.owner_of(id) {
-                Some(o) => o,
-                None => return false,
-            };
+        #[ink::test]
+        fn soulbound_burn_by_issuer_or_holder() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
 
-            spender == owner
-                || self.get_approved(id) == Some(spender)
-                || self.is_approved_for_all(owner, spender)
+            let mut nft = BeliNft::new(String::from("Diplomas"), String::from("DIP"), None, false);
+            let revoked = nft
+                .mint_soulbound(accounts.bob, String::from("ipfs://QmDiploma1"))
+                .unwrap();
+            let kept = nft
+                .mint_soulbound(accounts.bob, String::from("ipfs://QmDiploma2"))
+                .unwrap();
+
+            // Issuer revokes
+            assert!(nft.burn(revoked).is_ok());
+            assert_eq!(nft.owner_of(revoked), None);
+            assert!(!nft.is_soulbound(revoked));
+
+            // Third parties cannot burn
+            set_caller(accounts.charlie);
+            assert_eq!(nft.burn(kept), Err(Error::NotAuthorized));
+
+            // Holder can burn
+            set_caller(accounts.bob);
+            assert!(nft.burn(kept).is_ok());
+            assert_eq!(nft.balance_of(accounts.bob), 0);
         }
-    }
 
-    #[cfg(test)]
-    mod tests {
-        use super::*;
+        /// Signs a voucher with `secret` and returns the signer's account id
+        fn sign_voucher(
+            nft: &BeliNft,
+            voucher: &MintVoucher,
+            secret: [u8; 32],
+        ) -> (AccountId, Vec<u8>) {
+            use secp256k1::{Message, PublicKey, Secp256k1, SecretKey};
 
-        fn default_accounts() -> ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment> {
-            ink::env::test::default_accounts::<ink::env::DefaultEnvironment>()
+            let secp = Secp256k1::new();
+            let secret = SecretKey::from_slice(&secret).unwrap();
+            let public_key = PublicKey::from_secret_key(&secp, &secret).serialize();
+
+            let message = Message::from_digest(nft.voucher_hash(voucher.clone()));
+            let (recovery_id, compact) = secp
+                .sign_ecdsa_recoverable(&message, &secret)
+                .serialize_compact();
+
+            let mut signature = compact.to_vec();
+            signature.push(recovery_id.to_i32() as u8);
+
+            let mut account = [0u8; 32];
+            ink::env::hash_bytes::<Blake2x256>(&public_key, &mut account);
+
+            (AccountId::from(account), signature)
         }
 
-        fn set_caller(account: AccountId) {
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(account);
+        fn voucher(to: AccountId, nonce: u64) -> MintVoucher {
+            MintVoucher {
+                to,
+                uri: String::from("ipfs://QmLazy"),
+                price: 100,
+                nonce,
+                deadline: 1_000,
+            }
         }
 
         #[ink::test]
-        fn new_works() {
+        fn redeem_voucher_works() {
             let accounts = default_accounts();
             set_caller(accounts.alice);
 
-            let nft = BeliNft::new(String::from("Belize NFT Collection"), String::from("BNFT"));
+            let mut nft = BeliNft::new(
+                String::from("Belize Art"),
+                String::from("BART"),
+                None,
+                false,
+            );
+            let voucher = voucher(accounts.charlie, 1);
+            let (creator, signature) = sign_voucher(&nft, &voucher, [7u8; 32]);
+            nft.grant_role(MINTER_ROLE, creator).unwrap();
 
-            assert_eq!(nft.collection_name(), String::from("Belize NFT Collection"));
-            assert_eq!(nft.collection_symbol(), String::from("BNFT"));
-            assert_eq!(nft.total_supply(), 0);
-            assert_eq!(nft.contract_owner(), accounts.alice);
+            set_caller(accounts.bob);
+            set_balance(accounts.bob, 1_000);
+            set_balance(contract_id(), 150);
+            set_value(150);
+            let token_id = nft.redeem_voucher(voucher, signature).unwrap();
+
+            assert_eq!(nft.owner_of(token_id), Some(accounts.charlie));
+            assert_eq!(nft.token_uri(token_id), Some(String::from("ipfs://QmLazy")));
+            assert!(nft.is_voucher_used(1));
+            assert_eq!(nft.voucher_proceeds_of(creator), 100);
+            assert_eq!(get_balance(accounts.bob), 1_050);
+
+            // The creator withdraws their proceeds
+            set_caller(creator);
+            set_balance(creator, 0);
+            set_value(0);
+            assert_eq!(nft.withdraw_voucher_proceeds(), Ok(100));
+            assert_eq!(get_balance(creator), 100);
+            assert_eq!(nft.voucher_proceeds_of(creator), 0);
         }
 
         #[ink::test]
-        fn mint_works() {
+        fn redeem_voucher_rejects_replay_and_expiry() {
             let accounts = default_accounts();
             set_caller(accounts.alice);
 
-            let mut nft = BeliNft::new(String::from("Belize NFT"), String::from("BNFT"));
-
-            let uri = String::from("ipfs://QmTestHash");
-            let result = nft.mint(accounts.bob, uri.clone());
-            assert!(result.is_ok());
+            let mut nft = BeliNft::new(
+                String::from("Belize Art"),
+                String::from("BART"),
+                None,
+                false,
+            );
+            let first = voucher(accounts.charlie, 1);
+            let (creator, signature) = sign_voucher(&nft, &first, [7u8; 32]);
+            nft.grant_role(MINTER_ROLE, creator).unwrap();
 
-            let token_id = result.unwrap();
-            assert_eq!(token_id, 1);
-            assert_eq!(nft.owner_of(token_id), Some(accounts.bob));
-            assert_eq!(nft.balance_of(accounts.bob), 1);
-            assert_eq!(nft.total_supply(), 1);
-            assert_eq!(nft.token_uri(token_id), Some(uri));
+            set_caller(accounts.bob);
+            set_value(100);
+            nft.redeem_voucher(first.clone(), signature.clone())
+                .unwrap();
+            assert_eq!(
+                nft.redeem_voucher(first, signature),
+                Err(Error::VoucherUsed)
+            );
+
+            // Expired voucher
+            let late = voucher(accounts.charlie, 2);
+            let (_, signature) = sign_voucher(&nft, &late, [7u8; 32]);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_001);
+            assert_eq!(
+                nft.redeem_voucher(late, signature),
+                Err(Error::VoucherExpired)
+            );
         }
 
         #[ink::test]
-        fn mint_fails_not_owner() {
+        fn redeem_voucher_rejects_bad_signatures() {
             let accounts = default_accounts();
             set_caller(accounts.alice);
 
-            let mut nft = BeliNft::new(String::from("Belize NFT"), String::from("BNFT"));
+            let mut nft = BeliNft::new(
+                String::from("Belize Art"),
+                String::from("BART"),
+                None,
+                false,
+            );
+            let signed = voucher(accounts.charlie, 1);
 
-            // Try to mint as non-owner
+            // Signer without MINTER_ROLE
+            let (_, signature) = sign_voucher(&nft, &signed, [9u8; 32]);
             set_caller(accounts.bob);
-            let uri = String::from("ipfs://QmTestHash");
-            let result = nft.mint(accounts.charlie, uri);
-            assert_eq!(result, Err(Error::NotOwner));
+            set_value(100);
+            assert_eq!(
+                nft.redeem_voucher(signed.clone(), signature.clone()),
+                Err(Error::InvalidSignature)
+            );
+
+            // Tampered voucher recovers a different account
+            let mut tampered = signed.clone();
+            tampered.price = 1;
+            assert_eq!(
+                nft.redeem_voucher(tampered, signature),
+                Err(Error::InvalidSignature)
+            );
+
+            assert_eq!(
+                nft.redeem_voucher(signed, Vec::from([0u8; 10])),
+                Err(Error::InvalidSignature)
+            );
+            assert_eq!(nft.total_supply(), 0);
         }
 
         #[ink::test]
-        fn transfer_works() {
+        fn max_supply_caps_minting() {
             let accounts = default_accounts();
             set_caller(accounts.alice);
 
-            let mut nft = BeliNft::new(String::from("Belize NFT"), String::from("BNFT"));
+            let mut nft = BeliNft::new(
+                String::from("Limited Edition"),
+                String::from("LTD"),
+                Some(3),
+                false,
+            );
+            assert_eq!(nft.max_supply(), Some(3));
+
+            nft.batch_mint(accounts.bob, uris(2)).unwrap();
+            assert_eq!(
+                nft.batch_mint(accounts.bob, uris(2)),
+                Err(Error::MaxSupplyReached)
+            );
+            let last = nft.mint(accounts.bob, String::new()).unwrap();
+            assert_eq!(
+                nft.mint(accounts.bob, String::new()),
+                Err(Error::MaxSupplyReached)
+            );
+
+            // Burning does not reopen minting
+            set_caller(accounts.bob);
+            nft.burn(last).unwrap();
+            assert_eq!(nft.total_supply(), 2);
+            set_caller(accounts.alice);
+            assert_eq!(
+                nft.mint(accounts.bob, String::new()),
+                Err(Error::MaxSupplyReached)
+            );
+        }
 
-            // Mint token to Bob
-            let uri = String::from("ipfs://QmTestHash");
-            let token_id = nft.mint(accounts.bob, uri).unwrap();
+        #[ink::test]
+        fn set_max_supply_only_lowers() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
 
-            // Transfer from Bob to Charlie
-            set_caller(accounts.bob);
-            let result = nft.transfer(accounts.charlie, token_id);
-            assert!(result.is_ok());
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+            nft.batch_mint(accounts.bob, uris(2)).unwrap();
+
+            assert_eq!(nft.set_max_supply(1), Err(Error::InvalidMaxSupply));
+            nft.set_max_supply(5).unwrap();
+            assert_eq!(nft.set_max_supply(6), Err(Error::InvalidMaxSupply));
+            nft.set_max_supply(2).unwrap();
+            assert_eq!(nft.max_supply(), Some(2));
+            assert_eq!(
+                nft.mint(accounts.bob, String::new()),
+                Err(Error::MaxSupplyReached)
+            );
 
-            assert_eq!(nft.owner_of(token_id), Some(accounts.charlie));
-            assert_eq!(nft.balance_of(accounts.bob), 0);
-            assert_eq!(nft.balance_of(accounts.charlie), 1);
+            set_caller(accounts.bob);
+            assert_eq!(nft.set_max_supply(2), Err(Error::NotOwner));
         }
 
         #[ink::test]
-        fn transfer_fails_not_owner() {
+        fn offered_transfer_requires_acceptance() {
             let accounts = default_accounts();
             set_caller(accounts.alice);
 
-            let mut nft = BeliNft::new(String::from("Belize NFT"), String::from("BNFT"));
+            let mut nft = BeliNft::new(
+                String::from("Land Ledger"),
+                String::from("LAND"),
+                None,
+                false,
+            );
+            let parcel = nft
+                .mint(accounts.bob, String::from("ipfs://QmParcel1"))
+                .unwrap();
 
-            let uri = String::from("ipfs://QmTestHash");
-            let token_id = nft.mint(accounts.bob, uri).unwrap();
+            set_caller(accounts.bob);
+            nft.offer_transfer(accounts.charlie, parcel).unwrap();
+            assert_eq!(nft.pending_transfer(parcel), Some(accounts.charlie));
+
+            // Pending offers block normal transfers and burns
+            assert_eq!(
+                nft.transfer(accounts.django, parcel),
+                Err(Error::TransferPending)
+            );
+            assert_eq!(nft.burn(parcel), Err(Error::TransferPending));
+            assert_eq!(
+                nft.offer_transfer(accounts.django, parcel),
+                Err(Error::TransferPending)
+            );
+
+            // Third parties cannot accept
+            set_caller(accounts.django);
+            assert_eq!(nft.accept_transfer(parcel), Err(Error::NotAuthorized));
+            assert_eq!(nft.owner_of(parcel), Some(accounts.bob));
 
-            // Try to transfer as non-owner without approval
             set_caller(accounts.charlie);
-            let result = nft.transfer(accounts.charlie, token_id);
-            assert_eq!(result, Err(Error::NotAuthorized));
+            nft.accept_transfer(parcel).unwrap();
+            assert_eq!(nft.owner_of(parcel), Some(accounts.charlie));
+            assert_eq!(nft.pending_transfer(parcel), None);
+            assert_eq!(nft.accept_transfer(parcel), Err(Error::NoPendingTransfer));
         }
 
         #[ink::test]
-        fn approve_works() {
+        fn cancel_offer_unblocks_transfers() {
             let accounts = default_accounts();
             set_caller(accounts.alice);
 
-            let mut nft = BeliNft::new(String::from("Belize NFT"), String::from("BNFT"));
-
-            let uri = String::from("ipfs://QmTestHash");
-            let token_id = nft.mint(accounts.bob, uri).unwrap();
+            let mut nft = BeliNft::new(
+                String::from("Land Ledger"),
+                String::from("LAND"),
+                None,
+                false,
+            );
+            let parcel = nft
+                .mint(accounts.bob, String::from("ipfs://QmParcel1"))
+                .unwrap();
 
-            // Approve Charlie to transfer Bob's token
             set_caller(accounts.bob);
-            let result = nft.approve(accounts.charlie, token_id);
-            assert!(result.is_ok());
+            nft.offer_transfer(accounts.charlie, parcel).unwrap();
 
-            assert_eq!(nft.get_approved(token_id), Some(accounts.charlie));
+            set_caller(accounts.charlie);
+            assert_eq!(nft.cancel_offer(parcel), Err(Error::NotAuthorized));
+
+            set_caller(accounts.bob);
+            nft.cancel_offer(parcel).unwrap();
+            assert_eq!(nft.pending_transfer(parcel), None);
 
-            // Charlie can now transfer
             set_caller(accounts.charlie);
-            let result = nft.transfer(accounts.charlie, token_id);
-            assert!(result.is_ok());
-            assert_eq!(nft.owner_of(token_id), Some(accounts.charlie));
+            assert_eq!(nft.accept_transfer(parcel), Err(Error::NoPendingTransfer));
+
+            set_caller(accounts.bob);
+            assert!(nft.transfer(accounts.django, parcel).is_ok());
         }
 
         #[ink::test]
-        fn approval_for_all_works() {
+        fn burn_requires_burn_approval() {
             let accounts = default_accounts();
             set_caller(accounts.alice);
 
-            let mut nft = BeliNft::new(String::from("Belize NFT"), String::from("BNFT"));
-
-            // Mint two tokens to Bob
-            let uri1 = String::from("ipfs://QmHash1");
-            let uri2 = String::from("ipfs://QmHash2");
-            let token_id_1 = nft.mint(accounts.bob, uri1).unwrap();
-            let token_id_2 = nft.mint(accounts.bob, uri2).unwrap();
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+            nft.batch_mint(accounts.bob, uris(2)).unwrap();
 
-            // Bob approves Charlie as operator
+            // Transfer approvals do not authorize burning
             set_caller(accounts.bob);
-            let result = nft.set_approval_for_all(accounts.charlie, true);
-            assert!(result.is_ok());
-            assert!(nft.is_approved_for_all(accounts.bob, accounts.charlie));
+            nft.set_approval_for_all(accounts.charlie, true).unwrap();
+            nft.approve(accounts.django, 2).unwrap();
+            set_caller(accounts.charlie);
+            assert_eq!(nft.burn(1), Err(Error::NotAuthorized));
+            set_caller(accounts.django);
+            assert_eq!(nft.burn(2), Err(Error::NotAuthorized));
 
-            // Charlie can transfer both tokens
+            // A burn approval does
+            set_caller(accounts.bob);
+            nft.set_burn_approval(accounts.charlie, true).unwrap();
+            assert!(nft.is_burn_approved(accounts.bob, accounts.charlie));
             set_caller(accounts.charlie);
-            assert!(nft.transfer(accounts.charlie, token_id_1).is_ok());
-            assert!(nft.transfer(accounts.charlie, token_id_2).is_ok());
-            assert_eq!(nft.balance_of(accounts.charlie), 2);
+            assert!(nft.burn(1).is_ok());
+
+            // The owner can always burn
+            set_caller(accounts.bob);
+            nft.set_burn_approval(accounts.charlie, false).unwrap();
+            assert!(nft.burn(2).is_ok());
+            assert_eq!(nft.balance_of(accounts.bob), 0);
         }
 
         #[ink::test]
-        fn burn_works() {
+        fn legacy_burn_keeps_operator_burns() {
             let accounts = default_accounts();
             set_caller(accounts.alice);
 
-            let mut nft = BeliNft::new(String::from("Belize NFT"), String::from("BNFT"));
-
-            let uri = String::from("ipfs://QmTestHash");
-            let token_id = nft.mint(accounts.bob, uri).unwrap();
-            assert_eq!(nft.total_supply(), 1);
+            let mut nft =
+                BeliNft::new(String::from("Belize NFT"), String::from("BNFT"), None, true);
+            assert!(nft.legacy_burn());
+            let token_id = nft.mint(accounts.bob, String::new()).unwrap();
 
-            // Bob burns his token
             set_caller(accounts.bob);
-            let result = nft.burn(token_id);
-            assert!(result.is_ok());
-
-            assert_eq!(nft.owner_of(token_id), None);
-            assert_eq!(nft.balance_of(accounts.bob), 0);
-            assert_eq!(nft.total_supply(), 0);
+            nft.set_approval_for_all(accounts.charlie, true).unwrap();
+            set_caller(accounts.charlie);
+            assert!(nft.burn(token_id).is_ok());
         }
 
         #[ink::test]
-        fn transfer_ownership_works() {
+        fn safe_transfer_to_account_skips_hook() {
             let accounts = default_accounts();
             set_caller(accounts.alice);
 
-            let mut nft = BeliNft::new(String::from("Belize NFT"), String::from("BNFT"));
+            let mut nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+            let token_id = nft.mint(accounts.bob, String::new()).unwrap();
 
-            assert_eq!(nft.contract_owner(), accounts.alice);
+            set_caller(accounts.bob);
+            assert!(nft
+                .safe_transfer_from(accounts.bob, accounts.charlie, token_id, b"ref".to_vec())
+                .is_ok());
+            assert_eq!(nft.owner_of(token_id), Some(accounts.charlie));
 
-            let result = nft.transfer_ownership(accounts.bob);
-            assert!(result.is_ok());
-            assert_eq!(nft.contract_owner(), accounts.bob);
+            // The usual transfer checks still apply
+            assert_eq!(
+                nft.safe_transfer_from(accounts.charlie, accounts.bob, token_id, Vec::new()),
+                Err(Error::NotAuthorized)
+            );
+        }
 
-            // Alice can no longer mint
-            let uri = String::from("ipfs://QmTestHash");
+        fn psp34_collection() -> (
+            ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment>,
+            BeliNft,
+        ) {
+            let accounts = default_accounts();
             set_caller(accounts.alice);
-            let result = nft.mint(accounts.charlie, uri.clone());
-            assert_eq!(result, Err(Error::NotOwner));
 
-            // Bob can mint
+            let nft = BeliNft::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+            (accounts, nft)
+        }
+
+        #[ink::test]
+        fn psp34_selectors_match_standard() {
+            assert_eq!(
+                ink::selector_bytes!("PSP34::collection_id"),
+                [0xff, 0xa2, 0x7a, 0x5f]
+            );
+            assert_eq!(
+                ink::selector_bytes!("PSP34::balance_of"),
+                [0xcd, 0xe7, 0xe5, 0x5f]
+            );
+            assert_eq!(
+                ink::selector_bytes!("PSP34::owner_of"),
+                [0x11, 0x68, 0x62, 0x4d]
+            );
+            assert_eq!(
+                ink::selector_bytes!("PSP34::allowance"),
+                [0x47, 0x90, 0xf5, 0x5a]
+            );
+            assert_eq!(
+                ink::selector_bytes!("PSP34::approve"),
+                [0x19, 0x32, 0xa8, 0xb0]
+            );
+            assert_eq!(
+                ink::selector_bytes!("PSP34::transfer"),
+                [0x31, 0x28, 0xd6, 0x1b]
+            );
+            assert_eq!(
+                ink::selector_bytes!("PSP34::total_supply"),
+                [0x62, 0x84, 0x13, 0xfe]
+            );
+        }
+
+        #[ink::test]
+        fn id_maps_to_token_id() {
+            assert_eq!(BeliNft::token_id_of(&Id::U32(7)), Some(7));
+            assert_eq!(BeliNft::token_id_of(&Id::U32(u32::MAX)), Some(u32::MAX));
+            assert_eq!(BeliNft::token_id_of(&Id::U8(7)), None);
+            assert_eq!(BeliNft::token_id_of(&Id::U128(7)), None);
+            assert_eq!(BeliNft::token_id_of(&Id::Bytes(vec![7])), None);
+        }
+
+        #[ink::test]
+        fn psp34_views_match_simple_api() {
+            let (accounts, mut nft) = psp34_collection();
+            let token_id = nft.mint(accounts.bob, String::new()).unwrap();
+
+            assert_eq!(PSP34::owner_of(&nft, Id::U32(token_id)), Some(accounts.bob));
+            assert_eq!(PSP34::owner_of(&nft, Id::U64(token_id as u64)), None);
+            assert_eq!(PSP34::owner_of(&nft, Id::U32(99)), None);
+            assert_eq!(PSP34::balance_of(&nft, accounts.bob), 1);
+            assert_eq!(PSP34::total_supply(&nft), 1);
+            assert_eq!(
+                PSP34::collection_id(&nft),
+                Id::Bytes(
+                    ink::env::account_id::<ink::env::DefaultEnvironment>()
+                        .as_ref()
+                        .to_vec()
+                )
+            );
+        }
+
+        #[ink::test]
+        fn psp34_transfer_works() {
+            let (accounts, mut nft) = psp34_collection();
+            let token_id = nft.mint(accounts.bob, String::new()).unwrap();
+
             set_caller(accounts.bob);
-            let result = nft.mint(accounts.charlie, uri);
-            assert!(result.is_ok());
+            assert_eq!(
+                PSP34::transfer(&mut nft, accounts.charlie, Id::U32(token_id), Vec::new()),
+                Ok(())
+            );
+            assert_eq!(nft.owner_of(token_id), Some(accounts.charlie));
+
+            // Errors map onto PSP34Error
+            assert_eq!(
+                PSP34::transfer(&mut nft, accounts.bob, Id::U32(token_id), Vec::new()),
+                Err(PSP34Error::NotApproved)
+            );
+            assert_eq!(
+                PSP34::transfer(&mut nft, accounts.bob, Id::U8(1), Vec::new()),
+                Err(PSP34Error::TokenNotExists)
+            );
         }
 
         #[ink::test]
-        fn multiple_mints_increment_ids() {
-            let accounts = default_accounts();
-            set_caller(accounts.alice);
+        fn psp34_approve_and_allowance_work() {
+            let (accounts, mut nft) = psp34_collection();
+            let token_id = nft.mint(accounts.bob, String::new()).unwrap();
+            let id = Some(Id::U32(token_id));
 
-            let mut nft = BeliNft::new(String::from("Belize NFT"), String::from("BNFT"));
+            set_caller(accounts.bob);
 
-            let uri1 = String::from("ipfs://QmHash1");
-            let uri2 = String::from("ipfs://QmHash2");
-            let uri3 = String::from("ipfs://QmHash3");
+            // Single token
+            assert_eq!(
+                PSP34::approve(&mut nft, accounts.charlie, id.clone(), true),
+                Ok(())
+            );
+            assert_eq!(nft.get_approved(token_id), Some(accounts.charlie));
+            assert!(PSP34::allowance(
+                &nft,
+                accounts.bob,
+                accounts.charlie,
+                id.clone()
+            ));
+            // The owner holds no stored approval over its own token
+            assert!(!PSP34::allowance(
+                &nft,
+                accounts.bob,
+                accounts.bob,
+                id.clone()
+            ));
+            assert!(!PSP34::allowance(
+                &nft,
+                accounts.bob,
+                accounts.charlie,
+                None
+            ));
+            assert_eq!(
+                PSP34::approve(&mut nft, accounts.charlie, id.clone(), false),
+                Ok(())
+            );
+            assert_eq!(nft.get_approved(token_id), None);
+            assert!(!PSP34::allowance(
+                &nft,
+                accounts.bob,
+                accounts.charlie,
+                id.clone()
+            ));
+
+            // All tokens
+            assert_eq!(
+                PSP34::approve(&mut nft, accounts.django, None, true),
+                Ok(())
+            );
+            assert!(nft.is_approved_for_all(accounts.bob, accounts.django));
+            assert!(PSP34::allowance(&nft, accounts.bob, accounts.django, None));
+            assert!(PSP34::allowance(
+                &nft,
+                accounts.bob,
+                accounts.django,
+                id.clone()
+            ));
+
+            // The approved operator can move the token through PSP34
+            set_caller(accounts.django);
+            assert_eq!(
+                PSP34::transfer(&mut nft, accounts.eve, Id::U32(token_id), Vec::new()),
+                Ok(())
+            );
+            assert_eq!(nft.owner_of(token_id), Some(accounts.eve));
+
+            set_caller(accounts.eve);
+            assert_eq!(
+                PSP34::approve(&mut nft, accounts.eve, id, true),
+                Err(PSP34Error::SelfApprove)
+            );
+            assert_eq!(
+                PSP34::approve(&mut nft, accounts.bob, Some(Id::U32(99)), false),
+                Err(PSP34Error::TokenNotExists)
+            );
+        }
+    }
 
-            let token_1 = nft.mint(accounts.bob, uri1).unwrap();
-            let token_2 = nft.mint(accounts.bob, uri2).unwrap();
-            let token_3 = nft.mint(accounts.bob, uri3).unwrap();
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use ink_e2e::ContractsBackend;
+        use token_receiver_mock::token_receiver_mock::{TokenReceiverMock, TokenReceiverMockRef};
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn e2e_safe_transfer_calls_receiver<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+
+            let mut nft_constructor = BeliNftRef::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+            let nft = client
+                .instantiate("beli_nft", &ink_e2e::alice(), &mut nft_constructor)
+                .submit()
+                .await
+                .expect("beli_nft instantiate failed");
+            let mut nft_call = nft.call_builder::<BeliNft>();
+
+            let mut accepting_constructor = TokenReceiverMockRef::new(true);
+            let accepting = client
+                .instantiate(
+                    "token_receiver_mock",
+                    &ink_e2e::alice(),
+                    &mut accepting_constructor,
+                )
+                .submit()
+                .await
+                .expect("token_receiver_mock instantiate failed");
+            let accepting_call = accepting.call_builder::<TokenReceiverMock>();
+
+            let mut rejecting_constructor = TokenReceiverMockRef::new(false);
+            let rejecting = client
+                .instantiate(
+                    "token_receiver_mock",
+                    &ink_e2e::alice(),
+                    &mut rejecting_constructor,
+                )
+                .submit()
+                .await
+                .expect("token_receiver_mock instantiate failed");
+
+            let token_id = client
+                .call(&ink_e2e::alice(), &nft_call.mint(alice, String::new()))
+                .submit()
+                .await
+                .expect("mint failed")
+                .return_value()
+                .expect("mint reverted");
+
+            // A rejecting receiver reverts the whole transfer
+            let rejected = client
+                .call(
+                    &ink_e2e::alice(),
+                    &nft_call.safe_transfer_from(alice, rejecting.account_id, token_id, Vec::new()),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(rejected, Err(Error::TransferRejected));
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &nft_call.safe_transfer_from(
+                        alice,
+                        accepting.account_id,
+                        token_id,
+                        b"deed".to_vec(),
+                    ),
+                )
+                .submit()
+                .await
+                .expect("safe_transfer_from failed");
+
+            let owner = client
+                .call(&ink_e2e::alice(), &nft_call.owner_of(token_id))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(owner, Some(accepting.account_id));
+
+            let last = client
+                .call(&ink_e2e::alice(), &accepting_call.last_psp37_received())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(
+                last,
+                Some((
+                    alice,
+                    alice,
+                    vec![u128::from(token_id)],
+                    vec![1],
+                    b"deed".to_vec()
+                ))
+            );
 
-            assert_eq!(token_1, 1);
-            assert_eq!(token_2, 2);
-            assert_eq!(token_3, 3);
-            assert_eq!(nft.balance_of(accounts.bob), 3);
-            assert_eq!(nft.total_supply(), 3);
+            Ok(())
         }
     }
 }
@@ -0,0 +1,78 @@
+//! PSP34 Trait Reference
+//!
+//! Defines the PSP34 (Polkadot NFT standard) `Id` type, error type and core
+//! interface. Selectors are pinned to the standard so wallets, marketplaces
+//! and other contracts can call any compliant collection (including BeliNFT)
+//! without depending on the implementing crate.
+
+use ink::prelude::{string::String, vec::Vec};
+use ink::primitives::AccountId;
+
+/// PSP34 token identifier
+///
+/// Collections pick whichever variant fits their ids; BeliNFT uses `U32`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum Id {
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    Bytes(Vec<u8>),
+}
+
+/// PSP34 Error types
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub enum PSP34Error {
+    /// Custom error type for implementation-specific errors
+    Custom(String),
+    /// Returned when the owner approves themselves
+    SelfApprove,
+    /// Returned when the caller is not approved for the operation
+    NotApproved,
+    /// Returned when minting an id that already exists
+    TokenExists,
+    /// Returned when the token does not exist
+    TokenNotExists,
+    /// Returned when a safe transfer check fails
+    SafeTransferCheckFailed(String),
+}
+
+pub type Result<T> = core::result::Result<T, PSP34Error>;
+
+/// PSP34 NFT Interface
+#[ink::trait_definition]
+pub trait PSP34 {
+    /// Returns the collection id
+    #[ink(message, selector = 0xffa27a5f)]
+    fn collection_id(&self) -> Id;
+
+    /// Returns the number of tokens owned by `owner`
+    #[ink(message, selector = 0xcde7e55f)]
+    fn balance_of(&self, owner: AccountId) -> u32;
+
+    /// Returns the owner of the token, if it exists
+    #[ink(message, selector = 0x1168624d)]
+    fn owner_of(&self, id: Id) -> Option<AccountId>;
+
+    /// Returns whether `operator` may transfer the token `id` of `owner`,
+    /// or all of its tokens if `id` is `None`
+    #[ink(message, selector = 0x4790f55a)]
+    fn allowance(&self, owner: AccountId, operator: AccountId, id: Option<Id>) -> bool;
+
+    /// Approves or disapproves `operator` for the token `id` of the caller,
+    /// or for all of its tokens if `id` is `None`
+    #[ink(message, selector = 0x1932a8b0)]
+    fn approve(&mut self, operator: AccountId, id: Option<Id>, approved: bool) -> Result<()>;
+
+    /// Transfers the token `id` to `to`
+    #[ink(message, selector = 0x3128d61b)]
+    fn transfer(&mut self, to: AccountId, id: Id, data: Vec<u8>) -> Result<()>;
+
+    /// Returns the number of tokens in circulation
+    #[ink(message, selector = 0x628413fe)]
+    fn total_supply(&self) -> u128;
+}
@@ -1,16 +1,31 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
+#[path = "../dex/psp22_trait.rs"]
+pub mod psp22_trait;
+
 /// # DALLA Token - PSP22 Compliant
 ///
 /// The official wrapped token for BelizeChain's native DALLA currency.
 /// Implements the PSP22 standard (Polkadot's ERC20 equivalent).
 ///
 /// ## Features
-/// - PSP22 standard compliance (transfer, approve, transferFrom)
-/// - Minting and burning (controlled by owner)
+/// - PSP22 standard compliance (transfer, approve, transferFrom) with the
+///   standard selectors; the inherent messages are kept as aliases
+/// - Minting by MINTER_ROLE holders, with optional per-minter budgets
+/// - Burning from the caller's own balance, or on a holder's behalf via allowance
 /// - Total supply tracking
 /// - Event emission for all operations
 /// - Allowance management
+/// - Signature-based approvals (`permit`)
+/// - Checkpointed balances and supply for governance snapshots
+/// - Emergency pause and per-account freezes for compliance
+/// - Batch transfers and transfers carrying a remittance memo
+/// - Receiver hooks (`transfer_to_contract`) so contracts can accept or refuse tokens
+/// - Linear vesting schedules with a cliff, escrowed by the contract
+/// - Optional transfer fee (at most 1%) credited to a collector such as the
+///   DAO treasury, with exemptions for the DEX and other system accounts
+/// - Configurable metadata and supply cap (`new_with_metadata`) so the same
+///   code can back other BelizeChain assets
 ///
 /// ## Economics
 /// - Symbol: DALLA
@@ -19,10 +34,36 @@
 /// - Initial Supply: 21 million DALLA (like Bitcoin's 21M)
 
 #[ink::contract]
-mod dalla_token {
-    use ink::prelude::string::String;
+pub mod dalla_token {
+    use crate::psp22_trait::{
+        self, PSP22Burnable, PSP22Error, PSP22Metadata, PSP22Receiver, PSP22,
+    };
+    use access_control::access_control::{
+        AccessControlData, DEFAULT_ADMIN_ROLE, MINTER_ROLE, PAUSER_ROLE,
+    };
+    use access_control::pausable::PausableData;
+    use access_control::{AccessError, RoleType};
+    use ink::codegen::TraitCallBuilder;
+    use ink::env::call::{build_call, ExecutionInput, Selector};
+    use ink::env::hash::Blake2x256;
+    use ink::prelude::{string::String, vec::Vec};
     use ink::storage::Mapping;
 
+    /// Role allowed to freeze and unfreeze accounts
+    pub const COMPLIANCE_ROLE: RoleType = 5;
+
+    /// Maximum recipients in a single batch transfer (keeps weight bounded)
+    pub const MAX_BATCH_SIZE: u32 = 100;
+
+    /// Maximum memo length in bytes for `transfer_with_memo`
+    pub const MAX_MEMO_LENGTH: u32 = 128;
+
+    /// Maximum vesting schedules per beneficiary (keeps `claim_vested` bounded)
+    pub const MAX_VESTING_SCHEDULES: u32 = 20;
+
+    /// Maximum transfer fee in basis points (100 = 1%)
+    pub const MAX_TRANSFER_FEE_BPS: u16 = 100;
+
     /// The DALLA token error types
     #[derive(Debug, PartialEq, Eq)]
     #[ink::scale_derive(Encode, Decode, TypeInfo)]
@@ -33,30 +74,202 @@ mod dalla_token {
         InsufficientAllowance,
         /// Transfer to zero address
         InvalidRecipient,
-        /// Mint/burn only allowed by owner
+        /// Caller lacks the owner rights or role required for this operation
         UnauthorizedAccess,
         /// Minting would exceed max supply
         ExceedsMaxSupply,
         /// Arithmetic overflow
         Overflow,
+        /// Permit signature does not recover to the owner
+        InvalidSignature,
+        /// Permit deadline has passed
+        PermitExpired,
+        /// Permit was signed for a nonce that has already been consumed
+        NonceUsed,
+        /// Mint exceeds the minter's remaining emission allowance
+        MinterAllowanceExceeded,
+        /// Transfers, mints and burns are paused
+        Paused,
+        /// The token is not paused
+        NotPaused,
+        /// Sender or recipient account is frozen
+        AccountFrozen,
+        /// Batch is empty or exceeds MAX_BATCH_SIZE
+        InvalidBatchSize,
+        /// Input vectors have different lengths
+        LengthMismatch,
+        /// Memo exceeds MAX_MEMO_LENGTH
+        MemoTooLong,
+        /// Receiving contract refused the transfer (or has no receiver hook)
+        TransferRejected,
+        /// Vesting total is zero, duration is zero, or the cliff exceeds the duration
+        InvalidSchedule,
+        /// Beneficiary already has MAX_VESTING_SCHEDULES schedules
+        TooManySchedules,
+        /// DALLA itself cannot be pulled out through `recover_token`
+        CannotRecoverSelf,
+        /// Transfer call on the foreign token failed
+        RecoveryFailed,
+        /// Strict approvals are on: reset the allowance to zero first, or use
+        /// increase_allowance / decrease_allowance
+        NonZeroToNonZeroApproval,
+        /// Transfer fee above MAX_TRANSFER_FEE_BPS
+        FeeTooHigh,
     }
 
     /// Result type for DALLA operations
     pub type Result<T> = core::result::Result<T, Error>;
 
+    impl From<AccessError> for Error {
+        fn from(error: AccessError) -> Self {
+            match error {
+                AccessError::Paused => Error::Paused,
+                AccessError::NotPaused => Error::NotPaused,
+                _ => Error::UnauthorizedAccess,
+            }
+        }
+    }
+
+    /// A value recorded at the end of a block
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Checkpoint {
+        /// Block the value was written in
+        pub block: u32,
+        /// Balance (or total supply) as of that block
+        pub value: u128,
+    }
+
+    /// Tokens released linearly to a beneficiary
+    ///
+    /// `cliff` and `duration` are offsets from `start`, in block timestamp units.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct VestingSchedule {
+        /// Tokens escrowed for the schedule
+        pub total: u128,
+        /// Tokens already released
+        pub claimed: u128,
+        /// Vesting start timestamp
+        pub start: u64,
+        /// Nothing is claimable until `start + cliff`
+        pub cliff: u64,
+        /// Everything is claimable from `start + duration`
+        pub duration: u64,
+    }
+
+    impl VestingSchedule {
+        /// Amount vested (claimed or not) at timestamp `now`
+        fn vested_at(&self, now: u64) -> u128 {
+            let elapsed = now.saturating_sub(self.start);
+            if elapsed < self.cliff {
+                return 0;
+            }
+            if elapsed >= self.duration {
+                return self.total;
+            }
+
+            // total * elapsed / duration, split so the product fits in u128
+            let elapsed = u128::from(elapsed);
+            let duration = u128::from(self.duration);
+            (self.total / duration)
+                .saturating_mul(elapsed)
+                .saturating_add((self.total % duration).saturating_mul(elapsed) / duration)
+        }
+
+        /// Amount vested but not yet claimed at timestamp `now`
+        fn claimable_at(&self, now: u64) -> u128 {
+            self.vested_at(now).saturating_sub(self.claimed)
+        }
+    }
+
+    impl From<Error> for PSP22Error {
+        fn from(error: Error) -> Self {
+            match error {
+                Error::InsufficientBalance => PSP22Error::InsufficientBalance,
+                Error::InsufficientAllowance => PSP22Error::InsufficientAllowance,
+                Error::InvalidRecipient => PSP22Error::InvalidRecipient,
+                Error::UnauthorizedAccess => PSP22Error::Custom(String::from("UnauthorizedAccess")),
+                Error::ExceedsMaxSupply => PSP22Error::Custom(String::from("ExceedsMaxSupply")),
+                Error::Overflow => PSP22Error::Custom(String::from("Overflow")),
+                Error::InvalidSignature => PSP22Error::Custom(String::from("InvalidSignature")),
+                Error::PermitExpired => PSP22Error::Custom(String::from("PermitExpired")),
+                Error::NonceUsed => PSP22Error::Custom(String::from("NonceUsed")),
+                Error::MinterAllowanceExceeded => {
+                    PSP22Error::Custom(String::from("MinterAllowanceExceeded"))
+                }
+                Error::Paused => PSP22Error::Custom(String::from("Paused")),
+                Error::NotPaused => PSP22Error::Custom(String::from("NotPaused")),
+                Error::AccountFrozen => PSP22Error::Custom(String::from("AccountFrozen")),
+                Error::InvalidBatchSize => PSP22Error::Custom(String::from("InvalidBatchSize")),
+                Error::LengthMismatch => PSP22Error::Custom(String::from("LengthMismatch")),
+                Error::MemoTooLong => PSP22Error::Custom(String::from("MemoTooLong")),
+                Error::TransferRejected => PSP22Error::Custom(String::from("TransferRejected")),
+                Error::InvalidSchedule => PSP22Error::Custom(String::from("InvalidSchedule")),
+                Error::TooManySchedules => PSP22Error::Custom(String::from("TooManySchedules")),
+                Error::CannotRecoverSelf => PSP22Error::Custom(String::from("CannotRecoverSelf")),
+                Error::RecoveryFailed => PSP22Error::Custom(String::from("RecoveryFailed")),
+                Error::NonZeroToNonZeroApproval => {
+                    PSP22Error::Custom(String::from("NonZeroToNonZeroApproval"))
+                }
+                Error::FeeTooHigh => PSP22Error::Custom(String::from("FeeTooHigh")),
+            }
+        }
+    }
+
     /// The DALLA token storage
     #[ink(storage)]
     pub struct DallaToken {
         /// Total supply of DALLA tokens
         total_supply: u128,
-        /// Maximum supply cap (100M DALLA)
-        max_supply: u128,
+        /// Maximum supply cap (100M for DALLA); `None` is uncapped
+        max_supply: Option<u128>,
         /// Mapping from account to balance
         balances: Mapping<AccountId, u128>,
         /// Mapping from (owner, spender) to allowance
         allowances: Mapping<(AccountId, AccountId), u128>,
-        /// Contract owner (can mint/burn)
+        /// Contract owner; always holds DEFAULT_ADMIN_ROLE
         owner: AccountId,
+        /// Next permit nonce for each owner
+        nonces: Mapping<AccountId, u64>,
+        /// Balance history per account, indexed by position
+        checkpoints: Mapping<(AccountId, u32), Checkpoint>,
+        /// Number of balance checkpoints per account
+        checkpoint_counts: Mapping<AccountId, u32>,
+        /// Total supply history, indexed by position
+        supply_checkpoints: Mapping<u32, Checkpoint>,
+        /// Number of total supply checkpoints
+        supply_checkpoint_count: u32,
+        /// Role assignments (DEFAULT_ADMIN_ROLE, MINTER_ROLE, PAUSER_ROLE,
+        /// COMPLIANCE_ROLE)
+        access_control: AccessControlData,
+        /// Remaining emission budget per minter; minters without an entry
+        /// are only bounded by the max supply
+        minter_allowance: Mapping<AccountId, u128>,
+        /// Global transfer/mint/burn halt
+        pausable: PausableData,
+        /// Accounts blocked from sending or receiving
+        frozen: Mapping<AccountId, ()>,
+        /// Vesting schedules per beneficiary, indexed by position
+        vesting_schedules: Mapping<(AccountId, u32), VestingSchedule>,
+        /// Number of vesting schedules per beneficiary
+        vesting_counts: Mapping<AccountId, u32>,
+        /// Token name
+        name: String,
+        /// Token symbol
+        symbol: String,
+        /// Token decimals
+        decimals: u8,
+        /// Whether `approve` may only move an allowance to or from zero
+        strict_approvals: bool,
+        /// Transfer fee in basis points; 0 turns the fee off
+        transfer_fee_bps: u16,
+        /// Account credited with transfer fees
+        fee_collector: Option<AccountId>,
+        /// Accounts whose transfers (sent or received) are never charged a fee
+        fee_exempt: Mapping<AccountId, ()>,
     }
 
     /// Event emitted when tokens are transferred
@@ -69,6 +282,19 @@ mod dalla_token {
         value: u128,
     }
 
+    /// Event emitted alongside `Transfer` by `transfer_with_memo`
+    #[ink(event)]
+    pub struct TransferMemo {
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+        value: u128,
+        /// blake2_256 of the memo bytes
+        #[ink(topic)]
+        memo_hash: [u8; 32],
+    }
+
     /// Event emitted when allowance is approved
     #[ink(event)]
     pub struct Approval {
@@ -79,15 +305,169 @@ mod dalla_token {
         value: u128,
     }
 
+    /// Event emitted when a minter's emission allowance changes
+    #[ink(event)]
+    pub struct MinterAllowanceSet {
+        #[ink(topic)]
+        minter: AccountId,
+        allowance: Option<u128>,
+    }
+
+    /// Event emitted when an account is frozen
+    #[ink(event)]
+    pub struct AccountFrozen {
+        #[ink(topic)]
+        account: AccountId,
+        #[ink(topic)]
+        by: AccountId,
+    }
+
+    /// Event emitted when an account is unfrozen
+    #[ink(event)]
+    pub struct AccountUnfrozen {
+        #[ink(topic)]
+        account: AccountId,
+        #[ink(topic)]
+        by: AccountId,
+    }
+
+    /// Event emitted when tokens are escrowed into a vesting schedule
+    #[ink(event)]
+    pub struct VestingCreated {
+        #[ink(topic)]
+        beneficiary: AccountId,
+        #[ink(topic)]
+        creator: AccountId,
+        total: u128,
+        start: u64,
+        cliff: u64,
+        duration: u64,
+    }
+
+    /// Event emitted when vested tokens are released
+    #[ink(event)]
+    pub struct VestingClaimed {
+        #[ink(topic)]
+        beneficiary: AccountId,
+        amount: u128,
+    }
+
+    /// Event emitted when foreign tokens are sent out of the contract
+    #[ink(event)]
+    pub struct TokenRecovered {
+        #[ink(topic)]
+        token: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+        amount: u128,
+    }
+
+    /// Event emitted when strict approval mode is toggled
+    #[ink(event)]
+    pub struct StrictApprovalsSet {
+        enabled: bool,
+    }
+
+    /// Event emitted when the transfer fee configuration changes
+    #[ink(event)]
+    pub struct TransferFeeSet {
+        bps: u16,
+        #[ink(topic)]
+        collector: Option<AccountId>,
+    }
+
+    /// Event emitted when an account's fee exemption changes
+    #[ink(event)]
+    pub struct FeeExemptionSet {
+        #[ink(topic)]
+        account: AccountId,
+        exempt: bool,
+    }
+
+    /// Event emitted alongside `Transfer` when a transfer is charged a fee
+    #[ink(event)]
+    pub struct FeeCollected {
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+        #[ink(topic)]
+        collector: AccountId,
+        fee: u128,
+    }
+
     impl DallaToken {
         /// Creates a new DALLA token contract with initial supply
         #[ink(constructor)]
         pub fn new(initial_supply: u128) -> Self {
+            Self::new_with_metadata(
+                String::from("DALLA Token"),
+                String::from("DALLA"),
+                12,
+                initial_supply,
+                Some(100_000_000_000_000_000_000_u128), // 100M DALLA
+            )
+        }
+
+        /// Creates a token with custom metadata and supply cap
+        ///
+        /// `max_supply` of `None` leaves minting uncapped.
+        ///
+        /// # Panics
+        ///
+        /// If `initial_supply` exceeds `max_supply`.
+        #[ink(constructor)]
+        pub fn new_with_metadata(
+            name: String,
+            symbol: String,
+            decimals: u8,
+            initial_supply: u128,
+            max_supply: Option<u128>,
+        ) -> Self {
+            if max_supply.is_some_and(|cap| initial_supply > cap) {
+                panic!("Initial supply exceeds max supply");
+            }
+
             let caller = Self::env().caller();
-            let max_supply = 100_000_000_000_000_000_000_u128; // 100M DALLA
+            let now = Self::env().block_timestamp();
+
+            // The deployer starts with every role (minting unbudgeted); the
+            // grants cannot fail because the deployer already holds
+            // DEFAULT_ADMIN_ROLE.
+            let mut access_control = AccessControlData::new(caller);
+            for role in [MINTER_ROLE, PAUSER_ROLE, COMPLIANCE_ROLE] {
+                let _ = access_control.grant_role(caller, role, caller, now, |event| {
+                    Self::env().emit_event(event);
+                });
+            }
 
-            let mut balances = Mapping::default();
-            balances.insert(caller, &initial_supply);
+            let mut instance = Self {
+                total_supply: 0,
+                max_supply,
+                balances: Mapping::default(),
+                allowances: Mapping::default(),
+                owner: caller,
+                nonces: Mapping::default(),
+                checkpoints: Mapping::default(),
+                checkpoint_counts: Mapping::default(),
+                supply_checkpoints: Mapping::default(),
+                supply_checkpoint_count: 0,
+                access_control,
+                minter_allowance: Mapping::default(),
+                pausable: PausableData::new(),
+                frozen: Mapping::default(),
+                vesting_schedules: Mapping::default(),
+                vesting_counts: Mapping::default(),
+                name,
+                symbol,
+                decimals,
+                strict_approvals: false,
+                transfer_fee_bps: 0,
+                fee_collector: None,
+                fee_exempt: Mapping::default(),
+            };
+            instance.set_balance(caller, initial_supply);
+            instance.set_total_supply(initial_supply);
 
             Self::env().emit_event(Transfer {
                 from: None,
@@ -95,31 +475,25 @@ mod dalla_token {
                 value: initial_supply,
             });
 
-            Self {
-                total_supply: initial_supply,
-                max_supply,
-                balances,
-                allowances: Mapping::default(),
-                owner: caller,
-            }
+            instance
         }
 
         /// Returns the token name
         #[ink(message)]
         pub fn token_name(&self) -> String {
-            String::from("DALLA Token")
+            self.name.clone()
         }
 
         /// Returns the token symbol
         #[ink(message)]
         pub fn token_symbol(&self) -> String {
-            String::from("DALLA")
+            self.symbol.clone()
         }
 
         /// Returns the token decimals
         #[ink(message)]
         pub fn token_decimals(&self) -> u8 {
-            12
+            self.decimals
         }
 
         /// Returns the total supply
@@ -128,63 +502,171 @@ mod dalla_token {
             self.total_supply
         }
 
-        /// Returns the maximum supply cap
+        /// Returns the maximum supply cap (`None` if uncapped)
         #[ink(message)]
-        pub fn max_supply(&self) -> u128 {
+        pub fn max_supply(&self) -> Option<u128> {
             self.max_supply
         }
 
         /// Returns the balance of an account
+        ///
+        /// Alias of `PSP22::balance_of` kept for existing callers.
         #[ink(message)]
         pub fn balance_of(&self, owner: AccountId) -> u128 {
             self.balances.get(owner).unwrap_or(0)
         }
 
+        /// Returns the balance an account held at the end of `block`
+        ///
+        /// Binary search over the account's checkpoints; accounts with no
+        /// history before `block` report zero.
+        #[ink(message)]
+        pub fn balance_of_at(&self, account: AccountId, block: u32) -> u128 {
+            let count = self.checkpoint_counts.get(account).unwrap_or(0);
+            Self::lookup(count, block, |index| self.checkpoints.get((account, index)))
+        }
+
+        /// Returns the total supply at the end of `block`
+        #[ink(message)]
+        pub fn total_supply_at(&self, block: u32) -> u128 {
+            Self::lookup(self.supply_checkpoint_count, block, |index| {
+                self.supply_checkpoints.get(index)
+            })
+        }
+
+        /// Returns the number of balance checkpoints recorded for an account
+        #[ink(message)]
+        pub fn num_checkpoints(&self, account: AccountId) -> u32 {
+            self.checkpoint_counts.get(account).unwrap_or(0)
+        }
+
         /// Returns the allowance granted by owner to spender
+        ///
+        /// Alias of `PSP22::allowance` kept for existing callers.
         #[ink(message)]
         pub fn allowance(&self, owner: AccountId, spender: AccountId) -> u128 {
             self.allowances.get((owner, spender)).unwrap_or(0)
         }
 
         /// Transfers tokens from caller to recipient
+        ///
+        /// Alias of `PSP22::transfer` kept for existing callers.
         #[ink(message)]
         pub fn transfer(&mut self, to: AccountId, value: u128) -> Result<()> {
             let from = self.env().caller();
             self.transfer_from_to(from, to, value)
         }
 
-        /// Approves spender to spend tokens on behalf of caller
+        /// Transfers `amounts[i]` to `recipients[i]` for every index
+        ///
+        /// The whole batch is validated against the caller's balance before
+        /// any transfer is made, so it either fully succeeds or fails.
         #[ink(message)]
-        pub fn approve(&mut self, spender: AccountId, value: u128) -> Result<()> {
-            let owner = self.env().caller();
-            self.allowances.insert((owner, spender), &value);
+        pub fn batch_transfer(
+            &mut self,
+            recipients: Vec<AccountId>,
+            amounts: Vec<u128>,
+        ) -> Result<()> {
+            let from = self.env().caller();
 
-            self.env().emit_event(Approval {
-                owner,
-                spender,
+            if recipients.len() != amounts.len() {
+                return Err(Error::LengthMismatch);
+            }
+
+            if recipients.is_empty() || recipients.len() > MAX_BATCH_SIZE as usize {
+                return Err(Error::InvalidBatchSize);
+            }
+
+            let mut total: u128 = 0;
+            for amount in &amounts {
+                total = total.checked_add(*amount).ok_or(Error::Overflow)?;
+            }
+            if self.balance_of(from) < total {
+                return Err(Error::InsufficientBalance);
+            }
+
+            self.pausable.ensure_not_paused()?;
+            self.ensure_not_frozen(from)?;
+            for to in &recipients {
+                self.ensure_not_frozen(*to)?;
+            }
+
+            for (to, value) in recipients.into_iter().zip(amounts) {
+                self.transfer_from_to(from, to, value)?;
+            }
+
+            Ok(())
+        }
+
+        /// Transfers tokens and emits a `TransferMemo` carrying the memo hash
+        ///
+        /// Lets remittances be matched to an off-chain reference without
+        /// storing the memo itself.
+        #[ink(message)]
+        pub fn transfer_with_memo(
+            &mut self,
+            to: AccountId,
+            value: u128,
+            memo: Vec<u8>,
+        ) -> Result<()> {
+            if memo.len() > MAX_MEMO_LENGTH as usize {
+                return Err(Error::MemoTooLong);
+            }
+
+            let from = self.env().caller();
+            self.transfer_from_to(from, to, value)?;
+
+            let mut memo_hash = [0u8; 32];
+            ink::env::hash_bytes::<Blake2x256>(&memo, &mut memo_hash);
+
+            self.env().emit_event(TransferMemo {
+                from,
+                to,
                 value,
+                memo_hash,
             });
 
             Ok(())
         }
 
-        /// Transfers tokens from one account to another using allowance
+        /// Transfers tokens and, if `to` is a contract, notifies it through
+        /// `PSP22Receiver::on_psp22_received`
+        ///
+        /// The transfer reverts if the receiver returns an error or does not
+        /// implement the hook. Plain `transfer` never calls the hook.
         #[ink(message)]
-        pub fn transfer_from(&mut self, from: AccountId, to: AccountId, value: u128) -> Result<()> {
-            let caller = self.env().caller();
-            let allowance = self.allowance(from, caller);
+        pub fn transfer_to_contract(
+            &mut self,
+            to: AccountId,
+            value: u128,
+            data: Vec<u8>,
+        ) -> Result<()> {
+            let from = self.env().caller();
+            self.transfer_from_to(from, to, value)?;
 
-            if allowance < value {
-                return Err(Error::InsufficientAllowance);
+            if self.env().is_contract(&to) {
+                self.notify_receiver(from, from, to, value, data)?;
             }
 
-            self.transfer_from_to(from, to, value)?;
+            Ok(())
+        }
 
-            // Decrease allowance
-            let new_allowance = allowance.saturating_sub(value);
-            self.allowances.insert((from, caller), &new_allowance);
+        /// Approves spender to spend tokens on behalf of caller
+        ///
+        /// Alias of `PSP22::approve` kept for existing callers.
+        #[ink(message)]
+        pub fn approve(&mut self, spender: AccountId, value: u128) -> Result<()> {
+            let owner = self.env().caller();
+            self.approve_from_to(owner, spender, value)
+        }
 
-            Ok(())
+        /// Transfers tokens from one account to another using allowance
+        ///
+        /// Alias of `PSP22::transfer_from` kept for existing callers.
+        #[ink(message)]
+        pub fn transfer_from(&mut self, from: AccountId, to: AccountId, value: u128) -> Result<()> {
+            let caller = self.env().caller();
+            self.spend_allowance(from, caller, to, value)
         }
 
         /// Increases the allowance granted to spender
@@ -227,27 +709,130 @@ mod dalla_token {
             Ok(())
         }
 
-        /// Mints new tokens (owner only)
+        /// Sets `spender`'s allowance over `owner`'s tokens from a signed permit
+        ///
+        /// Anyone may submit the permit. `signature` is a 65-byte recoverable
+        /// ECDSA signature by `owner` over `permit_hash` at the owner's current
+        /// nonce, which is consumed on success.
+        #[ink(message)]
+        pub fn permit(
+            &mut self,
+            owner: AccountId,
+            spender: AccountId,
+            value: u128,
+            deadline: Timestamp,
+            signature: Vec<u8>,
+        ) -> Result<()> {
+            if self.env().block_timestamp() > deadline {
+                return Err(Error::PermitExpired);
+            }
+
+            let signature: [u8; 65] = signature
+                .as_slice()
+                .try_into()
+                .map_err(|_| Error::InvalidSignature)?;
+            let nonce = self.nonces(owner);
+
+            let hash = self.permit_hash(owner, spender, value, nonce, deadline);
+            if self.recover_signer(&signature, &hash)? != owner {
+                // A replayed permit still recovers to the owner under the
+                // nonce it consumed
+                if let Some(previous) = nonce.checked_sub(1) {
+                    let hash = self.permit_hash(owner, spender, value, previous, deadline);
+                    if self.recover_signer(&signature, &hash)? == owner {
+                        return Err(Error::NonceUsed);
+                    }
+                }
+                return Err(Error::InvalidSignature);
+            }
+
+            let next_nonce = nonce.checked_add(1).ok_or(Error::Overflow)?;
+            self.approve_from_to(owner, spender, value)?;
+            self.nonces.insert(owner, &next_nonce);
+
+            Ok(())
+        }
+
+        /// Returns the nonce the owner's next permit must be signed with
+        #[ink(message)]
+        pub fn nonces(&self, owner: AccountId) -> u64 {
+            self.nonces.get(owner).unwrap_or(0)
+        }
+
+        /// Returns the domain mixed into every permit digest
+        ///
+        /// Binds permits to this token contract so they cannot be replayed on
+        /// another deployment.
+        #[ink(message)]
+        pub fn domain_separator(&self) -> [u8; 32] {
+            let mut hash = [0u8; 32];
+            ink::env::hash_encoded::<Blake2x256, _>(
+                &(self.token_name(), self.env().account_id()),
+                &mut hash,
+            );
+            hash
+        }
+
+        /// Returns the digest an owner must sign to authorize a permit
+        #[ink(message)]
+        pub fn permit_hash(
+            &self,
+            owner: AccountId,
+            spender: AccountId,
+            value: u128,
+            nonce: u64,
+            deadline: Timestamp,
+        ) -> [u8; 32] {
+            let mut hash = [0u8; 32];
+            ink::env::hash_encoded::<Blake2x256, _>(
+                &(
+                    self.domain_separator(),
+                    owner,
+                    spender,
+                    value,
+                    nonce,
+                    deadline,
+                ),
+                &mut hash,
+            );
+            hash
+        }
+
+        /// Mints new tokens (MINTER_ROLE only)
+        ///
+        /// Draws down the caller's emission allowance when one is set.
         #[ink(message)]
         pub fn mint(&mut self, to: AccountId, value: u128) -> Result<()> {
             let caller = self.env().caller();
-            if caller != self.owner {
-                return Err(Error::UnauthorizedAccess);
-            }
+            self.access_control.ensure_role(caller, MINTER_ROLE)?;
+            self.pausable.ensure_not_paused()?;
+            self.ensure_not_frozen(to)?;
 
             let new_supply = self
                 .total_supply
                 .checked_add(value)
                 .ok_or(Error::Overflow)?;
-            if new_supply > self.max_supply {
+            if self.max_supply.is_some_and(|cap| new_supply > cap) {
                 return Err(Error::ExceedsMaxSupply);
             }
 
+            let remaining = match self.minter_allowance.get(caller) {
+                Some(allowance) => Some(
+                    allowance
+                        .checked_sub(value)
+                        .ok_or(Error::MinterAllowanceExceeded)?,
+                ),
+                None => None,
+            };
+
             let balance = self.balance_of(to);
             let new_balance = balance.checked_add(value).ok_or(Error::Overflow)?;
 
-            self.balances.insert(to, &new_balance);
-            self.total_supply = new_supply;
+            if let Some(remaining) = remaining {
+                self.minter_allowance.insert(caller, &remaining);
+            }
+            self.set_balance(to, new_balance);
+            self.set_total_supply(new_supply);
 
             self.env().emit_event(Transfer {
                 from: None,
@@ -262,176 +847,1612 @@ mod dalla_token {
         #[ink(message)]
         pub fn burn(&mut self, value: u128) -> Result<()> {
             let caller = self.env().caller();
-            let balance = self.balance_of(caller);
+            self.burn_balance(caller, value)
+        }
 
-            if balance < value {
-                return Err(Error::InsufficientBalance);
+        /// Burns tokens from `from` using the caller's allowance
+        ///
+        /// Alias of `PSP22Burnable::burn_from`.
+        #[ink(message)]
+        pub fn burn_from(&mut self, from: AccountId, value: u128) -> Result<()> {
+            let caller = self.env().caller();
+            self.spend_allowance_for_burn(from, caller, value)
+        }
+
+        /// Sets a minter's remaining emission allowance (admin only)
+        ///
+        /// `None` removes the budget, leaving the minter bounded only by the
+        /// max supply.
+        #[ink(message)]
+        pub fn set_minter_allowance(
+            &mut self,
+            minter: AccountId,
+            allowance: Option<u128>,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            self.access_control
+                .ensure_role(caller, DEFAULT_ADMIN_ROLE)?;
+
+            match allowance {
+                Some(allowance) => {
+                    self.minter_allowance.insert(minter, &allowance);
+                }
+                None => self.minter_allowance.remove(minter),
             }
 
-            let new_balance = balance.saturating_sub(value);
-            self.balances.insert(caller, &new_balance);
-            self.total_supply = self.total_supply.saturating_sub(value);
+            self.env()
+                .emit_event(MinterAllowanceSet { minter, allowance });
 
-            self.env().emit_event(Transfer {
-                from: Some(caller),
-                to: None,
-                value,
-            });
+            Ok(())
+        }
+
+        /// Returns a minter's remaining emission allowance (`None` if unbudgeted)
+        #[ink(message)]
+        pub fn minter_allowance(&self, account: AccountId) -> Option<u128> {
+            self.minter_allowance.get(account)
+        }
+
+        /// Grants `role` to `account` (role admin only)
+        #[ink(message)]
+        pub fn grant_role(&mut self, role: RoleType, account: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            let now = self.env().block_timestamp();
+            self.access_control
+                .grant_role(caller, role, account, now, |event| {
+                    Self::env().emit_event(event);
+                })?;
 
             Ok(())
         }
 
-        /// Transfers ownership of the contract
+        /// Revokes `role` from `account` (role admin only)
+        ///
+        /// The owner's admin role can only move through `transfer_ownership`.
         #[ink(message)]
-        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<()> {
+        pub fn revoke_role(&mut self, role: RoleType, account: AccountId) -> Result<()> {
             let caller = self.env().caller();
-            if caller != self.owner {
+            let now = self.env().block_timestamp();
+            if role == DEFAULT_ADMIN_ROLE && account == self.owner {
                 return Err(Error::UnauthorizedAccess);
             }
 
-            self.owner = new_owner;
+            self.access_control
+                .revoke_role(caller, role, account, now, |event| {
+                    Self::env().emit_event(event);
+                })?;
+
             Ok(())
         }
 
-        /// Returns the contract owner
+        /// Returns whether `account` holds `role`
         #[ink(message)]
-        pub fn owner(&self) -> AccountId {
-            self.owner
+        pub fn has_role(&self, role: RoleType, account: AccountId) -> bool {
+            self.access_control.has_role(role, account)
         }
 
-        /// Internal transfer function
-        fn transfer_from_to(&mut self, from: AccountId, to: AccountId, value: u128) -> Result<()> {
-            let from_balance = self.balance_of(from);
-            if from_balance < value {
-                return Err(Error::InsufficientBalance);
-            }
-
-            let to_balance = self.balance_of(to);
-            let new_to_balance = to_balance.checked_add(value).ok_or(Error::Overflow)?;
-
-            let new_from_balance = from_balance.saturating_sub(value);
-            self.balances.insert(from, &new_from_balance);
-            self.balances.insert(to, &new_to_balance);
+        /// Halts transfers, mints and burns (PAUSER_ROLE only)
+        #[ink(message)]
+        pub fn pause(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            self.access_control.ensure_role(caller, PAUSER_ROLE)?;
 
-            self.env().emit_event(Transfer {
-                from: Some(from),
-                to: Some(to),
-                value,
-            });
+            self.pausable.pause(caller, |event| {
+                Self::env().emit_event(event);
+            })?;
 
             Ok(())
         }
-    }
 
-    /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
-    /// module and test functions are marked with a `#[test]` attribute.
-    /// The below code is technically just normal Rust code.
-    #[cfg(test)]
-    mod tests {
-        use super::*;
+        /// Resumes transfers, mints and burns (PAUSER_ROLE only)
+        #[ink(message)]
+        pub fn unpause(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            self.access_control.ensure_role(caller, PAUSER_ROLE)?;
 
-        #[ink::test]
-        fn new_works() {
-            let initial_supply = 21_000_000_000_000_000_000_u128; // 21M DALLA
-            let token = DallaToken::new(initial_supply);
+            self.pausable.unpause(caller, |event| {
+                Self::env().emit_event(event);
+            })?;
 
-            assert_eq!(token.total_supply(), initial_supply);
-            assert_eq!(token.token_symbol(), "DALLA");
-            assert_eq!(token.token_decimals(), 12);
+            Ok(())
         }
 
-        #[ink::test]
-        fn balance_of_works() {
-            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-            let initial_supply = 1_000_000_000_000_u128; // 1M DALLA
-            let token = DallaToken::new(initial_supply);
-
-            assert_eq!(token.balance_of(accounts.alice), initial_supply);
-            assert_eq!(token.balance_of(accounts.bob), 0);
+        /// Returns whether transfers, mints and burns are paused
+        #[ink(message)]
+        pub fn is_paused(&self) -> bool {
+            self.pausable.is_paused()
         }
 
-        #[ink::test]
-        fn transfer_works() {
-            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-            let mut token = DallaToken::new(1_000_000_000_000_u128);
+        /// Blocks an account from sending or receiving DALLA (COMPLIANCE_ROLE only)
+        #[ink(message)]
+        pub fn freeze_account(&mut self, account: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            self.access_control.ensure_role(caller, COMPLIANCE_ROLE)?;
 
-            assert!(token.transfer(accounts.bob, 100_000_000_000).is_ok());
+            self.frozen.insert(account, &());
+            self.env().emit_event(AccountFrozen {
+                account,
+                by: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Lifts a freeze placed by `freeze_account` (COMPLIANCE_ROLE only)
+        #[ink(message)]
+        pub fn unfreeze_account(&mut self, account: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            self.access_control.ensure_role(caller, COMPLIANCE_ROLE)?;
+
+            self.frozen.remove(account);
+            self.env().emit_event(AccountUnfrozen {
+                account,
+                by: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Returns whether an account is frozen
+        #[ink(message)]
+        pub fn is_frozen(&self, account: AccountId) -> bool {
+            self.frozen.contains(account)
+        }
+
+        /// Turns strict approval mode on or off (admin only)
+        ///
+        /// In strict mode `approve` (and `permit`) cannot change one non-zero
+        /// allowance into another, closing the approve front-running race.
+        #[ink(message)]
+        pub fn set_strict_approvals(&mut self, enabled: bool) -> Result<()> {
+            let caller = self.env().caller();
+            self.access_control
+                .ensure_role(caller, DEFAULT_ADMIN_ROLE)?;
+
+            self.strict_approvals = enabled;
+            self.env().emit_event(StrictApprovalsSet { enabled });
+
+            Ok(())
+        }
+
+        /// Returns whether strict approval mode is on
+        #[ink(message)]
+        pub fn strict_approvals(&self) -> bool {
+            self.strict_approvals
+        }
+
+        /// Configures the transfer fee (admin only)
+        ///
+        /// `bps` of every taxable transfer is deducted from the amount received
+        /// and credited to `collector`. A `bps` of 0 turns the fee off.
+        #[ink(message)]
+        pub fn set_transfer_fee(&mut self, bps: u16, collector: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            self.access_control
+                .ensure_role(caller, DEFAULT_ADMIN_ROLE)?;
+
+            if bps > MAX_TRANSFER_FEE_BPS {
+                return Err(Error::FeeTooHigh);
+            }
+            if collector == AccountId::from([0u8; 32]) {
+                return Err(Error::InvalidRecipient);
+            }
+
+            self.transfer_fee_bps = bps;
+            self.fee_collector = Some(collector);
+            self.env().emit_event(TransferFeeSet {
+                bps,
+                collector: Some(collector),
+            });
+
+            Ok(())
+        }
+
+        /// Returns the transfer fee in basis points and its collector
+        #[ink(message)]
+        pub fn transfer_fee(&self) -> (u16, Option<AccountId>) {
+            (self.transfer_fee_bps, self.fee_collector)
+        }
+
+        /// Transfer fee in basis points, for routers and aggregators that
+        /// estimate fee-on-transfer costs (exempt accounts pay nothing)
+        #[ink(message)]
+        pub fn fee_on_transfer_bps(&self) -> u16 {
+            self.transfer_fee_bps
+        }
+
+        /// Exempts an account from the transfer fee, or removes the
+        /// exemption (admin only)
+        ///
+        /// Transfers are free when either side is exempt, e.g. a BelizeX pair
+        /// or router, whose swaps would otherwise break the pair's K invariant.
+        #[ink(message)]
+        pub fn set_fee_exempt(&mut self, account: AccountId, exempt: bool) -> Result<()> {
+            let caller = self.env().caller();
+            self.access_control
+                .ensure_role(caller, DEFAULT_ADMIN_ROLE)?;
+
+            if exempt {
+                self.fee_exempt.insert(account, &());
+            } else {
+                self.fee_exempt.remove(account);
+            }
+            self.env().emit_event(FeeExemptionSet { account, exempt });
+
+            Ok(())
+        }
+
+        /// Returns whether transfers to or from `account` are free
+        #[ink(message)]
+        pub fn is_fee_exempt(&self, account: AccountId) -> bool {
+            self.fee_exempt.contains(account)
+        }
+
+        /// Escrows `total` of the caller's tokens into a new vesting schedule
+        /// for `beneficiary` (admin only)
+        ///
+        /// The tokens are held by the contract account until claimed.
+        #[ink(message)]
+        pub fn create_vesting(
+            &mut self,
+            beneficiary: AccountId,
+            total: u128,
+            start: u64,
+            cliff: u64,
+            duration: u64,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            self.access_control
+                .ensure_role(caller, DEFAULT_ADMIN_ROLE)?;
+
+            if total == 0 || duration == 0 || cliff > duration {
+                return Err(Error::InvalidSchedule);
+            }
+
+            let count = self.vesting_counts.get(beneficiary).unwrap_or(0);
+            if count >= MAX_VESTING_SCHEDULES {
+                return Err(Error::TooManySchedules);
+            }
+
+            let escrow = self.env().account_id();
+            self.transfer_from_to(caller, escrow, total)?;
+
+            self.vesting_schedules.insert(
+                (beneficiary, count),
+                &VestingSchedule {
+                    total,
+                    claimed: 0,
+                    start,
+                    cliff,
+                    duration,
+                },
+            );
+            self.vesting_counts
+                .insert(beneficiary, &count.saturating_add(1));
+
+            self.env().emit_event(VestingCreated {
+                beneficiary,
+                creator: caller,
+                total,
+                start,
+                cliff,
+                duration,
+            });
+
+            Ok(())
+        }
+
+        /// Releases everything vested so far across the caller's schedules
+        ///
+        /// Returns the amount released; zero when nothing new has vested.
+        #[ink(message)]
+        pub fn claim_vested(&mut self) -> Result<u128> {
+            let caller = self.env().caller();
+            let now = self.env().block_timestamp();
+            let count = self.vesting_counts.get(caller).unwrap_or(0);
+
+            let mut amount: u128 = 0;
+            let mut updated = Vec::new();
+            for index in 0..count {
+                if let Some(mut schedule) = self.vesting_schedules.get((caller, index)) {
+                    let claimable = schedule.claimable_at(now);
+                    if claimable > 0 {
+                        schedule.claimed = schedule.claimed.saturating_add(claimable);
+                        amount = amount.saturating_add(claimable);
+                        updated.push((index, schedule));
+                    }
+                }
+            }
+
+            if amount > 0 {
+                let escrow = self.env().account_id();
+                self.transfer_from_to(escrow, caller, amount)?;
+
+                for (index, schedule) in updated {
+                    self.vesting_schedules.insert((caller, index), &schedule);
+                }
+
+                self.env().emit_event(VestingClaimed {
+                    beneficiary: caller,
+                    amount,
+                });
+            }
+
+            Ok(amount)
+        }
+
+        /// Returns every vesting schedule of a beneficiary
+        #[ink(message)]
+        pub fn vesting_of(&self, beneficiary: AccountId) -> Vec<VestingSchedule> {
+            let count = self.vesting_counts.get(beneficiary).unwrap_or(0);
+            (0..count)
+                .filter_map(|index| self.vesting_schedules.get((beneficiary, index)))
+                .collect()
+        }
+
+        /// Returns how much a beneficiary could claim right now
+        #[ink(message)]
+        pub fn claimable(&self, beneficiary: AccountId) -> u128 {
+            let now = self.env().block_timestamp();
+            self.vesting_of(beneficiary)
+                .iter()
+                .map(|schedule| schedule.claimable_at(now))
+                .fold(0, u128::saturating_add)
+        }
+
+        /// Sends PSP22 tokens mistakenly transferred to this contract's
+        /// address back out (owner only)
+        ///
+        /// DALLA itself is excluded so escrowed balances cannot be drained.
+        #[ink(message)]
+        pub fn recover_token(
+            &mut self,
+            token: AccountId,
+            to: AccountId,
+            amount: u128,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            if caller != self.owner {
+                return Err(Error::UnauthorizedAccess);
+            }
+
+            if token == self.env().account_id() {
+                return Err(Error::CannotRecoverSelf);
+            }
+
+            // PSP22::transfer selector is 0xdb20f9f5
+            let selector = [0xdb, 0x20, 0xf9, 0xf5];
+
+            let result = build_call::<Environment>()
+                .call(token)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(selector))
+                        .push_arg(to)
+                        .push_arg(amount),
+                )
+                .returns::<psp22_trait::Result<()>>()
+                .try_invoke();
+
+            match result {
+                Ok(Ok(Ok(()))) => {}
+                _ => return Err(Error::RecoveryFailed),
+            }
+
+            self.env().emit_event(TokenRecovered { token, to, amount });
+
+            Ok(())
+        }
+
+        /// Transfers ownership of the contract
+        ///
+        /// Every role held by the current owner moves to `new_owner`.
+        #[ink(message)]
+        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            let now = self.env().block_timestamp();
+            if caller != self.owner {
+                return Err(Error::UnauthorizedAccess);
+            }
+
+            if new_owner == caller {
+                return Ok(());
+            }
+
+            // DEFAULT_ADMIN_ROLE goes last so the caller can still revoke the others
+            for role in [
+                MINTER_ROLE,
+                PAUSER_ROLE,
+                COMPLIANCE_ROLE,
+                DEFAULT_ADMIN_ROLE,
+            ] {
+                if self.access_control.has_role(role, caller) {
+                    self.access_control
+                        .grant_role(caller, role, new_owner, now, |event| {
+                            Self::env().emit_event(event);
+                        })?;
+                    self.access_control
+                        .revoke_role(caller, role, caller, now, |event| {
+                            Self::env().emit_event(event);
+                        })?;
+                }
+            }
+
+            self.owner = new_owner;
+            Ok(())
+        }
+
+        /// Returns the contract owner
+        #[ink(message)]
+        pub fn owner(&self) -> AccountId {
+            self.owner
+        }
+
+        /// Internal transfer function
+        fn transfer_from_to(&mut self, from: AccountId, to: AccountId, value: u128) -> Result<()> {
+            // Sending to the zero address would destroy tokens without
+            // reducing total_supply; `burn` is the way to do that
+            if to == AccountId::from([0u8; 32]) {
+                return Err(Error::InvalidRecipient);
+            }
+
+            self.pausable.ensure_not_paused()?;
+            self.ensure_not_frozen(from)?;
+            self.ensure_not_frozen(to)?;
+
+            let from_balance = self.balance_of(from);
+            if from_balance < value {
+                return Err(Error::InsufficientBalance);
+            }
+
+            let (fee, collector) = self.transfer_fee_on(from, to, value);
+            let received = value.saturating_sub(fee);
+
+            self.set_balance(from, from_balance.saturating_sub(value));
+
+            // Read after writing `from`, so a transfer to self doesn't
+            // credit on top of the stale balance
+            let to_balance = self.balance_of(to);
+            let new_to_balance = to_balance.checked_add(received).ok_or(Error::Overflow)?;
+            self.set_balance(to, new_to_balance);
+
+            self.env().emit_event(Transfer {
+                from: Some(from),
+                to: Some(to),
+                value: received,
+            });
+
+            if fee > 0 {
+                // The collector is never `from` or `to`, so its balance is
+                // read after theirs were written
+                let collector_balance = self
+                    .balance_of(collector)
+                    .checked_add(fee)
+                    .ok_or(Error::Overflow)?;
+                self.set_balance(collector, collector_balance);
+
+                self.env().emit_event(Transfer {
+                    from: Some(from),
+                    to: Some(collector),
+                    value: fee,
+                });
+                self.env().emit_event(FeeCollected {
+                    from,
+                    to,
+                    collector,
+                    fee,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Fee charged on a transfer of `value` from `from` to `to`, and its
+        /// collector
+        ///
+        /// Free when the fee is off, for self-transfers, and when either side
+        /// is exempt, the collector, or the contract itself (vesting escrow).
+        /// Rounds down, so transfers below 10_000 / bps units are free.
+        fn transfer_fee_on(
+            &self,
+            from: AccountId,
+            to: AccountId,
+            value: u128,
+        ) -> (u128, AccountId) {
+            let Some(collector) = self.fee_collector else {
+                return (0, from);
+            };
+
+            let contract = self.env().account_id();
+            let free = self.transfer_fee_bps == 0
+                || from == to
+                || [from, to].iter().any(|account| {
+                    *account == collector
+                        || *account == contract
+                        || self.fee_exempt.contains(account)
+                });
+            if free {
+                return (0, collector);
+            }
+
+            // value * bps / 10_000, split so the product fits in u128
+            let bps = u128::from(self.transfer_fee_bps);
+            let fee = (value / 10_000)
+                .saturating_mul(bps)
+                .saturating_add((value % 10_000).saturating_mul(bps) / 10_000);
+
+            (fee, collector)
+        }
+
+        /// Internal burn function
+        fn burn_balance(&mut self, account: AccountId, value: u128) -> Result<()> {
+            self.pausable.ensure_not_paused()?;
+            self.ensure_not_frozen(account)?;
+
+            let balance = self.balance_of(account);
+
+            if balance < value {
+                return Err(Error::InsufficientBalance);
+            }
+
+            let new_balance = balance.saturating_sub(value);
+            self.set_balance(account, new_balance);
+            self.set_total_supply(self.total_supply.saturating_sub(value));
+
+            self.env().emit_event(Transfer {
+                from: Some(account),
+                to: None,
+                value,
+            });
+
+            Ok(())
+        }
+
+        /// Internal burn_from function: burns `value` from `from` and reduces
+        /// the allowance `from` granted to `spender`
+        fn spend_allowance_for_burn(
+            &mut self,
+            from: AccountId,
+            spender: AccountId,
+            value: u128,
+        ) -> Result<()> {
+            let new_allowance = self
+                .allowance(from, spender)
+                .checked_sub(value)
+                .ok_or(Error::InsufficientAllowance)?;
+
+            self.burn_balance(from, value)?;
+
+            self.allowances.insert((from, spender), &new_allowance);
+
+            Ok(())
+        }
+
+        /// Calls `PSP22Receiver::on_psp22_received` on a receiving contract
+        fn notify_receiver(
+            &self,
+            operator: AccountId,
+            from: AccountId,
+            to: AccountId,
+            value: u128,
+            data: Vec<u8>,
+        ) -> Result<()> {
+            let mut receiver: ink::contract_ref!(PSP22Receiver) = to.into();
+
+            let result = receiver
+                .call_mut()
+                .on_psp22_received(operator, from, value, data)
+                .try_invoke();
+
+            match result {
+                Ok(Ok(Ok(()))) => Ok(()),
+                _ => Err(Error::TransferRejected),
+            }
+        }
+
+        /// Ensures `account` is not frozen
+        fn ensure_not_frozen(&self, account: AccountId) -> Result<()> {
+            if self.frozen.contains(account) {
+                return Err(Error::AccountFrozen);
+            }
+
+            Ok(())
+        }
+
+        /// Writes an account balance and records it in the account's history
+        fn set_balance(&mut self, account: AccountId, balance: u128) {
+            self.balances.insert(account, &balance);
+
+            let block = self.env().block_number();
+            let count = self.checkpoint_counts.get(account).unwrap_or(0);
+            let last = count
+                .checked_sub(1)
+                .and_then(|index| self.checkpoints.get((account, index)));
+
+            match last {
+                // Several changes within one block share a checkpoint
+                Some(checkpoint) if checkpoint.block == block => {
+                    self.checkpoints.insert(
+                        (account, count.saturating_sub(1)),
+                        &Checkpoint {
+                            block,
+                            value: balance,
+                        },
+                    );
+                }
+                _ => {
+                    self.checkpoints.insert(
+                        (account, count),
+                        &Checkpoint {
+                            block,
+                            value: balance,
+                        },
+                    );
+                    self.checkpoint_counts
+                        .insert(account, &count.saturating_add(1));
+                }
+            }
+        }
+
+        /// Writes the total supply and records it in the supply history
+        fn set_total_supply(&mut self, supply: u128) {
+            self.total_supply = supply;
+
+            let block = self.env().block_number();
+            let count = self.supply_checkpoint_count;
+            let last = count
+                .checked_sub(1)
+                .and_then(|index| self.supply_checkpoints.get(index));
+
+            match last {
+                Some(checkpoint) if checkpoint.block == block => {
+                    self.supply_checkpoints.insert(
+                        count.saturating_sub(1),
+                        &Checkpoint {
+                            block,
+                            value: supply,
+                        },
+                    );
+                }
+                _ => {
+                    self.supply_checkpoints.insert(
+                        count,
+                        &Checkpoint {
+                            block,
+                            value: supply,
+                        },
+                    );
+                    self.supply_checkpoint_count = count.saturating_add(1);
+                }
+            }
+        }
+
+        /// Finds the value of the last checkpoint written at or before `block`
+        ///
+        /// Checkpoints are stored in ascending block order, so this is a binary
+        /// search over `count` entries read through `get`.
+        fn lookup<F>(count: u32, block: u32, get: F) -> u128
+        where
+            F: Fn(u32) -> Option<Checkpoint>,
+        {
+            let mut low = 0u32;
+            let mut high = count;
+
+            // Find the first checkpoint written after `block`
+            while low < high {
+                let mid = low + (high - low) / 2;
+                match get(mid) {
+                    Some(checkpoint) if checkpoint.block > block => high = mid,
+                    _ => low = mid.saturating_add(1),
+                }
+            }
+
+            low.checked_sub(1)
+                .and_then(get)
+                .map(|checkpoint| checkpoint.value)
+                .unwrap_or(0)
+        }
+
+        /// Recovers the account that signed `hash`
+        ///
+        /// The account id is `blake2_256` of the compressed public key, matching
+        /// Substrate's ECDSA account derivation.
+        fn recover_signer(&self, signature: &[u8; 65], hash: &[u8; 32]) -> Result<AccountId> {
+            let mut public_key = [0u8; 33];
+            self.env()
+                .ecdsa_recover(signature, hash, &mut public_key)
+                .map_err(|_| Error::InvalidSignature)?;
+
+            let mut account = [0u8; 32];
+            ink::env::hash_bytes::<Blake2x256>(&public_key, &mut account);
+
+            Ok(AccountId::from(account))
+        }
+
+        /// Internal approve function
+        fn approve_from_to(
+            &mut self,
+            owner: AccountId,
+            spender: AccountId,
+            value: u128,
+        ) -> Result<()> {
+            if self.strict_approvals && value != 0 && self.allowance(owner, spender) != 0 {
+                return Err(Error::NonZeroToNonZeroApproval);
+            }
+
+            self.allowances.insert((owner, spender), &value);
+
+            self.env().emit_event(Approval {
+                owner,
+                spender,
+                value,
+            });
+
+            Ok(())
+        }
+
+        /// Internal transfer_from function: moves `value` and reduces the
+        /// allowance `from` granted to `spender`
+        fn spend_allowance(
+            &mut self,
+            from: AccountId,
+            spender: AccountId,
+            to: AccountId,
+            value: u128,
+        ) -> Result<()> {
+            let new_allowance = self
+                .allowance(from, spender)
+                .checked_sub(value)
+                .ok_or(Error::InsufficientAllowance)?;
+
+            self.transfer_from_to(from, to, value)?;
+
+            // Decrease allowance
+            self.allowances.insert((from, spender), &new_allowance);
+
+            Ok(())
+        }
+    }
+
+    impl PSP22 for DallaToken {
+        #[ink(message)]
+        fn total_supply(&self) -> u128 {
+            self.total_supply
+        }
+
+        #[ink(message)]
+        fn balance_of(&self, owner: AccountId) -> u128 {
+            self.balances.get(owner).unwrap_or(0)
+        }
+
+        #[ink(message)]
+        fn transfer(&mut self, to: AccountId, value: u128) -> psp22_trait::Result<()> {
+            let from = self.env().caller();
+            Ok(self.transfer_from_to(from, to, value)?)
+        }
+
+        #[ink(message)]
+        fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: u128,
+        ) -> psp22_trait::Result<()> {
+            let caller = self.env().caller();
+            Ok(self.spend_allowance(from, caller, to, value)?)
+        }
+
+        #[ink(message)]
+        fn approve(&mut self, spender: AccountId, value: u128) -> psp22_trait::Result<()> {
+            let owner = self.env().caller();
+            Ok(self.approve_from_to(owner, spender, value)?)
+        }
+
+        #[ink(message)]
+        fn allowance(&self, owner: AccountId, spender: AccountId) -> u128 {
+            self.allowances.get((owner, spender)).unwrap_or(0)
+        }
+    }
+
+    impl PSP22Metadata for DallaToken {
+        #[ink(message)]
+        fn token_name(&self) -> Option<String> {
+            Some(self.name.clone())
+        }
+
+        #[ink(message)]
+        fn token_symbol(&self) -> Option<String> {
+            Some(self.symbol.clone())
+        }
+
+        #[ink(message)]
+        fn token_decimals(&self) -> u8 {
+            self.decimals
+        }
+    }
+
+    impl PSP22Burnable for DallaToken {
+        #[ink(message)]
+        fn burn_from(&mut self, from: AccountId, value: u128) -> psp22_trait::Result<()> {
+            let caller = self.env().caller();
+            Ok(self.spend_allowance_for_burn(from, caller, value)?)
+        }
+    }
+
+    /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
+    /// module and test functions are marked with a `#[test]` attribute.
+    /// The below code is technically just normal Rust code.
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        /// Signs a permit with `secret`; returns the signer's account id and
+        /// the signature
+        fn sign_permit(
+            token: &DallaToken,
+            secret: [u8; 32],
+            spender: AccountId,
+            value: u128,
+            nonce: u64,
+            deadline: Timestamp,
+        ) -> (AccountId, Vec<u8>) {
+            use secp256k1::{Message, PublicKey, Secp256k1, SecretKey};
+
+            let secp = Secp256k1::new();
+            let secret = SecretKey::from_slice(&secret).unwrap();
+            let public_key = PublicKey::from_secret_key(&secp, &secret).serialize();
+
+            let mut account = [0u8; 32];
+            ink::env::hash_bytes::<Blake2x256>(&public_key, &mut account);
+            let owner = AccountId::from(account);
+
+            let hash = token.permit_hash(owner, spender, value, nonce, deadline);
+            let (recovery_id, compact) = secp
+                .sign_ecdsa_recoverable(&Message::from_digest(hash), &secret)
+                .serialize_compact();
+
+            let mut signature = compact.to_vec();
+            signature.push(recovery_id.to_i32() as u8);
+
+            (owner, signature)
+        }
+
+        #[ink::test]
+        fn new_works() {
+            let initial_supply = 21_000_000_000_000_000_000_u128; // 21M DALLA
+            let token = DallaToken::new(initial_supply);
+
+            assert_eq!(token.total_supply(), initial_supply);
+            assert_eq!(token.token_symbol(), "DALLA");
+            assert_eq!(token.token_decimals(), 12);
+            assert_eq!(token.token_name(), "DALLA Token");
+            assert_eq!(token.max_supply(), Some(100_000_000_000_000_000_000_u128));
+        }
+
+        #[ink::test]
+        fn new_with_metadata_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new_with_metadata(
+                String::from("Belize Bond"),
+                String::from("BZB"),
+                6,
+                500,
+                Some(1_000),
+            );
+
+            assert_eq!(token.token_name(), "Belize Bond");
+            assert_eq!(token.token_symbol(), "BZB");
+            assert_eq!(token.token_decimals(), 6);
+            assert_eq!(token.max_supply(), Some(1_000));
+            assert_eq!(
+                PSP22Metadata::token_name(&token),
+                Some(String::from("Belize Bond"))
+            );
+            assert_eq!(
+                PSP22Metadata::token_symbol(&token),
+                Some(String::from("BZB"))
+            );
+            assert_eq!(PSP22Metadata::token_decimals(&token), 6);
+            assert_eq!(PSP22::total_supply(&token), 500);
+            assert_eq!(token.balance_of(accounts.alice), 500);
+
+            assert!(token.mint(accounts.bob, 500).is_ok());
+            assert_eq!(token.mint(accounts.bob, 1), Err(Error::ExceedsMaxSupply));
+        }
+
+        #[ink::test]
+        fn new_with_metadata_mints_up_to_cap() {
+            let token = DallaToken::new_with_metadata(
+                String::from("Belize Bond"),
+                String::from("BZB"),
+                6,
+                1_000,
+                Some(1_000),
+            );
+            assert_eq!(PSP22::total_supply(&token), 1_000);
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "Initial supply exceeds max supply")]
+        fn new_with_metadata_rejects_supply_above_cap() {
+            DallaToken::new_with_metadata(
+                String::from("Belize Bond"),
+                String::from("BZB"),
+                6,
+                1_001,
+                Some(1_000),
+            );
+        }
+
+        #[ink::test]
+        fn uncapped_token_mints_past_dalla_cap() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new_with_metadata(
+                String::from("Points"),
+                String::from("PTS"),
+                0,
+                0,
+                None,
+            );
+            assert_eq!(token.max_supply(), None);
+
+            let beyond_dalla_cap = 200_000_000_000_000_000_000_u128;
+            assert!(token.mint(accounts.bob, beyond_dalla_cap).is_ok());
+            assert_eq!(token.total_supply(), beyond_dalla_cap);
+
+            // Only arithmetic overflow limits an uncapped token
+            assert_eq!(token.mint(accounts.bob, u128::MAX), Err(Error::Overflow));
+        }
+
+        #[ink::test]
+        fn balance_of_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let initial_supply = 1_000_000_000_000_u128; // 1M DALLA
+            let token = DallaToken::new(initial_supply);
+
+            assert_eq!(token.balance_of(accounts.alice), initial_supply);
+            assert_eq!(token.balance_of(accounts.bob), 0);
+        }
+
+        #[ink::test]
+        fn transfer_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(1_000_000_000_000_u128);
+
+            assert!(token.transfer(accounts.bob, 100_000_000_000).is_ok());
             assert_eq!(token.balance_of(accounts.alice), 900_000_000_000);
             assert_eq!(token.balance_of(accounts.bob), 100_000_000_000);
         }
 
         #[ink::test]
-        fn transfer_fails_insufficient_balance() {
+        fn transfer_to_self_keeps_balance() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(1_000_000_000_000_u128);
+
+            assert!(token.transfer(accounts.alice, 100_000_000_000).is_ok());
+            assert!(token
+                .batch_transfer(vec![accounts.alice], vec![100_000_000_000])
+                .is_ok());
+            assert!(token.approve(accounts.alice, 100_000_000_000).is_ok());
+            assert!(token
+                .transfer_from(accounts.alice, accounts.alice, 100_000_000_000)
+                .is_ok());
+
+            assert_eq!(token.balance_of(accounts.alice), 1_000_000_000_000);
+            assert_eq!(token.total_supply(), 1_000_000_000_000);
+        }
+
+        #[ink::test]
+        fn transfer_fails_insufficient_balance() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(100_000_000_000_u128);
+
+            let result = token.transfer(accounts.bob, 200_000_000_000);
+            assert_eq!(result, Err(Error::InsufficientBalance));
+        }
+
+        #[ink::test]
+        fn batch_transfer_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(1_000);
+
+            assert!(token
+                .batch_transfer(
+                    vec![accounts.bob, accounts.charlie, accounts.bob],
+                    vec![100, 200, 50],
+                )
+                .is_ok());
+            assert_eq!(token.balance_of(accounts.alice), 650);
+            assert_eq!(token.balance_of(accounts.bob), 150);
+            assert_eq!(token.balance_of(accounts.charlie), 200);
+
+            assert_eq!(
+                token.batch_transfer(vec![accounts.bob], vec![1, 2]),
+                Err(Error::LengthMismatch)
+            );
+            assert_eq!(
+                token.batch_transfer(Vec::new(), Vec::new()),
+                Err(Error::InvalidBatchSize)
+            );
+        }
+
+        #[ink::test]
+        fn batch_transfer_insufficient_total_reverts_entirely() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(1_000);
+
+            // Each payment fits on its own, but the batch total does not
+            let recipients = vec![accounts.bob; MAX_BATCH_SIZE as usize];
+            let amounts = vec![11; MAX_BATCH_SIZE as usize];
+            assert_eq!(
+                token.batch_transfer(recipients, amounts),
+                Err(Error::InsufficientBalance)
+            );
+            assert_eq!(token.balance_of(accounts.alice), 1_000);
+            assert_eq!(token.balance_of(accounts.bob), 0);
+
+            // A frozen recipient anywhere in the batch blocks all of it
+            assert!(token.freeze_account(accounts.django).is_ok());
+            assert_eq!(
+                token.batch_transfer(vec![accounts.bob, accounts.django], vec![1, 1]),
+                Err(Error::AccountFrozen)
+            );
+            assert_eq!(token.balance_of(accounts.bob), 0);
+
+            let recipients = vec![accounts.bob; MAX_BATCH_SIZE as usize + 1];
+            let amounts = vec![1; MAX_BATCH_SIZE as usize + 1];
+            assert_eq!(
+                token.batch_transfer(recipients, amounts),
+                Err(Error::InvalidBatchSize)
+            );
+        }
+
+        #[ink::test]
+        fn transfer_with_memo_emits_event() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(1_000);
+
+            let memo = b"INV-2024-0042".to_vec();
+            assert!(token
+                .transfer_with_memo(accounts.bob, 250, memo.clone())
+                .is_ok());
+            assert_eq!(token.balance_of(accounts.bob), 250);
+
+            let event = ink::env::test::recorded_events()
+                .last()
+                .expect("no event recorded");
+            let decoded = <TransferMemo as ink::scale::Decode>::decode(&mut &event.data[..])
+                .expect("TransferMemo decode failed");
+
+            let mut memo_hash = [0u8; 32];
+            ink::env::hash_bytes::<Blake2x256>(&memo, &mut memo_hash);
+            assert_eq!(decoded.from, accounts.alice);
+            assert_eq!(decoded.to, accounts.bob);
+            assert_eq!(decoded.value, 250);
+            assert_eq!(decoded.memo_hash, memo_hash);
+
+            assert_eq!(
+                token.transfer_with_memo(accounts.bob, 1, vec![0u8; MAX_MEMO_LENGTH as usize + 1]),
+                Err(Error::MemoTooLong)
+            );
+        }
+
+        #[ink::test]
+        fn transfer_to_contract_skips_hook_for_accounts() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(1_000);
+
+            assert!(token
+                .transfer_to_contract(accounts.bob, 100, b"ref".to_vec())
+                .is_ok());
+            assert_eq!(token.balance_of(accounts.bob), 100);
+            assert_eq!(
+                token.transfer_to_contract(accounts.bob, 10_000, Vec::new()),
+                Err(Error::InsufficientBalance)
+            );
+        }
+
+        /// Deploys with a contract address distinct from the default accounts,
+        /// so the vesting escrow does not share Alice's balance
+        fn vesting_token() -> DallaToken {
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(AccountId::from([0x42; 32]));
+            DallaToken::new(1_000_000)
+        }
+
+        fn set_timestamp(timestamp: u64) {
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(timestamp);
+        }
+
+        #[ink::test]
+        fn vesting_releases_linearly_after_cliff() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = vesting_token();
+
+            // 1_000 tokens from t=1_000, cliff 250, fully vested at t=2_000
+            assert!(token
+                .create_vesting(accounts.bob, 1_000, 1_000, 250, 1_000)
+                .is_ok());
+            assert_eq!(token.balance_of(accounts.alice), 999_000);
+            assert_eq!(token.balance_of(AccountId::from([0x42; 32])), 1_000);
+            assert_eq!(token.total_supply(), 1_000_000);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+
+            // Before start and before the cliff nothing is claimable
+            set_timestamp(500);
+            assert_eq!(token.claimable(accounts.bob), 0);
+            set_timestamp(1_249);
+            assert_eq!(token.claimable(accounts.bob), 0);
+            assert_eq!(token.claim_vested(), Ok(0));
+
+            // At the cliff the elapsed share vests at once
+            set_timestamp(1_250);
+            assert_eq!(token.claimable(accounts.bob), 250);
+
+            set_timestamp(1_600);
+            assert_eq!(token.claim_vested(), Ok(600));
+            assert_eq!(token.balance_of(accounts.bob), 600);
+
+            // Claiming again at the same time yields nothing
+            assert_eq!(token.claim_vested(), Ok(0));
+
+            // Past the end everything is released
+            set_timestamp(5_000);
+            assert_eq!(token.claimable(accounts.bob), 400);
+            assert_eq!(token.claim_vested(), Ok(400));
+            assert_eq!(token.claim_vested(), Ok(0));
+            assert_eq!(token.balance_of(accounts.bob), 1_000);
+            assert_eq!(token.balance_of(AccountId::from([0x42; 32])), 0);
+            assert_eq!(token.vesting_of(accounts.bob)[0].claimed, 1_000);
+        }
+
+        #[ink::test]
+        fn vesting_supports_multiple_schedules() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = vesting_token();
+
+            assert!(token.create_vesting(accounts.bob, 100, 0, 0, 100).is_ok());
+            assert!(token.create_vesting(accounts.bob, 300, 50, 50, 100).is_ok());
+            assert_eq!(token.vesting_of(accounts.bob).len(), 2);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            set_timestamp(50);
+            assert_eq!(token.claimable(accounts.bob), 50);
+
+            set_timestamp(100);
+            assert_eq!(token.claimable(accounts.bob), 100 + 150);
+            assert_eq!(token.claim_vested(), Ok(250));
+
+            set_timestamp(150);
+            assert_eq!(token.claim_vested(), Ok(150));
+            assert_eq!(token.balance_of(accounts.bob), 400);
+        }
+
+        #[ink::test]
+        fn create_vesting_validates() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = vesting_token();
+
+            assert_eq!(
+                token.create_vesting(accounts.bob, 0, 0, 0, 100),
+                Err(Error::InvalidSchedule)
+            );
+            assert_eq!(
+                token.create_vesting(accounts.bob, 100, 0, 0, 0),
+                Err(Error::InvalidSchedule)
+            );
+            assert_eq!(
+                token.create_vesting(accounts.bob, 100, 0, 101, 100),
+                Err(Error::InvalidSchedule)
+            );
+            assert_eq!(
+                token.create_vesting(accounts.bob, 2_000_000, 0, 0, 100),
+                Err(Error::InsufficientBalance)
+            );
+
+            for _ in 0..MAX_VESTING_SCHEDULES {
+                assert!(token.create_vesting(accounts.bob, 1, 0, 0, 100).is_ok());
+            }
+            assert_eq!(
+                token.create_vesting(accounts.bob, 1, 0, 0, 100),
+                Err(Error::TooManySchedules)
+            );
+
+            // Admin only
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                token.create_vesting(accounts.charlie, 1, 0, 0, 100),
+                Err(Error::UnauthorizedAccess)
+            );
+        }
+
+        #[ink::test]
+        fn transfer_to_zero_address_fails() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(1_000);
+            let zero = AccountId::from([0u8; 32]);
+
+            assert_eq!(token.transfer(zero, 1), Err(Error::InvalidRecipient));
+            assert_eq!(
+                PSP22::transfer(&mut token, zero, 1),
+                Err(PSP22Error::InvalidRecipient)
+            );
+
+            assert!(token.approve(accounts.bob, 10).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                token.transfer_from(accounts.alice, zero, 1),
+                Err(Error::InvalidRecipient)
+            );
+
+            assert_eq!(token.balance_of(accounts.alice), 1_000);
+            assert_eq!(token.balance_of(zero), 0);
+            assert_eq!(token.allowance(accounts.alice, accounts.bob), 10);
+        }
+
+        #[ink::test]
+        fn recover_token_rejects_self_and_non_owner() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(1_000);
+            let dalla = ink::env::test::callee::<ink::env::DefaultEnvironment>();
+
+            assert_eq!(
+                token.recover_token(dalla, accounts.alice, 1),
+                Err(Error::CannotRecoverSelf)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                token.recover_token(accounts.charlie, accounts.bob, 1),
+                Err(Error::UnauthorizedAccess)
+            );
+        }
+
+        #[ink::test]
+        fn approve_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(1_000_000_000_000_u128);
+
+            assert!(token.approve(accounts.bob, 100_000_000_000).is_ok());
+            assert_eq!(
+                token.allowance(accounts.alice, accounts.bob),
+                100_000_000_000
+            );
+        }
+
+        #[ink::test]
+        fn transfer_from_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(1_000_000_000_000_u128);
+
+            // Alice approves Bob to spend 100 DALLA
+            assert!(token.approve(accounts.bob, 100_000_000_000).is_ok());
+
+            // Change caller to Bob
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+
+            // Bob transfers from Alice to Charlie
+            assert!(token
+                .transfer_from(accounts.alice, accounts.charlie, 50_000_000_000)
+                .is_ok());
+
+            assert_eq!(token.balance_of(accounts.alice), 950_000_000_000);
+            assert_eq!(token.balance_of(accounts.charlie), 50_000_000_000);
+            assert_eq!(
+                token.allowance(accounts.alice, accounts.bob),
+                50_000_000_000
+            );
+        }
+
+        #[ink::test]
+        fn mint_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(1_000_000_000_000_u128);
+
+            assert!(token.mint(accounts.bob, 500_000_000_000).is_ok());
+            assert_eq!(token.total_supply(), 1_500_000_000_000);
+            assert_eq!(token.balance_of(accounts.bob), 500_000_000_000);
+        }
+
+        #[ink::test]
+        fn mint_fails_exceeds_max_supply() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(1_000_000_000_000_u128);
+
+            let result = token.mint(accounts.bob, 100_000_000_000_000_000_000_u128);
+            assert_eq!(result, Err(Error::ExceedsMaxSupply));
+        }
+
+        #[ink::test]
+        fn minter_mints_within_budget() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(1_000);
+
+            assert!(token.grant_role(MINTER_ROLE, accounts.bob).is_ok());
+            assert!(token.set_minter_allowance(accounts.bob, Some(500)).is_ok());
+            assert_eq!(token.minter_allowance(accounts.bob), Some(500));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(token.mint(accounts.charlie, 300).is_ok());
+            assert_eq!(token.minter_allowance(accounts.bob), Some(200));
+            assert_eq!(token.balance_of(accounts.charlie), 300);
+
+            // Exceeding the budget fails without minting
+            assert_eq!(
+                token.mint(accounts.charlie, 201),
+                Err(Error::MinterAllowanceExceeded)
+            );
+            assert_eq!(token.minter_allowance(accounts.bob), Some(200));
+            assert_eq!(token.total_supply(), 1_300);
+
+            // Minters cannot top themselves up
+            assert_eq!(
+                token.set_minter_allowance(accounts.bob, Some(10_000)),
+                Err(Error::UnauthorizedAccess)
+            );
+
+            // The admin tops up
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(token
+                .set_minter_allowance(accounts.bob, Some(1_000))
+                .is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(token.mint(accounts.charlie, 1_000).is_ok());
+            assert_eq!(token.minter_allowance(accounts.bob), Some(0));
+        }
+
+        #[ink::test]
+        fn mint_requires_minter_role() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(1_000);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(token.mint(accounts.bob, 1), Err(Error::UnauthorizedAccess));
+            assert_eq!(
+                token.grant_role(MINTER_ROLE, accounts.bob),
+                Err(Error::UnauthorizedAccess)
+            );
+
+            // Ownership moves every role
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(token.transfer_ownership(accounts.bob).is_ok());
+            assert!(!token.has_role(MINTER_ROLE, accounts.alice));
+            assert!(!token.has_role(DEFAULT_ADMIN_ROLE, accounts.alice));
+            assert!(token.has_role(DEFAULT_ADMIN_ROLE, accounts.bob));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(token.mint(accounts.bob, 1).is_ok());
+            assert_eq!(
+                token.revoke_role(DEFAULT_ADMIN_ROLE, accounts.bob),
+                Err(Error::UnauthorizedAccess)
+            );
+        }
+
+        #[ink::test]
+        fn pause_blocks_balance_changes() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(1_000);
+            assert!(token.approve(accounts.bob, 100).is_ok());
+
+            assert!(token.pause().is_ok());
+            assert!(token.is_paused());
+            assert_eq!(token.pause(), Err(Error::Paused));
+
+            assert_eq!(token.transfer(accounts.bob, 1), Err(Error::Paused));
+            assert_eq!(token.mint(accounts.bob, 1), Err(Error::Paused));
+            assert_eq!(token.burn(1), Err(Error::Paused));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                token.transfer_from(accounts.alice, accounts.bob, 1),
+                Err(Error::Paused)
+            );
+            assert_eq!(token.allowance(accounts.alice, accounts.bob), 100);
+
+            // Only pausers can resume
+            assert_eq!(token.unpause(), Err(Error::UnauthorizedAccess));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(token.unpause().is_ok());
+            assert_eq!(token.unpause(), Err(Error::NotPaused));
+
+            assert!(token.transfer(accounts.bob, 1).is_ok());
+            assert!(token.mint(accounts.bob, 1).is_ok());
+            assert!(token.burn(1).is_ok());
+            assert_eq!(token.balance_of(accounts.bob), 2);
+        }
+
+        #[ink::test]
+        fn frozen_accounts_cannot_send_or_receive() {
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-            let mut token = DallaToken::new(100_000_000_000_u128);
+            let mut token = DallaToken::new(1_000);
+            assert!(token.transfer(accounts.bob, 100).is_ok());
+            assert!(token.approve(accounts.charlie, 100).is_ok());
 
-            let result = token.transfer(accounts.bob, 200_000_000_000);
-            assert_eq!(result, Err(Error::InsufficientBalance));
+            assert!(token.freeze_account(accounts.bob).is_ok());
+            assert!(token.is_frozen(accounts.bob));
+
+            // As recipient
+            assert_eq!(token.transfer(accounts.bob, 1), Err(Error::AccountFrozen));
+            assert_eq!(token.mint(accounts.bob, 1), Err(Error::AccountFrozen));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                token.transfer_from(accounts.alice, accounts.bob, 1),
+                Err(Error::AccountFrozen)
+            );
+
+            // As sender
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                token.transfer(accounts.charlie, 1),
+                Err(Error::AccountFrozen)
+            );
+            assert_eq!(token.burn(1), Err(Error::AccountFrozen));
+
+            // Other accounts are unaffected
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(token.transfer(accounts.charlie, 1).is_ok());
+
+            // Freezing and pausing stack: both must be lifted
+            assert!(token.pause().is_ok());
+            assert!(token.unfreeze_account(accounts.bob).is_ok());
+            assert!(!token.is_frozen(accounts.bob));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(token.transfer(accounts.charlie, 1), Err(Error::Paused));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(token.unpause().is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(token.transfer(accounts.charlie, 1).is_ok());
+            assert_eq!(token.balance_of(accounts.bob), 99);
         }
 
         #[ink::test]
-        fn approve_works() {
+        fn pause_and_freeze_require_roles() {
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-            let mut token = DallaToken::new(1_000_000_000_000_u128);
+            let mut token = DallaToken::new(1_000);
 
-            assert!(token.approve(accounts.bob, 100_000_000_000).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(token.pause(), Err(Error::UnauthorizedAccess));
             assert_eq!(
-                token.allowance(accounts.alice, accounts.bob),
-                100_000_000_000
+                token.freeze_account(accounts.charlie),
+                Err(Error::UnauthorizedAccess)
+            );
+
+            // Roles are independent: a pauser cannot freeze
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(token.grant_role(PAUSER_ROLE, accounts.bob).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(token.pause().is_ok());
+            assert_eq!(
+                token.freeze_account(accounts.charlie),
+                Err(Error::UnauthorizedAccess)
             );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(token.grant_role(COMPLIANCE_ROLE, accounts.charlie).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert!(token.freeze_account(accounts.django).is_ok());
+            assert_eq!(token.unpause(), Err(Error::UnauthorizedAccess));
         }
 
         #[ink::test]
-        fn transfer_from_works() {
+        fn burn_works() {
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             let mut token = DallaToken::new(1_000_000_000_000_u128);
 
-            // Alice approves Bob to spend 100 DALLA
-            assert!(token.approve(accounts.bob, 100_000_000_000).is_ok());
+            assert!(token.burn(200_000_000_000).is_ok());
+            assert_eq!(token.total_supply(), 800_000_000_000);
+            assert_eq!(token.balance_of(accounts.alice), 800_000_000_000);
+        }
+
+        #[ink::test]
+        fn burn_from_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(1_000);
+            assert!(token.approve(accounts.bob, 300).is_ok());
 
-            // Change caller to Bob
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(token.burn_from(accounts.alice, 100).is_ok());
+            assert_eq!(token.balance_of(accounts.alice), 900);
+            assert_eq!(token.total_supply(), 900);
+            assert_eq!(token.allowance(accounts.alice, accounts.bob), 200);
 
-            // Bob transfers from Alice to Charlie
-            assert!(token
-                .transfer_from(accounts.alice, accounts.charlie, 50_000_000_000)
-                .is_ok());
+            // The whole remaining allowance can be burned, but not a unit more
+            assert_eq!(
+                token.burn_from(accounts.alice, 201),
+                Err(Error::InsufficientAllowance)
+            );
+            assert!(PSP22Burnable::burn_from(&mut token, accounts.alice, 200).is_ok());
+            assert_eq!(token.allowance(accounts.alice, accounts.bob), 0);
+            assert_eq!(token.total_supply(), 700);
+        }
 
-            assert_eq!(token.balance_of(accounts.alice), 950_000_000_000);
-            assert_eq!(token.balance_of(accounts.charlie), 50_000_000_000);
+        #[ink::test]
+        fn burn_from_requires_allowance() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(1_000);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
             assert_eq!(
-                token.allowance(accounts.alice, accounts.bob),
-                50_000_000_000
+                token.burn_from(accounts.alice, 1),
+                Err(Error::InsufficientAllowance)
+            );
+            assert_eq!(
+                PSP22Burnable::burn_from(&mut token, accounts.alice, 1),
+                Err(PSP22Error::InsufficientAllowance)
+            );
+
+            // Allowance beyond the holder's balance still cannot burn it
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(token.approve(accounts.bob, 5_000).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                token.burn_from(accounts.alice, 1_001),
+                Err(Error::InsufficientBalance)
             );
+            assert_eq!(token.allowance(accounts.alice, accounts.bob), 5_000);
+            assert_eq!(token.total_supply(), 1_000);
         }
 
         #[ink::test]
-        fn mint_works() {
+        fn approve_overwrites_by_default() {
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-            let mut token = DallaToken::new(1_000_000_000_000_u128);
+            let mut token = DallaToken::new(1_000);
+            assert!(!token.strict_approvals());
 
-            assert!(token.mint(accounts.bob, 500_000_000_000).is_ok());
-            assert_eq!(token.total_supply(), 1_500_000_000_000);
-            assert_eq!(token.balance_of(accounts.bob), 500_000_000_000);
+            assert!(token.approve(accounts.bob, 50).is_ok());
+            assert!(token.approve(accounts.bob, 30).is_ok());
+            assert_eq!(token.allowance(accounts.alice, accounts.bob), 30);
         }
 
         #[ink::test]
-        fn mint_fails_exceeds_max_supply() {
+        fn strict_approvals_require_reset() {
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-            let mut token = DallaToken::new(1_000_000_000_000_u128);
+            let mut token = DallaToken::new(1_000);
 
-            let result = token.mint(accounts.bob, 100_000_000_000_000_000_000_u128);
-            assert_eq!(result, Err(Error::ExceedsMaxSupply));
+            assert!(token.set_strict_approvals(true).is_ok());
+            assert!(token.approve(accounts.bob, 50).is_ok());
+            assert_eq!(
+                token.approve(accounts.bob, 30),
+                Err(Error::NonZeroToNonZeroApproval)
+            );
+            assert_eq!(
+                PSP22::approve(&mut token, accounts.bob, 30),
+                Err(PSP22Error::Custom(String::from("NonZeroToNonZeroApproval")))
+            );
+            assert_eq!(token.allowance(accounts.alice, accounts.bob), 50);
+
+            // Resetting to zero, or adjusting by delta, still works
+            assert!(token.decrease_allowance(accounts.bob, 20).is_ok());
+            assert_eq!(token.allowance(accounts.alice, accounts.bob), 30);
+            assert!(token.approve(accounts.bob, 0).is_ok());
+            assert!(token.approve(accounts.bob, 40).is_ok());
+            assert_eq!(token.allowance(accounts.alice, accounts.bob), 40);
+
+            // Only admins toggle the mode
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                token.set_strict_approvals(false),
+                Err(Error::UnauthorizedAccess)
+            );
         }
 
         #[ink::test]
-        fn burn_works() {
+        fn transfer_from_spends_exact_allowance() {
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-            let mut token = DallaToken::new(1_000_000_000_000_u128);
+            let mut token = DallaToken::new(1_000);
+            assert!(token.approve(accounts.bob, 100).is_ok());
 
-            assert!(token.burn(200_000_000_000).is_ok());
-            assert_eq!(token.total_supply(), 800_000_000_000);
-            assert_eq!(token.balance_of(accounts.alice), 800_000_000_000);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                token.transfer_from(accounts.alice, accounts.bob, 101),
+                Err(Error::InsufficientAllowance)
+            );
+            assert!(token
+                .transfer_from(accounts.alice, accounts.bob, 100)
+                .is_ok());
+            assert_eq!(token.allowance(accounts.alice, accounts.bob), 0);
+            assert_eq!(
+                token.transfer_from(accounts.alice, accounts.bob, 1),
+                Err(Error::InsufficientAllowance)
+            );
         }
 
         #[ink::test]
@@ -463,5 +2484,728 @@ mod dalla_token {
                 70_000_000_000
             );
         }
+
+        #[ink::test]
+        fn psp22_transfer_from_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(1_000_000_000_000_u128);
+
+            assert!(PSP22::approve(&mut token, accounts.bob, 100_000_000_000).is_ok());
+            assert_eq!(
+                PSP22::allowance(&token, accounts.alice, accounts.bob),
+                100_000_000_000
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(PSP22::transfer_from(
+                &mut token,
+                accounts.alice,
+                accounts.charlie,
+                40_000_000_000
+            )
+            .is_ok());
+
+            assert_eq!(PSP22::balance_of(&token, accounts.charlie), 40_000_000_000);
+            assert_eq!(
+                token.allowance(accounts.alice, accounts.bob),
+                60_000_000_000
+            );
+        }
+
+        #[ink::test]
+        fn psp22_maps_errors() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(100_000_000_000_u128);
+
+            assert_eq!(
+                PSP22::transfer(&mut token, accounts.bob, 200_000_000_000),
+                Err(PSP22Error::InsufficientBalance)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                PSP22::transfer_from(&mut token, accounts.alice, accounts.bob, 1),
+                Err(PSP22Error::InsufficientAllowance)
+            );
+            assert_eq!(
+                PSP22Error::from(Error::Overflow),
+                PSP22Error::Custom(String::from("Overflow"))
+            );
+        }
+
+        #[ink::test]
+        fn balance_of_at_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            // Deployed at block 0
+            let mut token = DallaToken::new(1_000);
+
+            // Block 2: two transfers in the same block share one checkpoint
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            assert!(token.transfer(accounts.bob, 100).is_ok());
+            assert!(token.transfer(accounts.bob, 50).is_ok());
+
+            // Block 5: Bob passes some on
+            for _ in 0..3 {
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(token.transfer(accounts.charlie, 30).is_ok());
+
+            assert_eq!(token.num_checkpoints(accounts.alice), 2);
+            assert_eq!(token.num_checkpoints(accounts.bob), 2);
+
+            assert_eq!(token.balance_of_at(accounts.alice, 0), 1_000);
+            assert_eq!(token.balance_of_at(accounts.alice, 1), 1_000);
+            assert_eq!(token.balance_of_at(accounts.alice, 2), 850);
+            assert_eq!(token.balance_of_at(accounts.alice, 100), 850);
+
+            assert_eq!(token.balance_of_at(accounts.bob, 1), 0);
+            assert_eq!(token.balance_of_at(accounts.bob, 2), 150);
+            assert_eq!(token.balance_of_at(accounts.bob, 4), 150);
+            assert_eq!(token.balance_of_at(accounts.bob, 5), 120);
+
+            assert_eq!(token.balance_of_at(accounts.charlie, 4), 0);
+            assert_eq!(token.balance_of_at(accounts.charlie, 5), 30);
+            assert_eq!(token.balance_of_at(accounts.django, 5), 0);
+        }
+
+        #[ink::test]
+        fn total_supply_at_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(1_000);
+
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            assert!(token.mint(accounts.bob, 500).is_ok());
+
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            assert!(token.burn(200).is_ok());
+
+            assert_eq!(token.total_supply_at(0), 1_000);
+            assert_eq!(token.total_supply_at(1), 1_500);
+            assert_eq!(token.total_supply_at(2), 1_500);
+            assert_eq!(token.total_supply_at(3), 1_300);
+            assert_eq!(token.total_supply(), 1_300);
+
+            assert_eq!(token.balance_of_at(accounts.bob, 0), 0);
+            assert_eq!(token.balance_of_at(accounts.bob, 1), 500);
+            assert_eq!(token.balance_of_at(accounts.alice, 3), 800);
+        }
+
+        #[ink::test]
+        fn permit_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(1_000_000_000_000_u128);
+
+            let (holder, signature) =
+                sign_permit(&token, [7u8; 32], accounts.bob, 100_000_000_000, 0, 1_000);
+            assert!(token.transfer(holder, 500_000_000_000).is_ok());
+
+            // Bob submits the permit; the holder never sends an approve transaction
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(token
+                .permit(holder, accounts.bob, 100_000_000_000, 1_000, signature)
+                .is_ok());
+            assert_eq!(token.allowance(holder, accounts.bob), 100_000_000_000);
+            assert_eq!(token.nonces(holder), 1);
+
+            // The allowance is spendable
+            assert!(token
+                .transfer_from(holder, accounts.charlie, 100_000_000_000)
+                .is_ok());
+            assert_eq!(token.balance_of(accounts.charlie), 100_000_000_000);
+            assert_eq!(token.allowance(holder, accounts.bob), 0);
+        }
+
+        #[ink::test]
+        fn permit_rejects_replay() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(1_000_000_000_000_u128);
+
+            let (holder, signature) = sign_permit(&token, [7u8; 32], accounts.bob, 50, 0, 1_000);
+            assert!(token
+                .permit(holder, accounts.bob, 50, 1_000, signature.clone())
+                .is_ok());
+            assert_eq!(
+                token.permit(holder, accounts.bob, 50, 1_000, signature),
+                Err(Error::NonceUsed)
+            );
+
+            // The next nonce is accepted
+            let (_, signature) = sign_permit(&token, [7u8; 32], accounts.bob, 80, 1, 1_000);
+            assert!(token
+                .permit(holder, accounts.bob, 80, 1_000, signature)
+                .is_ok());
+            assert_eq!(token.allowance(holder, accounts.bob), 80);
+            assert_eq!(token.nonces(holder), 2);
+        }
+
+        #[ink::test]
+        fn permit_rejects_expired_and_forged() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = DallaToken::new(1_000_000_000_000_u128);
+
+            let (holder, signature) = sign_permit(&token, [7u8; 32], accounts.bob, 50, 0, 1_000);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_001);
+            assert_eq!(
+                token.permit(holder, accounts.bob, 50, 1_000, signature.clone()),
+                Err(Error::PermitExpired)
+            );
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(0);
+
+            // Tampered value
+            assert_eq!(
+                token.permit(holder, accounts.bob, 51, 1_000, signature),
+                Err(Error::InvalidSignature)
+            );
+
+            // Signed by someone else
+            let (_, signature) = sign_permit(&token, [9u8; 32], accounts.bob, 50, 0, 1_000);
+            assert_eq!(
+                token.permit(holder, accounts.bob, 50, 1_000, signature),
+                Err(Error::InvalidSignature)
+            );
+
+            assert_eq!(
+                token.permit(holder, accounts.bob, 50, 1_000, vec![0u8; 64]),
+                Err(Error::InvalidSignature)
+            );
+            assert_eq!(token.nonces(holder), 0);
+            assert_eq!(token.allowance(holder, accounts.bob), 0);
+        }
+
+        /// Deploys with a 1% fee collected by Eve; the contract address is
+        /// distinct from the default accounts so Alice is not treated as
+        /// the escrow
+        fn fee_token() -> DallaToken {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = vesting_token();
+            assert!(token.set_transfer_fee(100, accounts.eve).is_ok());
+            token
+        }
+
+        #[ink::test]
+        fn transfer_fee_is_off_by_default() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = vesting_token();
+            assert_eq!(token.transfer_fee(), (0, None));
+            assert_eq!(token.fee_on_transfer_bps(), 0);
+
+            let events_before = ink::env::test::recorded_events().count();
+            assert!(token.transfer(accounts.bob, 10_000).is_ok());
+            assert_eq!(token.balance_of(accounts.alice), 990_000);
+            assert_eq!(token.balance_of(accounts.bob), 10_000);
+            assert_eq!(token.total_supply(), 1_000_000);
+
+            // A single Transfer for the full amount, exactly as before
+            let events: Vec<_> = ink::env::test::recorded_events()
+                .skip(events_before)
+                .collect();
+            assert_eq!(events.len(), 1);
+            let decoded = <Transfer as ink::scale::Decode>::decode(&mut &events[0].data[..])
+                .expect("Transfer decode failed");
+            assert_eq!(decoded.value, 10_000);
+        }
+
+        #[ink::test]
+        fn transfer_fee_is_credited_to_collector() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = fee_token();
+            assert_eq!(token.transfer_fee(), (100, Some(accounts.eve)));
+            assert_eq!(token.fee_on_transfer_bps(), 100);
+
+            let events_before = ink::env::test::recorded_events().count();
+            assert!(token.transfer(accounts.bob, 10_000).is_ok());
+            assert_eq!(token.balance_of(accounts.alice), 990_000);
+            assert_eq!(token.balance_of(accounts.bob), 9_900);
+            assert_eq!(token.balance_of(accounts.eve), 100);
+            assert_eq!(token.total_supply(), 1_000_000);
+
+            let events: Vec<_> = ink::env::test::recorded_events()
+                .skip(events_before)
+                .collect();
+            assert_eq!(events.len(), 3);
+            let collected = <FeeCollected as ink::scale::Decode>::decode(&mut &events[2].data[..])
+                .expect("FeeCollected decode failed");
+            assert_eq!(collected.from, accounts.alice);
+            assert_eq!(collected.to, accounts.bob);
+            assert_eq!(collected.collector, accounts.eve);
+            assert_eq!(collected.fee, 100);
+
+            // transfer_from is charged the same way and spends the full allowance
+            assert!(token.approve(accounts.charlie, 1_000).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert!(token
+                .transfer_from(accounts.alice, accounts.charlie, 1_000)
+                .is_ok());
+            assert_eq!(token.allowance(accounts.alice, accounts.charlie), 0);
+            assert_eq!(token.balance_of(accounts.charlie), 990);
+            assert_eq!(token.balance_of(accounts.eve), 110);
+        }
+
+        #[ink::test]
+        fn transfer_fee_rounds_down() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = fee_token();
+
+            // Below 100 units a 1% fee rounds to zero
+            assert!(token.transfer(accounts.bob, 99).is_ok());
+            assert_eq!(token.balance_of(accounts.bob), 99);
+            assert_eq!(token.balance_of(accounts.eve), 0);
+
+            assert!(token.transfer(accounts.bob, 199).is_ok());
+            assert_eq!(token.balance_of(accounts.bob), 99 + 198);
+            assert_eq!(token.balance_of(accounts.eve), 1);
+
+            // Large amounts do not overflow the intermediate product
+            assert_eq!(
+                token
+                    .transfer_fee_on(accounts.alice, accounts.bob, u128::MAX)
+                    .0,
+                u128::MAX / 100
+            );
+        }
+
+        #[ink::test]
+        fn fee_exemptions_skip_the_fee() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = fee_token();
+
+            assert!(token.set_fee_exempt(accounts.bob, true).is_ok());
+            assert!(token.is_fee_exempt(accounts.bob));
+
+            // Exempt on either side
+            assert!(token.transfer(accounts.bob, 1_000).is_ok());
+            assert_eq!(token.balance_of(accounts.bob), 1_000);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(token.transfer(accounts.charlie, 500).is_ok());
+            assert_eq!(token.balance_of(accounts.charlie), 500);
+
+            // The collector is never charged
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(token.transfer(accounts.eve, 1_000).is_ok());
+            assert_eq!(token.balance_of(accounts.eve), 1_000);
+
+            // Vesting escrow in and out of the contract is free
+            assert!(token
+                .create_vesting(accounts.django, 1_000, 0, 0, 1)
+                .is_ok());
+            assert_eq!(token.balance_of(AccountId::from([0x42; 32])), 1_000);
+            set_timestamp(1);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(token.claim_vested(), Ok(1_000));
+            assert_eq!(token.balance_of(accounts.django), 1_000);
+
+            // Removing the exemption restores the fee
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(token.set_fee_exempt(accounts.bob, false).is_ok());
+            assert!(!token.is_fee_exempt(accounts.bob));
+            assert!(token.transfer(accounts.bob, 1_000).is_ok());
+            assert_eq!(token.balance_of(accounts.bob), 500 + 990);
+            assert_eq!(token.balance_of(accounts.eve), 1_010);
+        }
+
+        #[ink::test]
+        fn set_transfer_fee_validates() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = vesting_token();
+
+            assert_eq!(
+                token.set_transfer_fee(MAX_TRANSFER_FEE_BPS + 1, accounts.eve),
+                Err(Error::FeeTooHigh)
+            );
+            assert_eq!(
+                token.set_transfer_fee(50, AccountId::from([0u8; 32])),
+                Err(Error::InvalidRecipient)
+            );
+            assert_eq!(token.transfer_fee(), (0, None));
+
+            // Only admins configure the fee or exemptions
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                token.set_transfer_fee(50, accounts.bob),
+                Err(Error::UnauthorizedAccess)
+            );
+            assert_eq!(
+                token.set_fee_exempt(accounts.bob, true),
+                Err(Error::UnauthorizedAccess)
+            );
+
+            // A zero fee turns charging off again
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(token
+                .set_transfer_fee(MAX_TRANSFER_FEE_BPS, accounts.eve)
+                .is_ok());
+            assert!(token.set_transfer_fee(0, accounts.eve).is_ok());
+            assert!(token.transfer(accounts.bob, 10_000).is_ok());
+            assert_eq!(token.balance_of(accounts.bob), 10_000);
+            assert_eq!(token.balance_of(accounts.eve), 0);
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use belizex_factory::factory::{Factory, FactoryRef};
+        use belizex_router::router::{Router, RouterRef};
+        use ink_e2e::ContractsBackend;
+        use token_receiver_mock::token_receiver_mock::{TokenReceiverMock, TokenReceiverMockRef};
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn e2e_router_pulls_dalla_via_psp22<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+
+            // Two DALLA deployments stand in for the pair's tokens
+            let mut dalla_constructor = DallaTokenRef::new(1_000_000);
+            let dalla = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut dalla_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+            let mut dalla_call = dalla.call_builder::<DallaToken>();
+
+            let mut other_constructor = DallaTokenRef::new(1_000_000);
+            let other = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut other_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+            let mut other_call = other.call_builder::<DallaToken>();
+
+            let mut factory_constructor = FactoryRef::new(alice, Hash::from([0u8; 32]));
+            let factory = client
+                .instantiate(
+                    "belizex_factory",
+                    &ink_e2e::alice(),
+                    &mut factory_constructor,
+                )
+                .submit()
+                .await
+                .expect("belizex_factory instantiate failed");
+            let mut factory_call = factory.call_builder::<Factory>();
+            let pair = client
+                .call(
+                    &ink_e2e::alice(),
+                    &factory_call.create_pair(dalla.account_id, other.account_id),
+                )
+                .submit()
+                .await
+                .expect("create_pair failed")
+                .return_value()
+                .expect("create_pair reverted");
+
+            let mut router_constructor = RouterRef::new(factory.account_id, other.account_id);
+            let router = client
+                .instantiate("belizex_router", &ink_e2e::alice(), &mut router_constructor)
+                .submit()
+                .await
+                .expect("belizex_router instantiate failed");
+            let mut router_call = router.call_builder::<Router>();
+
+            // Approve through the standard PSP22 selector
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &PSP22::approve(&mut dalla_call, router.account_id, 10_000),
+                )
+                .submit()
+                .await
+                .expect("approve failed");
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &PSP22::approve(&mut other_call, router.account_id, 10_000),
+                )
+                .submit()
+                .await
+                .expect("approve failed");
+
+            // The router pulls both tokens with PSP22::transfer_from (0x54b3c76e)
+            let (amount_dalla, _, _) = client
+                .call(
+                    &ink_e2e::alice(),
+                    &router_call.add_liquidity(
+                        dalla.account_id,
+                        other.account_id,
+                        2_000,
+                        2_000,
+                        0,
+                        0,
+                        alice,
+                        u64::MAX,
+                    ),
+                )
+                .submit()
+                .await
+                .expect("add_liquidity failed")
+                .return_value()
+                .expect("add_liquidity reverted");
+            assert!(amount_dalla > 0);
+
+            let pair_balance = client
+                .call(&ink_e2e::alice(), &PSP22::balance_of(&dalla_call, pair))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(pair_balance, amount_dalla);
+
+            let allowance = client
+                .call(
+                    &ink_e2e::alice(),
+                    &dalla_call.allowance(alice, router.account_id),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(allowance, 10_000 - amount_dalla);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_router_reads_metadata_via_contract_ref<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+
+            let mut dalla_constructor = DallaTokenRef::new(1_000_000);
+            let dalla = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut dalla_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+
+            let mut router_constructor = RouterRef::new(alice, dalla.account_id);
+            let router = client
+                .instantiate("belizex_router", &ink_e2e::alice(), &mut router_constructor)
+                .submit()
+                .await
+                .expect("belizex_router instantiate failed");
+            let router_call = router.call_builder::<Router>();
+
+            // The router reaches PSP22::total_supply and PSP22Metadata through
+            // typed `contract_ref!` calls rather than raw selectors
+            let metadata = client
+                .call(
+                    &ink_e2e::alice(),
+                    &router_call.token_metadata(dalla.account_id),
+                )
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("dalla_token should answer PSP22Metadata");
+            assert_eq!(metadata.name, Some(String::from("DALLA Token")));
+            assert_eq!(metadata.symbol, Some(String::from("DALLA")));
+            assert_eq!(metadata.decimals, 12);
+            assert_eq!(metadata.total_supply, 1_000_000);
+
+            // A contract that is not a token yields None instead of trapping
+            let not_a_token = client
+                .call(
+                    &ink_e2e::alice(),
+                    &router_call.token_metadata(router.account_id),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(not_a_token, None);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_transfer_to_contract_calls_receiver<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+
+            let mut token_constructor = DallaTokenRef::new(1_000_000);
+            let token = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut token_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+            let mut token_call = token.call_builder::<DallaToken>();
+
+            let mut receiver_constructor = TokenReceiverMockRef::new(true);
+            let receiver = client
+                .instantiate(
+                    "token_receiver_mock",
+                    &ink_e2e::alice(),
+                    &mut receiver_constructor,
+                )
+                .submit()
+                .await
+                .expect("token_receiver_mock instantiate failed");
+            let receiver_call = receiver.call_builder::<TokenReceiverMock>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &token_call.transfer_to_contract(receiver.account_id, 500, b"order-7".to_vec()),
+                )
+                .submit()
+                .await
+                .expect("transfer_to_contract failed");
+
+            let balance = client
+                .call(
+                    &ink_e2e::alice(),
+                    &token_call.balance_of(receiver.account_id),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(balance, 500);
+
+            let received = client
+                .call(&ink_e2e::alice(), &receiver_call.last_received())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(received, Some((alice, alice, 500, b"order-7".to_vec())));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_transfer_to_contract_reverts_on_rejection<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+
+            let mut token_constructor = DallaTokenRef::new(1_000_000);
+            let token = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut token_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+            let mut token_call = token.call_builder::<DallaToken>();
+
+            let mut receiver_constructor = TokenReceiverMockRef::new(false);
+            let receiver = client
+                .instantiate(
+                    "token_receiver_mock",
+                    &ink_e2e::alice(),
+                    &mut receiver_constructor,
+                )
+                .submit()
+                .await
+                .expect("token_receiver_mock instantiate failed");
+
+            let result = client
+                .call(
+                    &ink_e2e::alice(),
+                    &token_call.transfer_to_contract(receiver.account_id, 500, Vec::new()),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(result, Err(Error::TransferRejected));
+
+            let rejected = client
+                .call(
+                    &ink_e2e::alice(),
+                    &token_call.transfer_to_contract(receiver.account_id, 500, Vec::new()),
+                )
+                .submit()
+                .await;
+            assert!(rejected.is_err());
+
+            let balance = client
+                .call(&ink_e2e::alice(), &token_call.balance_of(alice))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(balance, 1_000_000);
+
+            // Plain transfer stays hook-free for compatibility
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &token_call.transfer(receiver.account_id, 500),
+                )
+                .submit()
+                .await
+                .expect("transfer failed");
+            let balance = client
+                .call(
+                    &ink_e2e::alice(),
+                    &token_call.balance_of(receiver.account_id),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(balance, 500);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_recover_token_returns_foreign_tokens<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+
+            let mut dalla_constructor = DallaTokenRef::new(1_000_000);
+            let dalla = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut dalla_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+            let mut dalla_call = dalla.call_builder::<DallaToken>();
+
+            // Any PSP22 will do as the foreign token
+            let mut foreign_constructor = DallaTokenRef::new(1_000_000);
+            let foreign = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut foreign_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+            let mut foreign_call = foreign.call_builder::<DallaToken>();
+
+            // Tokens sent to the DALLA contract by mistake
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &foreign_call.transfer(dalla.account_id, 700),
+                )
+                .submit()
+                .await
+                .expect("transfer failed");
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &dalla_call.recover_token(foreign.account_id, bob, 700),
+                )
+                .submit()
+                .await
+                .expect("recover_token failed");
+
+            let recovered = client
+                .call(&ink_e2e::alice(), &foreign_call.balance_of(bob))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(recovered, 700);
+
+            let stuck = client
+                .call(
+                    &ink_e2e::alice(),
+                    &foreign_call.balance_of(dalla.account_id),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(stuck, 0);
+
+            Ok(())
+        }
     }
 }
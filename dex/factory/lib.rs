@@ -1,11 +1,25 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
+#[path = "../selectors.rs"]
+pub mod selectors;
+
+#[path = "../interfaces.rs"]
+pub mod interfaces;
+
+#[path = "../psp22_trait.rs"]
+pub mod psp22_trait;
+
 #[ink::contract]
 pub mod factory {
+    use crate::interfaces::{FeeInfo, FeeToListener};
+    use ink::codegen::TraitCallBuilder;
     use ink::prelude::vec::Vec;
     use ink::storage::Mapping;
     use scale::{Decode, Encode};
 
+    /// Share of trading fees paid to `fee_to` when enabled: 1/6 (in basis points)
+    pub const PROTOCOL_FEE_SHARE_BPS: u16 = 1_667;
+
     // ============================================================================
     // Storage
     // ============================================================================
@@ -18,6 +32,18 @@ pub mod factory {
         // Fee setter (can change fee_to)
         fee_to_setter: AccountId,
 
+        // Block timestamp of the last fee_to change (lets pairs spot stale caches)
+        fee_to_updated_at: u64,
+
+        // Push fee_to changes to every registered pair (best-effort)
+        notify_pairs: bool,
+
+        // Native fee charged by create_pair (0 = free, permissionless creation)
+        creation_fee: Balance,
+
+        // Creation fees held by the factory until withdrawn
+        accrued_creation_fees: Balance,
+
         // All created pairs: index => pair_address
         all_pairs: Mapping<u32, AccountId>,
 
@@ -54,6 +80,24 @@ pub mod factory {
         new_fee_to: Option<AccountId>,
     }
 
+    #[ink(event)]
+    pub struct NotifyPairsSet {
+        enabled: bool,
+    }
+
+    #[ink(event)]
+    pub struct CreationFeeSet {
+        old_fee: Balance,
+        new_fee: Balance,
+    }
+
+    #[ink(event)]
+    pub struct CreationFeesWithdrawn {
+        #[ink(topic)]
+        to: AccountId,
+        amount: Balance,
+    }
+
     #[ink(event)]
     pub struct FeeToSetterSet {
         #[ink(topic)]
@@ -79,6 +123,10 @@ pub mod factory {
         NotAuthorized,
         /// Pair instantiation failed
         PairInstantiationFailed,
+        /// Value sent with create_pair is below the creation fee
+        InsufficientCreationFee,
+        /// Native transfer (fee forward, refund or withdrawal) failed
+        TransferFailed,
     }
 
     pub type Result<T> = core::result::Result<T, Error>;
@@ -102,6 +150,10 @@ pub mod factory {
             Self {
                 fee_to: None,
                 fee_to_setter,
+                fee_to_updated_at: Self::env().block_timestamp(),
+                notify_pairs: false,
+                creation_fee: 0,
+                accrued_creation_fees: 0,
                 all_pairs: Mapping::default(),
                 get_pair: Mapping::default(),
                 all_pairs_length: 0,
@@ -152,12 +204,43 @@ pub mod factory {
             self.fee_to_setter
         }
 
+        /// Get all protocol fee settings in one call
+        ///
+        /// # Returns
+        /// (fee_to, fee share in basis points, timestamp of the last fee_to change)
+        #[ink(message)]
+        pub fn fee_info(&self) -> FeeInfo {
+            (self.fee_to, PROTOCOL_FEE_SHARE_BPS, self.fee_to_updated_at)
+        }
+
+        /// Whether fee_to changes are pushed to registered pairs
+        #[ink(message)]
+        pub fn notify_pairs(&self) -> bool {
+            self.notify_pairs
+        }
+
+        /// Get the native fee charged by `create_pair`
+        #[ink(message)]
+        pub fn creation_fee(&self) -> Balance {
+            self.creation_fee
+        }
+
+        /// Get the creation fees held by the factory awaiting withdrawal
+        #[ink(message)]
+        pub fn accrued_creation_fees(&self) -> Balance {
+            self.accrued_creation_fees
+        }
+
         // ========================================================================
         // State-Changing Functions
         // ========================================================================
 
         /// Create a new trading pair
         ///
+        /// The attached value must cover `creation_fee`. The fee goes to
+        /// `fee_to` (or stays in the factory for withdrawal when no recipient
+        /// is set or the forward fails) and any excess is refunded.
+        ///
         /// # Parameters
         /// * `token_a` - First token address
         /// * `token_b` - Second token address
@@ -169,7 +252,8 @@ pub mod factory {
         /// - Tokens must be different
         /// - Neither token can be zero address
         /// - Pair must not already exist
-        #[ink(message)]
+        /// - Transferred value must be at least `creation_fee`
+        #[ink(message, payable)]
         pub fn create_pair(&mut self, token_a: AccountId, token_b: AccountId) -> Result<AccountId> {
             // Validate inputs
             if token_a == token_b {
@@ -189,6 +273,12 @@ pub mod factory {
                 return Err(Error::PairExists);
             }
 
+            // Check creation fee
+            let paid = self.env().transferred_value();
+            if paid < self.creation_fee {
+                return Err(Error::InsufficientCreationFee);
+            }
+
             // Instantiate new Pair contract
             // NOTE: In production, this would use ink::env::call::create_contract
             // For now, we'll simulate the pair address
@@ -210,11 +300,25 @@ pub mod factory {
 
             self.all_pairs_length = self.all_pairs_length.saturating_add(1);
 
+            // Collect the fee and refund the rest
+            self._collect_creation_fee();
+            let excess = paid.saturating_sub(self.creation_fee);
+            if excess > 0 {
+                self.env()
+                    .transfer(self.env().caller(), excess)
+                    .map_err(|_| Error::TransferFailed)?;
+            }
+
             Ok(pair_address)
         }
 
         /// Set fee recipient address (fee_to_setter only)
         ///
+        /// When `notify_pairs` is on, every registered pair is told about the
+        /// change through `on_fee_to_changed`. Notifications are best-effort:
+        /// a pair that fails to take the update keeps its cached value until
+        /// it goes stale or someone calls `refresh_fee_to` on it.
+        ///
         /// # Parameters
         /// * `fee_to` - New fee recipient address (or None to disable fees)
         #[ink(message)]
@@ -226,15 +330,91 @@ pub mod factory {
 
             let old_fee_to = self.fee_to;
             self.fee_to = fee_to;
+            self.fee_to_updated_at = self.env().block_timestamp();
 
             self.env().emit_event(FeeToSet {
                 old_fee_to,
                 new_fee_to: fee_to,
             });
 
+            if self.notify_pairs {
+                self._notify_pairs();
+            }
+
             Ok(())
         }
 
+        /// Turn fee_to change notifications to pairs on or off (fee_to_setter only)
+        ///
+        /// Notifying costs one call per pair, so this is meant for deployments
+        /// with a modest number of pairs; pairs fall back to `refresh_fee_to`
+        /// either way.
+        ///
+        /// # Parameters
+        /// * `enabled` - Whether `set_fee_to` notifies registered pairs
+        #[ink(message)]
+        pub fn set_notify_pairs(&mut self, enabled: bool) -> Result<()> {
+            let caller = self.env().caller();
+            if caller != self.fee_to_setter {
+                return Err(Error::NotAuthorized);
+            }
+
+            self.notify_pairs = enabled;
+
+            self.env().emit_event(NotifyPairsSet { enabled });
+
+            Ok(())
+        }
+
+        /// Set the native fee charged by `create_pair` (fee_to_setter only)
+        ///
+        /// # Parameters
+        /// * `fee` - New creation fee (0 turns the fee off)
+        #[ink(message)]
+        pub fn set_creation_fee(&mut self, fee: Balance) -> Result<()> {
+            let caller = self.env().caller();
+            if caller != self.fee_to_setter {
+                return Err(Error::NotAuthorized);
+            }
+
+            let old_fee = self.creation_fee;
+            self.creation_fee = fee;
+
+            self.env().emit_event(CreationFeeSet {
+                old_fee,
+                new_fee: fee,
+            });
+
+            Ok(())
+        }
+
+        /// Withdraw creation fees held by the factory (fee_to_setter only)
+        ///
+        /// # Parameters
+        /// * `to` - Recipient of the accrued fees
+        ///
+        /// # Returns
+        /// Amount withdrawn
+        #[ink(message)]
+        pub fn withdraw_creation_fees(&mut self, to: AccountId) -> Result<Balance> {
+            let caller = self.env().caller();
+            if caller != self.fee_to_setter {
+                return Err(Error::NotAuthorized);
+            }
+
+            let amount = self.accrued_creation_fees;
+            if amount > 0 {
+                self.accrued_creation_fees = 0;
+                self.env()
+                    .transfer(to, amount)
+                    .map_err(|_| Error::TransferFailed)?;
+
+                self.env().emit_event(CreationFeesWithdrawn { to, amount });
+            }
+
+            Ok(amount)
+        }
+
         /// Set fee setter address (fee_to_setter only)
         ///
         /// # Parameters
@@ -279,6 +459,46 @@ pub mod factory {
             }
         }
 
+        /// Forward the creation fee to `fee_to`, or keep it for withdrawal
+        ///
+        /// A failed forward must not block pair creation, so the fee is
+        /// retained instead.
+        fn _collect_creation_fee(&mut self) {
+            let fee = self.creation_fee;
+            if fee == 0 {
+                return;
+            }
+
+            let forwarded = match self.fee_to {
+                Some(fee_to) => self.env().transfer(fee_to, fee).is_ok(),
+                None => false,
+            };
+
+            if !forwarded {
+                self.accrued_creation_fees = self.accrued_creation_fees.saturating_add(fee);
+            }
+        }
+
+        /// Push the current fee settings to every registered pair
+        ///
+        /// Failures (trapped pair, missing message, rejected caller) are
+        /// ignored so a single broken pair can never block `set_fee_to`.
+        fn _notify_pairs(&self) {
+            let (fee_to, fee_share_bps, updated_at) = self.fee_info();
+
+            for index in 0..self.all_pairs_length {
+                let Some(pair) = self.all_pairs.get(index) else {
+                    continue;
+                };
+
+                let mut listener: ink::contract_ref!(FeeToListener) = pair.into();
+                let _ = listener
+                    .call_mut()
+                    .on_fee_to_changed(fee_to, fee_share_bps, updated_at)
+                    .try_invoke();
+            }
+        }
+
         /// Create pair contract instance
         ///
         /// NOTE: This is a simplified version. Production implementation would use:
@@ -290,7 +510,7 @@ pub mod factory {
         ///     .gas_limit(0)
         ///     .endowment(0)
         ///     .exec_input(
-        ///         ExecutionInput::new(Selector::new(ink::selector_bytes!("new")))
+        ///         ExecutionInput::new(Selector::new(selectors::PAIR_NEW))
         ///             .push_arg(token0)
         ///             .push_arg(token1)
         ///     )
@@ -405,6 +625,114 @@ pub mod factory {
             );
         }
 
+        fn set_value(value: Balance) {
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(value);
+        }
+
+        fn set_balance(account: AccountId, balance: Balance) {
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(account, balance);
+        }
+
+        fn get_balance(account: AccountId) -> Balance {
+            ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(account)
+                .expect("account balance")
+        }
+
+        fn contract_id() -> AccountId {
+            ink::env::test::callee::<ink::env::DefaultEnvironment>()
+        }
+
+        /// Attach `value` to the next call, crediting it to the factory
+        fn pay(value: Balance) {
+            set_balance(contract_id(), value);
+            set_value(value);
+        }
+
+        #[ink::test]
+        fn create_pair_rejects_underpayment() {
+            let (setter, token_a, token_b) = get_test_accounts();
+            let mut factory = Factory::new(setter, Hash::from([0x42; 32]));
+            factory.set_creation_fee(100).unwrap();
+            assert_eq!(factory.creation_fee(), 100);
+
+            pay(99);
+            assert_eq!(
+                factory.create_pair(token_a, token_b),
+                Err(Error::InsufficientCreationFee)
+            );
+            assert_eq!(factory.all_pairs_length(), 0);
+        }
+
+        #[ink::test]
+        fn create_pair_forwards_exact_fee() {
+            let (setter, token_a, token_b) = get_test_accounts();
+            let fee_to = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>().django;
+            let mut factory = Factory::new(setter, Hash::from([0x42; 32]));
+            factory.set_creation_fee(100).unwrap();
+            factory.set_fee_to(Some(fee_to)).unwrap();
+            let fee_to_before = get_balance(fee_to);
+
+            pay(100);
+            factory.create_pair(token_a, token_b).unwrap();
+
+            assert_eq!(get_balance(fee_to), fee_to_before + 100);
+            assert_eq!(get_balance(contract_id()), 0);
+            assert_eq!(factory.accrued_creation_fees(), 0);
+        }
+
+        #[ink::test]
+        fn create_pair_refunds_excess() {
+            let (setter, token_a, token_b) = get_test_accounts();
+            let mut factory = Factory::new(setter, Hash::from([0x42; 32]));
+            factory.set_creation_fee(100).unwrap();
+            let caller_before = get_balance(setter);
+
+            // No fee_to: the fee stays in the factory, the excess goes back
+            pay(150);
+            factory.create_pair(token_a, token_b).unwrap();
+
+            assert_eq!(get_balance(setter), caller_before + 50);
+            assert_eq!(get_balance(contract_id()), 100);
+            assert_eq!(factory.accrued_creation_fees(), 100);
+
+            // The retained fee can be withdrawn by the fee_to_setter only
+            let recipient = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>().eve;
+            let recipient_before = get_balance(recipient);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(token_a);
+            assert_eq!(
+                factory.withdraw_creation_fees(recipient),
+                Err(Error::NotAuthorized)
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(setter);
+            assert_eq!(factory.withdraw_creation_fees(recipient), Ok(100));
+            assert_eq!(get_balance(recipient), recipient_before + 100);
+            assert_eq!(factory.accrued_creation_fees(), 0);
+        }
+
+        #[ink::test]
+        fn zero_creation_fee_keeps_creation_free() {
+            let (setter, token_a, token_b) = get_test_accounts();
+            let mut factory = Factory::new(setter, Hash::from([0x42; 32]));
+            assert_eq!(factory.creation_fee(), 0);
+            let contract_before = get_balance(contract_id());
+
+            factory.create_pair(token_a, token_b).unwrap();
+
+            assert_eq!(factory.all_pairs_length(), 1);
+            assert_eq!(factory.accrued_creation_fees(), 0);
+            assert_eq!(get_balance(contract_id()), contract_before);
+        }
+
+        #[ink::test]
+        fn set_creation_fee_fails_not_authorized() {
+            let (setter, _, other) = get_test_accounts();
+            let mut factory = Factory::new(setter, Hash::from([0x42; 32]));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(other);
+            assert_eq!(factory.set_creation_fee(100), Err(Error::NotAuthorized));
+            assert_eq!(factory.creation_fee(), 0);
+        }
+
         #[ink::test]
         fn set_fee_to_works() {
             let (setter, new_fee_to, _) = get_test_accounts();
@@ -434,6 +762,42 @@ pub mod factory {
             assert_eq!(factory.set_fee_to(Some(other)), Err(Error::NotAuthorized));
         }
 
+        #[ink::test]
+        fn fee_info_tracks_fee_to_changes() {
+            let (setter, new_fee_to, _) = get_test_accounts();
+            let code_hash = Hash::from([0x42; 32]);
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+            let mut factory = Factory::new(setter, code_hash);
+            assert_eq!(factory.fee_info(), (None, PROTOCOL_FEE_SHARE_BPS, 1_000));
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(5_000);
+            factory.set_fee_to(Some(new_fee_to)).unwrap();
+            assert_eq!(
+                factory.fee_info(),
+                (Some(new_fee_to), PROTOCOL_FEE_SHARE_BPS, 5_000)
+            );
+        }
+
+        #[ink::test]
+        fn set_notify_pairs_works() {
+            let (setter, _, other) = get_test_accounts();
+            let code_hash = Hash::from([0x42; 32]);
+
+            let mut factory = Factory::new(setter, code_hash);
+            assert!(!factory.notify_pairs());
+
+            factory.set_notify_pairs(true).unwrap();
+            assert!(factory.notify_pairs());
+
+            // No pairs registered yet, so there is nobody to notify
+            factory.set_fee_to(Some(other)).unwrap();
+            assert_eq!(factory.fee_to(), Some(other));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(other);
+            assert_eq!(factory.set_notify_pairs(false), Err(Error::NotAuthorized));
+        }
+
         #[ink::test]
         fn set_fee_to_setter_works() {
             let (setter, new_setter, _) = get_test_accounts();
@@ -444,5 +808,110 @@ pub mod factory {
             factory.set_fee_to_setter(new_setter).unwrap();
             assert_eq!(factory.fee_to_setter(), new_setter);
         }
+
+        #[ink::test]
+        fn selectors_match_message_names() {
+            assert_eq!(
+                crate::selectors::FACTORY_GET_PAIR_ADDRESS,
+                ink::selector_bytes!("get_pair_address")
+            );
+            assert_eq!(crate::selectors::PAIR_NEW, ink::selector_bytes!("new"));
+            // Pinned in interfaces.rs
+            assert_eq!(
+                crate::selectors::FACTORY_GET_PAIR_ADDRESS,
+                [0xe7, 0xac, 0xcb, 0x3e]
+            );
+            assert_eq!(
+                crate::selectors::FACTORY_CREATE_PAIR,
+                [0xad, 0xc9, 0x20, 0xb3]
+            );
+            assert_eq!(crate::selectors::FACTORY_FEE_TO, [0x35, 0x8e, 0x4c, 0x70]);
+            assert_eq!(crate::selectors::FACTORY_FEE_INFO, [0xa4, 0x2b, 0xd5, 0xf1]);
+            assert_eq!(
+                crate::selectors::PAIR_ON_FEE_TO_CHANGED,
+                [0xc0, 0x42, 0x2b, 0xa5]
+            );
+        }
+
+        #[ink::test]
+        fn error_encoding_matches_interface() {
+            use crate::interfaces::FactoryError;
+
+            assert_eq!(
+                Error::IdenticalAddresses.encode(),
+                FactoryError::IdenticalAddresses.encode()
+            );
+            assert_eq!(
+                Error::PairExists.encode(),
+                FactoryError::PairExists.encode()
+            );
+            assert_eq!(
+                Error::PairInstantiationFailed.encode(),
+                FactoryError::PairInstantiationFailed.encode()
+            );
+            assert_eq!(
+                Error::TransferFailed.encode(),
+                FactoryError::TransferFailed.encode()
+            );
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use ink_e2e::ContractsBackend;
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn e2e_failed_notifications_do_not_block_set_fee_to<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+
+            let mut constructor = FactoryRef::new(alice, Hash::from([0x42; 32]));
+            let factory = client
+                .instantiate("belizex_factory", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("belizex_factory instantiate failed");
+            let mut call = factory.call_builder::<Factory>();
+
+            // Registered pairs are plain accounts, so every notification fails
+            for (token_a, token_b) in [([0x01; 32], [0x02; 32]), ([0x03; 32], [0x04; 32])] {
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call.create_pair(AccountId::from(token_a), AccountId::from(token_b)),
+                    )
+                    .submit()
+                    .await
+                    .expect("create_pair failed");
+            }
+            client
+                .call(&ink_e2e::alice(), &call.set_notify_pairs(true))
+                .submit()
+                .await
+                .expect("set_notify_pairs failed");
+
+            let result = client
+                .call(&ink_e2e::alice(), &call.set_fee_to(Some(bob)))
+                .submit()
+                .await
+                .expect("set_fee_to failed")
+                .return_value();
+            assert_eq!(result, Ok(()));
+
+            let (fee_to, fee_share_bps, _) = client
+                .call(&ink_e2e::alice(), &call.fee_info())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(fee_to, Some(bob));
+            assert_eq!(fee_share_bps, PROTOCOL_FEE_SHARE_BPS);
+
+            Ok(())
+        }
     }
 }
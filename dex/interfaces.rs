@@ -0,0 +1,159 @@
+//! BelizeX Pair and Factory Interfaces
+//!
+//! Trait definitions for the Pair and Factory messages the BelizeX contracts
+//! call on one another, so cross-contract calls go through `ink::contract_ref!`
+//! with compile-time argument checking. Selectors are pinned to the contracts' inherent messages
+//! (see `selectors.rs`), which means any contract implementing these traits,
+//! including test mocks, can stand in for a real Pair or Factory.
+//!
+//! Crates including this module must also include `psp22_trait.rs`.
+
+use crate::psp22_trait::PSP22Error;
+use ink::primitives::AccountId;
+
+/// Protocol fee settings as returned by `Factory::fee_info`
+///
+/// (fee_to, fee share in basis points, timestamp of the last `fee_to` change)
+pub type FeeInfo = (Option<AccountId>, u16, u64);
+
+/// Pair Error types
+///
+/// Mirrors `pair::Error` variant for variant so error payloads decode.
+#[derive(Debug, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub enum PairError {
+    InsufficientLiquidityMinted,
+    InsufficientLiquidityBurned,
+    InsufficientOutputAmount,
+    InsufficientLiquidity,
+    InvalidTo,
+    InsufficientInputAmount,
+    KValueDecreased,
+    Overflow,
+    IdenticalAddresses,
+    ZeroAddress,
+    InsufficientBalance,
+    InsufficientAllowance,
+    Locked,
+    TransferFailed,
+    TokenError(PSP22Error),
+    NotFactory,
+    CallFailed,
+}
+
+/// Factory Error types
+///
+/// Mirrors `factory::Error` variant for variant so error payloads decode.
+#[derive(Debug, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub enum FactoryError {
+    IdenticalAddresses,
+    ZeroAddress,
+    PairExists,
+    NotAuthorized,
+    PairInstantiationFailed,
+    InsufficientCreationFee,
+    TransferFailed,
+}
+
+/// BelizeX Pair Interface
+///
+/// Liquidity and swap messages needed by the Router
+#[ink::trait_definition]
+pub trait PairInterface {
+    /// Returns (token0, token1), sorted by address
+    #[ink(message, selector = 0xba045271)]
+    fn get_tokens(&self) -> (AccountId, AccountId);
+
+    /// Returns (reserve0, reserve1, block_timestamp_last)
+    #[ink(message, selector = 0x8a0d116f)]
+    fn get_reserves(&self) -> (u128, u128, u64);
+
+    /// Mints LP tokens to `to` for the tokens transferred in since the last sync
+    #[ink(message, selector = 0xcfdd9aa2)]
+    fn mint(&mut self, to: AccountId) -> Result<u128, PairError>;
+
+    /// Burns the LP tokens held by the pair and sends the underlying tokens to `to`
+    #[ink(message, selector = 0xb1efc17b)]
+    fn burn(&mut self, to: AccountId) -> Result<(u128, u128), PairError>;
+
+    /// Sends the requested output amounts to `to`
+    #[ink(message, selector = 0x11004fa6)]
+    fn swap(
+        &mut self,
+        amount0_out: u128,
+        amount1_out: u128,
+        to: AccountId,
+    ) -> Result<(), PairError>;
+
+    /// Returns the swap fee in basis points
+    #[ink(message, selector = 0xf18316c5)]
+    fn fee_bps(&self) -> u32;
+}
+
+/// BelizeX fee_to listener
+///
+/// Message the Factory pushes to its pairs when `fee_to` changes, so they can
+/// cache the fee settings instead of querying the Factory on every liquidity
+/// event. Kept apart from `PairInterface` since only the Factory calls it.
+#[ink::trait_definition]
+pub trait FeeToListener {
+    /// Caches the new fee settings (Factory only)
+    #[ink(message, selector = 0xc0422ba5)]
+    fn on_fee_to_changed(
+        &mut self,
+        fee_to: Option<AccountId>,
+        fee_share_bps: u16,
+        updated_at: u64,
+    ) -> Result<(), PairError>;
+}
+
+/// BelizeX Pair LP token
+///
+/// The Pair's LP token messages are inherent (not `PSP22::`-prefixed), so
+/// they get their own trait rather than going through `psp22_trait::PSP22`
+#[ink::trait_definition]
+pub trait PairLpToken {
+    /// Returns the LP token balance of `owner`
+    #[ink(message, selector = 0x0f755a56)]
+    fn balance_of(&self, owner: AccountId) -> u128;
+
+    /// Transfers LP tokens to `to`
+    #[ink(message, selector = 0x84a15da1)]
+    fn transfer(&mut self, to: AccountId, value: u128) -> Result<(), PairError>;
+
+    /// Transfers LP tokens from `from` to `to` (requires allowance)
+    #[ink(message, selector = 0x0b396f18)]
+    fn transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        value: u128,
+    ) -> Result<(), PairError>;
+}
+
+/// BelizeX Factory Interface
+///
+/// Pair registry messages needed by the Router
+#[ink::trait_definition]
+pub trait FactoryInterface {
+    /// Returns the pair for two tokens, in either order
+    #[ink(message, selector = 0xe7accb3e)]
+    fn get_pair_address(&self, token_a: AccountId, token_b: AccountId) -> Option<AccountId>;
+
+    /// Creates the pair for two tokens, paying the native creation fee (if any)
+    #[ink(message, payable, selector = 0xadc920b3)]
+    fn create_pair(
+        &mut self,
+        token_a: AccountId,
+        token_b: AccountId,
+    ) -> Result<AccountId, FactoryError>;
+
+    /// Returns the protocol fee recipient, if fees are switched on
+    #[ink(message, selector = 0x358e4c70)]
+    fn fee_to(&self) -> Option<AccountId>;
+
+    /// Returns (fee_to, fee share in basis points, last updated) in one call
+    #[ink(message, selector = 0xa42bd5f1)]
+    fn fee_info(&self) -> FeeInfo;
+}
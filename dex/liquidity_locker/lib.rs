@@ -0,0 +1,555 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+//! # Liquidity Locker
+//!
+//! Third-party time lock for BelizeX Pair LP tokens, so projects can prove
+//! their liquidity can't be pulled before a given date.
+//!
+//! ## Features
+//! - `lock` pulls LP tokens with `transfer_from` and records a lock per
+//!   (owner, lock_id); one owner can hold any number of locks on any pairs
+//! - `extend` only ever pushes the unlock date later
+//! - `withdraw` returns the tokens once the lock has expired
+//! - Locks can be listed per owner and per pair, and `locked_amount` gives
+//!   the total still held for a pair, so explorers can show the share of a
+//!   pool that is locked
+
+#[path = "../psp22_trait.rs"]
+pub mod psp22_trait;
+
+#[path = "../interfaces.rs"]
+pub mod interfaces;
+
+#[ink::contract]
+pub mod liquidity_locker {
+    use crate::interfaces::{PairError, PairLpToken};
+    use ink::codegen::TraitCallBuilder;
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
+    use scale::{Decode, Encode};
+
+    /// Per-owner lock identifier
+    pub type LockId = u32;
+
+    /// Locked LP tokens
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Lock {
+        /// Pair whose LP tokens are locked
+        pub pair: AccountId,
+        /// LP tokens held
+        pub amount: Balance,
+        /// Time from which the owner can withdraw
+        pub unlock_at: Timestamp,
+    }
+
+    // ============================================================================
+    // Storage
+    // ============================================================================
+
+    #[ink(storage)]
+    pub struct LiquidityLocker {
+        // Open locks: (owner, lock_id) => lock
+        locks: Mapping<(AccountId, LockId), Lock>,
+
+        // Locks ever created per owner (next lock_id)
+        lock_count: Mapping<AccountId, LockId>,
+
+        // Locks ever created per pair: (pair, index) => (owner, lock_id)
+        pair_locks: Mapping<(AccountId, u32), (AccountId, LockId)>,
+
+        // Number of entries in pair_locks per pair
+        pair_lock_count: Mapping<AccountId, u32>,
+
+        // LP tokens currently locked per pair
+        locked_amounts: Mapping<AccountId, Balance>,
+    }
+
+    // ============================================================================
+    // Events
+    // ============================================================================
+
+    #[ink(event)]
+    pub struct Locked {
+        #[ink(topic)]
+        owner: AccountId,
+        lock_id: LockId,
+        #[ink(topic)]
+        pair: AccountId,
+        amount: Balance,
+        unlock_at: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct LockExtended {
+        #[ink(topic)]
+        owner: AccountId,
+        lock_id: LockId,
+        #[ink(topic)]
+        pair: AccountId,
+        unlock_at: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct Withdrawn {
+        #[ink(topic)]
+        owner: AccountId,
+        lock_id: LockId,
+        #[ink(topic)]
+        pair: AccountId,
+        amount: Balance,
+    }
+
+    // ============================================================================
+    // Errors
+    // ============================================================================
+
+    #[derive(Debug, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        /// Zero amount
+        ZeroAmount,
+        /// Unlock time is not in the future
+        UnlockInPast,
+        /// Lock does not exist (never created or already withdrawn)
+        LockNotFound,
+        /// Lock has not expired yet
+        StillLocked,
+        /// New unlock time is not later than the current one
+        UnlockNotLater,
+        /// Arithmetic overflow
+        Overflow,
+        /// LP token rejected the transfer
+        LpTokenError(PairError),
+        /// Cross-contract call failed
+        CallFailed,
+    }
+
+    pub type Result<T> = core::result::Result<T, Error>;
+
+    // ============================================================================
+    // Implementation
+    // ============================================================================
+
+    impl LiquidityLocker {
+        // ========================================================================
+        // Constructor
+        // ========================================================================
+
+        /// Create a locker
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {
+                locks: Mapping::default(),
+                lock_count: Mapping::default(),
+                pair_locks: Mapping::default(),
+                pair_lock_count: Mapping::default(),
+                locked_amounts: Mapping::default(),
+            }
+        }
+
+        // ========================================================================
+        // View Functions
+        // ========================================================================
+
+        /// Get an open lock
+        #[ink(message)]
+        pub fn lock_of(&self, owner: AccountId, lock_id: LockId) -> Option<Lock> {
+            self.locks.get((owner, lock_id))
+        }
+
+        /// Get the number of locks `owner` has ever created (withdrawn included)
+        #[ink(message)]
+        pub fn lock_count(&self, owner: AccountId) -> LockId {
+            self.lock_count.get(owner).unwrap_or(0)
+        }
+
+        /// Get the open locks of `owner` among lock ids `offset..offset + limit`
+        #[ink(message)]
+        pub fn locks_of(
+            &self,
+            owner: AccountId,
+            offset: LockId,
+            limit: u32,
+        ) -> Vec<(LockId, Lock)> {
+            let end = offset.saturating_add(limit).min(self.lock_count(owner));
+
+            (offset..end)
+                .filter_map(|lock_id| Some((lock_id, self.locks.get((owner, lock_id))?)))
+                .collect()
+        }
+
+        /// Get the number of locks ever created on `pair` (withdrawn included)
+        #[ink(message)]
+        pub fn pair_lock_count(&self, pair: AccountId) -> u32 {
+            self.pair_lock_count.get(pair).unwrap_or(0)
+        }
+
+        /// Get the open locks on `pair` among entries `offset..offset + limit`
+        ///
+        /// # Returns
+        /// (owner, lock_id, lock) for each open lock
+        #[ink(message)]
+        pub fn locks_for_pair(
+            &self,
+            pair: AccountId,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<(AccountId, LockId, Lock)> {
+            let end = offset.saturating_add(limit).min(self.pair_lock_count(pair));
+
+            (offset..end)
+                .filter_map(|index| {
+                    let (owner, lock_id) = self.pair_locks.get((pair, index))?;
+                    Some((owner, lock_id, self.locks.get((owner, lock_id))?))
+                })
+                .collect()
+        }
+
+        /// Get the LP tokens of `pair` currently held by the locker
+        ///
+        /// Divide by the pair's total supply for the share of the pool locked.
+        #[ink(message)]
+        pub fn locked_amount(&self, pair: AccountId) -> Balance {
+            self.locked_amounts.get(pair).unwrap_or(0)
+        }
+
+        // ========================================================================
+        // Lock Functions
+        // ========================================================================
+
+        /// Lock LP tokens of `pair` until `unlock_at`
+        ///
+        /// The caller must first approve this contract on the Pair.
+        ///
+        /// # Returns
+        /// Id of the new lock, unique per owner
+        #[ink(message)]
+        pub fn lock(
+            &mut self,
+            pair: AccountId,
+            amount: Balance,
+            unlock_at: Timestamp,
+        ) -> Result<LockId> {
+            let caller = self.env().caller();
+            let lock_id = self._lock(caller, pair, amount, unlock_at)?;
+
+            let mut lp_token: ink::contract_ref!(PairLpToken) = pair.into();
+            Self::_lp_result(
+                lp_token
+                    .call_mut()
+                    .transfer_from(caller, self.env().account_id(), amount)
+                    .try_invoke(),
+            )?;
+
+            self.env().emit_event(Locked {
+                owner: caller,
+                lock_id,
+                pair,
+                amount,
+                unlock_at,
+            });
+
+            Ok(lock_id)
+        }
+
+        /// Push back the unlock time of one of the caller's locks
+        ///
+        /// `new_unlock_at` must be later than the current unlock time; locks
+        /// can never be shortened.
+        #[ink(message)]
+        pub fn extend(&mut self, lock_id: LockId, new_unlock_at: Timestamp) -> Result<()> {
+            let caller = self.env().caller();
+            let mut lock = self
+                .locks
+                .get((caller, lock_id))
+                .ok_or(Error::LockNotFound)?;
+
+            if new_unlock_at <= lock.unlock_at {
+                return Err(Error::UnlockNotLater);
+            }
+
+            lock.unlock_at = new_unlock_at;
+            self.locks.insert((caller, lock_id), &lock);
+
+            self.env().emit_event(LockExtended {
+                owner: caller,
+                lock_id,
+                pair: lock.pair,
+                unlock_at: new_unlock_at,
+            });
+
+            Ok(())
+        }
+
+        /// Withdraw the LP tokens of an expired lock
+        ///
+        /// # Returns
+        /// Amount of LP tokens returned
+        #[ink(message)]
+        pub fn withdraw(&mut self, lock_id: LockId) -> Result<Balance> {
+            let caller = self.env().caller();
+            let lock = self._withdraw(caller, lock_id)?;
+
+            let mut lp_token: ink::contract_ref!(PairLpToken) = lock.pair.into();
+            Self::_lp_result(
+                lp_token
+                    .call_mut()
+                    .transfer(caller, lock.amount)
+                    .try_invoke(),
+            )?;
+
+            self.env().emit_event(Withdrawn {
+                owner: caller,
+                lock_id,
+                pair: lock.pair,
+                amount: lock.amount,
+            });
+
+            Ok(lock.amount)
+        }
+
+        // ========================================================================
+        // Internal Functions
+        // ========================================================================
+
+        /// Record a lock (the LP transfer is done by the caller)
+        fn _lock(
+            &mut self,
+            owner: AccountId,
+            pair: AccountId,
+            amount: Balance,
+            unlock_at: Timestamp,
+        ) -> Result<LockId> {
+            if amount == 0 {
+                return Err(Error::ZeroAmount);
+            }
+            if unlock_at <= self.env().block_timestamp() {
+                return Err(Error::UnlockInPast);
+            }
+
+            let locked = self
+                .locked_amount(pair)
+                .checked_add(amount)
+                .ok_or(Error::Overflow)?;
+
+            let lock_id = self.lock_count(owner);
+            let index = self.pair_lock_count(pair);
+
+            self.locks.insert(
+                (owner, lock_id),
+                &Lock {
+                    pair,
+                    amount,
+                    unlock_at,
+                },
+            );
+            self.lock_count
+                .insert(owner, &lock_id.checked_add(1).ok_or(Error::Overflow)?);
+            self.pair_locks.insert((pair, index), &(owner, lock_id));
+            self.pair_lock_count
+                .insert(pair, &index.checked_add(1).ok_or(Error::Overflow)?);
+            self.locked_amounts.insert(pair, &locked);
+
+            Ok(lock_id)
+        }
+
+        /// Remove an expired lock (the LP transfer is done by the caller)
+        fn _withdraw(&mut self, owner: AccountId, lock_id: LockId) -> Result<Lock> {
+            let lock = self
+                .locks
+                .get((owner, lock_id))
+                .ok_or(Error::LockNotFound)?;
+
+            if self.env().block_timestamp() < lock.unlock_at {
+                return Err(Error::StillLocked);
+            }
+
+            self.locks.remove((owner, lock_id));
+            self.locked_amounts.insert(
+                lock.pair,
+                &self.locked_amount(lock.pair).saturating_sub(lock.amount),
+            );
+
+            Ok(lock)
+        }
+
+        /// Map an LP token call result
+        fn _lp_result(
+            result: ink::env::Result<ink::MessageResult<core::result::Result<(), PairError>>>,
+        ) -> Result<()> {
+            match result {
+                Ok(Ok(Ok(()))) => Ok(()),
+                Ok(Ok(Err(e))) => Err(Error::LpTokenError(e)),
+                _ => Err(Error::CallFailed),
+            }
+        }
+    }
+
+    impl Default for LiquidityLocker {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    // ============================================================================
+    // Tests
+    // ============================================================================
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn accounts() -> ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment> {
+            ink::env::test::default_accounts::<ink::env::DefaultEnvironment>()
+        }
+
+        fn set_time(timestamp: Timestamp) {
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(timestamp);
+        }
+
+        fn set_caller(account: AccountId) {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(account);
+        }
+
+        /// Locker at t = 0 with bob's 100 LP tokens of django locked until t = 1_000
+        fn create_locker() -> LiquidityLocker {
+            let accounts = accounts();
+            set_time(0);
+            let mut locker = LiquidityLocker::new();
+            assert_eq!(
+                locker._lock(accounts.bob, accounts.django, 100, 1_000),
+                Ok(0)
+            );
+            locker
+        }
+
+        #[ink::test]
+        fn lock_is_recorded() {
+            let accounts = accounts();
+            let locker = create_locker();
+
+            assert_eq!(
+                locker.lock_of(accounts.bob, 0),
+                Some(Lock {
+                    pair: accounts.django,
+                    amount: 100,
+                    unlock_at: 1_000,
+                })
+            );
+            assert_eq!(locker.lock_count(accounts.bob), 1);
+            assert_eq!(locker.locked_amount(accounts.django), 100);
+        }
+
+        #[ink::test]
+        fn invalid_locks_are_rejected() {
+            let accounts = accounts();
+            let mut locker = create_locker();
+            set_time(500);
+
+            assert_eq!(
+                locker._lock(accounts.bob, accounts.django, 0, 1_000),
+                Err(Error::ZeroAmount)
+            );
+            assert_eq!(
+                locker._lock(accounts.bob, accounts.django, 100, 500),
+                Err(Error::UnlockInPast)
+            );
+        }
+
+        #[ink::test]
+        fn early_withdraw_is_rejected() {
+            let accounts = accounts();
+            let mut locker = create_locker();
+
+            set_time(999);
+            assert_eq!(locker._withdraw(accounts.bob, 0), Err(Error::StillLocked));
+            set_caller(accounts.bob);
+            assert_eq!(locker.withdraw(0), Err(Error::StillLocked));
+
+            // Nobody else can withdraw bob's lock
+            set_time(1_000);
+            assert_eq!(
+                locker._withdraw(accounts.charlie, 0),
+                Err(Error::LockNotFound)
+            );
+
+            let lock = locker._withdraw(accounts.bob, 0).unwrap();
+            assert_eq!(lock.amount, 100);
+            assert_eq!(locker.lock_of(accounts.bob, 0), None);
+            assert_eq!(locker.locked_amount(accounts.django), 0);
+            assert_eq!(locker._withdraw(accounts.bob, 0), Err(Error::LockNotFound));
+        }
+
+        #[ink::test]
+        fn extension_only_pushes_unlock_later() {
+            let accounts = accounts();
+            let mut locker = create_locker();
+            set_caller(accounts.bob);
+
+            assert_eq!(locker.extend(0, 999), Err(Error::UnlockNotLater));
+            assert_eq!(locker.extend(0, 1_000), Err(Error::UnlockNotLater));
+            assert_eq!(locker.extend(0, 2_000), Ok(()));
+            assert_eq!(locker.lock_of(accounts.bob, 0).unwrap().unlock_at, 2_000);
+            assert_eq!(locker.extend(0, 1_500), Err(Error::UnlockNotLater));
+
+            // The extended date applies to withdrawals
+            set_time(1_500);
+            assert_eq!(locker._withdraw(accounts.bob, 0), Err(Error::StillLocked));
+
+            // Only the owner can extend
+            set_caller(accounts.charlie);
+            assert_eq!(locker.extend(0, 3_000), Err(Error::LockNotFound));
+        }
+
+        #[ink::test]
+        fn multiple_locks_per_pair() {
+            let accounts = accounts();
+            let mut locker = create_locker();
+            let (pair, other_pair) = (accounts.django, accounts.eve);
+
+            assert_eq!(locker._lock(accounts.bob, pair, 50, 2_000), Ok(1));
+            assert_eq!(locker._lock(accounts.charlie, pair, 25, 3_000), Ok(0));
+            assert_eq!(locker._lock(accounts.bob, other_pair, 10, 2_000), Ok(2));
+
+            assert_eq!(locker.locked_amount(pair), 175);
+            assert_eq!(locker.locked_amount(other_pair), 10);
+            assert_eq!(locker.pair_lock_count(pair), 3);
+
+            let owners: Vec<_> = locker
+                .locks_for_pair(pair, 0, 10)
+                .into_iter()
+                .map(|(owner, lock_id, lock)| (owner, lock_id, lock.amount))
+                .collect();
+            assert_eq!(
+                owners,
+                vec![
+                    (accounts.bob, 0, 100),
+                    (accounts.bob, 1, 50),
+                    (accounts.charlie, 0, 25),
+                ]
+            );
+
+            let bob_locks: Vec<_> = locker
+                .locks_of(accounts.bob, 0, 10)
+                .into_iter()
+                .map(|(lock_id, lock)| (lock_id, lock.pair))
+                .collect();
+            assert_eq!(bob_locks, vec![(0, pair), (1, pair), (2, other_pair)]);
+
+            // Withdrawn locks drop out of both listings
+            set_time(1_000);
+            locker._withdraw(accounts.bob, 0).unwrap();
+            assert_eq!(locker.locked_amount(pair), 75);
+            assert_eq!(locker.locks_for_pair(pair, 0, 10).len(), 2);
+            assert_eq!(locker.locks_of(accounts.bob, 0, 10).len(), 2);
+
+            // Pagination
+            assert_eq!(locker.locks_for_pair(pair, 2, 10).len(), 1);
+            assert_eq!(locker.locks_for_pair(pair, 0, 1).len(), 0);
+        }
+    }
+}
@@ -0,0 +1,762 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![allow(clippy::arithmetic_side_effects)]
+
+//! # LP Staking Contract
+//!
+//! Liquidity mining for BelizeX: stake a Pair's LP tokens and earn DALLA.
+//!
+//! ## Features
+//! - Rewards stream at `reward_rate` tokens per millisecond, split pro rata
+//!   across stakers through a `reward_per_token` accumulator
+//! - The constructor's rate runs open-ended; `notify_reward_amount` switches
+//!   to a funded period of fixed length, rolling over any undistributed rewards
+//! - Owner-controlled emissions via the `access_control` Ownable module
+
+#[path = "../psp22_trait.rs"]
+pub mod psp22_trait;
+
+#[path = "../interfaces.rs"]
+pub mod interfaces;
+
+#[ink::contract]
+pub mod lp_staking {
+    use crate::interfaces::{PairError, PairLpToken};
+    use crate::psp22_trait::{PSP22Error, PSP22};
+    use access_control::ownable::OwnableData;
+    use access_control::AccessError;
+    use gem_math::mul_div;
+    use ink::codegen::TraitCallBuilder;
+    use ink::storage::Mapping;
+    use scale::{Decode, Encode};
+
+    /// Fixed-point scale of `reward_per_token`
+    pub const PRECISION: u128 = 1_000_000_000_000_000_000;
+
+    /// `period_finish` while the constructor's open-ended rate is in effect
+    pub const OPEN_ENDED: Timestamp = Timestamp::MAX;
+
+    // ============================================================================
+    // Storage
+    // ============================================================================
+
+    #[ink(storage)]
+    pub struct LpStaking {
+        // Owner allowed to start reward periods
+        ownable: OwnableData,
+
+        // Pair whose LP tokens are staked
+        staking_token: AccountId,
+
+        // Token paid out as rewards (DALLA)
+        rewards_token: AccountId,
+
+        // Reward tokens emitted per millisecond
+        reward_rate: Balance,
+
+        // End of the current reward period
+        period_finish: Timestamp,
+
+        // Last time reward_per_token_stored was brought up to date
+        last_update_time: Timestamp,
+
+        // Rewards per staked token (scaled by PRECISION) accrued so far
+        reward_per_token_stored: u128,
+
+        // reward_per_token_stored when each account was last settled
+        user_reward_per_token_paid: Mapping<AccountId, u128>,
+
+        // Settled, unclaimed rewards
+        rewards: Mapping<AccountId, Balance>,
+
+        // Total LP tokens staked
+        total_staked: Balance,
+
+        // LP tokens staked per account
+        balances: Mapping<AccountId, Balance>,
+    }
+
+    // ============================================================================
+    // Events
+    // ============================================================================
+
+    #[ink(event)]
+    pub struct Staked {
+        #[ink(topic)]
+        user: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Withdrawn {
+        #[ink(topic)]
+        user: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct RewardPaid {
+        #[ink(topic)]
+        user: AccountId,
+        reward: Balance,
+    }
+
+    #[ink(event)]
+    pub struct RewardAdded {
+        reward: Balance,
+        duration: Timestamp,
+        reward_rate: Balance,
+    }
+
+    // ============================================================================
+    // Errors
+    // ============================================================================
+
+    #[derive(Debug, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        /// Zero amount
+        ZeroAmount,
+        /// Reward period of zero length
+        ZeroDuration,
+        /// Withdrawal exceeds the caller's stake
+        InsufficientStake,
+        /// Arithmetic overflow
+        Overflow,
+        /// LP token rejected the transfer
+        LpTokenError(PairError),
+        /// Reward token rejected the transfer
+        TokenError(PSP22Error),
+        /// Cross-contract call failed
+        CallFailed,
+        /// Caller is not the owner
+        NotOwner,
+        /// Caller is not the pending owner
+        NotPendingOwner,
+        /// New owner is the zero address
+        ZeroAddress,
+    }
+
+    impl From<AccessError> for Error {
+        fn from(error: AccessError) -> Self {
+            match error {
+                AccessError::NotPendingOwner => Error::NotPendingOwner,
+                AccessError::ZeroAddress => Error::ZeroAddress,
+                _ => Error::NotOwner,
+            }
+        }
+    }
+
+    pub type Result<T> = core::result::Result<T, Error>;
+
+    // ============================================================================
+    // Implementation
+    // ============================================================================
+
+    impl LpStaking {
+        // ========================================================================
+        // Constructor
+        // ========================================================================
+
+        /// Create a staking pool
+        ///
+        /// # Parameters
+        /// * `staking_token` - Pair whose LP tokens are staked
+        /// * `rewards_token` - Reward token (DALLA)
+        /// * `reward_rate` - Reward tokens per millisecond, emitted until the
+        ///   first `notify_reward_amount`; the contract must hold enough
+        ///   reward tokens to pay out
+        #[ink(constructor)]
+        pub fn new(
+            staking_token: AccountId,
+            rewards_token: AccountId,
+            reward_rate: Balance,
+        ) -> Self {
+            Self {
+                ownable: OwnableData::new(Self::env().caller()),
+                staking_token,
+                rewards_token,
+                reward_rate,
+                period_finish: OPEN_ENDED,
+                last_update_time: Self::env().block_timestamp(),
+                reward_per_token_stored: 0,
+                user_reward_per_token_paid: Mapping::default(),
+                rewards: Mapping::default(),
+                total_staked: 0,
+                balances: Mapping::default(),
+            }
+        }
+
+        // ========================================================================
+        // View Functions
+        // ========================================================================
+
+        /// Get the staked LP token (Pair) address
+        #[ink(message)]
+        pub fn staking_token(&self) -> AccountId {
+            self.staking_token
+        }
+
+        /// Get the reward token address
+        #[ink(message)]
+        pub fn rewards_token(&self) -> AccountId {
+            self.rewards_token
+        }
+
+        /// Get the reward tokens emitted per millisecond
+        #[ink(message)]
+        pub fn reward_rate(&self) -> Balance {
+            self.reward_rate
+        }
+
+        /// Get the end of the current reward period (`OPEN_ENDED` if unbounded)
+        #[ink(message)]
+        pub fn period_finish(&self) -> Timestamp {
+            self.period_finish
+        }
+
+        /// Get the total LP tokens staked
+        #[ink(message)]
+        pub fn total_staked(&self) -> Balance {
+            self.total_staked
+        }
+
+        /// Get the LP tokens staked by `account`
+        #[ink(message)]
+        pub fn balance_of(&self, account: AccountId) -> Balance {
+            self.balances.get(account).unwrap_or(0)
+        }
+
+        /// Get the latest time rewards have been emitted up to
+        #[ink(message)]
+        pub fn last_time_reward_applicable(&self) -> Timestamp {
+            self.env().block_timestamp().min(self.period_finish)
+        }
+
+        /// Get the rewards accrued per staked LP token, scaled by `PRECISION`
+        #[ink(message)]
+        pub fn reward_per_token(&self) -> Result<u128> {
+            self._reward_per_token()
+        }
+
+        /// Get the rewards `account` could claim now
+        #[ink(message)]
+        pub fn earned(&self, account: AccountId) -> Result<Balance> {
+            self._earned(account, self._reward_per_token()?)
+        }
+
+        /// Get the current owner
+        #[ink(message)]
+        pub fn owner(&self) -> Option<AccountId> {
+            self.ownable.owner()
+        }
+
+        /// Get the account that may accept ownership, if a transfer is pending
+        #[ink(message)]
+        pub fn pending_owner(&self) -> Option<AccountId> {
+            self.ownable.pending_owner()
+        }
+
+        // ========================================================================
+        // Staking Functions
+        // ========================================================================
+
+        /// Stake LP tokens
+        ///
+        /// The caller must first approve this contract on the Pair.
+        #[ink(message)]
+        pub fn stake(&mut self, amount: Balance) -> Result<()> {
+            let caller = self.env().caller();
+            self._stake(caller, amount)?;
+
+            let mut lp_token: ink::contract_ref!(PairLpToken) = self.staking_token.into();
+            Self::_lp_result(
+                lp_token
+                    .call_mut()
+                    .transfer_from(caller, self.env().account_id(), amount)
+                    .try_invoke(),
+            )?;
+
+            self.env().emit_event(Staked {
+                user: caller,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Withdraw staked LP tokens
+        ///
+        /// Rewards earned so far stay claimable.
+        #[ink(message)]
+        pub fn withdraw(&mut self, amount: Balance) -> Result<()> {
+            let caller = self.env().caller();
+            self._withdraw(caller, amount)?;
+
+            let mut lp_token: ink::contract_ref!(PairLpToken) = self.staking_token.into();
+            Self::_lp_result(lp_token.call_mut().transfer(caller, amount).try_invoke())?;
+
+            self.env().emit_event(Withdrawn {
+                user: caller,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Claim all earned rewards
+        ///
+        /// # Returns
+        /// Amount of reward tokens paid out
+        #[ink(message)]
+        pub fn claim_rewards(&mut self) -> Result<Balance> {
+            let caller = self.env().caller();
+            let reward = self._take_reward(caller)?;
+
+            if reward > 0 {
+                let mut rewards_token: ink::contract_ref!(PSP22) = self.rewards_token.into();
+                match rewards_token
+                    .call_mut()
+                    .transfer(caller, reward)
+                    .try_invoke()
+                {
+                    Ok(Ok(Ok(()))) => {}
+                    Ok(Ok(Err(e))) => return Err(Error::TokenError(e)),
+                    _ => return Err(Error::CallFailed),
+                }
+
+                self.env().emit_event(RewardPaid {
+                    user: caller,
+                    reward,
+                });
+            }
+
+            Ok(reward)
+        }
+
+        // ========================================================================
+        // Admin Functions
+        // ========================================================================
+
+        /// Start a reward period distributing `amount` over `duration` ms (owner only)
+        ///
+        /// Rewards left from a running period are added to `amount`. The
+        /// caller must first approve this contract on the reward token.
+        #[ink(message)]
+        pub fn notify_reward_amount(&mut self, amount: Balance, duration: Timestamp) -> Result<()> {
+            let caller = self.env().caller();
+            self.ownable.ensure_owner(caller)?;
+
+            if amount == 0 {
+                return Err(Error::ZeroAmount);
+            }
+            self._notify(amount, duration)?;
+
+            let mut rewards_token: ink::contract_ref!(PSP22) = self.rewards_token.into();
+            match rewards_token
+                .call_mut()
+                .transfer_from(caller, self.env().account_id(), amount)
+                .try_invoke()
+            {
+                Ok(Ok(Ok(()))) => {}
+                Ok(Ok(Err(e))) => return Err(Error::TokenError(e)),
+                _ => return Err(Error::CallFailed),
+            }
+
+            self.env().emit_event(RewardAdded {
+                reward: amount,
+                duration,
+                reward_rate: self.reward_rate,
+            });
+
+            Ok(())
+        }
+
+        /// Start transferring ownership to `new_owner` (owner only)
+        ///
+        /// Ownership moves once `new_owner` calls `accept_ownership`.
+        #[ink(message)]
+        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            self.ownable
+                .transfer_ownership::<Environment>(caller, new_owner, |event| {
+                    Self::env().emit_event(event);
+                })?;
+            Ok(())
+        }
+
+        /// Accept a pending ownership transfer (pending owner only)
+        #[ink(message)]
+        pub fn accept_ownership(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            self.ownable.accept_ownership(caller, |event| {
+                Self::env().emit_event(event);
+            })?;
+            Ok(())
+        }
+
+        // ========================================================================
+        // Internal Functions
+        // ========================================================================
+
+        /// Accumulator value as of now
+        fn _reward_per_token(&self) -> Result<u128> {
+            if self.total_staked == 0 {
+                return Ok(self.reward_per_token_stored);
+            }
+
+            let elapsed = self
+                .last_time_reward_applicable()
+                .saturating_sub(self.last_update_time);
+            let emitted = u128::from(elapsed)
+                .checked_mul(self.reward_rate)
+                .ok_or(Error::Overflow)?;
+            let increase = mul_div(emitted, PRECISION, self.total_staked).ok_or(Error::Overflow)?;
+
+            self.reward_per_token_stored
+                .checked_add(increase)
+                .ok_or(Error::Overflow)
+        }
+
+        /// Rewards of `account` given the accumulator value `reward_per_token`
+        fn _earned(&self, account: AccountId, reward_per_token: u128) -> Result<Balance> {
+            let paid = self.user_reward_per_token_paid.get(account).unwrap_or(0);
+            let accrued = mul_div(
+                self.balance_of(account),
+                reward_per_token.saturating_sub(paid),
+                PRECISION,
+            )
+            .ok_or(Error::Overflow)?;
+
+            self.rewards
+                .get(account)
+                .unwrap_or(0)
+                .checked_add(accrued)
+                .ok_or(Error::Overflow)
+        }
+
+        /// Bring the accumulator up to date and settle `account`'s rewards
+        ///
+        /// Must run before any change to a stake or to the reward rate.
+        fn _update_reward(&mut self, account: Option<AccountId>) -> Result<()> {
+            let reward_per_token = self._reward_per_token()?;
+            self.reward_per_token_stored = reward_per_token;
+            self.last_update_time = self.last_time_reward_applicable();
+
+            if let Some(account) = account {
+                let earned = self._earned(account, reward_per_token)?;
+                self.rewards.insert(account, &earned);
+                self.user_reward_per_token_paid
+                    .insert(account, &reward_per_token);
+            }
+
+            Ok(())
+        }
+
+        /// Record a stake (the LP transfer is done by the caller)
+        fn _stake(&mut self, account: AccountId, amount: Balance) -> Result<()> {
+            if amount == 0 {
+                return Err(Error::ZeroAmount);
+            }
+            self._update_reward(Some(account))?;
+
+            let balance = self
+                .balance_of(account)
+                .checked_add(amount)
+                .ok_or(Error::Overflow)?;
+            self.total_staked = self
+                .total_staked
+                .checked_add(amount)
+                .ok_or(Error::Overflow)?;
+            self.balances.insert(account, &balance);
+
+            Ok(())
+        }
+
+        /// Record a withdrawal (the LP transfer is done by the caller)
+        fn _withdraw(&mut self, account: AccountId, amount: Balance) -> Result<()> {
+            if amount == 0 {
+                return Err(Error::ZeroAmount);
+            }
+            self._update_reward(Some(account))?;
+
+            let balance = self
+                .balance_of(account)
+                .checked_sub(amount)
+                .ok_or(Error::InsufficientStake)?;
+            self.total_staked = self.total_staked.saturating_sub(amount);
+            self.balances.insert(account, &balance);
+
+            Ok(())
+        }
+
+        /// Settle and reset `account`'s rewards, returning the amount owed
+        fn _take_reward(&mut self, account: AccountId) -> Result<Balance> {
+            self._update_reward(Some(account))?;
+
+            let reward = self.rewards.get(account).unwrap_or(0);
+            if reward > 0 {
+                self.rewards.insert(account, &0);
+            }
+
+            Ok(reward)
+        }
+
+        /// Start a reward period (the reward transfer is done by the caller)
+        fn _notify(&mut self, amount: Balance, duration: Timestamp) -> Result<()> {
+            if duration == 0 {
+                return Err(Error::ZeroDuration);
+            }
+            self._update_reward(None)?;
+
+            let now = self.env().block_timestamp();
+            let total = if self.period_finish == OPEN_ENDED || now >= self.period_finish {
+                amount
+            } else {
+                // Roll over what the running period has not emitted yet
+                let leftover = u128::from(self.period_finish - now)
+                    .checked_mul(self.reward_rate)
+                    .ok_or(Error::Overflow)?;
+                amount.checked_add(leftover).ok_or(Error::Overflow)?
+            };
+
+            self.reward_rate = total / u128::from(duration);
+            if self.reward_rate == 0 {
+                return Err(Error::ZeroAmount);
+            }
+            self.last_update_time = now;
+            self.period_finish = now.checked_add(duration).ok_or(Error::Overflow)?;
+
+            Ok(())
+        }
+
+        /// Map an LP token call result
+        fn _lp_result(
+            result: ink::env::Result<ink::MessageResult<core::result::Result<(), PairError>>>,
+        ) -> Result<()> {
+            match result {
+                Ok(Ok(Ok(()))) => Ok(()),
+                Ok(Ok(Err(e))) => Err(Error::LpTokenError(e)),
+                _ => Err(Error::CallFailed),
+            }
+        }
+    }
+
+    // ============================================================================
+    // Tests
+    // ============================================================================
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn accounts() -> ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment> {
+            ink::env::test::default_accounts::<ink::env::DefaultEnvironment>()
+        }
+
+        fn set_time(timestamp: Timestamp) {
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(timestamp);
+        }
+
+        /// Pool created at t = 0 by alice, with LP at django and DALLA at eve
+        fn create_pool(reward_rate: Balance) -> LpStaking {
+            let accounts = accounts();
+            set_time(0);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            LpStaking::new(accounts.django, accounts.eve, reward_rate)
+        }
+
+        #[ink::test]
+        fn new_works() {
+            let accounts = accounts();
+            let pool = create_pool(10);
+
+            assert_eq!(pool.staking_token(), accounts.django);
+            assert_eq!(pool.rewards_token(), accounts.eve);
+            assert_eq!(pool.reward_rate(), 10);
+            assert_eq!(pool.period_finish(), OPEN_ENDED);
+            assert_eq!(pool.total_staked(), 0);
+            assert_eq!(pool.owner(), Some(accounts.alice));
+            assert_eq!(pool.reward_per_token(), Ok(0));
+        }
+
+        #[ink::test]
+        fn rewards_accrue_over_time() {
+            let accounts = accounts();
+            let mut pool = create_pool(10);
+
+            pool._stake(accounts.bob, 100).unwrap();
+            assert_eq!(pool.earned(accounts.bob), Ok(0));
+
+            set_time(1_000);
+            assert_eq!(pool.earned(accounts.bob), Ok(10_000));
+            assert_eq!(pool.reward_per_token(), Ok(100 * PRECISION));
+
+            set_time(2_500);
+            assert_eq!(pool.earned(accounts.bob), Ok(25_000));
+        }
+
+        #[ink::test]
+        fn nothing_accrues_while_pool_is_empty() {
+            let accounts = accounts();
+            let mut pool = create_pool(10);
+
+            set_time(1_000);
+            pool._stake(accounts.bob, 100).unwrap();
+
+            set_time(1_500);
+            assert_eq!(pool.earned(accounts.bob), Ok(5_000));
+        }
+
+        #[ink::test]
+        fn stakers_split_rewards_proportionally() {
+            let accounts = accounts();
+            let mut pool = create_pool(10);
+
+            pool._stake(accounts.bob, 100).unwrap();
+            pool._stake(accounts.charlie, 300).unwrap();
+
+            set_time(1_000);
+            assert_eq!(pool.earned(accounts.bob), Ok(2_500));
+            assert_eq!(pool.earned(accounts.charlie), Ok(7_500));
+        }
+
+        #[ink::test]
+        fn late_staker_only_shares_later_rewards() {
+            let accounts = accounts();
+            let mut pool = create_pool(10);
+
+            pool._stake(accounts.bob, 100).unwrap();
+
+            set_time(1_000);
+            pool._stake(accounts.charlie, 100).unwrap();
+
+            set_time(2_000);
+            assert_eq!(pool.earned(accounts.bob), Ok(15_000));
+            assert_eq!(pool.earned(accounts.charlie), Ok(5_000));
+        }
+
+        #[ink::test]
+        fn withdrawing_mid_period_keeps_earned_rewards() {
+            let accounts = accounts();
+            let mut pool = create_pool(0);
+            pool._notify(10_000, 1_000).unwrap();
+
+            pool._stake(accounts.bob, 100).unwrap();
+
+            set_time(500);
+            pool._withdraw(accounts.bob, 100).unwrap();
+            pool._stake(accounts.charlie, 50).unwrap();
+            assert_eq!(pool.balance_of(accounts.bob), 0);
+            assert_eq!(pool.total_staked(), 50);
+
+            // Bob stops earning at withdrawal; Charlie takes the second half
+            set_time(1_000);
+            assert_eq!(pool.earned(accounts.bob), Ok(5_000));
+            assert_eq!(pool.earned(accounts.charlie), Ok(5_000));
+        }
+
+        #[ink::test]
+        fn partial_withdrawal_reduces_share() {
+            let accounts = accounts();
+            let mut pool = create_pool(10);
+
+            pool._stake(accounts.bob, 100).unwrap();
+            pool._stake(accounts.charlie, 100).unwrap();
+
+            set_time(1_000);
+            pool._withdraw(accounts.bob, 50).unwrap();
+
+            set_time(1_600);
+            assert_eq!(pool.earned(accounts.bob), Ok(5_000 + 2_000));
+            assert_eq!(pool.earned(accounts.charlie), Ok(5_000 + 4_000));
+        }
+
+        #[ink::test]
+        fn rewards_stop_at_period_finish() {
+            let accounts = accounts();
+            let mut pool = create_pool(0);
+            pool._notify(10_000, 1_000).unwrap();
+            assert_eq!(pool.reward_rate(), 10);
+            assert_eq!(pool.period_finish(), 1_000);
+
+            pool._stake(accounts.bob, 100).unwrap();
+
+            set_time(5_000);
+            assert_eq!(pool.last_time_reward_applicable(), 1_000);
+            assert_eq!(pool.earned(accounts.bob), Ok(10_000));
+        }
+
+        #[ink::test]
+        fn notify_rolls_over_undistributed_rewards() {
+            let accounts = accounts();
+            let mut pool = create_pool(0);
+            pool._notify(10_000, 1_000).unwrap();
+            pool._stake(accounts.bob, 100).unwrap();
+
+            // Half emitted; 5_000 left + 15_000 new over 2_000 ms
+            set_time(500);
+            pool._notify(15_000, 2_000).unwrap();
+            assert_eq!(pool.reward_rate(), 10);
+            assert_eq!(pool.period_finish(), 2_500);
+
+            set_time(2_500);
+            assert_eq!(pool.earned(accounts.bob), Ok(25_000));
+        }
+
+        #[ink::test]
+        fn notify_replaces_open_ended_rate() {
+            let accounts = accounts();
+            let mut pool = create_pool(10);
+            pool._stake(accounts.bob, 100).unwrap();
+
+            set_time(1_000);
+            pool._notify(2_000, 1_000).unwrap();
+            assert_eq!(pool.reward_rate(), 2);
+
+            set_time(3_000);
+            assert_eq!(pool.earned(accounts.bob), Ok(10_000 + 2_000));
+        }
+
+        #[ink::test]
+        fn take_reward_resets_earned() {
+            let accounts = accounts();
+            let mut pool = create_pool(10);
+            pool._stake(accounts.bob, 100).unwrap();
+
+            set_time(1_000);
+            assert_eq!(pool._take_reward(accounts.bob), Ok(10_000));
+            assert_eq!(pool.earned(accounts.bob), Ok(0));
+            assert_eq!(pool._take_reward(accounts.bob), Ok(0));
+
+            set_time(1_500);
+            assert_eq!(pool.earned(accounts.bob), Ok(5_000));
+        }
+
+        #[ink::test]
+        fn invalid_amounts_are_rejected() {
+            let accounts = accounts();
+            let mut pool = create_pool(10);
+
+            assert_eq!(pool._stake(accounts.bob, 0), Err(Error::ZeroAmount));
+            assert_eq!(pool._withdraw(accounts.bob, 0), Err(Error::ZeroAmount));
+            assert_eq!(
+                pool._withdraw(accounts.bob, 1),
+                Err(Error::InsufficientStake)
+            );
+            assert_eq!(pool._notify(1_000, 0), Err(Error::ZeroDuration));
+            assert_eq!(pool._notify(999, 1_000), Err(Error::ZeroAmount));
+        }
+
+        #[ink::test]
+        fn only_owner_can_notify() {
+            let accounts = accounts();
+            let mut pool = create_pool(10);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                pool.notify_reward_amount(1_000, 1_000),
+                Err(Error::NotOwner)
+            );
+        }
+    }
+}
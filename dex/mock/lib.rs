@@ -0,0 +1,216 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// # BelizeX Mock
+///
+/// Test-only contract implementing `PairInterface` and `FactoryInterface`.
+/// Acting as its own factory, it reports itself as the pair for every token
+/// combination (whatever tokens it was created with), serves fixed reserves
+/// and records the last mint, burn and swap, so Router logic can be exercised
+/// without deploying real tokens.
+
+#[path = "../interfaces.rs"]
+pub mod interfaces;
+
+#[path = "../psp22_trait.rs"]
+pub mod psp22_trait;
+
+#[ink::contract]
+pub mod belizex_mock {
+    use crate::interfaces::{FactoryError, FactoryInterface, FeeInfo, PairError, PairInterface};
+
+    /// LP tokens reported by every successful `mint`
+    pub const MOCK_LIQUIDITY: u128 = 1_000;
+
+    /// Mock storage
+    #[ink(storage)]
+    pub struct BelizexMock {
+        /// Tokens reported by `get_tokens`
+        tokens: (AccountId, AccountId),
+        /// Reserves reported by `get_reserves` and paid out by `burn`
+        reserves: (u128, u128),
+        /// Whether mint, burn and swap fail
+        failing: bool,
+        /// Recipient of the last mint
+        last_mint_to: Option<AccountId>,
+        /// Recipient of the last burn
+        last_burn_to: Option<AccountId>,
+        /// (amount0_out, amount1_out, to) of the last swap
+        last_swap: Option<(u128, u128, AccountId)>,
+    }
+
+    impl BelizexMock {
+        /// Creates a mock pair of `token0`/`token1` holding the given reserves
+        #[ink(constructor)]
+        pub fn new(token0: AccountId, token1: AccountId, reserve0: u128, reserve1: u128) -> Self {
+            Self {
+                tokens: (token0, token1),
+                reserves: (reserve0, reserve1),
+                failing: false,
+                last_mint_to: None,
+                last_burn_to: None,
+                last_swap: None,
+            }
+        }
+
+        /// Makes mint, burn and swap fail (or succeed again)
+        #[ink(message)]
+        pub fn set_failing(&mut self, failing: bool) {
+            self.failing = failing;
+        }
+
+        /// Returns the recipient of the last mint
+        #[ink(message)]
+        pub fn last_mint_to(&self) -> Option<AccountId> {
+            self.last_mint_to
+        }
+
+        /// Returns the recipient of the last burn
+        #[ink(message)]
+        pub fn last_burn_to(&self) -> Option<AccountId> {
+            self.last_burn_to
+        }
+
+        /// Returns the last swap
+        #[ink(message)]
+        pub fn last_swap(&self) -> Option<(u128, u128, AccountId)> {
+            self.last_swap
+        }
+    }
+
+    impl PairInterface for BelizexMock {
+        #[ink(message)]
+        fn get_tokens(&self) -> (AccountId, AccountId) {
+            self.tokens
+        }
+
+        #[ink(message)]
+        fn get_reserves(&self) -> (u128, u128, u64) {
+            (self.reserves.0, self.reserves.1, 0)
+        }
+
+        #[ink(message)]
+        fn mint(&mut self, to: AccountId) -> Result<u128, PairError> {
+            if self.failing {
+                return Err(PairError::InsufficientLiquidityMinted);
+            }
+
+            self.last_mint_to = Some(to);
+            Ok(MOCK_LIQUIDITY)
+        }
+
+        #[ink(message)]
+        fn burn(&mut self, to: AccountId) -> Result<(u128, u128), PairError> {
+            if self.failing {
+                return Err(PairError::InsufficientLiquidityBurned);
+            }
+
+            self.last_burn_to = Some(to);
+            Ok(self.reserves)
+        }
+
+        #[ink(message)]
+        fn swap(
+            &mut self,
+            amount0_out: u128,
+            amount1_out: u128,
+            to: AccountId,
+        ) -> Result<(), PairError> {
+            if self.failing {
+                return Err(PairError::InsufficientLiquidity);
+            }
+
+            self.last_swap = Some((amount0_out, amount1_out, to));
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn fee_bps(&self) -> u32 {
+            30
+        }
+    }
+
+    impl FactoryInterface for BelizexMock {
+        #[ink(message)]
+        fn get_pair_address(&self, _token_a: AccountId, _token_b: AccountId) -> Option<AccountId> {
+            Some(self.env().account_id())
+        }
+
+        #[ink(message)]
+        fn create_pair(
+            &mut self,
+            _token_a: AccountId,
+            _token_b: AccountId,
+        ) -> Result<AccountId, FactoryError> {
+            Err(FactoryError::PairExists)
+        }
+
+        #[ink(message)]
+        fn fee_to(&self) -> Option<AccountId> {
+            None
+        }
+
+        #[ink(message)]
+        fn fee_info(&self) -> FeeInfo {
+            (None, 0, 0)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[ink::test]
+        fn pair_interface_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut mock = BelizexMock::new(accounts.django, accounts.eve, 1_000, 2_000);
+
+            assert_eq!(
+                PairInterface::get_tokens(&mock),
+                (accounts.django, accounts.eve)
+            );
+            assert_eq!(PairInterface::get_reserves(&mock), (1_000, 2_000, 0));
+            assert_eq!(
+                PairInterface::mint(&mut mock, accounts.bob),
+                Ok(MOCK_LIQUIDITY)
+            );
+            assert_eq!(
+                PairInterface::burn(&mut mock, accounts.bob),
+                Ok((1_000, 2_000))
+            );
+            assert_eq!(PairInterface::swap(&mut mock, 0, 10, accounts.bob), Ok(()));
+            assert_eq!(mock.last_mint_to(), Some(accounts.bob));
+            assert_eq!(mock.last_burn_to(), Some(accounts.bob));
+            assert_eq!(mock.last_swap(), Some((0, 10, accounts.bob)));
+        }
+
+        #[ink::test]
+        fn failing_pair_rejects_calls() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut mock = BelizexMock::new(accounts.django, accounts.eve, 1_000, 2_000);
+            mock.set_failing(true);
+
+            assert_eq!(
+                PairInterface::mint(&mut mock, accounts.bob),
+                Err(PairError::InsufficientLiquidityMinted)
+            );
+            assert_eq!(
+                PairInterface::swap(&mut mock, 0, 10, accounts.bob),
+                Err(PairError::InsufficientLiquidity)
+            );
+            assert_eq!(mock.last_swap(), None);
+        }
+
+        #[ink::test]
+        fn factory_interface_returns_self() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mock = BelizexMock::new(accounts.django, accounts.eve, 1_000, 2_000);
+
+            assert_eq!(
+                FactoryInterface::get_pair_address(&mock, accounts.bob, accounts.charlie),
+                Some(ink::env::account_id::<ink::env::DefaultEnvironment>())
+            );
+            assert_eq!(FactoryInterface::fee_to(&mock), None);
+            assert_eq!(FactoryInterface::fee_info(&mock), (None, 0, 0));
+        }
+    }
+}
@@ -12,8 +12,22 @@
 //! - Price oracle (TWAP ready)
 //! - Minimum liquidity lock
 
+#[path = "../psp22_trait.rs"]
+pub mod psp22_trait;
+
+#[path = "../selectors.rs"]
+pub mod selectors;
+
+#[path = "../interfaces.rs"]
+pub mod interfaces;
+
 #[ink::contract]
 pub mod pair {
+    use crate::interfaces::{FactoryInterface, FeeInfo};
+    use crate::psp22_trait::{self, ErrorPayload, PSP22Error};
+    use crate::selectors;
+    use gem_math::{mul_div, sqrt};
+    use ink::codegen::TraitCallBuilder;
     use ink::env::call::{build_call, ExecutionInput, Selector};
 
     use ink::storage::Mapping;
@@ -26,6 +40,9 @@ pub mod pair {
     const FEE_NUMERATOR: u128 = 3;
     const FEE_DENOMINATOR: u128 = 1000;
 
+    /// Cached fee settings older than this (in ms) must be refreshed: 1 day
+    pub const FEE_INFO_MAX_AGE: u64 = 24 * 60 * 60 * 1000;
+
     // ============================================================================
     // Storage
     // ============================================================================
@@ -70,12 +87,20 @@ pub mod pair {
 
         /// Reentrancy lock
         locked: bool,
+
+        /// Factory fee settings (fee_to, share bps, last updated), as last cached
+        fee_info: FeeInfo,
+
+        /// Block timestamp the fee settings were cached at (None if never)
+        fee_info_cached_at: Option<u64>,
     }
 
     // ============================================================================
     // Events
     // ============================================================================
 
+    /// Liquidity added; carries the resulting LP supply and reserves so
+    /// indexers need no running state
     #[ink(event)]
     pub struct Mint {
         #[ink(topic)]
@@ -83,8 +108,13 @@ pub mod pair {
         amount0: Balance,
         amount1: Balance,
         liquidity: Balance,
+        total_supply_after: Balance,
+        reserve0_after: Balance,
+        reserve1_after: Balance,
     }
 
+    /// Liquidity removed; carries the resulting LP supply and reserves so
+    /// indexers need no running state
     #[ink(event)]
     pub struct Burn {
         #[ink(topic)]
@@ -94,8 +124,12 @@ pub mod pair {
         #[ink(topic)]
         to: AccountId,
         liquidity: Balance,
+        total_supply_after: Balance,
+        reserve0_after: Balance,
+        reserve1_after: Balance,
     }
 
+    /// Tokens swapped; `fee_bps` is the trading fee charged on the input
     #[ink(event)]
     pub struct Swap {
         #[ink(topic)]
@@ -106,6 +140,7 @@ pub mod pair {
         amount1_out: Balance,
         #[ink(topic)]
         to: AccountId,
+        fee_bps: u32,
     }
 
     #[ink(event)]
@@ -165,8 +200,14 @@ pub mod pair {
         InsufficientAllowance,
         /// Reentrancy detected
         Locked,
-        /// Transfer failed
+        /// Token call failed (trapped, missing message or undecodable reply)
         TransferFailed,
+        /// Token rejected the call with the given PSP22 error
+        TokenError(PSP22Error),
+        /// Caller is not the factory
+        NotFactory,
+        /// Factory call failed
+        CallFailed,
     }
 
     pub type Result<T> = core::result::Result<T, Error>;
@@ -197,6 +238,8 @@ pub mod pair {
                 price1_cumulative_last: 0,
                 k_last: 0,
                 locked: false,
+                fee_info: (None, 0, 0),
+                fee_info_cached_at: None,
             }
         }
 
@@ -279,6 +322,26 @@ pub mod pair {
             (self.reserve0, self.reserve1, self.block_timestamp_last)
         }
 
+        /// Get the cumulative price of token0 in token1 (reserve1 / reserve0 × ms)
+        ///
+        /// The accumulator wraps around at 2^128. Take two readings and
+        /// compute the average price over the interval as
+        /// `later.wrapping_sub(earlier) / elapsed`; the difference is correct
+        /// across a wrap as long as the interval itself spans less than one.
+        #[ink(message)]
+        pub fn price0_cumulative_last(&self) -> u128 {
+            self.price0_cumulative_last
+        }
+
+        /// Get the cumulative price of token1 in token0 (reserve0 / reserve1 × ms)
+        ///
+        /// Wraps around at 2^128 like `price0_cumulative_last`; use
+        /// `wrapping_sub` on readings to get interval averages.
+        #[ink(message)]
+        pub fn price1_cumulative_last(&self) -> u128 {
+            self.price1_cumulative_last
+        }
+
         /// Add liquidity and mint LP tokens
         ///
         /// # Parameters
@@ -303,18 +366,16 @@ pub mod pair {
             let (reserve0, reserve1) = (self.reserve0, self.reserve1);
 
             // Get actual token balances (caller must have transferred tokens first)
-            // In production, this would call token0.balance_of(self) and token1.balance_of(self)
-            // For now, we simulate this
-            let balance0 = reserve0; // TODO: Call token0 contract
-            let balance1 = reserve1; // TODO: Call token1 contract
+            let this = self.env().account_id();
+            let balance0 = self._token_balance_of(self.token0, this)?;
+            let balance1 = self._token_balance_of(self.token1, this)?;
 
             let amount0 = balance0.saturating_sub(reserve0);
             let amount1 = balance1.saturating_sub(reserve1);
 
             let liquidity = if self.total_supply == 0 {
                 // First liquidity provision
-                let initial_liquidity =
-                    Self::sqrt(amount0.checked_mul(amount1).ok_or(Error::Overflow)?);
+                let initial_liquidity = sqrt(amount0.checked_mul(amount1).ok_or(Error::Overflow)?);
 
                 if initial_liquidity <= MINIMUM_LIQUIDITY {
                     self.locked = false;
@@ -329,17 +390,15 @@ pub mod pair {
                 initial_liquidity - MINIMUM_LIQUIDITY
             } else {
                 // Subsequent liquidity provisions
-                let liquidity0 = amount0
-                    .checked_mul(self.total_supply)
-                    .ok_or(Error::Overflow)?
-                    .checked_div(reserve0)
-                    .ok_or(Error::InsufficientLiquidity)?;
-
-                let liquidity1 = amount1
-                    .checked_mul(self.total_supply)
-                    .ok_or(Error::Overflow)?
-                    .checked_div(reserve1)
-                    .ok_or(Error::InsufficientLiquidity)?;
+                if reserve0 == 0 || reserve1 == 0 {
+                    self.locked = false;
+                    return Err(Error::InsufficientLiquidity);
+                }
+
+                let liquidity0 =
+                    mul_div(amount0, self.total_supply, reserve0).ok_or(Error::Overflow)?;
+                let liquidity1 =
+                    mul_div(amount1, self.total_supply, reserve1).ok_or(Error::Overflow)?;
 
                 // Use minimum to maintain price ratio
                 if liquidity0 < liquidity1 {
@@ -363,12 +422,7 @@ pub mod pair {
             // Update reserves
             self._update(balance0, balance1)?;
 
-            self.env().emit_event(Mint {
-                sender: self.env().caller(),
-                amount0,
-                amount1,
-                liquidity,
-            });
+            self._emit_mint(amount0, amount1, liquidity);
 
             self.locked = false;
             Ok(liquidity)
@@ -402,17 +456,9 @@ pub mod pair {
             }
 
             // Calculate amounts to return
-            let amount0 = liquidity
-                .checked_mul(reserve0)
-                .ok_or(Error::Overflow)?
-                .checked_div(self.total_supply)
-                .ok_or(Error::InsufficientLiquidity)?;
-
-            let amount1 = liquidity
-                .checked_mul(reserve1)
-                .ok_or(Error::Overflow)?
-                .checked_div(self.total_supply)
-                .ok_or(Error::InsufficientLiquidity)?;
+            // total_supply >= liquidity > 0, so a failure here can only be overflow
+            let amount0 = mul_div(liquidity, reserve0, self.total_supply).ok_or(Error::Overflow)?;
+            let amount1 = mul_div(liquidity, reserve1, self.total_supply).ok_or(Error::Overflow)?;
 
             if amount0 == 0 || amount1 == 0 {
                 self.locked = false;
@@ -430,17 +476,12 @@ pub mod pair {
             self._token_transfer(self.token1, to, amount1)?;
 
             // Update reserves
-            let balance0 = reserve0 - amount0;
-            let balance1 = reserve1 - amount1;
+            let this = self.env().account_id();
+            let balance0 = self._token_balance_of(self.token0, this)?;
+            let balance1 = self._token_balance_of(self.token1, this)?;
             self._update(balance0, balance1)?;
 
-            self.env().emit_event(Burn {
-                sender: self.env().caller(),
-                amount0,
-                amount1,
-                to,
-                liquidity,
-            });
+            self._emit_burn(amount0, amount1, to, liquidity);
 
             self.locked = false;
             Ok((amount0, amount1))
@@ -493,49 +534,22 @@ pub mod pair {
             }
 
             // Get actual balances after transfer
-            let balance0 = reserve0.saturating_sub(amount0_out);
-            let balance1 = reserve1.saturating_sub(amount1_out);
-
-            // Calculate amounts in (what user sent)
-            let amount0_in = if balance0 > reserve0.saturating_sub(amount0_out) {
-                balance0 - (reserve0 - amount0_out)
-            } else {
-                0
-            };
-
-            let amount1_in = if balance1 > reserve1.saturating_sub(amount1_out) {
-                balance1 - (reserve1 - amount1_out)
-            } else {
-                0
+            let this = self.env().account_id();
+            let balance0 = self._token_balance_of(self.token0, this)?;
+            let balance1 = self._token_balance_of(self.token1, this)?;
+
+            let (amount0_in, amount1_in) = match Self::_verify_swap(
+                (reserve0, reserve1),
+                (balance0, balance1),
+                (amount0_out, amount1_out),
+            ) {
+                Ok(amounts_in) => amounts_in,
+                Err(e) => {
+                    self.locked = false;
+                    return Err(e);
+                }
             };
 
-            if amount0_in == 0 && amount1_in == 0 {
-                self.locked = false;
-                return Err(Error::InsufficientInputAmount);
-            }
-
-            // Verify K-value (with 0.3% fee)
-            let balance0_adjusted = balance0
-                .saturating_mul(1000)
-                .saturating_sub(amount0_in.saturating_mul(FEE_NUMERATOR));
-
-            let balance1_adjusted = balance1
-                .saturating_mul(1000)
-                .saturating_sub(amount1_in.saturating_mul(FEE_NUMERATOR));
-
-            let k_new = balance0_adjusted
-                .checked_mul(balance1_adjusted)
-                .ok_or(Error::Overflow)?;
-
-            let k_old = reserve0
-                .saturating_mul(reserve1)
-                .saturating_mul(1000 * 1000);
-
-            if k_new < k_old {
-                self.locked = false;
-                return Err(Error::KValueDecreased);
-            }
-
             // Update reserves
             self._update(balance0, balance1)?;
 
@@ -546,6 +560,7 @@ pub mod pair {
                 amount0_out,
                 amount1_out,
                 to,
+                fee_bps: self.fee_bps(),
             });
 
             self.locked = false;
@@ -558,18 +573,89 @@ pub mod pair {
             self.ensure_not_locked()?;
 
             // Get actual balances
-            // TODO: Call token contracts
-            let balance0 = self.reserve0;
-            let balance1 = self.reserve1;
+            let this = self.env().account_id();
+            let balance0 = self._token_balance_of(self.token0, this)?;
+            let balance1 = self._token_balance_of(self.token1, this)?;
 
             self._update(balance0, balance1)?;
             Ok(())
         }
 
+        // ========================================================================
+        // Protocol Fee Settings
+        // ========================================================================
+
+        /// Cache new fee settings pushed by the factory (factory only)
+        ///
+        /// Updates older than the cached ones are ignored, so notifications
+        /// arriving out of order cannot roll the cache back.
+        ///
+        /// # Parameters
+        /// * `fee_to` - Protocol fee recipient (None if fees are off)
+        /// * `fee_share_bps` - Share of trading fees paid to `fee_to`
+        /// * `updated_at` - Factory timestamp of the fee_to change
+        #[ink(message)]
+        pub fn on_fee_to_changed(
+            &mut self,
+            fee_to: Option<AccountId>,
+            fee_share_bps: u16,
+            updated_at: u64,
+        ) -> Result<()> {
+            if self.env().caller() != self.factory {
+                return Err(Error::NotFactory);
+            }
+
+            if self.fee_info_cached_at.is_some() && updated_at < self.fee_info.2 {
+                return Ok(());
+            }
+
+            self._cache_fee_info((fee_to, fee_share_bps, updated_at));
+            Ok(())
+        }
+
+        /// Re-read the fee settings from the factory and cache them
+        ///
+        /// Fallback for when the cache is stale or a notification was missed.
+        /// Anyone can call it since the values come from the factory.
+        #[ink(message)]
+        pub fn refresh_fee_to(&mut self) -> Result<FeeInfo> {
+            let factory: ink::contract_ref!(FactoryInterface) = self.factory.into();
+            let fee_info = match factory.call().fee_info().try_invoke() {
+                Ok(Ok(fee_info)) => fee_info,
+                _ => return Err(Error::CallFailed),
+            };
+
+            self._cache_fee_info(fee_info);
+            Ok(fee_info)
+        }
+
+        /// Get the cached fee settings
+        ///
+        /// # Returns
+        /// None if nothing was cached yet or the cache is older than
+        /// `FEE_INFO_MAX_AGE`; call `refresh_fee_to` in that case
+        #[ink(message)]
+        pub fn cached_fee_info(&self) -> Option<FeeInfo> {
+            let cached_at = self.fee_info_cached_at?;
+            let age = self.env().block_timestamp().saturating_sub(cached_at);
+
+            if age > FEE_INFO_MAX_AGE {
+                None
+            } else {
+                Some(self.fee_info)
+            }
+        }
+
         // ========================================================================
         // View Functions
         // ========================================================================
 
+        /// Get the swap fee in basis points (30 = 0.3%)
+        #[ink(message)]
+        pub fn fee_bps(&self) -> u32 {
+            (FEE_NUMERATOR * 10_000 / FEE_DENOMINATOR) as u32
+        }
+
         /// Calculate amount out for exact amount in (before fees)
         #[ink(message)]
         pub fn get_amount_out(
@@ -587,19 +673,19 @@ pub mod pair {
             }
 
             // Apply 0.3% fee
-            let amount_in_with_fee = amount_in.saturating_mul(FEE_DENOMINATOR - FEE_NUMERATOR);
-
-            let numerator = amount_in_with_fee
-                .checked_mul(reserve_out)
+            let amount_in_with_fee = amount_in
+                .checked_mul(FEE_DENOMINATOR - FEE_NUMERATOR)
                 .ok_or(Error::Overflow)?;
 
             let denominator = reserve_in
-                .saturating_mul(FEE_DENOMINATOR)
-                .saturating_add(amount_in_with_fee);
+                .checked_mul(FEE_DENOMINATOR)
+                .and_then(|scaled| scaled.checked_add(amount_in_with_fee))
+                .ok_or(Error::Overflow)?;
 
-            let amount_out = numerator
-                .checked_div(denominator)
-                .ok_or(Error::InsufficientLiquidity)?;
+            // amount_in_with_fee * reserve_out / denominator, without
+            // overflowing on the intermediate product
+            let amount_out =
+                mul_div(amount_in_with_fee, reserve_out, denominator).ok_or(Error::Overflow)?;
 
             Ok(amount_out)
         }
@@ -620,19 +706,19 @@ pub mod pair {
                 return Err(Error::InsufficientLiquidity);
             }
 
-            let numerator = reserve_in
-                .checked_mul(amount_out)
-                .ok_or(Error::Overflow)?
-                .saturating_mul(FEE_DENOMINATOR);
+            let scaled_out = amount_out
+                .checked_mul(FEE_DENOMINATOR)
+                .ok_or(Error::Overflow)?;
 
             let denominator = reserve_out
                 .saturating_sub(amount_out)
-                .saturating_mul(FEE_DENOMINATOR - FEE_NUMERATOR);
+                .checked_mul(FEE_DENOMINATOR - FEE_NUMERATOR)
+                .ok_or(Error::Overflow)?;
 
-            let amount_in = numerator
-                .checked_div(denominator)
-                .ok_or(Error::InsufficientLiquidity)?
-                .saturating_add(1); // Round up
+            let amount_in = mul_div(reserve_in, scaled_out, denominator)
+                .ok_or(Error::Overflow)?
+                .checked_add(1) // Round up
+                .ok_or(Error::Overflow)?;
 
             Ok(amount_in)
         }
@@ -645,22 +731,22 @@ pub mod pair {
         ///
         /// Calls the `transfer` method on a PSP22 token contract
         fn _token_transfer(&self, token: AccountId, to: AccountId, amount: Balance) -> Result<()> {
-            // PSP22::transfer selector is 0xdb20f9f5
-            let selector = [0xdb, 0x20, 0xf9, 0xf5];
-
             // Build cross-contract call
             let result = build_call::<Environment>()
                 .call(token)
                 .exec_input(
-                    ExecutionInput::new(Selector::new(selector))
+                    ExecutionInput::new(Selector::new(selectors::PSP22_TRANSFER))
                         .push_arg(to)
                         .push_arg(amount),
                 )
-                .returns::<core::result::Result<(), ink::prelude::vec::Vec<u8>>>()
+                .returns::<core::result::Result<(), ErrorPayload>>()
                 .try_invoke();
 
             match result {
-                Ok(Ok(_)) => Ok(()),
+                Ok(Ok(Ok(()))) => Ok(()),
+                Ok(Ok(Err(payload))) => {
+                    Err(Error::TokenError(psp22_trait::decode_error(&payload.0)))
+                }
                 _ => Err(Error::TransferFailed),
             }
         }
@@ -668,41 +754,97 @@ pub mod pair {
         /// Get token balance via PSP22 cross-contract call
         ///
         /// Calls the `balance_of` method on a PSP22 token contract
-        fn _token_balance_of(&self, token: AccountId, account: AccountId) -> Balance {
-            // PSP22::balance_of selector is 0x65682523
-            let selector = [0x65, 0x68, 0x25, 0x23];
-
+        fn _token_balance_of(&self, token: AccountId, account: AccountId) -> Result<Balance> {
             let result = build_call::<Environment>()
                 .call(token)
-                .exec_input(ExecutionInput::new(Selector::new(selector)).push_arg(account))
+                .exec_input(
+                    ExecutionInput::new(Selector::new(selectors::PSP22_BALANCE_OF))
+                        .push_arg(account),
+                )
                 .returns::<Balance>()
                 .try_invoke();
 
             match result {
-                Ok(Ok(balance)) => balance,
-                _ => 0,
+                Ok(Ok(balance)) => Ok(balance),
+                _ => Err(Error::TransferFailed),
             }
         }
 
+        /// Amount of a token the trader sent in during a swap
+        ///
+        /// The pair held `reserve` and sent `amount_out`, so anything above
+        /// `reserve - amount_out` in the fetched `balance` came from the trader.
+        /// Requires `amount_out < reserve`, which `swap` checks up front.
+        fn _amount_in(balance: Balance, reserve: Balance, amount_out: Balance) -> Balance {
+            let remaining = reserve - amount_out;
+            if balance > remaining {
+                balance - remaining
+            } else {
+                0
+            }
+        }
+
+        /// Derive the swap's input amounts from the fetched balances and check
+        /// the constant product (with the 0.3% fee charged on inputs)
+        ///
+        /// Both sides may receive input at once, e.g. from arbitrage contracts.
+        ///
+        /// # Returns
+        /// (amount0_in, amount1_in)
+        fn _verify_swap(
+            (reserve0, reserve1): (Balance, Balance),
+            (balance0, balance1): (Balance, Balance),
+            (amount0_out, amount1_out): (Balance, Balance),
+        ) -> Result<(Balance, Balance)> {
+            let amount0_in = Self::_amount_in(balance0, reserve0, amount0_out);
+            let amount1_in = Self::_amount_in(balance1, reserve1, amount1_out);
+
+            if amount0_in == 0 && amount1_in == 0 {
+                return Err(Error::InsufficientInputAmount);
+            }
+
+            let balance0_adjusted = balance0
+                .checked_mul(FEE_DENOMINATOR)
+                .ok_or(Error::Overflow)?
+                .saturating_sub(amount0_in.saturating_mul(FEE_NUMERATOR));
+
+            let balance1_adjusted = balance1
+                .checked_mul(FEE_DENOMINATOR)
+                .ok_or(Error::Overflow)?
+                .saturating_sub(amount1_in.saturating_mul(FEE_NUMERATOR));
+
+            let k_new = balance0_adjusted
+                .checked_mul(balance1_adjusted)
+                .ok_or(Error::Overflow)?;
+
+            let k_old = reserve0
+                .saturating_mul(reserve1)
+                .saturating_mul(FEE_DENOMINATOR * FEE_DENOMINATOR);
+
+            if k_new < k_old {
+                return Err(Error::KValueDecreased);
+            }
+
+            Ok((amount0_in, amount1_in))
+        }
+
         /// Update reserves and price accumulators
         fn _update(&mut self, balance0: Balance, balance1: Balance) -> Result<()> {
             // Update price oracle (TWAP)
             let block_timestamp = self.env().block_timestamp();
             let time_elapsed = block_timestamp.saturating_sub(self.block_timestamp_last);
 
+            // Accumulators wrap on purpose: consumers only use differences,
+            // which stay correct modulo 2^128 (saturating would freeze them)
             if time_elapsed > 0 && self.reserve0 > 0 && self.reserve1 > 0 {
                 // Price0 = reserve1 / reserve0
-                self.price0_cumulative_last = self.price0_cumulative_last.saturating_add(
-                    self.reserve1
-                        .saturating_mul(time_elapsed as u128)
-                        .saturating_div(self.reserve0),
+                self.price0_cumulative_last = self.price0_cumulative_last.wrapping_add(
+                    Self::_price_time(self.reserve1, self.reserve0, time_elapsed)?,
                 );
 
                 // Price1 = reserve0 / reserve1
-                self.price1_cumulative_last = self.price1_cumulative_last.saturating_add(
-                    self.reserve0
-                        .saturating_mul(time_elapsed as u128)
-                        .saturating_div(self.reserve1),
+                self.price1_cumulative_last = self.price1_cumulative_last.wrapping_add(
+                    Self::_price_time(self.reserve0, self.reserve1, time_elapsed)?,
                 );
             }
 
@@ -718,6 +860,63 @@ pub mod pair {
             Ok(())
         }
 
+        /// Emit `Mint` with the LP supply and reserves as they stand after
+        /// the mint (call after `_update`)
+        fn _emit_mint(&self, amount0: Balance, amount1: Balance, liquidity: Balance) {
+            self.env().emit_event(Mint {
+                sender: self.env().caller(),
+                amount0,
+                amount1,
+                liquidity,
+                total_supply_after: self.total_supply,
+                reserve0_after: self.reserve0,
+                reserve1_after: self.reserve1,
+            });
+        }
+
+        /// Emit `Burn` with the LP supply and reserves as they stand after
+        /// the burn (call after `_update`)
+        fn _emit_burn(
+            &self,
+            amount0: Balance,
+            amount1: Balance,
+            to: AccountId,
+            liquidity: Balance,
+        ) {
+            self.env().emit_event(Burn {
+                sender: self.env().caller(),
+                amount0,
+                amount1,
+                to,
+                liquidity,
+                total_supply_after: self.total_supply,
+                reserve0_after: self.reserve0,
+                reserve1_after: self.reserve1,
+            });
+        }
+
+        /// `numerator * time_elapsed / denominator`, modulo 2^128
+        ///
+        /// Split into whole and fractional price so the whole part can wrap
+        /// like the accumulators while the fraction, always below
+        /// `time_elapsed`, keeps full precision. Fails with `Overflow` on a
+        /// zero `denominator`.
+        fn _price_time(
+            numerator: Balance,
+            denominator: Balance,
+            time_elapsed: u64,
+        ) -> Result<u128> {
+            let time_elapsed = u128::from(time_elapsed);
+            let whole = numerator
+                .checked_div(denominator)
+                .ok_or(Error::Overflow)?
+                .wrapping_mul(time_elapsed);
+            let fraction = mul_div(numerator % denominator, time_elapsed, denominator)
+                .ok_or(Error::Overflow)?;
+
+            Ok(whole.wrapping_add(fraction))
+        }
+
         /// Transfer LP tokens
         fn _transfer(&mut self, from: AccountId, to: AccountId, value: Balance) -> Result<()> {
             if to == AccountId::from([0u8; 32]) {
@@ -743,6 +942,12 @@ pub mod pair {
             Ok(())
         }
 
+        /// Store fee settings along with the time they were cached
+        fn _cache_fee_info(&mut self, fee_info: FeeInfo) {
+            self.fee_info = fee_info;
+            self.fee_info_cached_at = Some(self.env().block_timestamp());
+        }
+
         /// Ensure contract is not locked (reentrancy guard)
         fn ensure_not_locked(&self) -> Result<()> {
             if self.locked {
@@ -751,23 +956,6 @@ pub mod pair {
                 Ok(())
             }
         }
-
-        /// Integer square root (Babylonian method)
-        fn sqrt(y: Balance) -> Balance {
-            if y > 3 {
-                let mut z = y;
-                let mut x = y / 2 + 1;
-                while x < z {
-                    z = x;
-                    x = (y / x + x) / 2;
-                }
-                z
-            } else if y != 0 {
-                1
-            } else {
-                0
-            }
-        }
     }
 
     // ============================================================================
@@ -796,13 +984,13 @@ pub mod pair {
 
         #[ink::test]
         fn sqrt_works() {
-            assert_eq!(Pair::sqrt(0), 0);
-            assert_eq!(Pair::sqrt(1), 1);
-            assert_eq!(Pair::sqrt(4), 2);
-            assert_eq!(Pair::sqrt(9), 3);
-            assert_eq!(Pair::sqrt(16), 4);
-            assert_eq!(Pair::sqrt(100), 10);
-            assert_eq!(Pair::sqrt(1000000), 1000);
+            assert_eq!(sqrt(0), 0);
+            assert_eq!(sqrt(1), 1);
+            assert_eq!(sqrt(4), 2);
+            assert_eq!(sqrt(9), 3);
+            assert_eq!(sqrt(16), 4);
+            assert_eq!(sqrt(100), 10);
+            assert_eq!(sqrt(1000000), 1000);
         }
 
         #[ink::test]
@@ -827,5 +1015,299 @@ pub mod pair {
             assert!(amount_in > 90); // Need more than 90 due to fee
             assert!(amount_in <= 100); // Approximately 100 tokens needed
         }
+
+        #[ink::test]
+        fn large_reserve_quotes_do_not_overflow() {
+            let (token0, token1) = create_tokens();
+            let pair = Pair::new(token0, token1);
+
+            // amount_in * 997 * reserve_out overflows u128 here
+            let reserve = 1u128 << 100;
+            assert_eq!(
+                pair.get_amount_out(1 << 90, reserve, reserve),
+                Ok(1_233_025_704_882_594_498_610_447_733)
+            );
+            assert_eq!(
+                pair.get_amount_in(1 << 90, reserve, reserve),
+                Ok(1_242_878_783_200_264_921_349_290_497)
+            );
+        }
+
+        #[ink::test]
+        fn swap_input_on_token0_only() {
+            // 100 token0 in for 90 token1 out of a 1000/1000 pool
+            assert_eq!(
+                Pair::_verify_swap((1000, 1000), (1100, 910), (0, 90)),
+                Ok((100, 0))
+            );
+        }
+
+        #[ink::test]
+        fn swap_input_on_token1_only() {
+            assert_eq!(
+                Pair::_verify_swap((1000, 1000), (910, 1100), (90, 0)),
+                Ok((0, 100))
+            );
+        }
+
+        #[ink::test]
+        fn swap_input_on_both_tokens() {
+            // 50 of each sent in, 40 of each taken out
+            assert_eq!(
+                Pair::_verify_swap((1000, 1000), (1010, 1010), (40, 40)),
+                Ok((50, 50))
+            );
+        }
+
+        #[ink::test]
+        fn swap_without_input_is_rejected() {
+            assert_eq!(
+                Pair::_verify_swap((1000, 1000), (1000, 910), (0, 90)),
+                Err(Error::InsufficientInputAmount)
+            );
+        }
+
+        #[ink::test]
+        fn swap_decreasing_k_is_rejected() {
+            // 100 token0 in cannot pay for 100 token1 out once the fee is charged
+            assert_eq!(
+                Pair::_verify_swap((1000, 1000), (1100, 900), (0, 100)),
+                Err(Error::KValueDecreased)
+            );
+        }
+
+        #[ink::test]
+        fn price_accumulators_wrap_around() {
+            let (token0, token1) = create_tokens();
+            let mut pair = Pair::new(token0, token1);
+
+            // Price0 = 3, price1 = 1/3; both accumulators just below the wrap
+            pair.reserve0 = 1_000;
+            pair.reserve1 = 3_000;
+            pair.block_timestamp_last = 1_000;
+            pair.price0_cumulative_last = u128::MAX - 5;
+            pair.price1_cumulative_last = u128::MAX;
+            let (start0, start1) = (pair.price0_cumulative_last(), pair.price1_cumulative_last());
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_010);
+            pair._update(1_000, 3_000).unwrap();
+
+            // Wrapped instead of freezing at u128::MAX
+            assert_eq!(pair.price0_cumulative_last(), 24);
+            assert_eq!(pair.price1_cumulative_last(), 2);
+
+            // Deltas across the wrap still give the average price
+            let elapsed = 10;
+            assert_eq!(
+                pair.price0_cumulative_last().wrapping_sub(start0) / elapsed,
+                3
+            );
+            assert_eq!(pair.price1_cumulative_last().wrapping_sub(start1), 3);
+
+            // And keep doing so after further updates at a new price
+            pair.reserve1 = 5_000;
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_030);
+            pair._update(1_000, 5_000).unwrap();
+            assert_eq!(
+                pair.price0_cumulative_last().wrapping_sub(start0) / 30,
+                (3 * 10 + 5 * 20) / 30
+            );
+        }
+
+        #[ink::test]
+        fn price_time_matches_exact_product() {
+            // Exact while the product fits
+            assert_eq!(Pair::_price_time(3_000, 1_000, 10), Ok(30));
+            assert_eq!(Pair::_price_time(1_000, 3_000, 10), Ok(3));
+            assert_eq!(Pair::_price_time(7, 2, 5), Ok(17));
+            assert_eq!(
+                Pair::_price_time(1 << 100, 3, u64::MAX),
+                Ok(mul_div(1 << 100, u64::MAX as u128, 3).unwrap())
+            );
+
+            // Reduced modulo 2^128 when it does not
+            let big = u128::MAX / 2;
+            assert_eq!(Pair::_price_time(big, 1, 4), Ok(big.wrapping_mul(4)));
+
+            // An empty reserve is an error, not a panic
+            assert_eq!(Pair::_price_time(1_000, 0, 10), Err(Error::Overflow));
+        }
+
+        #[ink::test]
+        fn selectors_match_message_names() {
+            assert_eq!(
+                selectors::PSP22_TRANSFER,
+                ink::selector_bytes!("PSP22::transfer")
+            );
+            assert_eq!(
+                selectors::PSP22_BALANCE_OF,
+                ink::selector_bytes!("PSP22::balance_of")
+            );
+            // Pinned by the PSP22 standard (see psp22_trait.rs)
+            assert_eq!(selectors::PSP22_TRANSFER, [0xdb, 0x20, 0xf9, 0xf5]);
+            assert_eq!(selectors::PSP22_BALANCE_OF, [0x65, 0x68, 0x38, 0x2f]);
+            // Messages other contracts call on the pair
+            assert_eq!(selectors::PAIR_NEW, ink::selector_bytes!("new"));
+            assert_eq!(selectors::PAIR_SWAP, ink::selector_bytes!("swap"));
+            assert_eq!(
+                selectors::PAIR_GET_RESERVES,
+                ink::selector_bytes!("get_reserves")
+            );
+            // Pinned in interfaces.rs
+            assert_eq!(selectors::PAIR_GET_TOKENS, [0xba, 0x04, 0x52, 0x71]);
+            assert_eq!(selectors::PAIR_GET_RESERVES, [0x8a, 0x0d, 0x11, 0x6f]);
+            assert_eq!(selectors::PAIR_MINT, [0xcf, 0xdd, 0x9a, 0xa2]);
+            assert_eq!(selectors::PAIR_BURN, [0xb1, 0xef, 0xc1, 0x7b]);
+            assert_eq!(selectors::PAIR_SWAP, [0x11, 0x00, 0x4f, 0xa6]);
+            assert_eq!(selectors::PAIR_FEE_BPS, [0xf1, 0x83, 0x16, 0xc5]);
+            assert_eq!(selectors::PAIR_BALANCE_OF, [0x0f, 0x75, 0x5a, 0x56]);
+            assert_eq!(selectors::PAIR_TRANSFER, [0x84, 0xa1, 0x5d, 0xa1]);
+            assert_eq!(selectors::PAIR_TRANSFER_FROM, [0x0b, 0x39, 0x6f, 0x18]);
+            assert_eq!(selectors::PAIR_ON_FEE_TO_CHANGED, [0xc0, 0x42, 0x2b, 0xa5]);
+        }
+
+        #[ink::test]
+        fn fee_bps_works() {
+            let (token0, token1) = create_tokens();
+            let pair = Pair::new(token0, token1);
+
+            assert_eq!(pair.fee_bps(), 30);
+        }
+
+        #[ink::test]
+        fn error_encoding_matches_interface() {
+            use crate::interfaces::PairError;
+
+            assert_eq!(
+                Error::InsufficientLiquidityMinted.encode(),
+                PairError::InsufficientLiquidityMinted.encode()
+            );
+            assert_eq!(
+                Error::KValueDecreased.encode(),
+                PairError::KValueDecreased.encode()
+            );
+            assert_eq!(Error::Locked.encode(), PairError::Locked.encode());
+            assert_eq!(
+                Error::TransferFailed.encode(),
+                PairError::TransferFailed.encode()
+            );
+            assert_eq!(
+                Error::TokenError(PSP22Error::InsufficientAllowance).encode(),
+                PairError::TokenError(PSP22Error::InsufficientAllowance).encode()
+            );
+            assert_eq!(Error::NotFactory.encode(), PairError::NotFactory.encode());
+            assert_eq!(Error::CallFailed.encode(), PairError::CallFailed.encode());
+        }
+
+        #[ink::test]
+        fn fee_to_notification_updates_cache() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let (token0, token1) = create_tokens();
+
+            // Alice deploys the pair, so she plays the factory
+            let mut pair = Pair::new(token0, token1);
+            assert_eq!(pair.cached_fee_info(), None);
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+            pair.on_fee_to_changed(Some(accounts.eve), 1_667, 900)
+                .unwrap();
+            assert_eq!(
+                pair.cached_fee_info(),
+                Some((Some(accounts.eve), 1_667, 900))
+            );
+
+            // A late notification for an older change is ignored
+            pair.on_fee_to_changed(None, 1_667, 800).unwrap();
+            assert_eq!(
+                pair.cached_fee_info(),
+                Some((Some(accounts.eve), 1_667, 900))
+            );
+
+            pair.on_fee_to_changed(None, 1_667, 1_000).unwrap();
+            assert_eq!(pair.cached_fee_info(), Some((None, 1_667, 1_000)));
+        }
+
+        #[ink::test]
+        fn fee_to_notification_requires_factory() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let (token0, token1) = create_tokens();
+            let mut pair = Pair::new(token0, token1);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                pair.on_fee_to_changed(Some(accounts.django), 10_000, 1),
+                Err(Error::NotFactory)
+            );
+            assert_eq!(pair.cached_fee_info(), None);
+        }
+
+        #[ink::test]
+        fn cached_fee_info_goes_stale() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let (token0, token1) = create_tokens();
+            let mut pair = Pair::new(token0, token1);
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+            pair.on_fee_to_changed(Some(accounts.eve), 1_667, 1_000)
+                .unwrap();
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                1_000 + FEE_INFO_MAX_AGE,
+            );
+            assert_eq!(
+                pair.cached_fee_info(),
+                Some((Some(accounts.eve), 1_667, 1_000))
+            );
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                1_001 + FEE_INFO_MAX_AGE,
+            );
+            assert_eq!(pair.cached_fee_info(), None);
+
+            // A fresh notification makes the cache usable again
+            pair.on_fee_to_changed(Some(accounts.eve), 1_667, 1_000)
+                .unwrap();
+            assert_eq!(
+                pair.cached_fee_info(),
+                Some((Some(accounts.eve), 1_667, 1_000))
+            );
+        }
+
+        #[ink::test]
+        fn liquidity_events_carry_resulting_state() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let (token0, token1) = create_tokens();
+            let mut pair = Pair::new(token0, token1);
+
+            // State as `mint` leaves it: supply bumped, reserves synced
+            pair.total_supply = 1_414;
+            pair._update(1_000, 2_000).unwrap();
+            pair._emit_mint(1_000, 2_000, 414);
+
+            let event = ink::env::test::recorded_events()
+                .last()
+                .expect("no event recorded");
+            let mint = <Mint as ink::scale::Decode>::decode(&mut &event.data[..])
+                .expect("Mint decode failed");
+            assert_eq!(mint.sender, accounts.alice);
+            assert_eq!(mint.liquidity, 414);
+            assert_eq!(mint.total_supply_after, 1_414);
+            assert_eq!((mint.reserve0_after, mint.reserve1_after), (1_000, 2_000));
+
+            // State as `burn` leaves it
+            pair.total_supply = 707;
+            pair._update(500, 1_000).unwrap();
+            pair._emit_burn(500, 1_000, accounts.bob, 707);
+
+            let event = ink::env::test::recorded_events()
+                .last()
+                .expect("no event recorded");
+            let burn = <Burn as ink::scale::Decode>::decode(&mut &event.data[..])
+                .expect("Burn decode failed");
+            assert_eq!(burn.to, accounts.bob);
+            assert_eq!(burn.liquidity, 707);
+            assert_eq!(burn.total_supply_after, 707);
+            assert_eq!((burn.reserve0_after, burn.reserve1_after), (500, 1_000));
+        }
     }
 }
@@ -1,12 +1,14 @@
 //! PSP22 Trait Reference for Cross-Contract Calls
 //!
 //! Defines the PSP22 token interface for making cross-contract calls from DEX contracts.
+//! Selectors are pinned to the PSP22 standard so any compliant token (including DALLA)
+//! can be called without depending on the implementing crate.
 
 use ink::primitives::AccountId;
 
 /// PSP22 Error types
-#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
-#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
 pub enum PSP22Error {
     InsufficientBalance,
     InsufficientAllowance,
@@ -16,28 +18,110 @@ pub enum PSP22Error {
 
 pub type Result<T> = core::result::Result<T, PSP22Error>;
 
+/// Undecoded error payload of a failed PSP22 message
+///
+/// Use as the error type of a raw `build_call` reply
+/// (`returns::<core::result::Result<(), ErrorPayload>>()`): it captures
+/// whatever bytes follow the `Err` tag, so a token with a non-standard error
+/// type still yields a reply instead of failing to decode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorPayload(pub ink::prelude::vec::Vec<u8>);
+
+impl ink::scale::Decode for ErrorPayload {
+    fn decode<I: ink::scale::Input>(
+        input: &mut I,
+    ) -> core::result::Result<Self, ink::scale::Error> {
+        let len = input.remaining_len()?.unwrap_or(0);
+        let mut bytes = ink::prelude::vec![0u8; len];
+        input.read(&mut bytes)?;
+        Ok(Self(bytes))
+    }
+}
+
+/// Decodes a PSP22 error payload
+///
+/// Payloads that are not a `PSP22Error` (e.g. a token's own error enum) are
+/// reported as `Custom` so the caller still learns the token refused.
+pub fn decode_error(payload: &[u8]) -> PSP22Error {
+    <PSP22Error as ink::scale::DecodeAll>::decode_all(&mut &payload[..]).unwrap_or_else(|_| {
+        PSP22Error::Custom(ink::prelude::string::String::from(
+            "Unrecognized token error",
+        ))
+    })
+}
+
 /// PSP22 Token Interface
 ///
 /// Minimal interface needed for DEX operations
 #[ink::trait_definition]
 pub trait PSP22 {
+    /// Returns the total token supply
+    #[ink(message, selector = 0x162df8c2)]
+    fn total_supply(&self) -> u128;
+
     /// Returns the balance of the given account
-    #[ink(message)]
+    #[ink(message, selector = 0x6568382f)]
     fn balance_of(&self, owner: AccountId) -> u128;
 
     /// Transfers tokens to the recipient
-    #[ink(message)]
+    #[ink(message, selector = 0xdb20f9f5)]
     fn transfer(&mut self, to: AccountId, value: u128) -> Result<()>;
 
     /// Transfers tokens from one account to another (requires allowance)
-    #[ink(message)]
+    #[ink(message, selector = 0x54b3c76e)]
     fn transfer_from(&mut self, from: AccountId, to: AccountId, value: u128) -> Result<()>;
 
     /// Approves spender to spend tokens
-    #[ink(message)]
+    #[ink(message, selector = 0xb20f1bbd)]
     fn approve(&mut self, spender: AccountId, value: u128) -> Result<()>;
 
     /// Returns the allowance granted by owner to spender
-    #[ink(message)]
+    #[ink(message, selector = 0x4d47d921)]
     fn allowance(&self, owner: AccountId, spender: AccountId) -> u128;
 }
+
+/// PSP22 Metadata extension
+///
+/// Name and symbol are optional in the standard; decimals default to 0
+#[ink::trait_definition]
+pub trait PSP22Metadata {
+    /// Returns the token name
+    #[ink(message, selector = 0x3d261bd4)]
+    fn token_name(&self) -> Option<ink::prelude::string::String>;
+
+    /// Returns the token symbol
+    #[ink(message, selector = 0x34205be5)]
+    fn token_symbol(&self) -> Option<ink::prelude::string::String>;
+
+    /// Returns the number of decimals
+    #[ink(message, selector = 0x7271b782)]
+    fn token_decimals(&self) -> u8;
+}
+
+/// PSP22 Burnable extension
+///
+/// Lets an approved spender (e.g. a bridge) burn on a holder's behalf
+#[ink::trait_definition]
+pub trait PSP22Burnable {
+    /// Burns tokens from `from`, spending the caller's allowance
+    #[ink(message, selector = 0x1d3e58b5)]
+    fn burn_from(&mut self, from: AccountId, value: u128) -> Result<()>;
+}
+
+/// PSP22 Receiver hook
+///
+/// Implemented by contracts that want to be notified of (and able to refuse)
+/// incoming tokens sent with `transfer_to_contract`
+#[ink::trait_definition]
+pub trait PSP22Receiver {
+    /// Called by the token after `value` has moved from `from` to this
+    /// contract on behalf of `operator`; returning an error reverts the transfer
+    #[ink(message, selector = 0x540fa731)]
+    fn on_psp22_received(
+        &mut self,
+        operator: AccountId,
+        from: AccountId,
+        value: u128,
+        data: ink::prelude::vec::Vec<u8>,
+    ) -> Result<()>;
+}
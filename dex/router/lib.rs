@@ -1,13 +1,36 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 #![allow(clippy::arithmetic_side_effects)]
 
+#[path = "../psp22_trait.rs"]
+pub mod psp22_trait;
+
+#[path = "../selectors.rs"]
+pub mod selectors;
+
+#[path = "../interfaces.rs"]
+pub mod interfaces;
+
 #[ink::contract]
 #[allow(clippy::too_many_arguments)]
 pub mod router {
+    use crate::interfaces::{FactoryInterface, PairError, PairInterface};
+    use crate::psp22_trait::{self, ErrorPayload, PSP22Error, PSP22Metadata, PSP22};
+    use crate::selectors;
+    use gem_math::mul_div;
+    use ink::codegen::TraitCallBuilder;
     use ink::env::call::{build_call, ExecutionInput, Selector};
+    use ink::prelude::string::String;
     use ink::prelude::{vec, vec::Vec};
     use scale::{Decode, Encode};
 
+    /// Trading fee charged by every pair, in basis points (0.3%)
+    pub const LP_FEE_BPS: u32 = 30;
+
+    /// A hop whose input exceeds this share of the pool's input reserve (in
+    /// basis points) moves the price by roughly 10% or more and is reported
+    /// as shallow by `simulate_swap`
+    pub const SHALLOW_HOP_BPS: u128 = 1_000;
+
     // ============================================================================
     // Storage
     // ============================================================================
@@ -21,6 +44,56 @@ pub mod router {
         wbzc: AccountId,
     }
 
+    // ============================================================================
+    // Types
+    // ============================================================================
+
+    /// PSP22 metadata and supply of a token, as reported by the token itself
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct TokenMetadata {
+        pub name: Option<String>,
+        pub symbol: Option<String>,
+        pub decimals: u8,
+        pub total_supply: Balance,
+    }
+
+    /// One hop of a simulated swap, amounts in the hop's own tokens
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct HopSimulation {
+        pub pair: AccountId,
+        pub token_in: AccountId,
+        pub token_out: AccountId,
+        /// Amount of `token_in` sent towards the pair
+        pub amount_sent: Balance,
+        /// Part of `amount_sent` kept by `token_in`'s transfer fee
+        pub transfer_fee: Balance,
+        /// Amount of `token_in` the pair actually receives
+        pub amount_in: Balance,
+        /// Part of `amount_in` kept by the pool as its trading fee
+        pub lp_fee: Balance,
+        /// Amount of `token_out` the pair pays out
+        pub amount_out: Balance,
+        /// Input above `SHALLOW_HOP_BPS` of the pool's input reserve
+        pub shallow: bool,
+    }
+
+    /// Estimated outcome of a swap, as returned by `simulate_swap`
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct SimulationResult {
+        /// Amount of the last token expected to reach the recipient
+        pub amount_out: Balance,
+        /// Part of the last hop's output kept by the output token's transfer fee
+        pub output_transfer_fee: Balance,
+        /// Every hop in path order
+        pub hops: Vec<HopSimulation>,
+        /// Trading and transfer fees compounded over the whole path, in basis
+        /// points of the input
+        pub total_fee_bps: u32,
+    }
+
     // ============================================================================
     // Events
     // ============================================================================
@@ -89,6 +162,14 @@ pub mod router {
         CallFailed,
         /// Arithmetic operation failed
         ArithmeticError,
+        /// Token rejected the call with the given PSP22 error
+        TokenError(PSP22Error),
+        /// Pair returned by the factory does not hold the hop's tokens
+        InvalidHop,
+        /// Swap output sent to the zero address or a token contract on the path
+        InvalidRecipient,
+        /// Pair returned by the factory does not hold the requested tokens
+        PairMismatch,
     }
 
     pub type Result<T> = core::result::Result<T, Error>;
@@ -128,6 +209,23 @@ pub mod router {
             self.wbzc
         }
 
+        /// Get a token's metadata and total supply
+        ///
+        /// Returns `None` if the token does not implement PSP22 and
+        /// PSP22Metadata, so UIs can fall back to the raw address.
+        #[ink(message)]
+        pub fn token_metadata(&self, token: AccountId) -> Option<TokenMetadata> {
+            let psp22: ink::contract_ref!(PSP22) = token.into();
+            let metadata: ink::contract_ref!(PSP22Metadata) = token.into();
+
+            Some(TokenMetadata {
+                name: metadata.call().token_name().try_invoke().ok()?.ok()?,
+                symbol: metadata.call().token_symbol().try_invoke().ok()?.ok()?,
+                decimals: metadata.call().token_decimals().try_invoke().ok()?.ok()?,
+                total_supply: psp22.call().total_supply().try_invoke().ok()?.ok()?,
+            })
+        }
+
         /// Quote: How much token B needed for exact token A?
         ///
         /// Maintains current price ratio.
@@ -145,11 +243,7 @@ pub mod router {
                 return Err(Error::InsufficientLiquidity);
             }
 
-            let amount_b = amount_a
-                .checked_mul(reserve_b)
-                .ok_or(Error::ArithmeticError)?
-                .checked_div(reserve_a)
-                .ok_or(Error::ArithmeticError)?;
+            let amount_b = mul_div(amount_a, reserve_b, reserve_a).ok_or(Error::ArithmeticError)?;
 
             Ok(amount_b)
         }
@@ -172,17 +266,13 @@ pub mod router {
             // 0.3% fee: amount_in * 997 / 1000
             let amount_in_with_fee = amount_in.checked_mul(997).ok_or(Error::ArithmeticError)?;
 
-            let numerator = amount_in_with_fee
-                .checked_mul(reserve_out)
-                .ok_or(Error::ArithmeticError)?;
-
             let denominator = reserve_in
                 .checked_mul(1000)
                 .ok_or(Error::ArithmeticError)?
                 .checked_add(amount_in_with_fee)
                 .ok_or(Error::ArithmeticError)?;
 
-            Ok(numerator / denominator)
+            mul_div(amount_in_with_fee, reserve_out, denominator).ok_or(Error::ArithmeticError)
         }
 
         /// Calculate input amount for exact output
@@ -203,11 +293,7 @@ pub mod router {
                 return Err(Error::InsufficientLiquidity);
             }
 
-            let numerator = reserve_in
-                .checked_mul(amount_out)
-                .ok_or(Error::ArithmeticError)?
-                .checked_mul(1000)
-                .ok_or(Error::ArithmeticError)?;
+            let scaled_out = amount_out.checked_mul(1000).ok_or(Error::ArithmeticError)?;
 
             let denominator = reserve_out
                 .checked_sub(amount_out)
@@ -215,7 +301,9 @@ pub mod router {
                 .checked_mul(997)
                 .ok_or(Error::ArithmeticError)?;
 
-            Ok(numerator / denominator + 1)
+            mul_div(reserve_in, scaled_out, denominator)
+                .and_then(|amount_in| amount_in.checked_add(1))
+                .ok_or(Error::ArithmeticError)
         }
 
         /// Calculate output amounts for multi-hop swap
@@ -267,6 +355,61 @@ pub mod router {
             Ok(amounts)
         }
 
+        /// Estimate a swap of exactly `amount_in` along `path`, transfer fees
+        /// included
+        ///
+        /// Quotes every hop against the current reserves, and charges each
+        /// token's transfer fee as reported by its optional
+        /// `fee_on_transfer_bps()` message (0 if the token has none). This is
+        /// an estimate, not a dry run of `swap_exact_tokens_for_tokens`: no
+        /// tokens move, fee exemptions are not known, and reserves may change
+        /// before a real swap lands. Hops too shallow for their input are
+        /// flagged rather than rejected; empty pools still fail with
+        /// `InsufficientLiquidity`.
+        #[ink(message)]
+        pub fn simulate_swap(
+            &self,
+            amount_in: Balance,
+            path: Vec<AccountId>,
+        ) -> Result<SimulationResult> {
+            if path.len() < 2 {
+                return Err(Error::InvalidPath);
+            }
+
+            let mut hops = Vec::new();
+            let mut fees_bps = Vec::new();
+            let mut amount_sent = amount_in;
+
+            for i in 0..path.len() - 1 {
+                let (token_in, token_out) = (path[i], path[i + 1]);
+                let pair = self._get_checked_pair(token_in, token_out)?;
+                let (reserve_in, reserve_out) = self._pair_reserves(pair, token_in, token_out)?;
+
+                let tax_bps = self._fee_on_transfer_bps(token_in);
+                let hop = self._simulate_hop(amount_sent, tax_bps, reserve_in, reserve_out)?;
+                fees_bps.extend([u32::from(tax_bps), LP_FEE_BPS]);
+
+                amount_sent = hop.amount_out;
+                hops.push(HopSimulation {
+                    pair,
+                    token_in,
+                    token_out,
+                    ..hop
+                });
+            }
+
+            let tax_bps = self._fee_on_transfer_bps(path[path.len() - 1]);
+            let output_transfer_fee = Self::_fee_of(amount_sent, u32::from(tax_bps))?;
+            fees_bps.push(u32::from(tax_bps));
+
+            Ok(SimulationResult {
+                amount_out: amount_sent - output_transfer_fee,
+                output_transfer_fee,
+                hops,
+                total_fee_bps: Self::_compound_fee_bps(&fees_bps),
+            })
+        }
+
         // ========================================================================
         // Liquidity Functions
         // ========================================================================
@@ -310,16 +453,20 @@ pub mod router {
                 amount_b_min,
             )?;
 
-            // Get pair address
-            let pair = self._get_pair(token_a, token_b)?;
+            // Get pair address, checked before any funds move
+            let pair = self._get_liquidity_pair(token_a, token_b)?;
 
             // Transfer tokens from caller to pair
             self._token_transfer_from(token_a, self.env().caller(), pair, amount_a)?;
             self._token_transfer_from(token_b, self.env().caller(), pair, amount_b)?;
 
-            // Call pair.mint(to) - placeholder for cross-contract call
-            let _to = to;
-            let liquidity = 0; // TODO: Implement pair.mint() cross-contract call
+            // Mint LP tokens to the recipient
+            let mut pair: ink::contract_ref!(PairInterface) = pair.into();
+            let liquidity = match pair.call_mut().mint(to).try_invoke() {
+                Ok(Ok(Ok(liquidity))) => liquidity,
+                Ok(Ok(Err(PairError::TokenError(e)))) => return Err(Error::TokenError(e)),
+                _ => return Err(Error::CallFailed),
+            };
 
             // Emit event
             self.env().emit_event(LiquidityAdded {
@@ -361,16 +508,21 @@ pub mod router {
             // Check deadline
             self._ensure_not_expired(deadline)?;
 
-            // Get pair address
-            let pair = self._get_pair(token_a, token_b)?;
+            // Get pair address, checked before burning
+            let pair = self._get_liquidity_pair(token_a, token_b)?;
 
             // Transfer LP tokens from caller to pair
             // Note: LP tokens are managed by the pair contract itself
             // In production,  would transfer pair LP tokens here
 
-            // Call pair.burn(to) - placeholder for cross-contract call
-            let (_to, _liquidity, _pair) = (to, liquidity, pair);
-            let (amount0, amount1) = (0, 0); // TODO: Implement pair.burn() cross-contract call
+            // Burn LP tokens held by the pair and send the underlying tokens out
+            let _liquidity = liquidity;
+            let mut pair: ink::contract_ref!(PairInterface) = pair.into();
+            let (amount0, amount1) = match pair.call_mut().burn(to).try_invoke() {
+                Ok(Ok(Ok(amounts))) => amounts,
+                Ok(Ok(Err(PairError::TokenError(e)))) => return Err(Error::TokenError(e)),
+                _ => return Err(Error::CallFailed),
+            };
 
             // Sort amounts based on token order
             let (token0, _) = Self::_sort_tokens(token_a, token_b)?;
@@ -506,40 +658,33 @@ pub mod router {
             to: AccountId,
             amount: Balance,
         ) -> Result<()> {
-            // PSP22::transfer_from selector is 0x54b3c76e
-            let selector = [0x54, 0xb3, 0xc7, 0x6e];
-
             let result = build_call::<Environment>()
                 .call(token)
                 .exec_input(
-                    ExecutionInput::new(Selector::new(selector))
+                    ExecutionInput::new(Selector::new(selectors::PSP22_TRANSFER_FROM))
                         .push_arg(from)
                         .push_arg(to)
                         .push_arg(amount),
                 )
-                .returns::<core::result::Result<(), ink::prelude::vec::Vec<u8>>>()
+                .returns::<core::result::Result<(), ErrorPayload>>()
                 .try_invoke();
 
             match result {
-                Ok(Ok(_)) => Ok(()),
+                Ok(Ok(Ok(()))) => Ok(()),
+                Ok(Ok(Err(payload))) => {
+                    Err(Error::TokenError(psp22_trait::decode_error(&payload.0)))
+                }
                 _ => Err(Error::CallFailed),
             }
         }
 
         /// Get token balance via PSP22 cross-contract call
-        fn _token_balance_of(&self, token: AccountId, account: AccountId) -> Balance {
-            // PSP22::balance_of selector is 0x65682523
-            let selector = [0x65, 0x68, 0x25, 0x23];
-
-            let result = build_call::<Environment>()
-                .call(token)
-                .exec_input(ExecutionInput::new(Selector::new(selector)).push_arg(account))
-                .returns::<Balance>()
-                .try_invoke();
+        fn _token_balance_of(&self, token: AccountId, account: AccountId) -> Result<Balance> {
+            let token: ink::contract_ref!(PSP22) = token.into();
 
-            match result {
-                Ok(Ok(balance)) => balance,
-                _ => 0,
+            match token.call().balance_of(account).try_invoke() {
+                Ok(Ok(balance)) => Ok(balance),
+                _ => Err(Error::CallFailed),
             }
         }
 
@@ -572,36 +717,77 @@ pub mod router {
 
         /// Get pair address for two tokens
         ///
-        /// Calls factory.get_pair(tokenA, tokenB) to retrieve the pair address.
+        /// Calls factory.get_pair_address(tokenA, tokenB) to retrieve the pair address.
         fn _get_pair(&self, token_a: AccountId, token_b: AccountId) -> Result<AccountId> {
-            let selector = [0x6a, 0x3d, 0x0f, 0x5f]; // get_pair method
-            let result = build_call::<Environment>()
-                .call(self.factory)
-                .exec_input(
-                    ExecutionInput::new(Selector::new(selector))
-                        .push_arg(token_a)
-                        .push_arg(token_b),
-                )
-                .returns::<Option<AccountId>>()
-                .try_invoke();
+            let factory: ink::contract_ref!(FactoryInterface) = self.factory.into();
 
-            match result {
+            match factory
+                .call()
+                .get_pair_address(token_a, token_b)
+                .try_invoke()
+            {
                 Ok(Ok(Some(pair))) => Ok(pair),
                 _ => Err(Error::PairNotFound),
             }
         }
 
+        /// Get the pair for two tokens, verifying it actually holds them
+        ///
+        /// Must be used before sending anything to the pair, so a wrong factory
+        /// answer can't route user funds to a pool of other tokens or to an
+        /// arbitrary account.
+        fn _get_checked_pair(&self, token_a: AccountId, token_b: AccountId) -> Result<AccountId> {
+            let pair = self._get_pair(token_a, token_b)?;
+            let pair_ref: ink::contract_ref!(PairInterface) = pair.into();
+
+            let tokens = match pair_ref.call().get_tokens().try_invoke() {
+                Ok(Ok(tokens)) => tokens,
+                _ => return Err(Error::CallFailed),
+            };
+
+            if tokens != Self::_sort_tokens(token_a, token_b)? {
+                return Err(Error::InvalidHop);
+            }
+
+            Ok(pair)
+        }
+
+        /// Get the checked pair for adding or removing liquidity
+        ///
+        /// Same check as the swap hops, reported as `PairMismatch` since there
+        /// is no hop involved.
+        fn _get_liquidity_pair(&self, token_a: AccountId, token_b: AccountId) -> Result<AccountId> {
+            self._get_checked_pair(token_a, token_b)
+                .map_err(|error| match error {
+                    Error::InvalidHop => Error::PairMismatch,
+                    error => error,
+                })
+        }
+
         /// Get reserves for two tokens
         fn _get_reserves(
             &self,
             token_a: AccountId,
             token_b: AccountId,
+        ) -> Result<(Balance, Balance)> {
+            let pair = self._get_pair(token_a, token_b)?;
+            self._pair_reserves(pair, token_a, token_b)
+        }
+
+        /// Get a pair's reserves, ordered as (token_a, token_b)
+        fn _pair_reserves(
+            &self,
+            pair: AccountId,
+            token_a: AccountId,
+            token_b: AccountId,
         ) -> Result<(Balance, Balance)> {
             let (token0, _) = Self::_sort_tokens(token_a, token_b)?;
+            let pair: ink::contract_ref!(PairInterface) = pair.into();
 
-            // TODO: Call pair.get_reserves()
-            // For now, return placeholder (1000 DALLA, 2000 BZC)
-            let (reserve0, reserve1) = (1000, 2000);
+            let (reserve0, reserve1, _) = match pair.call().get_reserves().try_invoke() {
+                Ok(Ok(reserves)) => reserves,
+                _ => return Err(Error::CallFailed),
+            };
 
             if token_a == token0 {
                 Ok((reserve0, reserve1))
@@ -610,6 +796,71 @@ pub mod router {
             }
         }
 
+        /// Transfer fee a token charges, via its optional
+        /// `fee_on_transfer_bps()` message
+        ///
+        /// Tokens without the message (or answering with garbage) are
+        /// treated as fee-free.
+        fn _fee_on_transfer_bps(&self, token: AccountId) -> u16 {
+            let result = build_call::<Environment>()
+                .call(token)
+                .exec_input(ExecutionInput::new(Selector::new(
+                    selectors::TOKEN_FEE_ON_TRANSFER_BPS,
+                )))
+                .returns::<u16>()
+                .try_invoke();
+
+            match result {
+                Ok(Ok(bps)) => bps.min(10_000),
+                _ => 0,
+            }
+        }
+
+        /// Simulate one hop: `amount_sent` of the input token, less its
+        /// transfer fee, swapped against the given reserves
+        ///
+        /// Pair and token fields are left for the caller to fill in.
+        fn _simulate_hop(
+            &self,
+            amount_sent: Balance,
+            tax_bps: u16,
+            reserve_in: Balance,
+            reserve_out: Balance,
+        ) -> Result<HopSimulation> {
+            let transfer_fee = Self::_fee_of(amount_sent, u32::from(tax_bps))?;
+            let amount_in = amount_sent - transfer_fee;
+            let amount_out = self.get_amount_out(amount_in, reserve_in, reserve_out)?;
+            let shallow = mul_div(amount_in, 10_000, reserve_in).ok_or(Error::ArithmeticError)?
+                > SHALLOW_HOP_BPS;
+
+            Ok(HopSimulation {
+                pair: AccountId::from([0u8; 32]),
+                token_in: AccountId::from([0u8; 32]),
+                token_out: AccountId::from([0u8; 32]),
+                amount_sent,
+                transfer_fee,
+                amount_in,
+                lp_fee: Self::_fee_of(amount_in, LP_FEE_BPS)?,
+                amount_out,
+                shallow,
+            })
+        }
+
+        /// `bps` of `amount`, rounded down
+        fn _fee_of(amount: Balance, bps: u32) -> Result<Balance> {
+            mul_div(amount, u128::from(bps), 10_000).ok_or(Error::ArithmeticError)
+        }
+
+        /// Fees charged one after another, as a single share in basis points
+        ///
+        /// 1% then 0.3% leaves 99% * 99.7% = 98.703% of the input, i.e. 129 bps.
+        fn _compound_fee_bps(fees_bps: &[u32]) -> u32 {
+            let kept = fees_bps.iter().fold(100_000_000u128, |kept, &bps| {
+                kept * u128::from(10_000 - bps.min(10_000)) / 10_000
+            });
+            ((100_000_000 - kept) / 10_000) as u32
+        }
+
         /// Calculate optimal liquidity amounts
         fn _calculate_liquidity_amounts(
             &self,
@@ -661,11 +912,17 @@ pub mod router {
         /// Swaps tokens through multiple pairs in sequence.
         /// Tokens are transferred directly between pairs for efficiency.
         fn _swap(&self, amounts: &[Balance], path: &[AccountId], to: AccountId) -> Result<()> {
+            // Tokens sent to a token contract are lost for good
+            if to == AccountId::from([0u8; 32]) || path.contains(&to) {
+                return Err(Error::InvalidRecipient);
+            }
+
             for i in 0..path.len() - 1 {
                 let (input, output) = (path[i], path[i + 1]);
 
                 // Get pair address
-                let pair = self._get_pair(input, output)?;
+                let mut pair: ink::contract_ref!(PairInterface) =
+                    self._get_checked_pair(input, output)?.into();
 
                 // Determine token order in pair (pairs use sorted addresses)
                 let (token0, _token1) = if input < output {
@@ -684,26 +941,19 @@ pub mod router {
 
                 // Determine recipient: next pair or final destination
                 let recipient = if i < path.len() - 2 {
-                    self._get_pair(output, path[i + 2])?
+                    self._get_checked_pair(output, path[i + 2])?
                 } else {
                     to
                 };
 
                 // Call pair.swap(amount0Out, amount1Out, to)
-                let selector = [0x1e, 0x6a, 0xf2, 0x6f]; // swap method
-                let result = build_call::<Environment>()
-                    .call(pair)
-                    .exec_input(
-                        ExecutionInput::new(Selector::new(selector))
-                            .push_arg(amount0_out)
-                            .push_arg(amount1_out)
-                            .push_arg(recipient),
-                    )
-                    .returns::<core::result::Result<(), Vec<u8>>>()
-                    .try_invoke();
-
-                match result {
-                    Ok(Ok(_)) => {}
+                match pair
+                    .call_mut()
+                    .swap(amount0_out, amount1_out, recipient)
+                    .try_invoke()
+                {
+                    Ok(Ok(Ok(()))) => {}
+                    Ok(Ok(Err(PairError::TokenError(e)))) => return Err(Error::TokenError(e)),
                     _ => return Err(Error::SwapFailed),
                 }
             }
@@ -771,5 +1021,698 @@ pub mod router {
             // Should be around 100
             assert!(amount_in > 99 && amount_in < 101);
         }
+
+        #[ink::test]
+        fn swap_rejects_invalid_recipient() {
+            let (factory, wbzc) = get_test_accounts();
+            let router = Router::new(factory, wbzc);
+            let path = [AccountId::from([0x01; 32]), AccountId::from([0x02; 32])];
+
+            assert_eq!(
+                router._swap(&[100, 90], &path, AccountId::from([0u8; 32])),
+                Err(Error::InvalidRecipient)
+            );
+            assert_eq!(
+                router._swap(&[100, 90], &path, path[1]),
+                Err(Error::InvalidRecipient)
+            );
+        }
+
+        #[ink::test]
+        fn large_reserve_quotes_do_not_overflow() {
+            let (factory, wbzc) = get_test_accounts();
+            let router = Router::new(factory, wbzc);
+
+            // The intermediate products overflow u128 with reserves this large
+            let reserve = 1u128 << 100;
+            assert_eq!(
+                router.quote(1 << 90, 1 << 99, reserve),
+                Ok(2_475_880_078_570_760_549_798_248_448)
+            );
+            assert_eq!(
+                router.get_amount_out(1 << 90, reserve, reserve),
+                Ok(1_233_025_704_882_594_498_610_447_733)
+            );
+            assert_eq!(
+                router.get_amount_in(1 << 90, reserve, reserve),
+                Ok(1_242_878_783_200_264_921_349_290_497)
+            );
+        }
+
+        #[ink::test]
+        fn selectors_match_message_names() {
+            assert_eq!(
+                selectors::PSP22_TRANSFER_FROM,
+                ink::selector_bytes!("PSP22::transfer_from")
+            );
+            assert_eq!(
+                selectors::PSP22_BALANCE_OF,
+                ink::selector_bytes!("PSP22::balance_of")
+            );
+            // Pinned by the PSP22 standard (see psp22_trait.rs)
+            assert_eq!(selectors::PSP22_TRANSFER_FROM, [0x54, 0xb3, 0xc7, 0x6e]);
+            assert_eq!(selectors::PSP22_BALANCE_OF, [0x65, 0x68, 0x38, 0x2f]);
+            assert_eq!(
+                selectors::FACTORY_GET_PAIR_ADDRESS,
+                ink::selector_bytes!("get_pair_address")
+            );
+            assert_eq!(selectors::PAIR_SWAP, ink::selector_bytes!("swap"));
+            assert_eq!(selectors::PAIR_MINT, ink::selector_bytes!("mint"));
+            assert_eq!(selectors::PAIR_BURN, ink::selector_bytes!("burn"));
+            assert_eq!(
+                selectors::PAIR_GET_RESERVES,
+                ink::selector_bytes!("get_reserves")
+            );
+            assert_eq!(
+                selectors::TOKEN_FEE_ON_TRANSFER_BPS,
+                ink::selector_bytes!("fee_on_transfer_bps")
+            );
+        }
+
+        #[ink::test]
+        fn simulated_hop_charges_transfer_and_lp_fees() {
+            let (factory, wbzc) = get_test_accounts();
+            let router = Router::new(factory, wbzc);
+
+            // Without a transfer fee the hop matches get_amount_out
+            let hop = router._simulate_hop(100, 0, 1_000, 2_000).unwrap();
+            assert_eq!(hop.transfer_fee, 0);
+            assert_eq!(hop.amount_in, 100);
+            assert_eq!(hop.lp_fee, 0); // 0.3% of 100 rounds down
+            assert_eq!(
+                hop.amount_out,
+                router.get_amount_out(100, 1_000, 2_000).unwrap()
+            );
+            assert!(!hop.shallow);
+
+            // A 1% token delivers 9_900 of 10_000 to the pair
+            let hop = router
+                ._simulate_hop(10_000, 100, 1_000_000, 1_000_000)
+                .unwrap();
+            assert_eq!(hop.transfer_fee, 100);
+            assert_eq!(hop.amount_in, 9_900);
+            assert_eq!(hop.lp_fee, 29);
+            assert_eq!(
+                hop.amount_out,
+                router.get_amount_out(9_900, 1_000_000, 1_000_000).unwrap()
+            );
+
+            // Empty pools still fail
+            assert_eq!(
+                router._simulate_hop(100, 0, 0, 2_000),
+                Err(Error::InsufficientLiquidity)
+            );
+        }
+
+        #[ink::test]
+        fn simulated_hop_flags_shallow_pools() {
+            let (factory, wbzc) = get_test_accounts();
+            let router = Router::new(factory, wbzc);
+
+            // Exactly SHALLOW_HOP_BPS of the input reserve is still fine
+            assert!(!router._simulate_hop(100, 0, 1_000, 1_000).unwrap().shallow);
+            assert!(router._simulate_hop(101, 0, 1_000, 1_000).unwrap().shallow);
+        }
+
+        #[ink::test]
+        fn fees_compound_over_the_path() {
+            assert_eq!(Router::_compound_fee_bps(&[]), 0);
+            assert_eq!(Router::_compound_fee_bps(&[0, LP_FEE_BPS, 0]), 30);
+            // 1 - 0.99 * 0.997
+            assert_eq!(Router::_compound_fee_bps(&[100, LP_FEE_BPS]), 129);
+            // Two plain hops: 1 - 0.997^2
+            assert_eq!(
+                Router::_compound_fee_bps(&[0, LP_FEE_BPS, 0, LP_FEE_BPS, 0]),
+                59
+            );
+            assert_eq!(Router::_compound_fee_bps(&[10_000, LP_FEE_BPS]), 10_000);
+        }
+
+        /// Decodes a raw `Result<(), _>` reply the way `_token_transfer_from` does
+        fn decode_reply(reply: &[u8]) -> core::result::Result<(), ErrorPayload> {
+            Decode::decode(&mut &reply[..]).expect("reply should decode")
+        }
+
+        #[ink::test]
+        fn psp22_errors_are_decoded() {
+            let errors = [
+                PSP22Error::InsufficientBalance,
+                PSP22Error::InsufficientAllowance,
+                PSP22Error::InvalidRecipient,
+                PSP22Error::Custom(String::from("Paused")),
+            ];
+
+            for error in errors {
+                let reply = core::result::Result::<(), PSP22Error>::Err(error.clone()).encode();
+                let payload = decode_reply(&reply).unwrap_err();
+                assert_eq!(psp22_trait::decode_error(&payload.0), error);
+            }
+        }
+
+        #[ink::test]
+        fn success_reply_is_ok() {
+            let reply = core::result::Result::<(), PSP22Error>::Ok(()).encode();
+            assert_eq!(decode_reply(&reply), Ok(()));
+        }
+
+        #[ink::test]
+        fn non_standard_errors_are_reported_as_custom() {
+            // A token-specific error enum with more variants than PSP22Error
+            let reply = core::result::Result::<(), u8>::Err(7).encode();
+            let payload = decode_reply(&reply).unwrap_err();
+            assert_eq!(payload.0, vec![7]);
+            assert_eq!(
+                psp22_trait::decode_error(&payload.0),
+                PSP22Error::Custom(String::from("Unrecognized token error"))
+            );
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use belizex_mock::belizex_mock::{BelizexMock, BelizexMockRef, MOCK_LIQUIDITY};
+        use belizex_token_mock::belizex_token_mock::{TokenMock, TokenMockRef};
+        use ink_e2e::ContractsBackend;
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        /// Token addresses in pair order (token0 < token1)
+        fn tokens() -> (AccountId, AccountId) {
+            (AccountId::from([0x01; 32]), AccountId::from([0x02; 32]))
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_swap_goes_through_pair_interface<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let (token0, token1) = tokens();
+
+            // The mock is both factory and pair, holding 1000 token0 / 2000 token1
+            let mut mock_constructor = BelizexMockRef::new(token0, token1, 1_000, 2_000);
+            let mock = client
+                .instantiate("belizex_mock", &ink_e2e::alice(), &mut mock_constructor)
+                .submit()
+                .await
+                .expect("belizex_mock instantiate failed");
+            let mock_call = mock.call_builder::<BelizexMock>();
+
+            let mut router_constructor = RouterRef::new(mock.account_id, token0);
+            let router = client
+                .instantiate("belizex_router", &ink_e2e::alice(), &mut router_constructor)
+                .submit()
+                .await
+                .expect("belizex_router instantiate failed");
+            let mut router_call = router.call_builder::<Router>();
+
+            // Quotes use the reserves read from the pair
+            let amounts = client
+                .call(
+                    &ink_e2e::alice(),
+                    &router_call.get_amounts_out(100, vec![token1, token0]),
+                )
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("get_amounts_out failed");
+            let expected = client
+                .call(
+                    &ink_e2e::alice(),
+                    &router_call.get_amount_out(100, 2_000, 1_000),
+                )
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("get_amount_out failed");
+            assert_eq!(amounts, vec![100, expected]);
+
+            // Selling token1 takes token0 out of the pair
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &router_call.swap_exact_tokens_for_tokens(
+                        100,
+                        0,
+                        vec![token1, token0],
+                        bob,
+                        u64::MAX,
+                    ),
+                )
+                .submit()
+                .await
+                .expect("swap failed");
+
+            let last_swap = client
+                .call(&ink_e2e::alice(), &mock_call.last_swap())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(last_swap, Some((expected, 0, bob)));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_remove_liquidity_burns_through_pair_interface<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let (token0, token1) = tokens();
+
+            let mut mock_constructor = BelizexMockRef::new(token0, token1, 1_000, 2_000);
+            let mock = client
+                .instantiate("belizex_mock", &ink_e2e::alice(), &mut mock_constructor)
+                .submit()
+                .await
+                .expect("belizex_mock instantiate failed");
+            let mut mock_call = mock.call_builder::<BelizexMock>();
+
+            let mut router_constructor = RouterRef::new(mock.account_id, token0);
+            let router = client
+                .instantiate("belizex_router", &ink_e2e::alice(), &mut router_constructor)
+                .submit()
+                .await
+                .expect("belizex_router instantiate failed");
+            let mut router_call = router.call_builder::<Router>();
+
+            // Burned amounts come back in the caller's token order
+            let amounts = client
+                .call(
+                    &ink_e2e::alice(),
+                    &router_call.remove_liquidity(
+                        token1,
+                        token0,
+                        MOCK_LIQUIDITY,
+                        0,
+                        0,
+                        bob,
+                        u64::MAX,
+                    ),
+                )
+                .submit()
+                .await
+                .expect("remove_liquidity failed")
+                .return_value();
+            assert_eq!(amounts, Ok((2_000, 1_000)));
+
+            let last_burn_to = client
+                .call(&ink_e2e::alice(), &mock_call.last_burn_to())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(last_burn_to, Some(bob));
+
+            // A failing pair surfaces as an error instead of zero amounts
+            client
+                .call(&ink_e2e::alice(), &mock_call.set_failing(true))
+                .submit()
+                .await
+                .expect("set_failing failed");
+
+            let result = client
+                .call(
+                    &ink_e2e::alice(),
+                    &router_call.remove_liquidity(
+                        token1,
+                        token0,
+                        MOCK_LIQUIDITY,
+                        0,
+                        0,
+                        bob,
+                        u64::MAX,
+                    ),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(result, Err(Error::CallFailed));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_token_errors_reach_the_caller<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+
+            let mut token_constructor = TokenMockRef::new(None);
+            let token = client
+                .instantiate(
+                    "belizex_token_mock",
+                    &ink_e2e::alice(),
+                    &mut token_constructor,
+                )
+                .submit()
+                .await
+                .expect("belizex_token_mock instantiate failed");
+            let mut token_call = token.call_builder::<TokenMock>();
+
+            // The pair must hold the token, or the router stops before any transfer
+            let other_token = AccountId::from([0x02; 32]);
+            let (token0, token1) = if token.account_id < other_token {
+                (token.account_id, other_token)
+            } else {
+                (other_token, token.account_id)
+            };
+            let mut mock_constructor = BelizexMockRef::new(token0, token1, 1_000, 2_000);
+            let mock = client
+                .instantiate("belizex_mock", &ink_e2e::alice(), &mut mock_constructor)
+                .submit()
+                .await
+                .expect("belizex_mock instantiate failed");
+
+            let mut router_constructor = RouterRef::new(mock.account_id, token.account_id);
+            let router = client
+                .instantiate("belizex_router", &ink_e2e::alice(), &mut router_constructor)
+                .submit()
+                .await
+                .expect("belizex_router instantiate failed");
+            let mut router_call = router.call_builder::<Router>();
+
+            let errors = [
+                PSP22Error::InsufficientBalance,
+                PSP22Error::InsufficientAllowance,
+                PSP22Error::InvalidRecipient,
+                PSP22Error::Custom(String::from("Paused")),
+            ];
+
+            for error in errors {
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &token_call.set_error(Some(error.clone())),
+                    )
+                    .submit()
+                    .await
+                    .expect("set_error failed");
+
+                // The first transfer_from into the pair is refused by the token
+                let result = client
+                    .call(
+                        &ink_e2e::alice(),
+                        &router_call.add_liquidity(
+                            token.account_id,
+                            other_token,
+                            100,
+                            200,
+                            0,
+                            0,
+                            bob,
+                            u64::MAX,
+                        ),
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value();
+                assert_eq!(result, Err(Error::TokenError(error)));
+            }
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_swap_rejects_wrong_next_pair<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let (token0, token1) = tokens();
+            let token2 = AccountId::from([0x03; 32]);
+
+            // The mock factory answers with the token0/token1 pool for every
+            // lookup, including the token1 -> token2 hop
+            let mut mock_constructor = BelizexMockRef::new(token0, token1, 1_000, 2_000);
+            let mock = client
+                .instantiate("belizex_mock", &ink_e2e::alice(), &mut mock_constructor)
+                .submit()
+                .await
+                .expect("belizex_mock instantiate failed");
+
+            let mut router_constructor = RouterRef::new(mock.account_id, token0);
+            let router = client
+                .instantiate("belizex_router", &ink_e2e::alice(), &mut router_constructor)
+                .submit()
+                .await
+                .expect("belizex_router instantiate failed");
+            let mut router_call = router.call_builder::<Router>();
+
+            let result = client
+                .call(
+                    &ink_e2e::alice(),
+                    &router_call.swap_exact_tokens_for_tokens(
+                        100,
+                        0,
+                        vec![token0, token1, token2],
+                        bob,
+                        u64::MAX,
+                    ),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(result, Err(Error::InvalidHop));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_liquidity_rejects_mismatched_pair<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let (token0, token1) = tokens();
+
+            // Every transfer_from on this token fails, so reaching the
+            // transfer step would surface as a TokenError
+            let mut token_constructor = TokenMockRef::new(Some(PSP22Error::InsufficientBalance));
+            let token = client
+                .instantiate(
+                    "belizex_token_mock",
+                    &ink_e2e::alice(),
+                    &mut token_constructor,
+                )
+                .submit()
+                .await
+                .expect("belizex_token_mock instantiate failed");
+
+            // The mock factory answers with its token0/token1 pool for the
+            // token/token1 lookup
+            let mut mock_constructor = BelizexMockRef::new(token0, token1, 1_000, 2_000);
+            let mock = client
+                .instantiate("belizex_mock", &ink_e2e::alice(), &mut mock_constructor)
+                .submit()
+                .await
+                .expect("belizex_mock instantiate failed");
+
+            let mut router_constructor = RouterRef::new(mock.account_id, token0);
+            let router = client
+                .instantiate("belizex_router", &ink_e2e::alice(), &mut router_constructor)
+                .submit()
+                .await
+                .expect("belizex_router instantiate failed");
+            let mut router_call = router.call_builder::<Router>();
+
+            let result = client
+                .call(
+                    &ink_e2e::alice(),
+                    &router_call.add_liquidity(
+                        token.account_id,
+                        token1,
+                        100,
+                        200,
+                        0,
+                        0,
+                        bob,
+                        u64::MAX,
+                    ),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(result, Err(Error::PairMismatch));
+
+            let result = client
+                .call(
+                    &ink_e2e::alice(),
+                    &router_call.remove_liquidity(
+                        token.account_id,
+                        token1,
+                        MOCK_LIQUIDITY,
+                        0,
+                        0,
+                        bob,
+                        u64::MAX,
+                    ),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(result, Err(Error::PairMismatch));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_simulation_matches_executed_swap<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let (token0, token1) = tokens();
+
+            let mut mock_constructor = BelizexMockRef::new(token0, token1, 1_000, 2_000);
+            let mock = client
+                .instantiate("belizex_mock", &ink_e2e::alice(), &mut mock_constructor)
+                .submit()
+                .await
+                .expect("belizex_mock instantiate failed");
+            let mock_call = mock.call_builder::<BelizexMock>();
+
+            let mut router_constructor = RouterRef::new(mock.account_id, token0);
+            let router = client
+                .instantiate("belizex_router", &ink_e2e::alice(), &mut router_constructor)
+                .submit()
+                .await
+                .expect("belizex_router instantiate failed");
+            let mut router_call = router.call_builder::<Router>();
+
+            let simulation = client
+                .call(
+                    &ink_e2e::alice(),
+                    &router_call.simulate_swap(100, vec![token1, token0]),
+                )
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("simulate_swap failed");
+
+            // The placeholder tokens have no fee_on_transfer_bps, so only the
+            // pool's trading fee applies
+            assert_eq!(simulation.hops.len(), 1);
+            let hop = &simulation.hops[0];
+            assert_eq!(hop.pair, mock.account_id);
+            assert_eq!((hop.token_in, hop.token_out), (token1, token0));
+            assert_eq!((hop.amount_sent, hop.transfer_fee), (100, 0));
+            assert!(!hop.shallow);
+            assert_eq!(simulation.output_transfer_fee, 0);
+            assert_eq!(simulation.total_fee_bps, LP_FEE_BPS);
+
+            let amounts = client
+                .call(
+                    &ink_e2e::alice(),
+                    &router_call.swap_exact_tokens_for_tokens(
+                        100,
+                        0,
+                        vec![token1, token0],
+                        bob,
+                        u64::MAX,
+                    ),
+                )
+                .submit()
+                .await
+                .expect("swap failed")
+                .return_value()
+                .expect("swap reverted");
+            assert_eq!(amounts, vec![100, simulation.amount_out]);
+
+            let last_swap = client
+                .call(&ink_e2e::alice(), &mock_call.last_swap())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(last_swap, Some((hop.amount_out, 0, bob)));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_simulation_applies_transfer_fees<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let mut token_constructor = TokenMockRef::new(None);
+            let token = client
+                .instantiate(
+                    "belizex_token_mock",
+                    &ink_e2e::alice(),
+                    &mut token_constructor,
+                )
+                .submit()
+                .await
+                .expect("belizex_token_mock instantiate failed");
+            let mut token_call = token.call_builder::<TokenMock>();
+            client
+                .call(&ink_e2e::alice(), &token_call.set_fee_on_transfer_bps(100))
+                .submit()
+                .await
+                .expect("set_fee_on_transfer_bps failed");
+
+            let other_token = AccountId::from([0x02; 32]);
+            let (token0, token1) = if token.account_id < other_token {
+                (token.account_id, other_token)
+            } else {
+                (other_token, token.account_id)
+            };
+            let mut mock_constructor = BelizexMockRef::new(token0, token1, 10_000, 10_000);
+            let mock = client
+                .instantiate("belizex_mock", &ink_e2e::alice(), &mut mock_constructor)
+                .submit()
+                .await
+                .expect("belizex_mock instantiate failed");
+
+            let mut router_constructor = RouterRef::new(mock.account_id, token.account_id);
+            let router = client
+                .instantiate("belizex_router", &ink_e2e::alice(), &mut router_constructor)
+                .submit()
+                .await
+                .expect("belizex_router instantiate failed");
+            let mut router_call = router.call_builder::<Router>();
+
+            // 1% of the input is lost on the way into the pair
+            let simulation = client
+                .call(
+                    &ink_e2e::alice(),
+                    &router_call.simulate_swap(1_000, vec![token.account_id, other_token]),
+                )
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("simulate_swap failed");
+            let expected = client
+                .call(
+                    &ink_e2e::alice(),
+                    &router_call.get_amount_out(990, 10_000, 10_000),
+                )
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("get_amount_out failed");
+            let hop = &simulation.hops[0];
+            assert_eq!((hop.transfer_fee, hop.amount_in), (10, 990));
+            assert_eq!(hop.amount_out, expected);
+            assert!(!hop.shallow);
+            assert_eq!(simulation.amount_out, expected);
+            assert_eq!(simulation.total_fee_bps, 129);
+
+            // Bought the other way round, the fee is taken from the output
+            let simulation = client
+                .call(
+                    &ink_e2e::alice(),
+                    &router_call.simulate_swap(2_000, vec![other_token, token.account_id]),
+                )
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("simulate_swap failed");
+            let hop = &simulation.hops[0];
+            assert_eq!(hop.transfer_fee, 0);
+            assert!(hop.shallow);
+            assert_eq!(simulation.output_transfer_fee, hop.amount_out / 100);
+            assert_eq!(
+                simulation.amount_out,
+                hop.amount_out - simulation.output_transfer_fee
+            );
+
+            Ok(())
+        }
     }
 }
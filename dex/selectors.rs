@@ -0,0 +1,129 @@
+//! Shared Selectors for Cross-Contract Calls
+//!
+//! Every message selector the GEM contracts call on one another, derived with
+//! `ink::selector_bytes!` from the canonical message name so a hand-typed byte
+//! array cannot drift from the callee. Trait messages are prefixed with the
+//! trait name (`PSP22::transfer`); inherent messages use the bare name.
+
+// ============================================================================
+// PSP22
+// ============================================================================
+
+/// `PSP22::total_supply() -> Balance`
+pub const PSP22_TOTAL_SUPPLY: [u8; 4] = ink::selector_bytes!("PSP22::total_supply");
+
+/// `PSP22::balance_of(owner) -> Balance`
+pub const PSP22_BALANCE_OF: [u8; 4] = ink::selector_bytes!("PSP22::balance_of");
+
+/// `PSP22::transfer(to, value) -> Result<()>`
+pub const PSP22_TRANSFER: [u8; 4] = ink::selector_bytes!("PSP22::transfer");
+
+/// `PSP22::transfer_from(from, to, value) -> Result<()>`
+pub const PSP22_TRANSFER_FROM: [u8; 4] = ink::selector_bytes!("PSP22::transfer_from");
+
+/// `PSP22::approve(spender, value) -> Result<()>`
+pub const PSP22_APPROVE: [u8; 4] = ink::selector_bytes!("PSP22::approve");
+
+/// `PSP22::allowance(owner, spender) -> Balance`
+pub const PSP22_ALLOWANCE: [u8; 4] = ink::selector_bytes!("PSP22::allowance");
+
+/// `PSP22Metadata::token_name() -> Option<String>`
+pub const PSP22_TOKEN_NAME: [u8; 4] = ink::selector_bytes!("PSP22Metadata::token_name");
+
+/// `PSP22Metadata::token_symbol() -> Option<String>`
+pub const PSP22_TOKEN_SYMBOL: [u8; 4] = ink::selector_bytes!("PSP22Metadata::token_symbol");
+
+/// `PSP22Metadata::token_decimals() -> u8`
+pub const PSP22_TOKEN_DECIMALS: [u8; 4] = ink::selector_bytes!("PSP22Metadata::token_decimals");
+
+/// `PSP22Burnable::burn_from(from, value) -> Result<()>`
+pub const PSP22_BURN_FROM: [u8; 4] = ink::selector_bytes!("PSP22Burnable::burn_from");
+
+/// `PSP22Receiver::on_psp22_received(operator, from, value, data) -> Result<()>`
+pub const PSP22_ON_RECEIVED: [u8; 4] = ink::selector_bytes!("PSP22Receiver::on_psp22_received");
+
+/// `fee_on_transfer_bps() -> u16`, optional on fee-on-transfer tokens (DALLA)
+pub const TOKEN_FEE_ON_TRANSFER_BPS: [u8; 4] = ink::selector_bytes!("fee_on_transfer_bps");
+
+// ============================================================================
+// PSP37
+// ============================================================================
+
+/// `PSP37Receiver::before_received(operator, from, ids, values, data) -> Result<()>`
+pub const PSP37_BEFORE_RECEIVED: [u8; 4] = ink::selector_bytes!("PSP37Receiver::before_received");
+
+// ============================================================================
+// DALLA checkpoints
+// ============================================================================
+
+/// `DallaToken::total_supply() -> Balance`
+pub const DALLA_TOTAL_SUPPLY: [u8; 4] = ink::selector_bytes!("total_supply");
+
+/// `DallaToken::balance_of_at(account, block) -> Balance`
+pub const DALLA_BALANCE_OF_AT: [u8; 4] = ink::selector_bytes!("balance_of_at");
+
+/// `DallaToken::total_supply_at(block) -> Balance`
+pub const DALLA_TOTAL_SUPPLY_AT: [u8; 4] = ink::selector_bytes!("total_supply_at");
+
+// ============================================================================
+// BeliNFT
+// ============================================================================
+
+/// `BeliNft::balance_of(owner) -> u32`
+pub const BELI_NFT_BALANCE_OF: [u8; 4] = ink::selector_bytes!("balance_of");
+
+// ============================================================================
+// BelizeX
+// ============================================================================
+
+/// `Factory::get_pair_address(token_a, token_b) -> Option<AccountId>`
+pub const FACTORY_GET_PAIR_ADDRESS: [u8; 4] = ink::selector_bytes!("get_pair_address");
+
+/// `Factory::create_pair(token_a, token_b) -> Result<AccountId>`
+pub const FACTORY_CREATE_PAIR: [u8; 4] = ink::selector_bytes!("create_pair");
+
+/// `Factory::fee_to() -> Option<AccountId>`
+pub const FACTORY_FEE_TO: [u8; 4] = ink::selector_bytes!("fee_to");
+
+/// `Factory::fee_info() -> (Option<AccountId>, u16, u64)`
+pub const FACTORY_FEE_INFO: [u8; 4] = ink::selector_bytes!("fee_info");
+
+/// `Pair::new(token0, token1)` constructor
+pub const PAIR_NEW: [u8; 4] = ink::selector_bytes!("new");
+
+/// `Pair::get_tokens() -> (AccountId, AccountId)`
+pub const PAIR_GET_TOKENS: [u8; 4] = ink::selector_bytes!("get_tokens");
+
+/// `Pair::get_reserves() -> (Balance, Balance, u64)`
+pub const PAIR_GET_RESERVES: [u8; 4] = ink::selector_bytes!("get_reserves");
+
+/// `Pair::mint(to) -> Result<Balance>`
+pub const PAIR_MINT: [u8; 4] = ink::selector_bytes!("mint");
+
+/// `Pair::burn(to) -> Result<(Balance, Balance)>`
+pub const PAIR_BURN: [u8; 4] = ink::selector_bytes!("burn");
+
+/// `Pair::swap(amount0_out, amount1_out, to) -> Result<()>`
+pub const PAIR_SWAP: [u8; 4] = ink::selector_bytes!("swap");
+
+/// `Pair::balance_of(owner) -> Balance` (LP token)
+pub const PAIR_BALANCE_OF: [u8; 4] = ink::selector_bytes!("balance_of");
+
+/// `Pair::transfer(to, value) -> Result<()>` (LP token)
+pub const PAIR_TRANSFER: [u8; 4] = ink::selector_bytes!("transfer");
+
+/// `Pair::transfer_from(from, to, value) -> Result<()>` (LP token)
+pub const PAIR_TRANSFER_FROM: [u8; 4] = ink::selector_bytes!("transfer_from");
+
+/// `Pair::fee_bps() -> u32`
+pub const PAIR_FEE_BPS: [u8; 4] = ink::selector_bytes!("fee_bps");
+
+/// `Pair::on_fee_to_changed(fee_to, fee_share_bps, updated_at) -> Result<()>`
+pub const PAIR_ON_FEE_TO_CHANGED: [u8; 4] = ink::selector_bytes!("on_fee_to_changed");
+
+// ============================================================================
+// Faucet
+// ============================================================================
+
+/// `HumanityVerifier::is_verified(account) -> bool`
+pub const HUMANITY_IS_VERIFIED: [u8; 4] = ink::selector_bytes!("HumanityVerifier::is_verified");
@@ -0,0 +1,116 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// # BelizeX Token Mock
+///
+/// Test-only PSP22 token whose transfers either succeed without moving
+/// anything or fail with a configurable `PSP22Error`. Used by the Router
+/// end-to-end tests to check that token errors reach the caller intact, and
+/// that a reported transfer fee shows up in swap simulations.
+
+#[path = "../psp22_trait.rs"]
+pub mod psp22_trait;
+
+#[ink::contract]
+pub mod belizex_token_mock {
+    use crate::psp22_trait::{PSP22Error, Result, PSP22};
+
+    /// Mock storage
+    #[ink(storage)]
+    pub struct TokenMock {
+        /// Error returned by transfer, transfer_from and approve
+        error: Option<PSP22Error>,
+        /// Transfer fee reported by `fee_on_transfer_bps`
+        fee_bps: u16,
+    }
+
+    impl TokenMock {
+        /// Creates a token that fails with `error`, or succeeds if `None`
+        #[ink(constructor)]
+        pub fn new(error: Option<PSP22Error>) -> Self {
+            Self { error, fee_bps: 0 }
+        }
+
+        /// Replaces the error returned by state-changing messages
+        #[ink(message)]
+        pub fn set_error(&mut self, error: Option<PSP22Error>) {
+            self.error = error;
+        }
+
+        /// Sets the transfer fee the token reports (nothing is charged)
+        #[ink(message)]
+        pub fn set_fee_on_transfer_bps(&mut self, fee_bps: u16) {
+            self.fee_bps = fee_bps;
+        }
+
+        /// Transfer fee in basis points, as read by `Router::simulate_swap`
+        #[ink(message)]
+        pub fn fee_on_transfer_bps(&self) -> u16 {
+            self.fee_bps
+        }
+
+        fn result(&self) -> Result<()> {
+            match &self.error {
+                Some(error) => Err(error.clone()),
+                None => Ok(()),
+            }
+        }
+    }
+
+    impl PSP22 for TokenMock {
+        #[ink(message)]
+        fn total_supply(&self) -> u128 {
+            0
+        }
+
+        #[ink(message)]
+        fn balance_of(&self, _owner: AccountId) -> u128 {
+            0
+        }
+
+        #[ink(message)]
+        fn transfer(&mut self, _to: AccountId, _value: u128) -> Result<()> {
+            self.result()
+        }
+
+        #[ink(message)]
+        fn transfer_from(&mut self, _from: AccountId, _to: AccountId, _value: u128) -> Result<()> {
+            self.result()
+        }
+
+        #[ink(message)]
+        fn approve(&mut self, _spender: AccountId, _value: u128) -> Result<()> {
+            self.result()
+        }
+
+        #[ink(message)]
+        fn allowance(&self, _owner: AccountId, _spender: AccountId) -> u128 {
+            0
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::prelude::string::String;
+
+        #[ink::test]
+        fn configured_error_is_returned() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = TokenMock::new(Some(PSP22Error::InsufficientAllowance));
+
+            assert_eq!(
+                PSP22::transfer_from(&mut token, accounts.alice, accounts.bob, 10),
+                Err(PSP22Error::InsufficientAllowance)
+            );
+
+            token.set_error(Some(PSP22Error::Custom(String::from("Paused"))));
+            assert_eq!(
+                PSP22::transfer(&mut token, accounts.bob, 10),
+                Err(PSP22Error::Custom(String::from("Paused")))
+            );
+
+            token.set_error(None);
+            assert_eq!(PSP22::transfer(&mut token, accounts.bob, 10), Ok(()));
+        }
+    }
+}
@@ -0,0 +1,476 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![allow(clippy::arithmetic_side_effects)]
+
+//! # Wrapped BZC (WBZC)
+//!
+//! PSP22 token backed 1:1 by native BZC held in the contract, so the DEX can
+//! route native currency through the same paths as any other token.
+//!
+//! ## Features
+//! - Payable `deposit` mints WBZC for the transferred value
+//! - `withdraw` burns WBZC and returns the native BZC
+//! - Full PSP22 interface (standard selectors)
+
+#[path = "../psp22_trait.rs"]
+pub mod psp22_trait;
+
+#[ink::contract]
+pub mod wbzc {
+    use crate::psp22_trait::{self, PSP22Error, PSP22Metadata, PSP22};
+    use ink::prelude::string::String;
+    use ink::storage::Mapping;
+    use scale::{Decode, Encode};
+
+    // ============================================================================
+    // Storage
+    // ============================================================================
+
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct Wbzc {
+        /// Total WBZC in circulation; always equals the native BZC held
+        total_supply: Balance,
+
+        /// WBZC balances: account => balance
+        balances: Mapping<AccountId, Balance>,
+
+        /// WBZC allowances: (owner, spender) => amount
+        allowances: Mapping<(AccountId, AccountId), Balance>,
+    }
+
+    // ============================================================================
+    // Events
+    // ============================================================================
+
+    #[ink(event)]
+    pub struct Deposit {
+        #[ink(topic)]
+        account: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Withdrawal {
+        #[ink(topic)]
+        account: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        value: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Approval {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        spender: AccountId,
+        value: Balance,
+    }
+
+    // ============================================================================
+    // Errors
+    // ============================================================================
+
+    #[derive(Debug, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        /// Deposit or withdrawal of zero
+        ZeroAmount,
+        /// Insufficient balance
+        InsufficientBalance,
+        /// Insufficient allowance
+        InsufficientAllowance,
+        /// Overflow occurred
+        Overflow,
+        /// Native transfer failed
+        TransferFailed,
+    }
+
+    pub type Result<T> = core::result::Result<T, Error>;
+
+    impl From<Error> for PSP22Error {
+        fn from(error: Error) -> Self {
+            match error {
+                Error::InsufficientBalance => PSP22Error::InsufficientBalance,
+                Error::InsufficientAllowance => PSP22Error::InsufficientAllowance,
+                Error::ZeroAmount => PSP22Error::Custom(String::from("ZeroAmount")),
+                Error::Overflow => PSP22Error::Custom(String::from("Overflow")),
+                Error::TransferFailed => PSP22Error::Custom(String::from("TransferFailed")),
+            }
+        }
+    }
+
+    // ============================================================================
+    // Implementation
+    // ============================================================================
+
+    impl Wbzc {
+        // ========================================================================
+        // Constructor
+        // ========================================================================
+
+        /// Create an empty wrapper
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        // ========================================================================
+        // View Functions
+        // ========================================================================
+
+        /// Get token name
+        #[ink(message)]
+        pub fn token_name(&self) -> String {
+            String::from("Wrapped BZC")
+        }
+
+        /// Get token symbol
+        #[ink(message)]
+        pub fn token_symbol(&self) -> String {
+            String::from("WBZC")
+        }
+
+        /// Get token decimals (same as native BZC)
+        #[ink(message)]
+        pub fn token_decimals(&self) -> u8 {
+            12
+        }
+
+        /// Get total supply
+        #[ink(message)]
+        pub fn total_supply(&self) -> Balance {
+            self.total_supply
+        }
+
+        // ========================================================================
+        // Wrapping
+        // ========================================================================
+
+        /// Wrap the transferred native BZC into WBZC for the caller
+        #[ink(message, payable)]
+        pub fn deposit(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            let amount = self.env().transferred_value();
+            if amount == 0 {
+                return Err(Error::ZeroAmount);
+            }
+
+            let balance = self.balance_of_or_zero(caller);
+            let new_balance = balance.checked_add(amount).ok_or(Error::Overflow)?;
+            let new_supply = self
+                .total_supply
+                .checked_add(amount)
+                .ok_or(Error::Overflow)?;
+
+            self.balances.insert(caller, &new_balance);
+            self.total_supply = new_supply;
+
+            self.env().emit_event(Transfer {
+                from: None,
+                to: Some(caller),
+                value: amount,
+            });
+            self.env().emit_event(Deposit {
+                account: caller,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Burn `amount` WBZC from the caller and send back the native BZC
+        ///
+        /// If the native transfer fails the burn is rolled back.
+        #[ink(message)]
+        pub fn withdraw(&mut self, amount: Balance) -> Result<()> {
+            let caller = self.env().caller();
+            if amount == 0 {
+                return Err(Error::ZeroAmount);
+            }
+
+            let balance = self.balance_of_or_zero(caller);
+            if balance < amount {
+                return Err(Error::InsufficientBalance);
+            }
+
+            // Burn before the external transfer
+            self.balances.insert(caller, &(balance - amount));
+            self.total_supply -= amount;
+
+            if self.env().transfer(caller, amount).is_err() {
+                self.balances.insert(caller, &balance);
+                self.total_supply += amount;
+                return Err(Error::TransferFailed);
+            }
+
+            self.env().emit_event(Transfer {
+                from: Some(caller),
+                to: None,
+                value: amount,
+            });
+            self.env().emit_event(Withdrawal {
+                account: caller,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        // ========================================================================
+        // Internal Functions
+        // ========================================================================
+
+        /// Get balance (zero for unknown accounts)
+        fn balance_of_or_zero(&self, owner: AccountId) -> Balance {
+            self.balances.get(owner).unwrap_or(0)
+        }
+
+        /// Move `value` between accounts
+        fn transfer_from_to(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+        ) -> Result<()> {
+            let from_balance = self.balance_of_or_zero(from);
+            if from_balance < value {
+                return Err(Error::InsufficientBalance);
+            }
+
+            self.balances.insert(from, &(from_balance - value));
+            let to_balance = self.balance_of_or_zero(to);
+            let new_to_balance = to_balance.checked_add(value).ok_or(Error::Overflow)?;
+            self.balances.insert(to, &new_to_balance);
+
+            self.env().emit_event(Transfer {
+                from: Some(from),
+                to: Some(to),
+                value,
+            });
+
+            Ok(())
+        }
+    }
+
+    // ============================================================================
+    // PSP22
+    // ============================================================================
+
+    impl PSP22 for Wbzc {
+        #[ink(message)]
+        fn total_supply(&self) -> Balance {
+            self.total_supply
+        }
+
+        #[ink(message)]
+        fn balance_of(&self, owner: AccountId) -> Balance {
+            self.balance_of_or_zero(owner)
+        }
+
+        #[ink(message)]
+        fn transfer(&mut self, to: AccountId, value: Balance) -> psp22_trait::Result<()> {
+            let from = self.env().caller();
+            Ok(self.transfer_from_to(from, to, value)?)
+        }
+
+        #[ink(message)]
+        fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+        ) -> psp22_trait::Result<()> {
+            let caller = self.env().caller();
+            let allowance = self.allowances.get((from, caller)).unwrap_or(0);
+            if allowance < value {
+                return Err(PSP22Error::InsufficientAllowance);
+            }
+
+            self.transfer_from_to(from, to, value)?;
+            self.allowances.insert((from, caller), &(allowance - value));
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn approve(&mut self, spender: AccountId, value: Balance) -> psp22_trait::Result<()> {
+            let owner = self.env().caller();
+            self.allowances.insert((owner, spender), &value);
+
+            self.env().emit_event(Approval {
+                owner,
+                spender,
+                value,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
+            self.allowances.get((owner, spender)).unwrap_or(0)
+        }
+    }
+
+    impl PSP22Metadata for Wbzc {
+        #[ink(message)]
+        fn token_name(&self) -> Option<String> {
+            Some(Wbzc::token_name(self))
+        }
+
+        #[ink(message)]
+        fn token_symbol(&self) -> Option<String> {
+            Some(Wbzc::token_symbol(self))
+        }
+
+        #[ink(message)]
+        fn token_decimals(&self) -> u8 {
+            Wbzc::token_decimals(self)
+        }
+    }
+
+    // ============================================================================
+    // Tests
+    // ============================================================================
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        type Env = ink::env::DefaultEnvironment;
+
+        /// Address of the wrapper; kept apart from the default accounts so
+        /// native balances are not mixed up with Alice's
+        fn contract_id() -> AccountId {
+            AccountId::from([0x42; 32])
+        }
+
+        fn new_wbzc() -> Wbzc {
+            ink::env::test::set_callee::<Env>(contract_id());
+            ink::env::test::set_account_balance::<Env>(contract_id(), 0);
+            Wbzc::new()
+        }
+
+        fn native_balance(account: AccountId) -> Balance {
+            ink::env::test::get_account_balance::<Env>(account).unwrap_or(0)
+        }
+
+        /// Deposit `amount` from the current caller, crediting the contract's
+        /// native balance the way a real payable call would
+        fn deposit(wbzc: &mut Wbzc, amount: Balance) -> Result<()> {
+            let contract = contract_id();
+            ink::env::test::set_account_balance::<Env>(contract, native_balance(contract) + amount);
+            ink::env::test::set_value_transferred::<Env>(amount);
+            let result = wbzc.deposit();
+            ink::env::test::set_value_transferred::<Env>(0);
+            result
+        }
+
+        #[ink::test]
+        fn deposit_works() {
+            let accounts = ink::env::test::default_accounts::<Env>();
+            let mut wbzc = new_wbzc();
+
+            assert_eq!(deposit(&mut wbzc, 500), Ok(()));
+            ink::env::test::set_caller::<Env>(accounts.bob);
+            assert_eq!(deposit(&mut wbzc, 300), Ok(()));
+
+            assert_eq!(wbzc.balance_of(accounts.alice), 500);
+            assert_eq!(wbzc.balance_of(accounts.bob), 300);
+            assert_eq!(wbzc.total_supply(), 800);
+            assert_eq!(wbzc.total_supply(), native_balance(contract_id()));
+            assert_eq!(PSP22::total_supply(&wbzc), 800);
+
+            assert_eq!(deposit(&mut wbzc, 0), Err(Error::ZeroAmount));
+        }
+
+        #[ink::test]
+        fn psp22_metadata_works() {
+            let wbzc = new_wbzc();
+            assert_eq!(
+                PSP22Metadata::token_name(&wbzc),
+                Some(String::from("Wrapped BZC"))
+            );
+            assert_eq!(
+                PSP22Metadata::token_symbol(&wbzc),
+                Some(String::from("WBZC"))
+            );
+            assert_eq!(PSP22Metadata::token_decimals(&wbzc), 12);
+        }
+
+        #[ink::test]
+        fn withdraw_works() {
+            let accounts = ink::env::test::default_accounts::<Env>();
+            let mut wbzc = new_wbzc();
+            assert_eq!(deposit(&mut wbzc, 500), Ok(()));
+
+            let before = native_balance(accounts.alice);
+            assert_eq!(wbzc.withdraw(200), Ok(()));
+
+            assert_eq!(wbzc.balance_of(accounts.alice), 300);
+            assert_eq!(native_balance(accounts.alice), before + 200);
+            assert_eq!(wbzc.total_supply(), 300);
+            assert_eq!(wbzc.total_supply(), native_balance(contract_id()));
+        }
+
+        #[ink::test]
+        fn withdraw_fails_insufficient_balance() {
+            let accounts = ink::env::test::default_accounts::<Env>();
+            let mut wbzc = new_wbzc();
+            assert_eq!(deposit(&mut wbzc, 100), Ok(()));
+
+            assert_eq!(wbzc.withdraw(101), Err(Error::InsufficientBalance));
+            assert_eq!(wbzc.withdraw(0), Err(Error::ZeroAmount));
+
+            // Balances are wrapper-local: Bob cannot withdraw Alice's deposit
+            ink::env::test::set_caller::<Env>(accounts.bob);
+            assert_eq!(wbzc.withdraw(1), Err(Error::InsufficientBalance));
+            assert_eq!(wbzc.total_supply(), native_balance(contract_id()));
+        }
+
+        #[ink::test]
+        fn failed_native_transfer_reverts_burn() {
+            let accounts = ink::env::test::default_accounts::<Env>();
+            let mut wbzc = new_wbzc();
+            assert_eq!(deposit(&mut wbzc, 100), Ok(()));
+
+            // Drain the backing so the native transfer cannot succeed
+            ink::env::test::set_account_balance::<Env>(contract_id(), 0);
+            assert_eq!(wbzc.withdraw(100), Err(Error::TransferFailed));
+
+            assert_eq!(wbzc.balance_of(accounts.alice), 100);
+            assert_eq!(wbzc.total_supply(), 100);
+        }
+
+        #[ink::test]
+        fn psp22_transfer_from_works() {
+            let accounts = ink::env::test::default_accounts::<Env>();
+            let mut wbzc = new_wbzc();
+            assert_eq!(deposit(&mut wbzc, 1_000), Ok(()));
+
+            assert!(wbzc.approve(accounts.bob, 400).is_ok());
+            ink::env::test::set_caller::<Env>(accounts.bob);
+            assert!(wbzc
+                .transfer_from(accounts.alice, accounts.charlie, 300)
+                .is_ok());
+            assert_eq!(wbzc.allowance(accounts.alice, accounts.bob), 100);
+            assert_eq!(
+                wbzc.transfer_from(accounts.alice, accounts.charlie, 101),
+                Err(PSP22Error::InsufficientAllowance)
+            );
+
+            // The wrapped tokens are still redeemable by their new holder
+            ink::env::test::set_caller::<Env>(accounts.charlie);
+            assert_eq!(wbzc.withdraw(300), Ok(()));
+            assert_eq!(wbzc.total_supply(), native_balance(contract_id()));
+        }
+    }
+}
@@ -1,24 +1,193 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
+#[path = "../dex/psp22_trait.rs"]
+pub mod psp22_trait;
+
+pub mod verifier_trait;
+
 #[ink::contract]
 mod faucet {
+    use crate::psp22_trait;
+    use crate::verifier_trait::IS_VERIFIED_SELECTOR;
+    use access_control::pausable::PausableData;
+    use access_control::AccessError;
+    use ink::env::call::{build_call, ExecutionInput, Selector};
+    use ink::env::hash::Blake2x256;
+    use ink::prelude::vec::Vec;
     use ink::storage::Mapping;
 
+    /// Maximum recipients in a single airdrop (keeps weight bounded)
+    pub const MAX_AIRDROP_RECIPIENTS: u32 = 200;
+
+    /// Maximum vouchers created in a single call (keeps weight bounded)
+    pub const MAX_VOUCHER_BATCH: u32 = 200;
+
+    /// Maximum number of extra assets (keeps `assets` bounded)
+    pub const MAX_ASSETS: u32 = 16;
+
+    /// Claim history slots kept by default
+    pub const DEFAULT_HISTORY_CAPACITY: u32 = 100;
+
+    /// Upper bound for the owner-configured history capacity
+    pub const MAX_HISTORY_CAPACITY: u32 = 1000;
+
+    /// Maximum records returned by a single history query
+    pub const MAX_PAGE_SIZE: u32 = 50;
+
+    /// Length of the daily cap window in milliseconds
+    pub const DAY_MS: Timestamp = 24 * 60 * 60 * 1000;
+
+    /// How the cooldown between claims is measured
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum CooldownMode {
+        /// Number of blocks between claims
+        Blocks(u32),
+        /// Number of seconds between claims, by block timestamp
+        Seconds(u64),
+    }
+
+    /// An asset dispensed by the faucet, with its running totals
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct AssetConfig {
+        /// PSP22 token (`None` for native balance)
+        pub token: Option<AccountId>,
+        /// Amount dispensed per claim
+        pub drip_amount: Balance,
+        /// Cooldown between claims of this asset
+        pub cooldown: CooldownMode,
+        /// Total amount claimed
+        pub total_claimed: Balance,
+        /// Total number of claims
+        pub claim_count: u32,
+    }
+
+    /// A single claim in the on-chain history
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct ClaimRecord {
+        /// Account that received the drip
+        pub account: AccountId,
+        /// Amount dispensed
+        pub amount: Balance,
+        /// Block of the claim
+        pub block: BlockNumber,
+        /// Asset claimed (0 for the legacy asset)
+        pub asset_id: u32,
+    }
+
+    /// A one-time claim code, stored by the blake2 hash of its preimage
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Voucher {
+        /// Amount paid on redemption
+        pub amount: Balance,
+        /// Timestamp after which the voucher can no longer be redeemed
+        pub expiry: Timestamp,
+        /// Whether the voucher has been redeemed
+        pub redeemed: bool,
+    }
+
     /// Faucet contract for distributing test DALLA tokens
     #[ink(storage)]
     pub struct Faucet {
-        /// Tracks last claim time per account
+        /// Tracks last claim block per account
         last_claim: Mapping<AccountId, BlockNumber>,
+        /// Tracks last claim timestamp per account
+        last_claim_time: Mapping<AccountId, Timestamp>,
         /// Amount to dispense per claim (in base units)
         drip_amount: Balance,
-        /// Cooldown period in blocks
-        cooldown: u32,
+        /// Cooldown period between claims
+        cooldown: CooldownMode,
+        /// Per-account cooldowns that replace the global one (e.g. partner bots)
+        cooldown_overrides: Mapping<AccountId, CooldownMode>,
         /// Contract owner who can refill and adjust settings
         owner: AccountId,
         /// Total tokens claimed
         total_claimed: Balance,
         /// Total number of claims
         claim_count: u32,
+        /// PSP22 token to dispense (`None` dispenses native balance)
+        token: Option<AccountId>,
+        /// Lifetime cap on claims per account (`None` for unlimited)
+        max_claims_per_account: Option<u32>,
+        /// Lifetime cap on the amount claimed per account (`None` for unlimited)
+        max_total_per_account: Option<Balance>,
+        /// Number of claims made per account
+        claims: Mapping<AccountId, u32>,
+        /// Cumulative amount claimed per account
+        claimed_amount: Mapping<AccountId, Balance>,
+        /// Accounts blocked from claiming
+        banned: Mapping<AccountId, ()>,
+        /// Accounts allowed to claim while allowlist mode is on
+        allowlist: Mapping<AccountId, ()>,
+        /// Whether only allowlisted accounts (or NFT holders) may claim
+        allowlist_enabled: bool,
+        /// BeliNFT collection whose holders get boosted drips
+        nft_gate: Option<AccountId>,
+        /// Drip multiplier for NFT holders
+        nft_multiplier: u32,
+        /// Emergency stop for claims
+        pausable: PausableData,
+        /// Maximum dispensed per day across all accounts (`None` for unlimited)
+        daily_cap: Option<Balance>,
+        /// Start of the current daily window
+        window_start: Timestamp,
+        /// Amount dispensed since `window_start`
+        dispensed_in_window: Balance,
+        /// Bonus credited to a referrer per referred account
+        referral_bonus: Balance,
+        /// Lifetime cap on bonuses credited per referrer (`None` for unlimited)
+        max_referral_rewards: Option<Balance>,
+        /// Who referred each account
+        referrer_of: Mapping<AccountId, AccountId>,
+        /// Number of accounts referred per referrer
+        referrals: Mapping<AccountId, u32>,
+        /// Lifetime bonuses credited per referrer
+        referral_earned: Mapping<AccountId, Balance>,
+        /// Referral rewards awaiting `claim_referral_rewards`
+        pending_rewards: Mapping<AccountId, Balance>,
+        /// Registered relayers for `claim_for`
+        relayers: Mapping<AccountId, ()>,
+        /// Whether only registered relayers may call `claim_for`
+        relayers_only: bool,
+        /// Maximum `claim_for` calls per relayer per day (`None` for unlimited)
+        relayer_limit: Option<u32>,
+        /// (window start, claims in window) per relayer
+        relayer_usage: Mapping<AccountId, (Timestamp, u32)>,
+        /// Extra assets by id (asset 0 is the legacy configuration above)
+        assets: Mapping<u32, AssetConfig>,
+        /// Ids of the extra assets
+        asset_ids: Vec<u32>,
+        /// (block, timestamp) of each account's last claim per extra asset
+        asset_last_claim: Mapping<(u32, AccountId), (BlockNumber, Timestamp)>,
+        /// Ring buffer of recent claims, by slot, tagged with their sequence number
+        history: Mapping<u32, (u64, ClaimRecord)>,
+        /// Number of slots in the ring buffer
+        history_capacity: u32,
+        /// Sequence number of the next claim record
+        history_seq: u64,
+        /// First sequence number since the buffer was last cleared
+        history_base: u64,
+        /// Sequence numbers of each account's claims, by per-account index
+        account_history: Mapping<(AccountId, u32), u64>,
+        /// Number of sequence numbers recorded per account
+        account_history_count: Mapping<AccountId, u32>,
+        /// Attestation contract consulted before dispensing
+        verifier: Option<AccountId>,
+        /// Selector of the verifier's `AccountId -> bool` query
+        verifier_selector: [u8; 4],
+        /// Whether the drip shrinks with the remaining balance
+        adaptive: bool,
+        /// Smallest drip in adaptive mode
+        min_drip: Balance,
+        /// Vouchers by code hash
+        vouchers: Mapping<[u8; 32], Voucher>,
     }
 
     /// Errors that can occur during faucet operations
@@ -33,6 +202,66 @@ mod faucet {
         NotOwner,
         /// Transfer failed
         TransferFailed,
+        /// Account has used up its lifetime claims
+        ClaimLimitReached,
+        /// Claim would exceed the account's lifetime amount
+        AmountLimitReached,
+        /// Account is on the denylist
+        Banned,
+        /// Allowlist mode is on and the account is not listed
+        NotAllowlisted,
+        /// NFT multiplier must be at least 1
+        InvalidMultiplier,
+        /// Airdrop recipient list is empty or too long
+        InvalidBatchSize,
+        /// Claims are paused
+        Paused,
+        /// Claims are not paused
+        NotPaused,
+        /// Claim would exceed the global daily cap
+        DailyCapReached,
+        /// Accounts cannot refer themselves
+        SelfReferral,
+        /// Referrer was referred by the caller
+        CircularReferral,
+        /// Caller already has a referrer
+        AlreadyReferred,
+        /// No referral rewards to claim
+        NoPendingRewards,
+        /// Caller is not a registered relayer
+        NotRelayer,
+        /// Relayer has used up its daily `claim_for` allowance
+        RelayerLimitReached,
+        /// No asset with this id
+        UnknownAsset,
+        /// An asset with this id already exists
+        AssetExists,
+        /// Asset 0 is the legacy asset and cannot be added or removed
+        ReservedAsset,
+        /// Asset limit reached
+        TooManyAssets,
+        /// History capacity must be between 1 and `MAX_HISTORY_CAPACITY`
+        InvalidCapacity,
+        /// The verifier did not attest the account
+        NotVerified,
+        /// A voucher with this code hash already exists
+        VoucherExists,
+        /// No voucher matches the code
+        UnknownVoucher,
+        /// Voucher has already been redeemed
+        VoucherUsed,
+        /// Voucher has expired
+        VoucherExpired,
+    }
+
+    impl From<AccessError> for Error {
+        fn from(error: AccessError) -> Self {
+            match error {
+                AccessError::Paused => Error::Paused,
+                AccessError::NotPaused => Error::NotPaused,
+                _ => Error::NotOwner,
+            }
+        }
     }
 
     pub type Result<T> = core::result::Result<T, Error>;
@@ -56,7 +285,170 @@ mod faucet {
     #[ink(event)]
     pub struct SettingsUpdated {
         drip_amount: Balance,
-        cooldown: u32,
+        cooldown: CooldownMode,
+        max_claims_per_account: Option<u32>,
+        max_total_per_account: Option<Balance>,
+    }
+
+    #[ink(event)]
+    pub struct EmergencyWithdrawal {
+        #[ink(topic)]
+        asset: Option<AccountId>,
+        #[ink(topic)]
+        to: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct AdaptiveSet {
+        enabled: bool,
+        min_drip: Balance,
+    }
+
+    #[ink(event)]
+    pub struct VouchersCreated {
+        count: u32,
+        amount: Balance,
+        expiry: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct VoucherRedeemed {
+        #[ink(topic)]
+        code_hash: [u8; 32],
+        #[ink(topic)]
+        account: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct TokenSet {
+        #[ink(topic)]
+        token: Option<AccountId>,
+    }
+
+    #[ink(event)]
+    pub struct CooldownOverrideSet {
+        #[ink(topic)]
+        account: AccountId,
+        cooldown: Option<CooldownMode>,
+    }
+
+    #[ink(event)]
+    pub struct DailyCapSet {
+        daily_cap: Option<Balance>,
+    }
+
+    #[ink(event)]
+    pub struct ReferralSettingsSet {
+        referral_bonus: Balance,
+        max_referral_rewards: Option<Balance>,
+    }
+
+    #[ink(event)]
+    pub struct ReferralRecorded {
+        #[ink(topic)]
+        referrer: AccountId,
+        #[ink(topic)]
+        referee: AccountId,
+        bonus: Balance,
+    }
+
+    #[ink(event)]
+    pub struct ReferralRewardsClaimed {
+        #[ink(topic)]
+        account: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct RelayerClaim {
+        #[ink(topic)]
+        relayer: AccountId,
+        #[ink(topic)]
+        recipient: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct RelayerUpdated {
+        #[ink(topic)]
+        relayer: AccountId,
+        registered: bool,
+    }
+
+    #[ink(event)]
+    pub struct RelayerSettingsSet {
+        relayers_only: bool,
+        relayer_limit: Option<u32>,
+    }
+
+    #[ink(event)]
+    pub struct AssetAdded {
+        #[ink(topic)]
+        asset_id: u32,
+        token: Option<AccountId>,
+        drip_amount: Balance,
+        cooldown: CooldownMode,
+    }
+
+    #[ink(event)]
+    pub struct AssetRemoved {
+        #[ink(topic)]
+        asset_id: u32,
+    }
+
+    #[ink(event)]
+    pub struct AssetClaimed {
+        #[ink(topic)]
+        account: AccountId,
+        #[ink(topic)]
+        asset_id: u32,
+        amount: Balance,
+        block: BlockNumber,
+    }
+
+    #[ink(event)]
+    pub struct HistoryCapacitySet {
+        capacity: u32,
+    }
+
+    #[ink(event)]
+    pub struct VerifierSet {
+        #[ink(topic)]
+        verifier: Option<AccountId>,
+        selector: [u8; 4],
+    }
+
+    #[ink(event)]
+    pub struct AccountBanned {
+        #[ink(topic)]
+        account: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct AccountUnbanned {
+        #[ink(topic)]
+        account: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct AllowlistUpdated {
+        #[ink(topic)]
+        account: AccountId,
+        allowed: bool,
+    }
+
+    #[ink(event)]
+    pub struct AllowlistModeSet {
+        enabled: bool,
+    }
+
+    #[ink(event)]
+    pub struct NftGateSet {
+        #[ink(topic)]
+        nft_gate: Option<AccountId>,
+        multiplier: u32,
     }
 
     impl Faucet {
@@ -67,51 +459,280 @@ mod faucet {
         /// * `cooldown` - Blocks between claims (e.g., 100 blocks ≈ 10 minutes)
         #[ink(constructor, payable)]
         pub fn new(drip_amount: Balance, cooldown: u32) -> Self {
+            Self::new_with_token(drip_amount, cooldown, None)
+        }
+
+        /// Create a new faucet contract that dispenses a PSP22 token
+        ///
+        /// # Arguments
+        /// * `drip_amount` - Amount to dispense per claim
+        /// * `cooldown` - Blocks between claims (switch to seconds via `update_settings`)
+        /// * `token` - PSP22 contract to dispense (`None` for native balance)
+        #[ink(constructor, payable)]
+        pub fn new_with_token(
+            drip_amount: Balance,
+            cooldown: u32,
+            token: Option<AccountId>,
+        ) -> Self {
             Self {
                 last_claim: Mapping::default(),
+                last_claim_time: Mapping::default(),
                 drip_amount,
-                cooldown,
+                cooldown: CooldownMode::Blocks(cooldown),
+                cooldown_overrides: Mapping::default(),
                 owner: Self::env().caller(),
                 total_claimed: 0,
                 claim_count: 0,
+                token,
+                max_claims_per_account: None,
+                max_total_per_account: None,
+                claims: Mapping::default(),
+                claimed_amount: Mapping::default(),
+                banned: Mapping::default(),
+                allowlist: Mapping::default(),
+                allowlist_enabled: false,
+                nft_gate: None,
+                nft_multiplier: 1,
+                pausable: PausableData::new(),
+                daily_cap: None,
+                window_start: Self::env().block_timestamp(),
+                dispensed_in_window: 0,
+                referral_bonus: 0,
+                max_referral_rewards: None,
+                referrer_of: Mapping::default(),
+                referrals: Mapping::default(),
+                referral_earned: Mapping::default(),
+                pending_rewards: Mapping::default(),
+                relayers: Mapping::default(),
+                relayers_only: false,
+                relayer_limit: None,
+                relayer_usage: Mapping::default(),
+                assets: Mapping::default(),
+                asset_ids: Vec::new(),
+                asset_last_claim: Mapping::default(),
+                history: Mapping::default(),
+                history_capacity: DEFAULT_HISTORY_CAPACITY,
+                history_seq: 0,
+                history_base: 0,
+                account_history: Mapping::default(),
+                account_history_count: Mapping::default(),
+                verifier: None,
+                verifier_selector: IS_VERIFIED_SELECTOR,
+                adaptive: false,
+                min_drip: 0,
+                vouchers: Mapping::default(),
             }
         }
 
         /// Claim test DALLA tokens
+        ///
+        /// Holders of the gate NFT receive `drip_amount * nft_multiplier`.
         #[ink(message)]
         pub fn claim(&mut self) -> Result<()> {
             let caller = self.env().caller();
+            self.claim_to(caller)?;
+            Ok(())
+        }
+
+        /// Claim one drip of an asset
+        ///
+        /// Asset 0 is the legacy asset and behaves exactly like `claim`. Extra
+        /// assets share the pause switch, denylist and allowlist, but have
+        /// their own cooldowns and totals; per-account limits, the daily cap
+        /// and the NFT boost only apply to asset 0.
+        #[ink(message)]
+        pub fn claim_asset(&mut self, asset_id: u32) -> Result<()> {
+            if asset_id == 0 {
+                return self.claim();
+            }
+
+            self.pausable.ensure_not_paused()?;
+
+            let caller = self.env().caller();
+            self.ensure_eligible(caller)?;
+
+            let mut asset = self.assets.get(asset_id).ok_or(Error::UnknownAsset)?;
+
+            if self.asset_time_until_claim(asset_id, caller) > 0 {
+                return Err(Error::TooSoon);
+            }
+
+            if self.balance_in(asset.token) < asset.drip_amount {
+                return Err(Error::InsufficientBalance);
+            }
+
+            self.dispense_in(asset.token, caller, asset.drip_amount)?;
+
             let current_block = self.env().block_number();
+            self.asset_last_claim.insert(
+                (asset_id, caller),
+                &(current_block, self.env().block_timestamp()),
+            );
+            asset.total_claimed = asset.total_claimed.saturating_add(asset.drip_amount);
+            asset.claim_count = asset.claim_count.saturating_add(1);
+            self.assets.insert(asset_id, &asset);
+            self.record_history(caller, asset.drip_amount, asset_id);
 
-            // Check cooldown
-            if let Some(last) = self.last_claim.get(caller) {
-                let blocks_since = current_block.saturating_sub(last);
-                if blocks_since < self.cooldown {
-                    return Err(Error::TooSoon);
-                }
+            self.env().emit_event(AssetClaimed {
+                account: caller,
+                asset_id,
+                amount: asset.drip_amount,
+                block: current_block,
+            });
+
+            Ok(())
+        }
+
+        /// Redeem a one-time voucher code
+        ///
+        /// Bypasses the cooldown, limits and gating (except the denylist), but
+        /// counts towards the stats like a regular claim.
+        #[ink(message)]
+        pub fn redeem_voucher(&mut self, code: Vec<u8>) -> Result<()> {
+            self.pausable.ensure_not_paused()?;
+
+            let caller = self.env().caller();
+            if self.banned.contains(caller) {
+                return Err(Error::Banned);
             }
 
-            // Check faucet balance
-            let balance = self.env().balance();
-            if balance < self.drip_amount {
+            let mut code_hash = [0u8; 32];
+            ink::env::hash_bytes::<Blake2x256>(&code, &mut code_hash);
+
+            let mut voucher = self.vouchers.get(code_hash).ok_or(Error::UnknownVoucher)?;
+            if voucher.redeemed {
+                return Err(Error::VoucherUsed);
+            }
+            if self.env().block_timestamp() > voucher.expiry {
+                return Err(Error::VoucherExpired);
+            }
+            if self.balance() < voucher.amount {
                 return Err(Error::InsufficientBalance);
             }
 
-            // Transfer tokens
-            if self.env().transfer(caller, self.drip_amount).is_err() {
-                return Err(Error::TransferFailed);
+            self.dispense(caller, voucher.amount)?;
+
+            voucher.redeemed = true;
+            self.vouchers.insert(code_hash, &voucher);
+            self.record_claim(caller, voucher.amount);
+
+            self.env().emit_event(VoucherRedeemed {
+                code_hash,
+                account: caller,
+                amount: voucher.amount,
+            });
+
+            Ok(())
+        }
+
+        /// Claim on behalf of `recipient`, with the caller paying the fees
+        ///
+        /// Lets new accounts with no balance get their first drip. Cooldowns,
+        /// limits and gating apply to `recipient`; the caller is only subject
+        /// to the relayer registry and the per-relayer daily limit.
+        #[ink(message)]
+        pub fn claim_for(&mut self, recipient: AccountId) -> Result<()> {
+            let relayer = self.env().caller();
+
+            if self.relayers_only && !self.relayers.contains(relayer) {
+                return Err(Error::NotRelayer);
+            }
+            if self.banned.contains(relayer) {
+                return Err(Error::Banned);
             }
 
-            // Update state
-            self.last_claim.insert(caller, &current_block);
-            self.total_claimed = self.total_claimed.saturating_add(self.drip_amount);
-            self.claim_count = self.claim_count.saturating_add(1);
+            let now = self.env().block_timestamp();
+            let (window_start, used) = match self.relayer_usage.get(relayer) {
+                Some((start, used)) if now < start.saturating_add(DAY_MS) => (start, used),
+                _ => (now, 0),
+            };
+            if let Some(limit) = self.relayer_limit {
+                if used >= limit {
+                    return Err(Error::RelayerLimitReached);
+                }
+            }
 
-            // Emit event
-            self.env().emit_event(Claimed {
+            let amount = self.claim_to(recipient)?;
+
+            self.relayer_usage
+                .insert(relayer, &(window_start, used.saturating_add(1)));
+
+            self.env().emit_event(RelayerClaim {
+                relayer,
+                recipient,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Claim and credit `referrer` with the referral bonus
+        ///
+        /// An account can be referred once. The bonus is credited to the
+        /// referrer's pending rewards (up to the per-referrer cap) and paid out
+        /// by `claim_referral_rewards`.
+        #[ink(message)]
+        pub fn claim_with_referrer(&mut self, referrer: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+
+            if referrer == caller {
+                return Err(Error::SelfReferral);
+            }
+            if self.referrer_of.get(referrer) == Some(caller) {
+                return Err(Error::CircularReferral);
+            }
+            if self.referrer_of.contains(caller) {
+                return Err(Error::AlreadyReferred);
+            }
+
+            self.claim_to(caller)?;
+
+            let earned = self.referral_earned.get(referrer).unwrap_or(0);
+            let bonus = match self.max_referral_rewards {
+                Some(max) => self.referral_bonus.min(max.saturating_sub(earned)),
+                None => self.referral_bonus,
+            };
+
+            self.referrer_of.insert(caller, &referrer);
+            self.referrals
+                .insert(referrer, &self.referrals_of(referrer).saturating_add(1));
+            self.referral_earned
+                .insert(referrer, &earned.saturating_add(bonus));
+            self.pending_rewards.insert(
+                referrer,
+                &self.pending_rewards(referrer).saturating_add(bonus),
+            );
+
+            self.env().emit_event(ReferralRecorded {
+                referrer,
+                referee: caller,
+                bonus,
+            });
+
+            Ok(())
+        }
+
+        /// Pay out the caller's pending referral rewards
+        #[ink(message)]
+        pub fn claim_referral_rewards(&mut self) -> Result<()> {
+            self.pausable.ensure_not_paused()?;
+
+            let caller = self.env().caller();
+            let amount = self.pending_rewards(caller);
+            if amount == 0 {
+                return Err(Error::NoPendingRewards);
+            }
+
+            if self.balance() < amount {
+                return Err(Error::InsufficientBalance);
+            }
+
+            self.dispense(caller, amount)?;
+            self.pending_rewards.remove(caller);
+
+            self.env().emit_event(ReferralRewardsClaimed {
                 account: caller,
-                amount: self.drip_amount,
-                block: current_block,
+                amount,
             });
 
             Ok(())
@@ -131,12 +752,33 @@ mod faucet {
             Ok(())
         }
 
+        /// Record a PSP22 refill
+        ///
+        /// In token mode the faucet is refilled by transferring tokens to its
+        /// address; this only emits the `Refilled` event for indexers.
+        #[ink(message)]
+        pub fn notify_refill(&mut self, amount: Balance) -> Result<()> {
+            let caller = self.env().caller();
+
+            self.env().emit_event(Refilled {
+                from: caller,
+                amount,
+            });
+
+            Ok(())
+        }
+
         /// Update faucet settings (owner only)
+        ///
+        /// `None` leaves a setting unchanged. For the per-account limits,
+        /// `Some(None)` removes the limit.
         #[ink(message)]
         pub fn update_settings(
             &mut self,
             drip_amount: Option<Balance>,
-            cooldown: Option<u32>,
+            cooldown: Option<CooldownMode>,
+            max_claims_per_account: Option<Option<u32>>,
+            max_total_per_account: Option<Option<Balance>>,
         ) -> Result<()> {
             self.ensure_owner()?;
 
@@ -148,179 +790,2719 @@ mod faucet {
                 self.cooldown = cd;
             }
 
+            if let Some(max_claims) = max_claims_per_account {
+                self.max_claims_per_account = max_claims;
+            }
+
+            if let Some(max_total) = max_total_per_account {
+                self.max_total_per_account = max_total;
+            }
+
             self.env().emit_event(SettingsUpdated {
                 drip_amount: self.drip_amount,
                 cooldown: self.cooldown,
+                max_claims_per_account: self.max_claims_per_account,
+                max_total_per_account: self.max_total_per_account,
             });
 
             Ok(())
         }
 
-        /// Transfer ownership (owner only)
+        /// Turn adaptive drips on or off (owner only)
+        ///
+        /// In adaptive mode the drip is `min(drip_amount, balance / 100)`, but
+        /// never below `min_drip`, so the faucet tapers off as it runs dry.
         #[ink(message)]
-        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<()> {
+        pub fn set_adaptive(&mut self, enabled: bool, min_drip: Balance) -> Result<()> {
             self.ensure_owner()?;
-            self.owner = new_owner;
+            self.adaptive = enabled;
+            self.min_drip = min_drip;
+
+            self.env().emit_event(AdaptiveSet { enabled, min_drip });
+
             Ok(())
         }
 
-        /// Withdraw all funds (owner only, emergency use)
+        /// Set the PSP22 token to dispense, or `None` for native balance (owner only)
         #[ink(message)]
-        pub fn emergency_withdraw(&mut self) -> Result<()> {
+        pub fn set_token(&mut self, token: Option<AccountId>) -> Result<()> {
             self.ensure_owner()?;
+            self.token = token;
 
-            let balance = self.env().balance();
-            if self.env().transfer(self.owner, balance).is_err() {
-                return Err(Error::TransferFailed);
-            }
+            self.env().emit_event(TokenSet { token });
 
             Ok(())
         }
 
-        // === Query Functions ===
-
-        /// Get the current drip amount
+        /// Send `amount_each` to every recipient in one call (owner only)
+        ///
+        /// Bypasses pausing, cooldowns, limits and gating, but counts towards
+        /// the per-account trackers and the daily total. The faucet balance
+        /// must cover the whole batch.
         #[ink(message)]
-        pub fn drip_amount(&self) -> Balance {
-            self.drip_amount
+        pub fn airdrop(&mut self, recipients: Vec<AccountId>, amount_each: Balance) -> Result<()> {
+            self.ensure_owner()?;
+
+            let count = recipients.len();
+            if count == 0 || count > MAX_AIRDROP_RECIPIENTS as usize {
+                return Err(Error::InvalidBatchSize);
+            }
+
+            let total = amount_each
+                .checked_mul(count as Balance)
+                .ok_or(Error::InsufficientBalance)?;
+            if self.balance() < total {
+                return Err(Error::InsufficientBalance);
+            }
+
+            let current_block = self.env().block_number();
+            for recipient in recipients {
+                self.dispense(recipient, amount_each)?;
+                self.record_claim(recipient, amount_each);
+
+                self.env().emit_event(Claimed {
+                    account: recipient,
+                    amount: amount_each,
+                    block: current_block,
+                });
+            }
+
+            Ok(())
         }
 
-        /// Get the cooldown period
+        /// Set the global daily dispense cap, or `None` to remove it (owner only)
         #[ink(message)]
-        pub fn cooldown(&self) -> u32 {
-            self.cooldown
+        pub fn set_daily_cap(&mut self, daily_cap: Option<Balance>) -> Result<()> {
+            self.ensure_owner()?;
+            self.daily_cap = daily_cap;
+
+            self.env().emit_event(DailyCapSet { daily_cap });
+
+            Ok(())
         }
 
-        /// Get the contract owner
+        /// Add an extra asset to dispense (owner only)
         #[ink(message)]
-        pub fn owner(&self) -> AccountId {
-            self.owner
+        pub fn add_asset(
+            &mut self,
+            asset_id: u32,
+            token: Option<AccountId>,
+            drip_amount: Balance,
+            cooldown: CooldownMode,
+        ) -> Result<()> {
+            self.ensure_owner()?;
+
+            if asset_id == 0 {
+                return Err(Error::ReservedAsset);
+            }
+            if self.assets.contains(asset_id) {
+                return Err(Error::AssetExists);
+            }
+            if self.asset_ids.len() >= MAX_ASSETS as usize {
+                return Err(Error::TooManyAssets);
+            }
+
+            self.assets.insert(
+                asset_id,
+                &AssetConfig {
+                    token,
+                    drip_amount,
+                    cooldown,
+                    total_claimed: 0,
+                    claim_count: 0,
+                },
+            );
+            self.asset_ids.push(asset_id);
+
+            self.env().emit_event(AssetAdded {
+                asset_id,
+                token,
+                drip_amount,
+                cooldown,
+            });
+
+            Ok(())
         }
 
-        /// Get the last claim block for an account
+        /// Remove an extra asset (owner only)
         #[ink(message)]
-        pub fn last_claim_block(&self, account: AccountId) -> Option<BlockNumber> {
-            self.last_claim.get(account)
+        pub fn remove_asset(&mut self, asset_id: u32) -> Result<()> {
+            self.ensure_owner()?;
+
+            if asset_id == 0 {
+                return Err(Error::ReservedAsset);
+            }
+            if !self.assets.contains(asset_id) {
+                return Err(Error::UnknownAsset);
+            }
+
+            self.assets.remove(asset_id);
+            self.asset_ids.retain(|id| *id != asset_id);
+
+            self.env().emit_event(AssetRemoved { asset_id });
+
+            Ok(())
         }
 
-        /// Check if an account can claim now
+        /// Resize the claim history ring buffer (owner only)
+        ///
+        /// Resizing clears the history; older records are no longer returned.
         #[ink(message)]
-        pub fn can_claim(&self, account: AccountId) -> bool {
-            let current_block = self.env().block_number();
+        pub fn set_history_capacity(&mut self, capacity: u32) -> Result<()> {
+            self.ensure_owner()?;
+
+            if capacity == 0 || capacity > MAX_HISTORY_CAPACITY {
+                return Err(Error::InvalidCapacity);
+            }
+
+            self.history_capacity = capacity;
+            self.history_base = self.history_seq;
+
+            self.env().emit_event(HistoryCapacitySet { capacity });
+
+            Ok(())
+        }
+
+        /// Set the proof-of-humanity verifier, or `None` to disable it (owner only)
+        ///
+        /// The verifier is called as `selector(account) -> bool`; contracts
+        /// implementing `HumanityVerifier` use `IS_VERIFIED_SELECTOR`.
+        #[ink(message)]
+        pub fn set_verifier(
+            &mut self,
+            verifier: Option<AccountId>,
+            selector: [u8; 4],
+        ) -> Result<()> {
+            self.ensure_owner()?;
+            self.verifier = verifier;
+            self.verifier_selector = selector;
+
+            self.env().emit_event(VerifierSet { verifier, selector });
+
+            Ok(())
+        }
+
+        /// Register or deregister a relayer (owner only)
+        #[ink(message)]
+        pub fn set_relayer(&mut self, relayer: AccountId, registered: bool) -> Result<()> {
+            self.ensure_owner()?;
+
+            if registered {
+                self.relayers.insert(relayer, &());
+            } else {
+                self.relayers.remove(relayer);
+            }
+
+            self.env().emit_event(RelayerUpdated {
+                relayer,
+                registered,
+            });
+
+            Ok(())
+        }
+
+        /// Restrict `claim_for` to registered relayers and set the per-relayer
+        /// daily limit (owner only)
+        #[ink(message)]
+        pub fn set_relayer_settings(
+            &mut self,
+            relayers_only: bool,
+            relayer_limit: Option<u32>,
+        ) -> Result<()> {
+            self.ensure_owner()?;
+            self.relayers_only = relayers_only;
+            self.relayer_limit = relayer_limit;
+
+            self.env().emit_event(RelayerSettingsSet {
+                relayers_only,
+                relayer_limit,
+            });
+
+            Ok(())
+        }
+
+        /// Configure the referral bonus and per-referrer cap (owner only)
+        #[ink(message)]
+        pub fn set_referral_settings(
+            &mut self,
+            referral_bonus: Balance,
+            max_referral_rewards: Option<Balance>,
+        ) -> Result<()> {
+            self.ensure_owner()?;
+            self.referral_bonus = referral_bonus;
+            self.max_referral_rewards = max_referral_rewards;
+
+            self.env().emit_event(ReferralSettingsSet {
+                referral_bonus,
+                max_referral_rewards,
+            });
+
+            Ok(())
+        }
+
+        /// Halt claims (owner only)
+        #[ink(message)]
+        pub fn pause(&mut self) -> Result<()> {
+            self.ensure_owner()?;
+
+            let caller = self.env().caller();
+            self.pausable.pause(caller, |event| {
+                Self::env().emit_event(event);
+            })?;
+
+            Ok(())
+        }
+
+        /// Resume claims (owner only)
+        #[ink(message)]
+        pub fn unpause(&mut self) -> Result<()> {
+            self.ensure_owner()?;
+
+            let caller = self.env().caller();
+            self.pausable.unpause(caller, |event| {
+                Self::env().emit_event(event);
+            })?;
+
+            Ok(())
+        }
+
+        /// Set or clear a per-account cooldown override (owner only)
+        #[ink(message)]
+        pub fn set_cooldown_override(
+            &mut self,
+            account: AccountId,
+            cooldown: Option<CooldownMode>,
+        ) -> Result<()> {
+            self.ensure_owner()?;
 
-            match self.last_claim.get(account) {
-                Some(last) => {
-                    let blocks_since = current_block.saturating_sub(last);
-                    blocks_since >= self.cooldown
+            match cooldown {
+                Some(mode) => {
+                    self.cooldown_overrides.insert(account, &mode);
                 }
-                None => true, // First claim
+                None => self.cooldown_overrides.remove(account),
             }
+
+            self.env()
+                .emit_event(CooldownOverrideSet { account, cooldown });
+
+            Ok(())
         }
 
-        /// Get blocks remaining until next claim
+        /// Create vouchers from blake2 hashes of their codes (owner only)
+        ///
+        /// Each voucher pays `amount` once, until the `expiry` timestamp.
         #[ink(message)]
-        pub fn blocks_until_claim(&self, account: AccountId) -> u32 {
-            let current_block = self.env().block_number();
+        pub fn create_vouchers(
+            &mut self,
+            code_hashes: Vec<[u8; 32]>,
+            amount: Balance,
+            expiry: Timestamp,
+        ) -> Result<()> {
+            self.ensure_owner()?;
 
-            match self.last_claim.get(account) {
-                Some(last) => {
-                    let blocks_since = current_block.saturating_sub(last);
-                    if blocks_since >= self.cooldown {
-                        0
-                    } else {
-                        self.cooldown.saturating_sub(blocks_since)
-                    }
+            let count = code_hashes.len();
+            if count == 0 || count > MAX_VOUCHER_BATCH as usize {
+                return Err(Error::InvalidBatchSize);
+            }
+
+            for (i, code_hash) in code_hashes.iter().enumerate() {
+                if self.vouchers.contains(code_hash) || code_hashes[..i].contains(code_hash) {
+                    return Err(Error::VoucherExists);
                 }
-                None => 0, // Can claim now
             }
+
+            let voucher = Voucher {
+                amount,
+                expiry,
+                redeemed: false,
+            };
+            for code_hash in code_hashes {
+                self.vouchers.insert(code_hash, &voucher);
+            }
+
+            self.env().emit_event(VouchersCreated {
+                count: count as u32,
+                amount,
+                expiry,
+            });
+
+            Ok(())
         }
 
-        /// Get faucet balance
+        /// Block an account from claiming (owner only)
         #[ink(message)]
-        pub fn balance(&self) -> Balance {
-            self.env().balance()
+        pub fn ban(&mut self, account: AccountId) -> Result<()> {
+            self.ensure_owner()?;
+            self.banned.insert(account, &());
+
+            self.env().emit_event(AccountBanned { account });
+
+            Ok(())
         }
 
-        /// Get total claimed amount
+        /// Lift a ban (owner only)
         #[ink(message)]
-        pub fn total_claimed(&self) -> Balance {
-            self.total_claimed
+        pub fn unban(&mut self, account: AccountId) -> Result<()> {
+            self.ensure_owner()?;
+            self.banned.remove(account);
+
+            self.env().emit_event(AccountUnbanned { account });
+
+            Ok(())
         }
 
-        /// Get total number of claims
+        /// Add or remove an account from the allowlist (owner only)
         #[ink(message)]
-        pub fn claim_count(&self) -> u32 {
-            self.claim_count
+        pub fn set_allowlisted(&mut self, account: AccountId, allowed: bool) -> Result<()> {
+            self.ensure_owner()?;
+
+            if allowed {
+                self.allowlist.insert(account, &());
+            } else {
+                self.allowlist.remove(account);
+            }
+
+            self.env().emit_event(AllowlistUpdated { account, allowed });
+
+            Ok(())
         }
 
-        /// Get faucet statistics
+        /// Turn allowlist mode on or off (owner only)
         #[ink(message)]
-        pub fn stats(&self) -> (Balance, u32, Balance) {
-            (self.total_claimed, self.claim_count, self.env().balance())
+        pub fn set_allowlist_enabled(&mut self, enabled: bool) -> Result<()> {
+            self.ensure_owner()?;
+            self.allowlist_enabled = enabled;
+
+            self.env().emit_event(AllowlistModeSet { enabled });
+
+            Ok(())
         }
 
-        // === Helper Functions ===
+        /// Configure the NFT gate (owner only)
+        ///
+        /// Holders of at least one token on `nft_gate` bypass the allowlist and
+        /// receive `multiplier` times the drip amount.
+        #[ink(message)]
+        pub fn set_nft_gate(&mut self, nft_gate: Option<AccountId>, multiplier: u32) -> Result<()> {
+            self.ensure_owner()?;
 
-        /// Ensure caller is the owner
-        fn ensure_owner(&self) -> Result<()> {
-            if self.env().caller() != self.owner {
-                return Err(Error::NotOwner);
+            if multiplier == 0 {
+                return Err(Error::InvalidMultiplier);
             }
+
+            self.nft_gate = nft_gate;
+            self.nft_multiplier = multiplier;
+
+            self.env().emit_event(NftGateSet {
+                nft_gate,
+                multiplier,
+            });
+
             Ok(())
         }
-    }
 
-    #[cfg(test)]
-    mod tests {
-        use super::*;
+        /// Transfer ownership (owner only)
+        #[ink(message)]
+        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<()> {
+            self.ensure_owner()?;
+            self.owner = new_owner;
+            Ok(())
+        }
 
-        #[ink::test]
-        fn new_works() {
-            let faucet = Faucet::new(1000, 100);
-            assert_eq!(faucet.drip_amount(), 1000);
-            assert_eq!(faucet.cooldown(), 100);
-            assert_eq!(faucet.total_claimed(), 0);
-            assert_eq!(faucet.claim_count(), 0);
+        /// Withdraw funds to the owner (owner only, emergency use)
+        ///
+        /// `asset` is a PSP22 token, or `None` for native balance. `amount`
+        /// of `None` withdraws everything the faucet holds of that asset.
+        #[ink(message)]
+        pub fn emergency_withdraw(
+            &mut self,
+            asset: Option<AccountId>,
+            amount: Option<Balance>,
+        ) -> Result<()> {
+            self.ensure_owner()?;
+
+            let balance = self.balance_in(asset);
+            let amount = amount.unwrap_or(balance);
+            if amount > balance {
+                return Err(Error::InsufficientBalance);
+            }
+
+            self.dispense_in(asset, self.owner, amount)?;
+
+            self.env().emit_event(EmergencyWithdrawal {
+                asset,
+                to: self.owner,
+                amount,
+            });
+
+            Ok(())
         }
 
-        #[ink::test]
-        fn can_claim_works() {
-            let faucet = Faucet::new(1000, 100);
-            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        // === Query Functions ===
 
-            // New account should be able to claim
-            assert!(faucet.can_claim(accounts.bob));
+        /// Get the current drip amount
+        #[ink(message)]
+        pub fn drip_amount(&self) -> Balance {
+            self.drip_amount
         }
 
-        #[ink::test]
-        fn update_settings_works() {
-            let mut faucet = Faucet::new(1000, 100);
+        /// Check if adaptive drips are on
+        #[ink(message)]
+        pub fn is_adaptive(&self) -> bool {
+            self.adaptive
+        }
 
-            assert!(faucet.update_settings(Some(2000), Some(200)).is_ok());
-            assert_eq!(faucet.drip_amount(), 2000);
-            assert_eq!(faucet.cooldown(), 200);
+        /// Get the smallest drip in adaptive mode
+        #[ink(message)]
+        pub fn min_drip(&self) -> Balance {
+            self.min_drip
         }
 
-        #[ink::test]
-        fn only_owner_can_update() {
-            let mut faucet = Faucet::new(1000, 100);
-            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// Get the drip a claim would receive right now, before any NFT boost
+        #[ink(message)]
+        pub fn current_drip(&self) -> Balance {
+            self.base_drip(self.balance())
+        }
 
-            // Non-owner cannot update
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            assert_eq!(
-                faucet.update_settings(Some(2000), None),
-                Err(Error::NotOwner)
-            );
+        /// Get the global cooldown
+        #[ink(message)]
+        pub fn cooldown(&self) -> CooldownMode {
+            self.cooldown
         }
 
-        #[ink::test]
-        fn transfer_ownership_works() {
-            let mut faucet = Faucet::new(1000, 100);
-            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// Get the cooldown override for an account, if any
+        #[ink(message)]
+        pub fn cooldown_override(&self, account: AccountId) -> Option<CooldownMode> {
+            self.cooldown_overrides.get(account)
+        }
 
-            assert!(faucet.transfer_ownership(accounts.bob).is_ok());
-            assert_eq!(faucet.owner(), accounts.bob);
+        /// Get the contract owner
+        #[ink(message)]
+        pub fn owner(&self) -> AccountId {
+            self.owner
+        }
+
+        /// Get the PSP22 token being dispensed (`None` for native balance)
+        #[ink(message)]
+        pub fn token(&self) -> Option<AccountId> {
+            self.token
+        }
+
+        /// Check if an account is banned
+        #[ink(message)]
+        pub fn is_banned(&self, account: AccountId) -> bool {
+            self.banned.contains(account)
+        }
+
+        /// Check if an account is on the allowlist
+        #[ink(message)]
+        pub fn is_allowlisted(&self, account: AccountId) -> bool {
+            self.allowlist.contains(account)
+        }
+
+        /// Check if allowlist mode is on
+        #[ink(message)]
+        pub fn allowlist_enabled(&self) -> bool {
+            self.allowlist_enabled
+        }
+
+        /// Get the NFT gate contract
+        #[ink(message)]
+        pub fn nft_gate(&self) -> Option<AccountId> {
+            self.nft_gate
+        }
+
+        /// Get the drip multiplier for NFT holders
+        #[ink(message)]
+        pub fn nft_multiplier(&self) -> u32 {
+            self.nft_multiplier
+        }
+
+        /// Get the lifetime claim limit per account
+        #[ink(message)]
+        pub fn max_claims_per_account(&self) -> Option<u32> {
+            self.max_claims_per_account
+        }
+
+        /// Get the lifetime amount limit per account
+        #[ink(message)]
+        pub fn max_total_per_account(&self) -> Option<Balance> {
+            self.max_total_per_account
+        }
+
+        /// Get the number of claims made by an account
+        #[ink(message)]
+        pub fn claims_of(&self, account: AccountId) -> u32 {
+            self.claims.get(account).unwrap_or(0)
+        }
+
+        /// Get the cumulative amount claimed by an account
+        #[ink(message)]
+        pub fn claimed_amount_of(&self, account: AccountId) -> Balance {
+            self.claimed_amount.get(account).unwrap_or(0)
+        }
+
+        /// Get the last claim block for an account
+        #[ink(message)]
+        pub fn last_claim_block(&self, account: AccountId) -> Option<BlockNumber> {
+            self.last_claim.get(account)
+        }
+
+        /// Get the last claim timestamp for an account
+        #[ink(message)]
+        pub fn last_claim_timestamp(&self, account: AccountId) -> Option<Timestamp> {
+            self.last_claim_time.get(account)
+        }
+
+        /// Check if an account can claim now
+        #[ink(message)]
+        pub fn can_claim(&self, account: AccountId) -> bool {
+            let Ok(holds_nft) = self.ensure_eligible(account) else {
+                return false;
+            };
+
+            self.time_until_claim(account) == 0 && self.balance() >= self.drip_for(holds_nft)
+        }
+
+        /// Get the time remaining until the next claim
+        ///
+        /// Measured in blocks or seconds, following the account's active
+        /// cooldown mode.
+        #[ink(message)]
+        pub fn time_until_claim(&self, account: AccountId) -> u64 {
+            let cooldown = self
+                .cooldown_overrides
+                .get(account)
+                .unwrap_or(self.cooldown);
+
+            self.cooldown_remaining(
+                cooldown,
+                self.last_claim.get(account),
+                self.last_claim_time.get(account),
+            )
+        }
+
+        /// Get the time remaining until `account` can claim an asset again
+        ///
+        /// Returns 0 for unknown assets.
+        #[ink(message)]
+        pub fn asset_time_until_claim(&self, asset_id: u32, account: AccountId) -> u64 {
+            if asset_id == 0 {
+                return self.time_until_claim(account);
+            }
+
+            let Some(asset) = self.assets.get(asset_id) else {
+                return 0;
+            };
+            let last = self.asset_last_claim.get((asset_id, account));
+
+            self.cooldown_remaining(
+                asset.cooldown,
+                last.map(|(block, _)| block),
+                last.map(|(_, timestamp)| timestamp),
+            )
+        }
+
+        /// Get an asset's configuration and totals
+        ///
+        /// Asset 0 reflects the legacy settings and global totals.
+        #[ink(message)]
+        pub fn asset(&self, asset_id: u32) -> Option<AssetConfig> {
+            if asset_id == 0 {
+                return Some(AssetConfig {
+                    token: self.token,
+                    drip_amount: self.drip_amount,
+                    cooldown: self.cooldown,
+                    total_claimed: self.total_claimed,
+                    claim_count: self.claim_count,
+                });
+            }
+
+            self.assets.get(asset_id)
+        }
+
+        /// List every asset with its configuration and current faucet balance
+        #[ink(message)]
+        pub fn assets(&self) -> Vec<(u32, AssetConfig, Balance)> {
+            core::iter::once(0)
+                .chain(self.asset_ids.iter().copied())
+                .filter_map(|id| self.asset(id).map(|asset| (id, asset)))
+                .map(|(id, asset)| {
+                    let balance = self.balance_in(asset.token);
+                    (id, asset, balance)
+                })
+                .collect()
+        }
+
+        /// Get the number of slots in the claim history
+        #[ink(message)]
+        pub fn history_capacity(&self) -> u32 {
+            self.history_capacity
+        }
+
+        /// Get recent claims, newest first
+        ///
+        /// Once the buffer is full each new claim overwrites the oldest one.
+        /// `limit` is capped at `MAX_PAGE_SIZE`.
+        #[ink(message)]
+        pub fn recent_claims(&self, offset: u32, limit: u32) -> Vec<ClaimRecord> {
+            let oldest = self.oldest_retained_seq();
+            let retained = self.history_seq.saturating_sub(oldest);
+            let limit = u64::from(limit.min(MAX_PAGE_SIZE));
+
+            (u64::from(offset)..retained.min(u64::from(offset).saturating_add(limit)))
+                .filter_map(|i| {
+                    self.history_record(self.history_seq.saturating_sub(i).saturating_sub(1))
+                })
+                .collect()
+        }
+
+        /// Get an account's claims still held in the history, newest first
+        ///
+        /// `limit` is capped at `MAX_PAGE_SIZE`.
+        #[ink(message)]
+        pub fn claims_by_account(
+            &self,
+            account: AccountId,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<ClaimRecord> {
+            let oldest = self.oldest_retained_seq();
+            let count = self.account_history_count.get(account).unwrap_or(0);
+
+            (0..count)
+                .rev()
+                .map_while(|index| {
+                    self.account_history
+                        .get((account, index))
+                        .filter(|seq| *seq >= oldest)
+                })
+                .skip(offset as usize)
+                .take(limit.min(MAX_PAGE_SIZE) as usize)
+                .filter_map(|seq| self.history_record(seq))
+                .collect()
+        }
+
+        /// Get faucet balance (token balance in token mode)
+        #[ink(message)]
+        pub fn balance(&self) -> Balance {
+            self.balance_in(self.token)
+        }
+
+        /// Get total claimed amount
+        #[ink(message)]
+        pub fn total_claimed(&self) -> Balance {
+            self.total_claimed
+        }
+
+        /// Get total number of claims
+        #[ink(message)]
+        pub fn claim_count(&self) -> u32 {
+            self.claim_count
+        }
+
+        /// Get the bonus credited per referral
+        #[ink(message)]
+        pub fn referral_bonus(&self) -> Balance {
+            self.referral_bonus
+        }
+
+        /// Get the lifetime bonus cap per referrer
+        #[ink(message)]
+        pub fn max_referral_rewards(&self) -> Option<Balance> {
+            self.max_referral_rewards
+        }
+
+        /// Get who referred an account
+        #[ink(message)]
+        pub fn referrer_of(&self, account: AccountId) -> Option<AccountId> {
+            self.referrer_of.get(account)
+        }
+
+        /// Get the number of accounts referred by an account
+        #[ink(message)]
+        pub fn referrals_of(&self, account: AccountId) -> u32 {
+            self.referrals.get(account).unwrap_or(0)
+        }
+
+        /// Get an account's unclaimed referral rewards
+        #[ink(message)]
+        pub fn pending_rewards(&self, account: AccountId) -> Balance {
+            self.pending_rewards.get(account).unwrap_or(0)
+        }
+
+        /// Get the verifier contract and the selector it is called with
+        #[ink(message)]
+        pub fn verifier(&self) -> Option<(AccountId, [u8; 4])> {
+            self.verifier
+                .map(|verifier| (verifier, self.verifier_selector))
+        }
+
+        /// Get a voucher by code hash
+        #[ink(message)]
+        pub fn voucher(&self, code_hash: [u8; 32]) -> Option<Voucher> {
+            self.vouchers.get(code_hash)
+        }
+
+        /// Check if an account is a registered relayer
+        #[ink(message)]
+        pub fn is_relayer(&self, account: AccountId) -> bool {
+            self.relayers.contains(account)
+        }
+
+        /// Check if `claim_for` is restricted to registered relayers
+        #[ink(message)]
+        pub fn relayers_only(&self) -> bool {
+            self.relayers_only
+        }
+
+        /// Get the per-relayer daily `claim_for` limit
+        #[ink(message)]
+        pub fn relayer_limit(&self) -> Option<u32> {
+            self.relayer_limit
+        }
+
+        /// Get the number of `claim_for` calls a relayer made in its current window
+        #[ink(message)]
+        pub fn relayer_claims_today(&self, relayer: AccountId) -> u32 {
+            match self.relayer_usage.get(relayer) {
+                Some((start, used))
+                    if self.env().block_timestamp() < start.saturating_add(DAY_MS) =>
+                {
+                    used
+                }
+                _ => 0,
+            }
+        }
+
+        /// Check if claims are paused
+        #[ink(message)]
+        pub fn is_paused(&self) -> bool {
+            self.pausable.is_paused()
+        }
+
+        /// Get the global daily dispense cap
+        #[ink(message)]
+        pub fn daily_cap(&self) -> Option<Balance> {
+            self.daily_cap
+        }
+
+        /// Get the amount dispensed in the current daily window
+        #[ink(message)]
+        pub fn dispensed_today(&self) -> Balance {
+            if self.window_expired() {
+                0
+            } else {
+                self.dispensed_in_window
+            }
+        }
+
+        /// Get faucet statistics
+        ///
+        /// Returns `(total_claimed, claim_count, balance, max_claims_per_account,
+        /// max_total_per_account)`.
+        #[ink(message)]
+        pub fn stats(&self) -> (Balance, u32, Balance, Option<u32>, Option<Balance>) {
+            (
+                self.total_claimed,
+                self.claim_count,
+                self.balance(),
+                self.max_claims_per_account,
+                self.max_total_per_account,
+            )
+        }
+
+        // === Helper Functions ===
+
+        /// Ensure caller is the owner
+        fn ensure_owner(&self) -> Result<()> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            Ok(())
+        }
+
+        /// Run a drip to `account`, returning the amount dispensed
+        ///
+        /// Holders of the gate NFT receive `drip_amount * nft_multiplier`.
+        fn claim_to(&mut self, account: AccountId) -> Result<Balance> {
+            self.pausable.ensure_not_paused()?;
+
+            let current_block = self.env().block_number();
+
+            // Check denylist and allowlist
+            let holds_nft = self.ensure_eligible(account)?;
+            let amount = self.drip_for(holds_nft);
+
+            // Check cooldown
+            if self.time_until_claim(account) > 0 {
+                return Err(Error::TooSoon);
+            }
+
+            // Check lifetime limits and the global daily cap
+            self.ensure_within_limits(account, amount)?;
+            self.ensure_within_daily_cap(amount)?;
+
+            // Check faucet balance
+            let balance = self.balance();
+            if balance < amount {
+                return Err(Error::InsufficientBalance);
+            }
+
+            // Transfer tokens
+            self.dispense(account, amount)?;
+
+            // Update state
+            self.last_claim.insert(account, &current_block);
+            self.last_claim_time
+                .insert(account, &self.env().block_timestamp());
+            self.record_claim(account, amount);
+
+            // Emit event
+            self.env().emit_event(Claimed {
+                account,
+                amount,
+                block: current_block,
+            });
+
+            Ok(amount)
+        }
+
+        /// Drip before any NFT boost, given the faucet balance
+        fn base_drip(&self, balance: Balance) -> Balance {
+            if !self.adaptive {
+                return self.drip_amount;
+            }
+
+            self.drip_amount.min((balance / 100).max(self.min_drip))
+        }
+
+        /// Drip for a claimant, including the NFT boost
+        fn drip_for(&self, holds_nft: bool) -> Balance {
+            let drip = self.base_drip(self.balance());
+            if holds_nft {
+                drip.saturating_mul(Balance::from(self.nft_multiplier))
+            } else {
+                drip
+            }
+        }
+
+        /// Ensure `account` passes the denylist, verifier and allowlist
+        ///
+        /// Returns whether the account holds the gate NFT.
+        fn ensure_eligible(&self, account: AccountId) -> Result<bool> {
+            if self.banned.contains(account) {
+                return Err(Error::Banned);
+            }
+
+            if !self.is_verified(account) {
+                return Err(Error::NotVerified);
+            }
+
+            let holds_nft = self.holds_gate_nft(account);
+
+            if self.allowlist_enabled && !holds_nft && !self.allowlist.contains(account) {
+                return Err(Error::NotAllowlisted);
+            }
+
+            Ok(holds_nft)
+        }
+
+        /// Ask the verifier about `account` (always true when no verifier is set)
+        fn is_verified(&self, account: AccountId) -> bool {
+            let Some(verifier) = self.verifier else {
+                return true;
+            };
+
+            build_call::<Environment>()
+                .call(verifier)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(self.verifier_selector)).push_arg(account),
+                )
+                .returns::<bool>()
+                .try_invoke()
+                .ok()
+                .and_then(|result| result.ok())
+                .unwrap_or(false)
+        }
+
+        /// Check whether `account` owns at least one token on the NFT gate
+        fn holds_gate_nft(&self, account: AccountId) -> bool {
+            let Some(nft_gate) = self.nft_gate else {
+                return false;
+            };
+
+            // BeliNFT balance_of selector is 0x0f755a56
+            let selector = [0x0f, 0x75, 0x5a, 0x56];
+
+            build_call::<Environment>()
+                .call(nft_gate)
+                .exec_input(ExecutionInput::new(Selector::new(selector)).push_arg(account))
+                .returns::<u32>()
+                .try_invoke()
+                .ok()
+                .and_then(|result| result.ok())
+                .is_some_and(|balance| balance > 0)
+        }
+
+        /// Ensure `account` can receive `amount` more without breaching its lifetime limits
+        fn ensure_within_limits(&self, account: AccountId, amount: Balance) -> Result<()> {
+            if let Some(max_claims) = self.max_claims_per_account {
+                if self.claims_of(account) >= max_claims {
+                    return Err(Error::ClaimLimitReached);
+                }
+            }
+
+            if let Some(max_total) = self.max_total_per_account {
+                let total = self.claimed_amount_of(account).saturating_add(amount);
+                if total > max_total {
+                    return Err(Error::AmountLimitReached);
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Ensure dispensing `amount` keeps the current window within the daily cap
+        fn ensure_within_daily_cap(&self, amount: Balance) -> Result<()> {
+            if let Some(daily_cap) = self.daily_cap {
+                if self.dispensed_today().saturating_add(amount) > daily_cap {
+                    return Err(Error::DailyCapReached);
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Check whether the daily window has rolled over
+        fn window_expired(&self) -> bool {
+            self.env().block_timestamp() >= self.window_start.saturating_add(DAY_MS)
+        }
+
+        /// Append a claim to the history, overwriting the oldest slot when full
+        fn record_history(&mut self, account: AccountId, amount: Balance, asset_id: u32) {
+            let seq = self.history_seq;
+            let slot = self.history_slot(seq);
+            let record = ClaimRecord {
+                account,
+                amount,
+                block: self.env().block_number(),
+                asset_id,
+            };
+            self.history.insert(slot, &(seq, record));
+            self.history_seq = seq.saturating_add(1);
+
+            let index = self.account_history_count.get(account).unwrap_or(0);
+            self.account_history.insert((account, index), &seq);
+            self.account_history_count
+                .insert(account, &index.saturating_add(1));
+        }
+
+        /// Ring buffer slot for a sequence number
+        fn history_slot(&self, seq: u64) -> u32 {
+            let offset = seq.saturating_sub(self.history_base);
+            // Capacity is at most `MAX_HISTORY_CAPACITY`, so the slot fits in a u32
+            offset
+                .checked_rem(u64::from(self.history_capacity))
+                .unwrap_or(0) as u32
+        }
+
+        /// First sequence number still held in the history
+        fn oldest_retained_seq(&self) -> u64 {
+            self.history_seq
+                .saturating_sub(u64::from(self.history_capacity))
+                .max(self.history_base)
+        }
+
+        /// Look up a claim record by sequence number, if still retained
+        fn history_record(&self, seq: u64) -> Option<ClaimRecord> {
+            if seq < self.oldest_retained_seq() || seq >= self.history_seq {
+                return None;
+            }
+
+            let slot = self.history_slot(seq);
+            self.history
+                .get(slot)
+                .filter(|(stored, _)| *stored == seq)
+                .map(|(_, record)| record)
+        }
+
+        /// Update the global and per-account claim counters
+        fn record_claim(&mut self, account: AccountId, amount: Balance) {
+            self.total_claimed = self.total_claimed.saturating_add(amount);
+            self.claim_count = self.claim_count.saturating_add(1);
+
+            if self.window_expired() {
+                self.window_start = self.env().block_timestamp();
+                self.dispensed_in_window = 0;
+            }
+            self.dispensed_in_window = self.dispensed_in_window.saturating_add(amount);
+
+            let claims = self.claims_of(account).saturating_add(1);
+            self.claims.insert(account, &claims);
+
+            let claimed = self.claimed_amount_of(account).saturating_add(amount);
+            self.claimed_amount.insert(account, &claimed);
+
+            self.record_history(account, amount, 0);
+        }
+
+        /// Remaining cooldown given the last claim block and timestamp
+        ///
+        /// This is the single source of truth for `claim`, `can_claim` and
+        /// `time_until_claim`. A last claim recorded in the future (the chain
+        /// was purged and block numbers or timestamps restarted) counts as
+        /// claimable rather than locking the account out.
+        fn cooldown_remaining(
+            &self,
+            cooldown: CooldownMode,
+            last_block: Option<BlockNumber>,
+            last_time: Option<Timestamp>,
+        ) -> u64 {
+            match cooldown {
+                CooldownMode::Blocks(blocks) => {
+                    let current_block = self.env().block_number();
+                    match last_block {
+                        Some(last) if last <= current_block => {
+                            let blocks_since = current_block.saturating_sub(last);
+                            u64::from(blocks.saturating_sub(blocks_since))
+                        }
+                        // First claim, or the chain was reset
+                        _ => 0,
+                    }
+                }
+                CooldownMode::Seconds(seconds) => {
+                    let now = self.env().block_timestamp();
+                    match last_time {
+                        Some(last) if last <= now => {
+                            let elapsed = now.saturating_sub(last) / 1000;
+                            seconds.saturating_sub(elapsed)
+                        }
+                        // First claim, or the chain was reset
+                        _ => 0,
+                    }
+                }
+            }
+        }
+
+        /// Send `amount` to `to` in native balance or the configured token
+        fn dispense(&self, to: AccountId, amount: Balance) -> Result<()> {
+            self.dispense_in(self.token, to, amount)
+        }
+
+        /// Send `amount` to `to` in native balance (`None`) or a PSP22 token
+        fn dispense_in(
+            &self,
+            token: Option<AccountId>,
+            to: AccountId,
+            amount: Balance,
+        ) -> Result<()> {
+            let Some(token) = token else {
+                return self
+                    .env()
+                    .transfer(to, amount)
+                    .map_err(|_| Error::TransferFailed);
+            };
+
+            // PSP22::transfer selector is 0xdb20f9f5
+            let selector = [0xdb, 0x20, 0xf9, 0xf5];
+
+            let result = build_call::<Environment>()
+                .call(token)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(selector))
+                        .push_arg(to)
+                        .push_arg(amount),
+                )
+                .returns::<psp22_trait::Result<()>>()
+                .try_invoke();
+
+            match result {
+                Ok(Ok(Ok(()))) => Ok(()),
+                _ => Err(Error::TransferFailed),
+            }
+        }
+
+        /// Query the faucet's native balance (`None`) or PSP22 balance
+        fn balance_in(&self, token: Option<AccountId>) -> Balance {
+            match token {
+                Some(token) => self.token_balance(token),
+                None => self.env().balance(),
+            }
+        }
+
+        /// Query the faucet's balance on a PSP22 token
+        fn token_balance(&self, token: AccountId) -> Balance {
+            // PSP22::balance_of selector is 0x6568382f
+            let selector = [0x65, 0x68, 0x38, 0x2f];
+
+            build_call::<Environment>()
+                .call(token)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(selector)).push_arg(self.env().account_id()),
+                )
+                .returns::<Balance>()
+                .try_invoke()
+                .ok()
+                .and_then(|result| result.ok())
+                .unwrap_or(0)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[ink::test]
+        fn new_works() {
+            let faucet = Faucet::new(1000, 100);
+            assert_eq!(faucet.drip_amount(), 1000);
+            assert_eq!(faucet.cooldown(), CooldownMode::Blocks(100));
+            assert_eq!(faucet.total_claimed(), 0);
+            assert_eq!(faucet.claim_count(), 0);
+        }
+
+        #[ink::test]
+        fn can_claim_works() {
+            let faucet = Faucet::new(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            // New account should be able to claim
+            assert!(faucet.can_claim(accounts.bob));
+        }
+
+        #[ink::test]
+        fn update_settings_works() {
+            let mut faucet = Faucet::new(1000, 100);
+
+            assert!(faucet
+                .update_settings(Some(2000), Some(CooldownMode::Blocks(200)), None, None)
+                .is_ok());
+            assert_eq!(faucet.drip_amount(), 2000);
+            assert_eq!(faucet.cooldown(), CooldownMode::Blocks(200));
+        }
+
+        #[ink::test]
+        fn only_owner_can_update() {
+            let mut faucet = Faucet::new(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            // Non-owner cannot update
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                faucet.update_settings(Some(2000), None, None, None),
+                Err(Error::NotOwner)
+            );
+        }
+
+        #[ink::test]
+        fn transfer_ownership_works() {
+            let mut faucet = Faucet::new(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            assert!(faucet.transfer_ownership(accounts.bob).is_ok());
+            assert_eq!(faucet.owner(), accounts.bob);
+        }
+
+        #[ink::test]
+        fn new_defaults_to_native_mode() {
+            let faucet = Faucet::new(1000, 100);
+            assert_eq!(faucet.token(), None);
+        }
+
+        #[ink::test]
+        fn set_token_works() {
+            let mut faucet = Faucet::new(1000, 100);
+            let token = AccountId::from([0x07; 32]);
+
+            assert!(faucet.set_token(Some(token)).is_ok());
+            assert_eq!(faucet.token(), Some(token));
+
+            assert!(faucet.set_token(None).is_ok());
+            assert_eq!(faucet.token(), None);
+        }
+
+        #[ink::test]
+        fn only_owner_can_set_token() {
+            let mut faucet = Faucet::new(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                faucet.set_token(Some(AccountId::from([0x07; 32]))),
+                Err(Error::NotOwner)
+            );
+        }
+
+        #[ink::test]
+        fn notify_refill_emits_event() {
+            let mut faucet = Faucet::new_with_token(1000, 100, Some(AccountId::from([0x07; 32])));
+
+            assert!(faucet.notify_refill(5000).is_ok());
+            assert_eq!(ink::env::test::recorded_events().count(), 1);
+        }
+
+        /// Deploys a native-mode faucet with its own funded account
+        fn funded_faucet(drip_amount: Balance, cooldown: u32) -> Faucet {
+            let contract = AccountId::from([0x42; 32]);
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(contract);
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(
+                contract, 1_000_000,
+            );
+            Faucet::new(drip_amount, cooldown)
+        }
+
+        #[ink::test]
+        fn claim_updates_counters() {
+            let mut faucet = funded_faucet(1000, 0);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim().is_ok());
+            assert!(faucet.claim().is_ok());
+
+            assert_eq!(faucet.claims_of(accounts.bob), 2);
+            assert_eq!(faucet.claimed_amount_of(accounts.bob), 2000);
+            assert_eq!(faucet.claims_of(accounts.charlie), 0);
+            assert_eq!(faucet.claim_count(), 2);
+            assert_eq!(faucet.total_claimed(), 2000);
+        }
+
+        #[ink::test]
+        fn claim_limit_is_enforced() {
+            let mut faucet = funded_faucet(1000, 0);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert!(faucet
+                .update_settings(None, None, Some(Some(2)), None)
+                .is_ok());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim().is_ok());
+            assert!(faucet.claim().is_ok());
+            assert_eq!(faucet.claim(), Err(Error::ClaimLimitReached));
+            assert_eq!(faucet.claims_of(accounts.bob), 2);
+
+            // Other accounts keep their own allowance
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert!(faucet.claim().is_ok());
+
+            // Lifting the limit lets bob claim again
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(faucet.update_settings(None, None, Some(None), None).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim().is_ok());
+            assert_eq!(faucet.claims_of(accounts.bob), 3);
+        }
+
+        #[ink::test]
+        fn amount_limit_is_enforced() {
+            let mut faucet = funded_faucet(1000, 0);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert!(faucet
+                .update_settings(None, None, None, Some(Some(2500)))
+                .is_ok());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim().is_ok());
+            assert!(faucet.claim().is_ok());
+            // A third drip would take bob to 3000
+            assert_eq!(faucet.claim(), Err(Error::AmountLimitReached));
+            assert_eq!(faucet.claimed_amount_of(accounts.bob), 2000);
+            assert_eq!(faucet.total_claimed(), 2000);
+        }
+
+        #[ink::test]
+        fn stats_include_limits() {
+            let mut faucet = Faucet::new(1000, 100);
+            assert!(faucet
+                .update_settings(None, None, Some(Some(5)), Some(Some(5000)))
+                .is_ok());
+
+            let (total_claimed, claim_count, _, max_claims, max_total) = faucet.stats();
+            assert_eq!(total_claimed, 0);
+            assert_eq!(claim_count, 0);
+            assert_eq!(max_claims, Some(5));
+            assert_eq!(max_total, Some(5000));
+        }
+
+        #[ink::test]
+        fn banned_account_cannot_claim() {
+            let mut faucet = funded_faucet(1000, 0);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            assert!(faucet.ban(accounts.bob).is_ok());
+            assert!(faucet.is_banned(accounts.bob));
+            assert!(!faucet.can_claim(accounts.bob));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(faucet.claim(), Err(Error::Banned));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(faucet.unban(accounts.bob).is_ok());
+            assert!(!faucet.is_banned(accounts.bob));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim().is_ok());
+        }
+
+        #[ink::test]
+        fn allowlist_mode_gates_claims() {
+            let mut faucet = funded_faucet(1000, 0);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            assert!(faucet.set_allowlist_enabled(true).is_ok());
+            assert!(faucet.set_allowlisted(accounts.bob, true).is_ok());
+            assert!(faucet.allowlist_enabled());
+            assert!(faucet.is_allowlisted(accounts.bob));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(faucet.claim(), Err(Error::NotAllowlisted));
+            assert!(!faucet.can_claim(accounts.charlie));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim().is_ok());
+
+            // Removing bob closes the door again
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(faucet.set_allowlisted(accounts.bob, false).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(faucet.claim(), Err(Error::NotAllowlisted));
+        }
+
+        #[ink::test]
+        fn ban_overrides_allowlist() {
+            let mut faucet = funded_faucet(1000, 0);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            assert!(faucet.set_allowlist_enabled(true).is_ok());
+            assert!(faucet.set_allowlisted(accounts.bob, true).is_ok());
+            assert!(faucet.ban(accounts.bob).is_ok());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(faucet.claim(), Err(Error::Banned));
+        }
+
+        #[ink::test]
+        fn gating_is_owner_only() {
+            let mut faucet = Faucet::new(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(faucet.ban(accounts.charlie), Err(Error::NotOwner));
+            assert_eq!(faucet.unban(accounts.charlie), Err(Error::NotOwner));
+            assert_eq!(
+                faucet.set_allowlisted(accounts.bob, true),
+                Err(Error::NotOwner)
+            );
+            assert_eq!(faucet.set_allowlist_enabled(true), Err(Error::NotOwner));
+            assert_eq!(
+                faucet.set_nft_gate(Some(AccountId::from([0x09; 32])), 2),
+                Err(Error::NotOwner)
+            );
+        }
+
+        #[ink::test]
+        fn set_nft_gate_rejects_zero_multiplier() {
+            let mut faucet = Faucet::new(1000, 100);
+            let gate = AccountId::from([0x09; 32]);
+
+            assert_eq!(
+                faucet.set_nft_gate(Some(gate), 0),
+                Err(Error::InvalidMultiplier)
+            );
+            assert_eq!(faucet.nft_gate(), None);
+            assert_eq!(faucet.nft_multiplier(), 1);
+
+            assert!(faucet.set_nft_gate(Some(gate), 2).is_ok());
+            assert_eq!(faucet.nft_gate(), Some(gate));
+            assert_eq!(faucet.nft_multiplier(), 2);
+        }
+
+        #[ink::test]
+        fn airdrop_works() {
+            let mut faucet = funded_faucet(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let recipients = vec![accounts.bob, accounts.charlie, accounts.django];
+
+            assert!(faucet.airdrop(recipients, 500).is_ok());
+
+            assert_eq!(faucet.balance(), 1_000_000 - 1500);
+            assert_eq!(faucet.total_claimed(), 1500);
+            assert_eq!(faucet.claim_count(), 3);
+            assert_eq!(faucet.claims_of(accounts.charlie), 1);
+            assert_eq!(faucet.claimed_amount_of(accounts.django), 500);
+            assert_eq!(ink::env::test::recorded_events().count(), 3);
+
+            // Airdrops do not start the recipient's cooldown
+            assert!(faucet.can_claim(accounts.bob));
+        }
+
+        #[ink::test]
+        fn airdrop_rejects_insufficient_funds_before_transferring() {
+            let mut faucet = funded_faucet(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let recipients = vec![accounts.bob, accounts.charlie];
+
+            assert_eq!(
+                faucet.airdrop(recipients, 600_000),
+                Err(Error::InsufficientBalance)
+            );
+            assert_eq!(faucet.balance(), 1_000_000);
+            assert_eq!(faucet.claim_count(), 0);
+            assert_eq!(faucet.claims_of(accounts.bob), 0);
+        }
+
+        #[ink::test]
+        fn airdrop_rejects_invalid_batch_size() {
+            let mut faucet = funded_faucet(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            assert_eq!(faucet.airdrop(vec![], 1), Err(Error::InvalidBatchSize));
+
+            let recipients = vec![accounts.bob; MAX_AIRDROP_RECIPIENTS as usize + 1];
+            assert_eq!(faucet.airdrop(recipients, 1), Err(Error::InvalidBatchSize));
+        }
+
+        #[ink::test]
+        fn only_owner_can_airdrop() {
+            let mut faucet = funded_faucet(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(faucet.airdrop(vec![accounts.bob], 1), Err(Error::NotOwner));
+        }
+
+        #[ink::test]
+        fn block_cooldown_works() {
+            let mut faucet = funded_faucet(1000, 10);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim().is_ok());
+            assert_eq!(faucet.claim(), Err(Error::TooSoon));
+            assert_eq!(faucet.time_until_claim(accounts.bob), 10);
+
+            for _ in 0..4 {
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            }
+            assert_eq!(faucet.time_until_claim(accounts.bob), 6);
+            assert!(!faucet.can_claim(accounts.bob));
+
+            for _ in 0..6 {
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            }
+            assert_eq!(faucet.time_until_claim(accounts.bob), 0);
+            assert!(faucet.claim().is_ok());
+        }
+
+        #[ink::test]
+        fn seconds_cooldown_works() {
+            let mut faucet = funded_faucet(1000, 10);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert!(faucet
+                .update_settings(None, Some(CooldownMode::Seconds(60)), None, None)
+                .is_ok());
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000_000);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim().is_ok());
+            assert_eq!(faucet.last_claim_timestamp(accounts.bob), Some(1_000_000));
+
+            // Plenty of blocks, but not enough time
+            for _ in 0..20 {
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            }
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_030_000);
+            assert_eq!(faucet.time_until_claim(accounts.bob), 30);
+            assert_eq!(faucet.claim(), Err(Error::TooSoon));
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_060_000);
+            assert_eq!(faucet.time_until_claim(accounts.bob), 0);
+            assert!(faucet.claim().is_ok());
+        }
+
+        #[ink::test]
+        fn cooldown_override_replaces_global_cooldown() {
+            let mut faucet = funded_faucet(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            assert!(faucet
+                .set_cooldown_override(accounts.bob, Some(CooldownMode::Blocks(0)))
+                .is_ok());
+            assert_eq!(
+                faucet.cooldown_override(accounts.bob),
+                Some(CooldownMode::Blocks(0))
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim().is_ok());
+            assert!(faucet.claim().is_ok());
+
+            // Charlie still follows the global cooldown
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert!(faucet.claim().is_ok());
+            assert_eq!(faucet.claim(), Err(Error::TooSoon));
+
+            // Clearing the override puts bob back on the global cooldown
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(faucet.set_cooldown_override(accounts.bob, None).is_ok());
+            assert_eq!(faucet.cooldown_override(accounts.bob), None);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(faucet.claim(), Err(Error::TooSoon));
+        }
+
+        #[ink::test]
+        fn only_owner_can_set_cooldown_override() {
+            let mut faucet = Faucet::new(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                faucet.set_cooldown_override(accounts.bob, Some(CooldownMode::Seconds(0))),
+                Err(Error::NotOwner)
+            );
+        }
+
+        #[ink::test]
+        fn paused_faucet_rejects_claims() {
+            let mut faucet = funded_faucet(1000, 0);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            assert!(faucet.pause().is_ok());
+            assert!(faucet.is_paused());
+            assert_eq!(faucet.pause(), Err(Error::Paused));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(faucet.claim(), Err(Error::Paused));
+            assert_eq!(faucet.unpause(), Err(Error::NotOwner));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(faucet.unpause().is_ok());
+            assert_eq!(faucet.unpause(), Err(Error::NotPaused));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim().is_ok());
+        }
+
+        #[ink::test]
+        fn daily_cap_is_enforced_until_rollover() {
+            let mut faucet = funded_faucet(1000, 0);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert!(faucet.set_daily_cap(Some(2500)).is_ok());
+            assert_eq!(faucet.daily_cap(), Some(2500));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim().is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert!(faucet.claim().is_ok());
+            assert_eq!(faucet.dispensed_today(), 2000);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(faucet.claim(), Err(Error::DailyCapReached));
+
+            // Just short of a day later the window is still full
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(DAY_MS - 1);
+            assert_eq!(faucet.claim(), Err(Error::DailyCapReached));
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(DAY_MS);
+            assert_eq!(faucet.dispensed_today(), 0);
+            assert!(faucet.claim().is_ok());
+            assert_eq!(faucet.dispensed_today(), 1000);
+        }
+
+        #[ink::test]
+        fn only_owner_can_set_daily_cap() {
+            let mut faucet = Faucet::new(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(faucet.set_daily_cap(Some(1)), Err(Error::NotOwner));
+            assert_eq!(faucet.pause(), Err(Error::NotOwner));
+        }
+
+        #[ink::test]
+        fn referral_credits_referrer() {
+            let mut faucet = funded_faucet(1000, 0);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert!(faucet.set_referral_settings(250, None).is_ok());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert!(faucet.claim_with_referrer(accounts.bob).is_ok());
+
+            assert_eq!(faucet.claims_of(accounts.charlie), 1);
+            assert_eq!(faucet.referrer_of(accounts.charlie), Some(accounts.bob));
+            assert_eq!(faucet.referrals_of(accounts.bob), 1);
+            assert_eq!(faucet.pending_rewards(accounts.bob), 250);
+
+            // An account can only be referred once
+            assert_eq!(
+                faucet.claim_with_referrer(accounts.django),
+                Err(Error::AlreadyReferred)
+            );
+        }
+
+        #[ink::test]
+        fn self_and_circular_referrals_are_rejected() {
+            let mut faucet = funded_faucet(1000, 0);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert!(faucet.set_referral_settings(250, None).is_ok());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                faucet.claim_with_referrer(accounts.bob),
+                Err(Error::SelfReferral)
+            );
+            assert_eq!(faucet.claims_of(accounts.bob), 0);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert!(faucet.claim_with_referrer(accounts.bob).is_ok());
+
+            // Bob cannot in turn be referred by charlie
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                faucet.claim_with_referrer(accounts.charlie),
+                Err(Error::CircularReferral)
+            );
+        }
+
+        #[ink::test]
+        fn referral_bonus_is_capped_per_referrer() {
+            let mut faucet = funded_faucet(1000, 0);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert!(faucet.set_referral_settings(250, Some(400)).is_ok());
+
+            for referee in [accounts.charlie, accounts.django, accounts.eve] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(referee);
+                assert!(faucet.claim_with_referrer(accounts.bob).is_ok());
+            }
+
+            // The second referral is topped up to the cap, the third earns nothing
+            assert_eq!(faucet.referrals_of(accounts.bob), 3);
+            assert_eq!(faucet.pending_rewards(accounts.bob), 400);
+        }
+
+        #[ink::test]
+        fn claim_referral_rewards_pays_out_and_resets() {
+            let mut faucet = funded_faucet(1000, 0);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert!(faucet.set_referral_settings(250, None).is_ok());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert!(faucet.claim_with_referrer(accounts.bob).is_ok());
+
+            let before = faucet.balance();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim_referral_rewards().is_ok());
+
+            assert_eq!(faucet.pending_rewards(accounts.bob), 0);
+            assert_eq!(faucet.balance(), before - 250);
+            assert_eq!(
+                faucet.claim_referral_rewards(),
+                Err(Error::NoPendingRewards)
+            );
+        }
+
+        #[ink::test]
+        fn claim_for_tracks_recipient() {
+            let mut faucet = funded_faucet(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let recipient = AccountId::from([0x11; 32]);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim_for(recipient).is_ok());
+
+            assert_eq!(
+                ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(recipient),
+                Ok(1000)
+            );
+            assert_eq!(faucet.claims_of(recipient), 1);
+            assert_eq!(faucet.claims_of(accounts.bob), 0);
+            assert_eq!(faucet.relayer_claims_today(accounts.bob), 1);
+
+            // The relayer's own cooldown is untouched, the recipient's has started
+            assert!(faucet.can_claim(accounts.bob));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(recipient);
+            assert_eq!(faucet.claim(), Err(Error::TooSoon));
+        }
+
+        #[ink::test]
+        fn claim_for_respects_relayer_registry() {
+            let mut faucet = funded_faucet(1000, 0);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert!(faucet.set_relayer_settings(true, None).is_ok());
+            assert!(faucet.set_relayer(accounts.charlie, true).is_ok());
+            assert!(faucet.relayers_only());
+            assert!(faucet.is_relayer(accounts.charlie));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(faucet.claim_for(accounts.eve), Err(Error::NotRelayer));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert!(faucet.claim_for(accounts.eve).is_ok());
+        }
+
+        #[ink::test]
+        fn relayer_limit_is_enforced_per_day() {
+            let mut faucet = funded_faucet(1000, 0);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert!(faucet.set_relayer_settings(false, Some(2)).is_ok());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim_for(accounts.charlie).is_ok());
+            assert!(faucet.claim_for(accounts.django).is_ok());
+            assert_eq!(
+                faucet.claim_for(accounts.eve),
+                Err(Error::RelayerLimitReached)
+            );
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(DAY_MS);
+            assert_eq!(faucet.relayer_claims_today(accounts.bob), 0);
+            assert!(faucet.claim_for(accounts.eve).is_ok());
+        }
+
+        #[ink::test]
+        fn extra_assets_have_independent_cooldowns() {
+            let mut faucet = funded_faucet(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert!(faucet
+                .add_asset(1, None, 300, CooldownMode::Blocks(5))
+                .is_ok());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim_asset(0).is_ok());
+            assert!(faucet.claim_asset(1).is_ok());
+            assert_eq!(faucet.claim(), Err(Error::TooSoon));
+            assert_eq!(faucet.claim_asset(1), Err(Error::TooSoon));
+
+            for _ in 0..5 {
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            }
+            assert_eq!(faucet.asset_time_until_claim(1, accounts.bob), 0);
+            assert_eq!(faucet.asset_time_until_claim(0, accounts.bob), 95);
+            assert!(faucet.claim_asset(1).is_ok());
+
+            let asset = faucet.asset(1).unwrap();
+            assert_eq!(asset.total_claimed, 600);
+            assert_eq!(asset.claim_count, 2);
+            // Extra assets do not count towards the legacy totals
+            assert_eq!(faucet.total_claimed(), 1000);
+            assert_eq!(faucet.claim_count(), 1);
+        }
+
+        #[ink::test]
+        fn assets_lists_legacy_and_extra_assets() {
+            let mut faucet = funded_faucet(1000, 100);
+            assert!(faucet
+                .add_asset(7, None, 300, CooldownMode::Seconds(60))
+                .is_ok());
+
+            let assets = faucet.assets();
+            assert_eq!(assets.len(), 2);
+            assert_eq!(assets[0].0, 0);
+            assert_eq!(assets[0].1.drip_amount, 1000);
+            assert_eq!(assets[0].2, 1_000_000);
+            assert_eq!(assets[1].0, 7);
+            assert_eq!(assets[1].1.cooldown, CooldownMode::Seconds(60));
+
+            assert!(faucet.remove_asset(7).is_ok());
+            assert_eq!(faucet.assets().len(), 1);
+            assert_eq!(faucet.asset(7), None);
+        }
+
+        #[ink::test]
+        fn asset_management_errors() {
+            let mut faucet = Faucet::new(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            assert_eq!(
+                faucet.add_asset(0, None, 1, CooldownMode::Blocks(1)),
+                Err(Error::ReservedAsset)
+            );
+            assert_eq!(faucet.remove_asset(0), Err(Error::ReservedAsset));
+            assert_eq!(faucet.remove_asset(3), Err(Error::UnknownAsset));
+            assert_eq!(faucet.claim_asset(3), Err(Error::UnknownAsset));
+
+            assert!(faucet
+                .add_asset(3, None, 1, CooldownMode::Blocks(1))
+                .is_ok());
+            assert_eq!(
+                faucet.add_asset(3, None, 1, CooldownMode::Blocks(1)),
+                Err(Error::AssetExists)
+            );
+
+            for id in 4..(3 + MAX_ASSETS) {
+                assert!(faucet
+                    .add_asset(id, None, 1, CooldownMode::Blocks(1))
+                    .is_ok());
+            }
+            assert_eq!(
+                faucet.add_asset(100, None, 1, CooldownMode::Blocks(1)),
+                Err(Error::TooManyAssets)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                faucet.add_asset(200, None, 1, CooldownMode::Blocks(1)),
+                Err(Error::NotOwner)
+            );
+        }
+
+        #[ink::test]
+        fn recent_claims_pages_newest_first() {
+            let mut faucet = funded_faucet(1000, 0);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            for _ in 0..3 {
+                assert!(faucet.claim().is_ok());
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            }
+
+            let page = faucet.recent_claims(0, 2);
+            assert_eq!(page.len(), 2);
+            assert_eq!(page[0].block, 2);
+            assert_eq!(page[1].block, 1);
+            assert_eq!(page[0].account, accounts.bob);
+            assert_eq!(page[0].amount, 1000);
+            assert_eq!(page[0].asset_id, 0);
+
+            // The last page is short, and paging past the end is empty
+            let page = faucet.recent_claims(2, 2);
+            assert_eq!(page.len(), 1);
+            assert_eq!(page[0].block, 0);
+            assert!(faucet.recent_claims(3, 2).is_empty());
+            assert!(faucet.recent_claims(u32::MAX, u32::MAX).is_empty());
+        }
+
+        #[ink::test]
+        fn history_overwrites_oldest_when_full() {
+            let mut faucet = funded_faucet(1000, 0);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert!(faucet.set_history_capacity(3).is_ok());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            for _ in 0..5 {
+                assert!(faucet.claim().is_ok());
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            }
+
+            // Claims from blocks 0 and 1 have been overwritten
+            let blocks: Vec<BlockNumber> = faucet
+                .recent_claims(0, 10)
+                .iter()
+                .map(|record| record.block)
+                .collect();
+            assert_eq!(blocks, vec![4, 3, 2]);
+
+            let blocks: Vec<BlockNumber> = faucet
+                .claims_by_account(accounts.bob, 0, 10)
+                .iter()
+                .map(|record| record.block)
+                .collect();
+            assert_eq!(blocks, vec![4, 3, 2]);
+        }
+
+        #[ink::test]
+        fn claims_by_account_filters_and_pages() {
+            let mut faucet = funded_faucet(1000, 0);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert!(faucet
+                .add_asset(1, None, 300, CooldownMode::Blocks(0))
+                .is_ok());
+
+            for caller in [accounts.bob, accounts.charlie, accounts.bob] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(caller);
+                assert!(faucet.claim().is_ok());
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim_asset(1).is_ok());
+
+            let bob = faucet.claims_by_account(accounts.bob, 0, 10);
+            assert_eq!(bob.len(), 3);
+            assert_eq!(bob[0].asset_id, 1);
+            assert_eq!(bob[0].amount, 300);
+            assert_eq!(bob[1].block, 2);
+            assert_eq!(bob[2].block, 0);
+
+            let page = faucet.claims_by_account(accounts.bob, 1, 1);
+            assert_eq!(page.len(), 1);
+            assert_eq!(page[0].block, 2);
+            assert!(faucet.claims_by_account(accounts.bob, 3, 10).is_empty());
+            assert!(faucet.claims_by_account(accounts.django, 0, 10).is_empty());
+        }
+
+        #[ink::test]
+        fn history_limit_is_capped_and_resize_clears() {
+            let mut faucet = funded_faucet(1, 0);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            for _ in 0..(MAX_PAGE_SIZE + 5) {
+                assert!(faucet.claim().is_ok());
+            }
+            assert_eq!(
+                faucet.recent_claims(0, u32::MAX).len(),
+                MAX_PAGE_SIZE as usize
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(faucet.set_history_capacity(0), Err(Error::InvalidCapacity));
+            assert_eq!(
+                faucet.set_history_capacity(MAX_HISTORY_CAPACITY + 1),
+                Err(Error::InvalidCapacity)
+            );
+            assert!(faucet.set_history_capacity(10).is_ok());
+            assert_eq!(faucet.history_capacity(), 10);
+            assert!(faucet.recent_claims(0, 10).is_empty());
+            assert!(faucet.claims_by_account(accounts.bob, 0, 10).is_empty());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim().is_ok());
+            assert_eq!(faucet.recent_claims(0, 10).len(), 1);
+            assert_eq!(faucet.claims_by_account(accounts.bob, 0, 10).len(), 1);
+        }
+
+        #[ink::test]
+        fn set_verifier_works() {
+            let mut faucet = Faucet::new(1000, 100);
+            let verifier = AccountId::from([0x0a; 32]);
+            assert_eq!(faucet.verifier(), None);
+
+            assert!(faucet
+                .set_verifier(Some(verifier), IS_VERIFIED_SELECTOR)
+                .is_ok());
+            assert_eq!(faucet.verifier(), Some((verifier, IS_VERIFIED_SELECTOR)));
+
+            assert!(faucet.set_verifier(None, IS_VERIFIED_SELECTOR).is_ok());
+            assert_eq!(faucet.verifier(), None);
+        }
+
+        #[ink::test]
+        fn only_owner_can_set_verifier() {
+            let mut faucet = Faucet::new(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                faucet.set_verifier(None, IS_VERIFIED_SELECTOR),
+                Err(Error::NotOwner)
+            );
+        }
+
+        #[ink::test]
+        fn unset_verifier_is_bypassed() {
+            let mut faucet = funded_faucet(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.can_claim(accounts.bob));
+            assert!(faucet.claim().is_ok());
+        }
+
+        #[ink::test]
+        fn emergency_withdraw_partial_native() {
+            let mut faucet = funded_faucet(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let owner_before =
+                ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.alice)
+                    .unwrap();
+
+            assert!(faucet.emergency_withdraw(None, Some(400_000)).is_ok());
+
+            assert_eq!(faucet.balance(), 600_000);
+            assert_eq!(
+                ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.alice),
+                Ok(owner_before + 400_000)
+            );
+
+            let event = ink::env::test::recorded_events()
+                .last()
+                .expect("no event emitted");
+            let decoded = <EmergencyWithdrawal as ink::scale::Decode>::decode(&mut &event.data[..])
+                .expect("EmergencyWithdrawal decode failed");
+            assert_eq!(decoded.asset, None);
+            assert_eq!(decoded.to, accounts.alice);
+            assert_eq!(decoded.amount, 400_000);
+        }
+
+        #[ink::test]
+        fn emergency_withdraw_all_native() {
+            let mut faucet = funded_faucet(1000, 100);
+
+            assert!(faucet.emergency_withdraw(None, None).is_ok());
+            assert_eq!(faucet.balance(), 0);
+        }
+
+        #[ink::test]
+        fn emergency_withdraw_rejects_excess_and_non_owner() {
+            let mut faucet = funded_faucet(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            assert_eq!(
+                faucet.emergency_withdraw(None, Some(1_000_001)),
+                Err(Error::InsufficientBalance)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(faucet.emergency_withdraw(None, None), Err(Error::NotOwner));
+            assert_eq!(faucet.balance(), 1_000_000);
+        }
+
+        #[ink::test]
+        fn adaptive_drip_tracks_balance() {
+            let mut faucet = funded_faucet(1000, 0);
+            let contract = AccountId::from([0x42; 32]);
+            assert!(faucet.set_adaptive(true, 100).is_ok());
+            assert!(faucet.is_adaptive());
+            assert_eq!(faucet.min_drip(), 100);
+
+            // Plenty left: the full drip
+            assert_eq!(faucet.current_drip(), 1000);
+
+            // 1% of the balance once that is below the drip
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contract, 50_000);
+            assert_eq!(faucet.current_drip(), 500);
+
+            // Never below the floor
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contract, 5_000);
+            assert_eq!(faucet.current_drip(), 100);
+        }
+
+        #[ink::test]
+        fn adaptive_claim_pays_effective_drip() {
+            let mut faucet = funded_faucet(1000, 0);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let contract = AccountId::from([0x42; 32]);
+            assert!(faucet.set_adaptive(true, 100).is_ok());
+
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contract, 50_000);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim().is_ok());
+            assert_eq!(faucet.claimed_amount_of(accounts.bob), 500);
+            assert_eq!(faucet.balance(), 49_500);
+        }
+
+        #[ink::test]
+        fn adaptive_floor_that_cannot_be_covered_fails() {
+            let mut faucet = funded_faucet(1000, 0);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let contract = AccountId::from([0x42; 32]);
+            assert!(faucet.set_adaptive(true, 100).is_ok());
+
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contract, 50);
+            assert_eq!(faucet.current_drip(), 100);
+            assert!(!faucet.can_claim(accounts.bob));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(faucet.claim(), Err(Error::InsufficientBalance));
+        }
+
+        #[ink::test]
+        fn adaptive_is_owner_only_and_off_by_default() {
+            let mut faucet = funded_faucet(1000, 0);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let contract = AccountId::from([0x42; 32]);
+
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contract, 50_000);
+            assert!(!faucet.is_adaptive());
+            assert_eq!(faucet.current_drip(), 1000);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(faucet.set_adaptive(true, 100), Err(Error::NotOwner));
+        }
+
+        fn code_hash(code: &[u8]) -> [u8; 32] {
+            let mut hash = [0u8; 32];
+            ink::env::hash_bytes::<Blake2x256>(code, &mut hash);
+            hash
+        }
+
+        #[ink::test]
+        fn redeem_voucher_works() {
+            let mut faucet = funded_faucet(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert!(faucet
+                .create_vouchers(vec![code_hash(b"workshop-1")], 5000, 1_000)
+                .is_ok());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim().is_ok());
+
+            // Redeemable despite the cooldown
+            assert!(faucet.redeem_voucher(b"workshop-1".to_vec()).is_ok());
+            assert_eq!(faucet.claimed_amount_of(accounts.bob), 6000);
+            assert_eq!(faucet.claim_count(), 2);
+            assert!(faucet.voucher(code_hash(b"workshop-1")).unwrap().redeemed);
+        }
+
+        #[ink::test]
+        fn redeem_voucher_rejects_replay_and_wrong_code() {
+            let mut faucet = funded_faucet(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert!(faucet
+                .create_vouchers(vec![code_hash(b"workshop-1")], 5000, 1_000)
+                .is_ok());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                faucet.redeem_voucher(b"workshop-2".to_vec()),
+                Err(Error::UnknownVoucher)
+            );
+            assert!(faucet.redeem_voucher(b"workshop-1".to_vec()).is_ok());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                faucet.redeem_voucher(b"workshop-1".to_vec()),
+                Err(Error::VoucherUsed)
+            );
+            assert_eq!(faucet.claims_of(accounts.charlie), 0);
+        }
+
+        #[ink::test]
+        fn redeem_voucher_rejects_expired() {
+            let mut faucet = funded_faucet(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert!(faucet
+                .create_vouchers(vec![code_hash(b"workshop-1")], 5000, 1_000)
+                .is_ok());
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_001);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                faucet.redeem_voucher(b"workshop-1".to_vec()),
+                Err(Error::VoucherExpired)
+            );
+            assert!(!faucet.voucher(code_hash(b"workshop-1")).unwrap().redeemed);
+        }
+
+        #[ink::test]
+        fn create_vouchers_validates_batch() {
+            let mut faucet = funded_faucet(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let hash = code_hash(b"workshop-1");
+
+            assert_eq!(
+                faucet.create_vouchers(vec![], 5000, 1_000),
+                Err(Error::InvalidBatchSize)
+            );
+            assert_eq!(
+                faucet.create_vouchers(vec![hash, hash], 5000, 1_000),
+                Err(Error::VoucherExists)
+            );
+            assert_eq!(faucet.voucher(hash), None);
+
+            assert!(faucet.create_vouchers(vec![hash], 5000, 1_000).is_ok());
+            assert_eq!(
+                faucet.create_vouchers(vec![hash], 5000, 1_000),
+                Err(Error::VoucherExists)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                faucet.create_vouchers(vec![code_hash(b"other")], 5000, 1_000),
+                Err(Error::NotOwner)
+            );
+        }
+
+        #[ink::test]
+        fn lowering_cooldown_between_claims_is_consistent() {
+            let mut faucet = funded_faucet(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim().is_ok());
+            for _ in 0..10 {
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            }
+            assert_eq!(faucet.time_until_claim(accounts.bob), 90);
+            assert!(!faucet.can_claim(accounts.bob));
+
+            // Lowering the cooldown below the elapsed blocks frees bob at once
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(faucet
+                .update_settings(None, Some(CooldownMode::Blocks(5)), None, None)
+                .is_ok());
+            assert_eq!(faucet.time_until_claim(accounts.bob), 0);
+            assert!(faucet.can_claim(accounts.bob));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim().is_ok());
+        }
+
+        #[ink::test]
+        fn raising_cooldown_between_claims_is_consistent() {
+            let mut faucet = funded_faucet(1000, 5);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim().is_ok());
+            for _ in 0..10 {
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            }
+            assert!(faucet.can_claim(accounts.bob));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(faucet
+                .update_settings(None, Some(CooldownMode::Blocks(20)), None, None)
+                .is_ok());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(faucet.time_until_claim(accounts.bob), 10);
+            assert!(!faucet.can_claim(accounts.bob));
+            assert_eq!(faucet.claim(), Err(Error::TooSoon));
+        }
+
+        #[ink::test]
+        fn chain_reset_does_not_lock_out_accounts() {
+            let mut faucet = funded_faucet(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_block_number::<ink::env::DefaultEnvironment>(500);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim().is_ok());
+            assert_eq!(faucet.last_claim_block(accounts.bob), Some(500));
+
+            // Dev chain purged: block numbers restart below the last claim
+            ink::env::test::set_block_number::<ink::env::DefaultEnvironment>(3);
+            assert_eq!(faucet.time_until_claim(accounts.bob), 0);
+            assert!(faucet.can_claim(accounts.bob));
+            assert!(faucet.claim().is_ok());
+
+            // The cooldown applies normally from the new claim
+            assert_eq!(faucet.time_until_claim(accounts.bob), 100);
+            assert_eq!(faucet.claim(), Err(Error::TooSoon));
+        }
+
+        #[ink::test]
+        fn timestamp_reset_does_not_lock_out_accounts() {
+            let mut faucet = funded_faucet(1000, 100);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert!(faucet
+                .update_settings(None, Some(CooldownMode::Seconds(60)), None, None)
+                .is_ok());
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000_000);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(faucet.claim().is_ok());
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(10_000);
+            assert!(faucet.can_claim(accounts.bob));
+            assert!(faucet.claim().is_ok());
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use beli_nft::beli_nft::{BeliNft, BeliNftRef};
+        use dalla_token::dalla_token::{DallaToken, DallaTokenRef};
+        use humanity_verifier_mock::humanity_verifier_mock::{
+            HumanityVerifierMock, HumanityVerifierMockRef,
+        };
+        use ink::prelude::string::String;
+        use ink_e2e::ContractsBackend;
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn e2e_claim_psp22_respects_cooldown<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+
+            let mut dalla_constructor = DallaTokenRef::new(1_000_000);
+            let dalla = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut dalla_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+            let mut dalla_call = dalla.call_builder::<DallaToken>();
+
+            let mut faucet_constructor =
+                FaucetRef::new_with_token(100, 1_000, Some(dalla.account_id));
+            let faucet = client
+                .instantiate("faucet", &ink_e2e::alice(), &mut faucet_constructor)
+                .submit()
+                .await
+                .expect("faucet instantiate failed");
+            let mut faucet_call = faucet.call_builder::<Faucet>();
+
+            // Token-mode refill is a plain PSP22 transfer
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &dalla_call.transfer(faucet.account_id, 1_000),
+                )
+                .submit()
+                .await
+                .expect("transfer failed");
+
+            let balance = client
+                .call(&ink_e2e::alice(), &faucet_call.balance())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(balance, 1_000);
+
+            client
+                .call(&ink_e2e::bob(), &faucet_call.claim())
+                .submit()
+                .await
+                .expect("claim failed");
+
+            let claimed = client
+                .call(&ink_e2e::alice(), &dalla_call.balance_of(bob))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(claimed, 100);
+
+            // Second claim lands inside the cooldown
+            let again = client
+                .call(&ink_e2e::bob(), &faucet_call.claim())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(again, Err(Error::TooSoon));
+
+            let balance = client
+                .call(&ink_e2e::alice(), &faucet_call.balance())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(balance, 900);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_nft_holders_get_boosted_drip<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let charlie = ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie);
+
+            let mut nft_constructor =
+                BeliNftRef::new(String::from("Builders"), String::from("BLD"), None, false);
+            let nft = client
+                .instantiate("beli_nft", &ink_e2e::alice(), &mut nft_constructor)
+                .submit()
+                .await
+                .expect("beli_nft instantiate failed");
+            let mut nft_call = nft.call_builder::<BeliNft>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &nft_call.mint(bob, String::from("ipfs://builder")),
+                )
+                .submit()
+                .await
+                .expect("mint failed");
+
+            let mut dalla_constructor = DallaTokenRef::new(1_000_000);
+            let dalla = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut dalla_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+            let mut dalla_call = dalla.call_builder::<DallaToken>();
+
+            let mut faucet_constructor =
+                FaucetRef::new_with_token(100, 1_000, Some(dalla.account_id));
+            let faucet = client
+                .instantiate("faucet", &ink_e2e::alice(), &mut faucet_constructor)
+                .submit()
+                .await
+                .expect("faucet instantiate failed");
+            let mut faucet_call = faucet.call_builder::<Faucet>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &dalla_call.transfer(faucet.account_id, 1_000),
+                )
+                .submit()
+                .await
+                .expect("transfer failed");
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &faucet_call.set_nft_gate(Some(nft.account_id), 2),
+                )
+                .submit()
+                .await
+                .expect("set_nft_gate failed");
+            client
+                .call(&ink_e2e::alice(), &faucet_call.set_allowlist_enabled(true))
+                .submit()
+                .await
+                .expect("set_allowlist_enabled failed");
+
+            // Charlie holds no NFT and is not allowlisted
+            let rejected = client
+                .call(&ink_e2e::charlie(), &faucet_call.claim())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(rejected, Err(Error::NotAllowlisted));
+
+            // Bob's NFT bypasses the allowlist and doubles the drip
+            client
+                .call(&ink_e2e::bob(), &faucet_call.claim())
+                .submit()
+                .await
+                .expect("claim failed");
+
+            let boosted = client
+                .call(&ink_e2e::alice(), &dalla_call.balance_of(bob))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(boosted, 200);
+
+            let untouched = client
+                .call(&ink_e2e::alice(), &dalla_call.balance_of(charlie))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(untouched, 0);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_multi_asset_claims<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+
+            let mut first_constructor = DallaTokenRef::new(1_000_000);
+            let first = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut first_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+            let mut first_call = first.call_builder::<DallaToken>();
+
+            let mut second_constructor = DallaTokenRef::new(1_000_000);
+            let second = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut second_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+            let mut second_call = second.call_builder::<DallaToken>();
+
+            // Asset 0 dispenses native balance
+            let mut faucet_constructor = FaucetRef::new(1_000, 1_000);
+            let faucet = client
+                .instantiate("faucet", &ink_e2e::alice(), &mut faucet_constructor)
+                .value(1_000_000_000_000)
+                .submit()
+                .await
+                .expect("faucet instantiate failed");
+            let mut faucet_call = faucet.call_builder::<Faucet>();
+
+            for (id, token, drip) in [(1, first.account_id, 100), (2, second.account_id, 50)] {
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &faucet_call.add_asset(id, Some(token), drip, CooldownMode::Blocks(1_000)),
+                    )
+                    .submit()
+                    .await
+                    .expect("add_asset failed");
+            }
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &first_call.transfer(faucet.account_id, 1_000),
+                )
+                .submit()
+                .await
+                .expect("transfer failed");
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &second_call.transfer(faucet.account_id, 1_000),
+                )
+                .submit()
+                .await
+                .expect("transfer failed");
+
+            for id in [0, 1, 2] {
+                client
+                    .call(&ink_e2e::bob(), &faucet_call.claim_asset(id))
+                    .submit()
+                    .await
+                    .expect("claim_asset failed");
+            }
+
+            let first_balance = client
+                .call(&ink_e2e::alice(), &first_call.balance_of(bob))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(first_balance, 100);
+
+            let second_balance = client
+                .call(&ink_e2e::alice(), &second_call.balance_of(bob))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(second_balance, 50);
+
+            // Each asset is now on its own cooldown
+            for id in [0, 1, 2] {
+                let again = client
+                    .call(&ink_e2e::bob(), &faucet_call.claim_asset(id))
+                    .dry_run()
+                    .await?
+                    .return_value();
+                assert_eq!(again, Err(Error::TooSoon));
+            }
+
+            let assets = client
+                .call(&ink_e2e::alice(), &faucet_call.assets())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(assets.len(), 3);
+            assert_eq!(assets[1].2, 900);
+            assert_eq!(assets[2].2, 950);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_verifier_gates_claims<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+
+            // Rejects everyone except bob
+            let mut verifier_constructor = HumanityVerifierMockRef::new(false);
+            let verifier = client
+                .instantiate(
+                    "humanity_verifier_mock",
+                    &ink_e2e::alice(),
+                    &mut verifier_constructor,
+                )
+                .submit()
+                .await
+                .expect("humanity_verifier_mock instantiate failed");
+            let mut verifier_call = verifier.call_builder::<HumanityVerifierMock>();
+
+            client
+                .call(&ink_e2e::alice(), &verifier_call.set_verified(bob, true))
+                .submit()
+                .await
+                .expect("set_verified failed");
+
+            let mut faucet_constructor = FaucetRef::new(1_000, 1_000);
+            let faucet = client
+                .instantiate("faucet", &ink_e2e::alice(), &mut faucet_constructor)
+                .value(1_000_000_000_000)
+                .submit()
+                .await
+                .expect("faucet instantiate failed");
+            let mut faucet_call = faucet.call_builder::<Faucet>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &faucet_call.set_verifier(Some(verifier.account_id), IS_VERIFIED_SELECTOR),
+                )
+                .submit()
+                .await
+                .expect("set_verifier failed");
+
+            let rejected = client
+                .call(&ink_e2e::charlie(), &faucet_call.claim())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(rejected, Err(Error::NotVerified));
+
+            client
+                .call(&ink_e2e::bob(), &faucet_call.claim())
+                .submit()
+                .await
+                .expect("claim failed");
+
+            let claims = client
+                .call(&ink_e2e::alice(), &faucet_call.claims_of(bob))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(claims, 1);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_emergency_withdraw_all_tokens<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+
+            let mut dalla_constructor = DallaTokenRef::new(1_000_000);
+            let dalla = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut dalla_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+            let mut dalla_call = dalla.call_builder::<DallaToken>();
+
+            let mut faucet_constructor =
+                FaucetRef::new_with_token(100, 1_000, Some(dalla.account_id));
+            let faucet = client
+                .instantiate("faucet", &ink_e2e::alice(), &mut faucet_constructor)
+                .submit()
+                .await
+                .expect("faucet instantiate failed");
+            let mut faucet_call = faucet.call_builder::<Faucet>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &dalla_call.transfer(faucet.account_id, 5_000),
+                )
+                .submit()
+                .await
+                .expect("transfer failed");
+
+            let before = client
+                .call(&ink_e2e::alice(), &dalla_call.balance_of(alice))
+                .dry_run()
+                .await?
+                .return_value();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &faucet_call.emergency_withdraw(Some(dalla.account_id), None),
+                )
+                .submit()
+                .await
+                .expect("emergency_withdraw failed");
+
+            let after = client
+                .call(&ink_e2e::alice(), &dalla_call.balance_of(alice))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(after, before + 5_000);
+
+            let remaining = client
+                .call(&ink_e2e::alice(), &faucet_call.balance())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(remaining, 0);
+
+            Ok(())
         }
     }
 }
@@ -0,0 +1,49 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// # Humanity Verifier Mock
+///
+/// Test-only contract implementing `HumanityVerifier` that verifies every
+/// account or none, with per-account overrides. Used by the faucet end-to-end
+/// tests for `set_verifier`.
+
+#[path = "../verifier_trait.rs"]
+pub mod verifier_trait;
+
+#[ink::contract]
+pub mod humanity_verifier_mock {
+    use crate::verifier_trait::HumanityVerifier;
+    use ink::storage::Mapping;
+
+    /// Verifier storage
+    #[ink(storage)]
+    pub struct HumanityVerifierMock {
+        /// Answer for accounts without an override
+        verify_all: bool,
+        /// Per-account answers
+        overrides: Mapping<AccountId, bool>,
+    }
+
+    impl HumanityVerifierMock {
+        /// Creates a verifier that accepts or rejects every account
+        #[ink(constructor)]
+        pub fn new(verify_all: bool) -> Self {
+            Self {
+                verify_all,
+                overrides: Mapping::default(),
+            }
+        }
+
+        /// Overrides the answer for one account
+        #[ink(message)]
+        pub fn set_verified(&mut self, account: AccountId, verified: bool) {
+            self.overrides.insert(account, &verified);
+        }
+    }
+
+    impl HumanityVerifier for HumanityVerifierMock {
+        #[ink(message)]
+        fn is_verified(&self, account: AccountId) -> bool {
+            self.overrides.get(account).unwrap_or(self.verify_all)
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! Humanity Verifier Trait Reference
+//!
+//! Interface for attestation contracts (identity NFTs, registries, ...) that the
+//! faucet can consult before dispensing. The faucet calls the verifier through a
+//! configurable selector, so any contract exposing an `AccountId -> bool` query
+//! works; implementing this trait gives the pinned selector below.
+
+use ink::primitives::AccountId;
+
+/// Selector of `HumanityVerifier::is_verified`
+pub const IS_VERIFIED_SELECTOR: [u8; 4] = [0xbc, 0xc7, 0xfc, 0x31];
+
+/// Proof-of-humanity verifier interface
+#[ink::trait_definition]
+pub trait HumanityVerifier {
+    /// Returns whether the account has a valid attestation
+    #[ink(message, selector = 0xbcc7fc31)]
+    fn is_verified(&self, account: AccountId) -> bool;
+}
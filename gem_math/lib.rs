@@ -0,0 +1,186 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # GEM Math
+//!
+//! Integer helpers shared by the GEM contracts:
+//! - **mul_div**: `a * b / denominator` with a 256-bit intermediate product,
+//!   so large reserves don't overflow before the division brings the result
+//!   back into range
+//! - **sqrt**: Integer square root, rounded down
+//!
+//! ## Usage
+//! ```ignore
+//! use gem_math::{mul_div, sqrt};
+//!
+//! let amount_b = mul_div(amount_a, reserve_b, reserve_a).ok_or(Error::Overflow)?;
+//! let liquidity = sqrt(amount0 * amount1);
+//! ```
+
+/// Low 64 bits of a u128
+const LOW_MASK: u128 = u64::MAX as u128;
+
+/// Computes `a * b / denominator`, rounded down
+///
+/// The product is kept at full 256-bit precision, so this only fails when
+/// `denominator` is zero or the final quotient does not fit in a u128.
+pub fn mul_div(a: u128, b: u128, denominator: u128) -> Option<u128> {
+    if denominator == 0 {
+        return None;
+    }
+
+    let (high, low) = full_mul(a, b);
+    if high == 0 {
+        return Some(low / denominator);
+    }
+
+    // The quotient fits in 128 bits only if the high limb is below the divisor
+    if high >= denominator {
+        return None;
+    }
+
+    Some(div_rem_256(high, low, denominator))
+}
+
+/// Integer square root (Babylonian method), rounded down
+pub fn sqrt(y: u128) -> u128 {
+    if y > 3 {
+        let mut z = y;
+        let mut x = y / 2 + 1;
+        while x < z {
+            z = x;
+            x = (y / x + x) / 2;
+        }
+        z
+    } else if y != 0 {
+        1
+    } else {
+        0
+    }
+}
+
+/// Full product of two u128 values as (high, low) 128-bit limbs
+fn full_mul(a: u128, b: u128) -> (u128, u128) {
+    let (a_high, a_low) = (a >> 64, a & LOW_MASK);
+    let (b_high, b_low) = (b >> 64, b & LOW_MASK);
+
+    // Each partial product of two 64-bit halves fits in a u128
+    let low_low = a_low * b_low;
+    let low_high = a_low * b_high;
+    let high_low = a_high * b_low;
+    let high_high = a_high * b_high;
+
+    // Bits 64..128: at most three 64-bit values, so no overflow
+    let middle = (low_low >> 64) + (low_high & LOW_MASK) + (high_low & LOW_MASK);
+
+    let low = (middle << 64) | (low_low & LOW_MASK);
+    let high = high_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64);
+
+    (high, low)
+}
+
+/// Divides the 256-bit value (high, low) by `denominator`
+///
+/// Requires `high < denominator`, which guarantees the quotient fits in a
+/// u128. Plain shift-and-subtract long division over the low limb's bits.
+fn div_rem_256(high: u128, low: u128, denominator: u128) -> u128 {
+    let mut remainder = high;
+    let mut quotient = 0u128;
+
+    for bit in (0..128).rev() {
+        // The remainder is below the divisor, so after shifting it is below
+        // 2 * denominator; a carried-out top bit means it is certainly larger
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((low >> bit) & 1);
+        quotient <<= 1;
+
+        if carry == 1 || remainder >= denominator {
+            remainder = remainder.wrapping_sub(denominator);
+            quotient |= 1;
+        }
+    }
+
+    quotient
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigUint;
+    use proptest::prelude::*;
+
+    /// Reference implementation on arbitrary-precision integers
+    fn reference_mul_div(a: u128, b: u128, denominator: u128) -> Option<u128> {
+        if denominator == 0 {
+            return None;
+        }
+
+        let quotient = BigUint::from(a) * BigUint::from(b) / BigUint::from(denominator);
+        u128::try_from(quotient).ok()
+    }
+
+    fn reference_sqrt(y: u128) -> u128 {
+        u128::try_from(BigUint::from(y).sqrt()).unwrap()
+    }
+
+    #[test]
+    fn mul_div_works() {
+        assert_eq!(mul_div(6, 7, 2), Some(21));
+        assert_eq!(mul_div(10, 10, 3), Some(33));
+        assert_eq!(mul_div(0, u128::MAX, 1), Some(0));
+        assert_eq!(mul_div(1, 1, 0), None);
+    }
+
+    #[test]
+    fn mul_div_survives_overflowing_product() {
+        // 2^100 * 2^100 overflows u128 but the quotient does not
+        let big = 1u128 << 100;
+        assert_eq!(mul_div(big, big, big), Some(big));
+        assert_eq!(mul_div(u128::MAX, u128::MAX, u128::MAX), Some(u128::MAX));
+        assert_eq!(mul_div(u128::MAX, 3, 4), Some(u128::MAX / 4 * 3 + 2));
+    }
+
+    #[test]
+    fn mul_div_rejects_oversized_quotient() {
+        assert_eq!(mul_div(u128::MAX, 2, 1), None);
+        assert_eq!(mul_div(1u128 << 64, 1u128 << 64, 1), None);
+    }
+
+    #[test]
+    fn sqrt_works() {
+        assert_eq!(sqrt(0), 0);
+        assert_eq!(sqrt(1), 1);
+        assert_eq!(sqrt(3), 1);
+        assert_eq!(sqrt(4), 2);
+        assert_eq!(sqrt(99), 9);
+        assert_eq!(sqrt(100), 10);
+        assert_eq!(sqrt(u128::MAX), u64::MAX as u128);
+    }
+
+    proptest! {
+        #[test]
+        fn mul_div_matches_reference(a: u128, b: u128, denominator: u128) {
+            prop_assert_eq!(mul_div(a, b, denominator), reference_mul_div(a, b, denominator));
+        }
+
+        #[test]
+        fn mul_div_matches_reference_near_2_96(
+            a in (1u128 << 90)..(1u128 << 100),
+            b in (1u128 << 90)..(1u128 << 100),
+            denominator in (1u128 << 90)..(1u128 << 100),
+        ) {
+            prop_assert_eq!(mul_div(a, b, denominator), reference_mul_div(a, b, denominator));
+        }
+
+        #[test]
+        fn full_mul_matches_reference(a: u128, b: u128) {
+            let (high, low) = full_mul(a, b);
+            let product = (BigUint::from(high) << 128u32) + BigUint::from(low);
+            prop_assert_eq!(product, BigUint::from(a) * BigUint::from(b));
+        }
+
+        #[test]
+        fn sqrt_matches_reference(y: u128) {
+            prop_assert_eq!(sqrt(y), reference_sqrt(y));
+        }
+    }
+}
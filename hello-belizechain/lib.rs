@@ -5,9 +5,13 @@
 /// The first Gem smart contract - a simple message storage and counter example.
 ///
 /// ## Features
-/// - Store a custom welcome message
+/// - Store a custom welcome message, keeping every past message
 /// - Increment a counter
 /// - Query current state
+/// - Top-visitor leaderboard
+/// - Tip jar (payable messages)
+/// - Per-account greetings
+/// - Owner-only administration via the `access_control` Ownable module
 ///
 /// ## Usage
 /// ```bash
@@ -23,19 +27,62 @@
 /// ```
 
 #[ink::contract]
-mod hello_belizechain {
+pub mod hello_belizechain {
+    use access_control::ownable::OwnableData;
+    use access_control::AccessError;
     use ink::prelude::string::{String, ToString};
+    use ink::prelude::vec::Vec;
     use ink::storage::Mapping;
 
+    /// Number of accounts kept on the visitor leaderboard
+    pub const LEADERBOARD_SIZE: usize = 10;
+
+    /// Maximum length of a message in bytes
+    pub const MAX_MESSAGE_LENGTH: usize = 256;
+
+    /// Maximum records returned by a single history query
+    pub const MAX_PAGE_SIZE: u32 = 50;
+
+    /// A message as it was set, kept in the append-only history
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct MessageRecord {
+        /// Account that set the message
+        pub author: AccountId,
+        /// The message text
+        pub message: String,
+        /// Block timestamp when the message was set
+        pub timestamp: Timestamp,
+    }
+
     /// Storage for our Hello BelizeChain contract
     #[ink(storage)]
     pub struct HelloBelizeChain {
-        /// The welcome message
-        message: String,
+        /// Every message ever set, indexed from 0 (the constructor's message)
+        messages: Mapping<u32, MessageRecord>,
+        /// Number of entries in `messages`
+        message_count: u32,
         /// Global counter tracking all increments
         counter: u32,
         /// Per-account visit counter
         visits: Mapping<AccountId, u32>,
+        /// Top visitors, sorted by visit count descending
+        leaderboard: Vec<(AccountId, u32)>,
+        /// Number of distinct accounts that have visited
+        unique_visitors: u32,
+        /// Cumulative tips per account
+        tips: Mapping<AccountId, Balance>,
+        /// Cumulative tips across all accounts
+        total_tips: Balance,
+        /// Account with the highest cumulative tips, and its total
+        top_tipper: Option<(AccountId, Balance)>,
+        /// Each account's own greeting
+        greetings: Mapping<AccountId, String>,
+        /// Number of accounts with a greeting registered
+        greeting_count: u32,
+        /// Contract owner (the deployer until ownership is transferred)
+        ownable: OwnableData,
     }
 
     /// Events emitted by the contract
@@ -61,29 +108,105 @@ mod hello_belizechain {
         visit_count: u32,
     }
 
+    #[ink(event)]
+    pub struct Tipped {
+        #[ink(topic)]
+        tipper: AccountId,
+        amount: Balance,
+        total_from_tipper: Balance,
+    }
+
+    #[ink(event)]
+    pub struct TipsWithdrawn {
+        #[ink(topic)]
+        to: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct GreetingSet {
+        #[ink(topic)]
+        account: AccountId,
+        greeting: String,
+    }
+
+    #[ink(event)]
+    pub struct GreetingCleared {
+        #[ink(topic)]
+        account: AccountId,
+    }
+
     /// Error types
     #[derive(Debug, PartialEq, Eq)]
     #[ink::scale_derive(Encode, Decode, TypeInfo)]
     pub enum Error {
         /// Message is empty
         EmptyMessage,
+        /// Message exceeds `MAX_MESSAGE_LENGTH` bytes
+        MessageTooLong,
+        /// Tip carries no value
+        ZeroTip,
+        /// Native transfer failed
+        TransferFailed,
+        /// Caller has no greeting registered
+        NoGreeting,
         /// Counter overflow
         CounterOverflow,
+        /// Caller is not the owner
+        NotOwner,
+        /// Caller is not the pending owner
+        NotPendingOwner,
+        /// New owner is the zero address
+        ZeroAddress,
+    }
+
+    impl From<AccessError> for Error {
+        fn from(error: AccessError) -> Self {
+            match error {
+                AccessError::NotPendingOwner => Error::NotPendingOwner,
+                AccessError::ZeroAddress => Error::ZeroAddress,
+                _ => Error::NotOwner,
+            }
+        }
     }
 
     pub type Result<T> = core::result::Result<T, Error>;
 
     impl HelloBelizeChain {
         /// Constructor - initializes with a welcome message
+        ///
+        /// The message becomes entry 0 of the history.
         #[ink(constructor)]
         pub fn new(message: String) -> Self {
             if message.is_empty() {
                 panic!("Message cannot be empty");
             }
+            if message.len() > MAX_MESSAGE_LENGTH {
+                panic!("Message too long");
+            }
+            let caller = Self::env().caller();
+            let mut messages = Mapping::default();
+            messages.insert(
+                0,
+                &MessageRecord {
+                    author: caller,
+                    message,
+                    timestamp: Self::env().block_timestamp(),
+                },
+            );
             Self {
-                message,
+                messages,
+                message_count: 1,
                 counter: 0,
                 visits: Mapping::default(),
+                leaderboard: Vec::new(),
+                unique_visitors: 0,
+                tips: Mapping::default(),
+                total_tips: 0,
+                top_tipper: None,
+                greetings: Mapping::default(),
+                greeting_count: 0,
+                ownable: OwnableData::new(caller),
             }
         }
 
@@ -93,22 +216,60 @@ mod hello_belizechain {
             Self::new("Welcome to BelizeChain 💎".to_string())
         }
 
-        /// Get the current message
+        /// Get the current message (the latest history entry)
         #[ink(message)]
         pub fn get_message(&self) -> String {
-            self.message.clone()
+            self.messages
+                .get(self.message_count.saturating_sub(1))
+                .map(|record| record.message)
+                .unwrap_or_default()
+        }
+
+        /// Get past messages, oldest first
+        ///
+        /// `limit` is capped at `MAX_PAGE_SIZE`.
+        #[ink(message)]
+        pub fn message_history(&self, offset: u32, limit: u32) -> Vec<MessageRecord> {
+            let end = offset
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(self.message_count);
+
+            (offset..end)
+                .filter_map(|index| self.messages.get(index))
+                .collect()
         }
 
-        /// Update the welcome message (only original deployer can update)
+        /// Get the number of messages in the history
+        #[ink(message)]
+        pub fn message_count(&self) -> u32 {
+            self.message_count
+        }
+
+        /// Update the welcome message (owner only)
         #[ink(message)]
         pub fn set_message(&mut self, new_message: String) -> Result<()> {
+            let caller = self.env().caller();
+            self.ownable.ensure_owner(caller)?;
+
             if new_message.is_empty() {
                 return Err(Error::EmptyMessage);
             }
+            if new_message.len() > MAX_MESSAGE_LENGTH {
+                return Err(Error::MessageTooLong);
+            }
+            let index = self.message_count;
+            let message_count = index.checked_add(1).ok_or(Error::CounterOverflow)?;
 
-            let caller = self.env().caller();
-            let old_message = self.message.clone();
-            self.message = new_message.clone();
+            let old_message = self.get_message();
+            self.messages.insert(
+                index,
+                &MessageRecord {
+                    author: caller,
+                    message: new_message.clone(),
+                    timestamp: self.env().block_timestamp(),
+                },
+            );
+            self.message_count = message_count;
 
             self.env().emit_event(MessageUpdated {
                 from: caller,
@@ -124,15 +285,26 @@ mod hello_belizechain {
         pub fn increment(&mut self) -> Result<()> {
             let caller = self.env().caller();
 
-            // Increment global counter
-            self.counter = self.counter.checked_add(1).ok_or(Error::CounterOverflow)?;
-
-            // Record visitor
+            let new_counter = self.counter.checked_add(1).ok_or(Error::CounterOverflow)?;
             let current_visits = self.visits.get(caller).unwrap_or(0);
             let new_visits = current_visits
                 .checked_add(1)
                 .ok_or(Error::CounterOverflow)?;
+            let unique_visitors = if current_visits == 0 {
+                self.unique_visitors
+                    .checked_add(1)
+                    .ok_or(Error::CounterOverflow)?
+            } else {
+                self.unique_visitors
+            };
+
+            // Increment global counter
+            self.counter = new_counter;
+
+            // Record visitor
             self.visits.insert(caller, &new_visits);
+            self.unique_visitors = unique_visitors;
+            self.update_leaderboard(caller, new_visits);
 
             self.env().emit_event(CounterIncremented {
                 from: caller,
@@ -166,10 +338,212 @@ mod hello_belizechain {
             self.get_visits(caller)
         }
 
-        /// Reset the counter to zero (only for demo purposes)
+        /// Get the top visitors, sorted by visit count descending
+        ///
+        /// Holds at most `LEADERBOARD_SIZE` entries. Ties keep the account
+        /// that reached the count first ahead.
+        #[ink(message)]
+        pub fn leaderboard(&self) -> Vec<(AccountId, u32)> {
+            self.leaderboard.clone()
+        }
+
+        /// Get the number of distinct accounts that have visited
         #[ink(message)]
-        pub fn reset(&mut self) {
+        pub fn total_unique_visitors(&self) -> u32 {
+            self.unique_visitors
+        }
+
+        /// Reset the counter to zero (owner only)
+        #[ink(message)]
+        pub fn reset(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            self.ownable.ensure_owner(caller)?;
+
             self.counter = 0;
+            Ok(())
+        }
+
+        /// Tip the contract with the transferred value
+        #[ink(message, payable)]
+        pub fn tip(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            let amount = self.env().transferred_value();
+            if amount == 0 {
+                return Err(Error::ZeroTip);
+            }
+
+            let total_from_tipper = self.tips_of(caller).saturating_add(amount);
+            self.tips.insert(caller, &total_from_tipper);
+            self.total_tips = self.total_tips.saturating_add(amount);
+            if self
+                .top_tipper
+                .is_none_or(|(_, top)| total_from_tipper > top)
+            {
+                self.top_tipper = Some((caller, total_from_tipper));
+            }
+
+            self.env().emit_event(Tipped {
+                tipper: caller,
+                amount,
+                total_from_tipper,
+            });
+
+            Ok(())
+        }
+
+        /// Send the contract's whole balance to `to` (owner only)
+        #[ink(message)]
+        pub fn withdraw_tips(&mut self, to: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            self.ownable.ensure_owner(caller)?;
+
+            let amount = self.env().balance();
+            self.env()
+                .transfer(to, amount)
+                .map_err(|_| Error::TransferFailed)?;
+
+            self.env().emit_event(TipsWithdrawn { to, amount });
+
+            Ok(())
+        }
+
+        /// Get the cumulative tips from an account
+        #[ink(message)]
+        pub fn tips_of(&self, account: AccountId) -> Balance {
+            self.tips.get(account).unwrap_or(0)
+        }
+
+        /// Get the cumulative tips across all accounts
+        #[ink(message)]
+        pub fn total_tips(&self) -> Balance {
+            self.total_tips
+        }
+
+        /// Get the account with the highest cumulative tips, and its total
+        #[ink(message)]
+        pub fn top_tipper(&self) -> Option<(AccountId, Balance)> {
+            self.top_tipper
+        }
+
+        /// Register or replace the caller's greeting
+        ///
+        /// Length is capped at `MAX_MESSAGE_LENGTH` bytes.
+        #[ink(message)]
+        pub fn set_my_greeting(&mut self, text: String) -> Result<()> {
+            if text.is_empty() {
+                return Err(Error::EmptyMessage);
+            }
+            if text.len() > MAX_MESSAGE_LENGTH {
+                return Err(Error::MessageTooLong);
+            }
+
+            let caller = self.env().caller();
+            if !self.greetings.contains(caller) {
+                self.greeting_count = self
+                    .greeting_count
+                    .checked_add(1)
+                    .ok_or(Error::CounterOverflow)?;
+            }
+            self.greetings.insert(caller, &text);
+
+            self.env().emit_event(GreetingSet {
+                account: caller,
+                greeting: text,
+            });
+
+            Ok(())
+        }
+
+        /// Remove the caller's greeting
+        #[ink(message)]
+        pub fn clear_my_greeting(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            if !self.greetings.contains(caller) {
+                return Err(Error::NoGreeting);
+            }
+
+            self.greetings.remove(caller);
+            self.greeting_count = self.greeting_count.saturating_sub(1);
+
+            self.env().emit_event(GreetingCleared { account: caller });
+
+            Ok(())
+        }
+
+        /// Get an account's greeting, if registered
+        #[ink(message)]
+        pub fn get_greeting(&self, account: AccountId) -> Option<String> {
+            self.greetings.get(account)
+        }
+
+        /// Get the number of accounts with a greeting registered
+        #[ink(message)]
+        pub fn greeting_count(&self) -> u32 {
+            self.greeting_count
+        }
+
+        /// Get the current owner
+        #[ink(message)]
+        pub fn owner(&self) -> Option<AccountId> {
+            self.ownable.owner()
+        }
+
+        /// Get the account that may accept ownership, if a transfer is pending
+        #[ink(message)]
+        pub fn pending_owner(&self) -> Option<AccountId> {
+            self.ownable.pending_owner()
+        }
+
+        /// Place `account` on the leaderboard with its new visit count
+        ///
+        /// Visit counts only grow, so an entry never needs to move down: an
+        /// existing entry is updated in place, a newcomer takes a free slot or
+        /// displaces the lowest entry it beats, and either then bubbles up.
+        fn update_leaderboard(&mut self, account: AccountId, visits: u32) {
+            let mut index = match self.leaderboard.iter().position(|(a, _)| *a == account) {
+                Some(index) => index,
+                None if self.leaderboard.len() < LEADERBOARD_SIZE => {
+                    self.leaderboard.push((account, visits));
+                    self.leaderboard.len().saturating_sub(1)
+                }
+                None => match self.leaderboard.last() {
+                    Some(&(_, lowest)) if lowest < visits => {
+                        let last = self.leaderboard.len().saturating_sub(1);
+                        self.leaderboard[last] = (account, visits);
+                        last
+                    }
+                    _ => return,
+                },
+            };
+            self.leaderboard[index].1 = visits;
+
+            while index > 0 && self.leaderboard[index.saturating_sub(1)].1 < visits {
+                self.leaderboard.swap(index, index.saturating_sub(1));
+                index = index.saturating_sub(1);
+            }
+        }
+
+        /// Start transferring ownership to `new_owner` (owner only)
+        ///
+        /// Ownership moves once `new_owner` calls `accept_ownership`.
+        #[ink(message)]
+        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            self.ownable
+                .transfer_ownership::<Environment>(caller, new_owner, |event| {
+                    Self::env().emit_event(event);
+                })?;
+            Ok(())
+        }
+
+        /// Accept a pending ownership transfer (pending owner only)
+        #[ink(message)]
+        pub fn accept_ownership(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            self.ownable.accept_ownership(caller, |event| {
+                Self::env().emit_event(event);
+            })?;
+            Ok(())
         }
     }
 
@@ -215,6 +589,71 @@ mod hello_belizechain {
             );
         }
 
+        #[ink::test]
+        fn constructor_seeds_history() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let contract = HelloBelizeChain::new("Genesis".to_string());
+            assert_eq!(contract.message_count(), 1);
+            assert_eq!(
+                contract.message_history(0, 10),
+                vec![MessageRecord {
+                    author: accounts.alice,
+                    message: "Genesis".to_string(),
+                    timestamp: 0,
+                }]
+            );
+        }
+
+        #[ink::test]
+        fn message_history_keeps_order() {
+            let mut contract = HelloBelizeChain::new("First".to_string());
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1000);
+            assert!(contract.set_message("Second".to_string()).is_ok());
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(2000);
+            assert!(contract.set_message("Third".to_string()).is_ok());
+
+            assert_eq!(contract.get_message(), "Third");
+            assert_eq!(contract.message_count(), 3);
+            let history = contract.message_history(0, 10);
+            let messages: Vec<&str> = history.iter().map(|r| r.message.as_str()).collect();
+            assert_eq!(messages, vec!["First", "Second", "Third"]);
+            let timestamps: Vec<Timestamp> = history.iter().map(|r| r.timestamp).collect();
+            assert_eq!(timestamps, vec![0, 1000, 2000]);
+        }
+
+        #[ink::test]
+        fn message_history_paginates() {
+            let mut contract = HelloBelizeChain::new("0".to_string());
+            for i in 1..60 {
+                assert!(contract.set_message(i.to_string()).is_ok());
+            }
+            assert_eq!(contract.message_count(), 60);
+
+            let page = contract.message_history(2, 3);
+            let messages: Vec<&str> = page.iter().map(|r| r.message.as_str()).collect();
+            assert_eq!(messages, vec!["2", "3", "4"]);
+
+            assert_eq!(
+                contract.message_history(0, 100).len(),
+                MAX_PAGE_SIZE as usize
+            );
+            assert_eq!(contract.message_history(55, 10).len(), 5);
+            assert!(contract.message_history(60, 10).is_empty());
+            assert!(contract.message_history(u32::MAX, u32::MAX).is_empty());
+        }
+
+        #[ink::test]
+        fn message_length_is_capped() {
+            let mut contract = HelloBelizeChain::default();
+            assert!(contract.set_message("a".repeat(MAX_MESSAGE_LENGTH)).is_ok());
+            assert_eq!(
+                contract.set_message("a".repeat(MAX_MESSAGE_LENGTH + 1)),
+                Err(Error::MessageTooLong)
+            );
+            assert_eq!(contract.message_count(), 2);
+            assert_eq!(contract.get_message().len(), MAX_MESSAGE_LENGTH);
+        }
+
         #[ink::test]
         fn visits_tracking_works() {
             let mut contract = HelloBelizeChain::default();
@@ -234,9 +673,329 @@ mod hello_belizechain {
             assert!(contract.increment().is_ok());
             assert_eq!(contract.get_counter(), 2);
 
-            contract.reset();
+            assert!(contract.reset().is_ok());
             assert_eq!(contract.get_counter(), 0);
         }
+
+        #[ink::test]
+        fn leaderboard_orders_by_visits() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut contract = HelloBelizeChain::default();
+            assert!(contract.leaderboard().is_empty());
+
+            for (account, times) in [
+                (accounts.alice, 1),
+                (accounts.bob, 3),
+                (accounts.charlie, 2),
+            ] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(account);
+                for _ in 0..times {
+                    assert!(contract.increment().is_ok());
+                }
+            }
+            assert_eq!(
+                contract.leaderboard(),
+                vec![
+                    (accounts.bob, 3),
+                    (accounts.charlie, 2),
+                    (accounts.alice, 1)
+                ]
+            );
+
+            // Alice overtakes both; ties keep the earlier account ahead
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(contract.increment().is_ok());
+            assert!(contract.increment().is_ok());
+            assert_eq!(
+                contract.leaderboard(),
+                vec![
+                    (accounts.bob, 3),
+                    (accounts.alice, 3),
+                    (accounts.charlie, 2)
+                ]
+            );
+            assert!(contract.increment().is_ok());
+            assert_eq!(
+                contract.leaderboard(),
+                vec![
+                    (accounts.alice, 4),
+                    (accounts.bob, 3),
+                    (accounts.charlie, 2)
+                ]
+            );
+        }
+
+        #[ink::test]
+        fn leaderboard_displaces_lowest_entry() {
+            let mut contract = HelloBelizeChain::default();
+
+            // Fill the board: account i visits i + 1 times
+            for i in 0..LEADERBOARD_SIZE {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from(
+                    [i as u8 + 1; 32],
+                ));
+                for _ in 0..=i {
+                    assert!(contract.increment().is_ok());
+                }
+            }
+            let board = contract.leaderboard();
+            assert_eq!(board.len(), LEADERBOARD_SIZE);
+            assert_eq!(board[LEADERBOARD_SIZE - 1], (AccountId::from([1u8; 32]), 1));
+
+            // A newcomer with a single visit only ties the lowest and stays off
+            let newcomer = AccountId::from([0xAA; 32]);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(newcomer);
+            assert!(contract.increment().is_ok());
+            assert!(!contract.leaderboard().iter().any(|(a, _)| *a == newcomer));
+
+            // A second visit beats the lowest entry and displaces it
+            assert!(contract.increment().is_ok());
+            let board = contract.leaderboard();
+            assert_eq!(board.len(), LEADERBOARD_SIZE);
+            assert!(!board.iter().any(|(a, _)| *a == AccountId::from([1u8; 32])));
+            assert_eq!(board[LEADERBOARD_SIZE - 2], (AccountId::from([2u8; 32]), 2));
+            assert_eq!(board[LEADERBOARD_SIZE - 1], (newcomer, 2));
+            assert!(board.windows(2).all(|w| w[0].1 >= w[1].1));
+        }
+
+        #[ink::test]
+        fn unique_visitors_counted_once() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut contract = HelloBelizeChain::default();
+            assert_eq!(contract.total_unique_visitors(), 0);
+
+            assert!(contract.increment().is_ok());
+            assert!(contract.increment().is_ok());
+            assert_eq!(contract.total_unique_visitors(), 1);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(contract.increment().is_ok());
+            assert_eq!(contract.total_unique_visitors(), 2);
+
+            // Resetting the counter does not forget visitors
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(contract.reset().is_ok());
+            assert!(contract.increment().is_ok());
+            assert_eq!(contract.total_unique_visitors(), 2);
+        }
+
+        #[ink::test]
+        fn tip_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut contract = HelloBelizeChain::default();
+            assert_eq!(contract.top_tipper(), None);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(100);
+            assert!(contract.tip().is_ok());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(150);
+            assert!(contract.tip().is_ok());
+            assert_eq!(contract.top_tipper(), Some((accounts.charlie, 150)));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(60);
+            assert!(contract.tip().is_ok());
+
+            assert_eq!(contract.tips_of(accounts.bob), 160);
+            assert_eq!(contract.tips_of(accounts.charlie), 150);
+            assert_eq!(contract.tips_of(accounts.django), 0);
+            assert_eq!(contract.total_tips(), 310);
+            assert_eq!(contract.top_tipper(), Some((accounts.bob, 160)));
+
+            let emitted = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let last = emitted.last().expect("tip event");
+            let event =
+                <Tipped as ink::scale::Decode>::decode(&mut &last.data[..]).expect("decode Tipped");
+            assert_eq!(event.tipper, accounts.bob);
+            assert_eq!(event.amount, 60);
+            assert_eq!(event.total_from_tipper, 160);
+        }
+
+        #[ink::test]
+        fn zero_tip_rejected() {
+            let mut contract = HelloBelizeChain::default();
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+            assert_eq!(contract.tip(), Err(Error::ZeroTip));
+            assert_eq!(contract.total_tips(), 0);
+            assert_eq!(contract.top_tipper(), None);
+        }
+
+        #[ink::test]
+        fn withdraw_tips_requires_owner() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut contract = HelloBelizeChain::default();
+            let contract_id = ink::env::test::callee::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contract_id, 500);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.withdraw_tips(accounts.bob), Err(Error::NotOwner));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(contract.withdraw_tips(accounts.django).is_ok());
+            assert_eq!(
+                ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(
+                    accounts.django
+                ),
+                Ok(500)
+            );
+            assert_eq!(
+                ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(contract_id),
+                Ok(0)
+            );
+        }
+
+        #[ink::test]
+        fn greeting_set_overwrite_clear() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut contract = HelloBelizeChain::default();
+            assert_eq!(contract.get_greeting(accounts.alice), None);
+
+            assert!(contract.set_my_greeting("Hi".to_string()).is_ok());
+            assert_eq!(
+                contract.get_greeting(accounts.alice),
+                Some("Hi".to_string())
+            );
+            assert_eq!(contract.greeting_count(), 1);
+
+            assert!(contract.set_my_greeting("Hello again".to_string()).is_ok());
+            assert_eq!(
+                contract.get_greeting(accounts.alice),
+                Some("Hello again".to_string())
+            );
+            assert_eq!(contract.greeting_count(), 1);
+
+            assert!(contract.clear_my_greeting().is_ok());
+            assert_eq!(contract.get_greeting(accounts.alice), None);
+            assert_eq!(contract.greeting_count(), 0);
+            assert_eq!(contract.clear_my_greeting(), Err(Error::NoGreeting));
+            assert_eq!(contract.greeting_count(), 0);
+
+            // The global message is untouched
+            assert_eq!(contract.get_message(), "Welcome to BelizeChain 💎");
+        }
+
+        #[ink::test]
+        fn greeting_validation() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut contract = HelloBelizeChain::default();
+
+            assert_eq!(
+                contract.set_my_greeting(String::new()),
+                Err(Error::EmptyMessage)
+            );
+            assert_eq!(
+                contract.set_my_greeting("a".repeat(MAX_MESSAGE_LENGTH + 1)),
+                Err(Error::MessageTooLong)
+            );
+            assert_eq!(contract.get_greeting(accounts.alice), None);
+            assert_eq!(contract.greeting_count(), 0);
+
+            assert!(contract
+                .set_my_greeting("a".repeat(MAX_MESSAGE_LENGTH))
+                .is_ok());
+        }
+
+        #[ink::test]
+        fn greetings_are_per_account() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut contract = HelloBelizeChain::default();
+
+            assert!(contract.set_my_greeting("From Alice".to_string()).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(contract.set_my_greeting("From Bob".to_string()).is_ok());
+            assert_eq!(contract.greeting_count(), 2);
+
+            // Non-owners may set greetings but not the global message
+            assert_eq!(
+                contract.set_message("From Bob".to_string()),
+                Err(Error::NotOwner)
+            );
+
+            assert!(contract.clear_my_greeting().is_ok());
+            assert_eq!(contract.get_greeting(accounts.bob), None);
+            assert_eq!(
+                contract.get_greeting(accounts.alice),
+                Some("From Alice".to_string())
+            );
+            assert_eq!(contract.greeting_count(), 1);
+
+            let emitted = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let last = emitted.last().expect("clear event");
+            let event = <GreetingCleared as ink::scale::Decode>::decode(&mut &last.data[..])
+                .expect("decode GreetingCleared");
+            assert_eq!(event.account, accounts.bob);
+        }
+
+        #[ink::test]
+        fn deployer_is_owner() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let contract = HelloBelizeChain::default();
+            assert_eq!(contract.owner(), Some(accounts.alice));
+            assert_eq!(contract.pending_owner(), None);
+        }
+
+        #[ink::test]
+        fn non_owner_cannot_set_message_or_reset() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut contract = HelloBelizeChain::default();
+            assert!(contract.increment().is_ok());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                contract.set_message("Hijacked".to_string()),
+                Err(Error::NotOwner)
+            );
+            assert_eq!(contract.reset(), Err(Error::NotOwner));
+            assert_eq!(contract.get_message(), "Welcome to BelizeChain 💎");
+            assert_eq!(contract.get_counter(), 1);
+        }
+
+        #[ink::test]
+        fn ownership_transfer_flips_permissions() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut contract = HelloBelizeChain::default();
+
+            assert!(contract.transfer_ownership(accounts.bob).is_ok());
+            assert_eq!(contract.pending_owner(), Some(accounts.bob));
+            assert_eq!(contract.owner(), Some(accounts.alice));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(contract.accept_ownership(), Err(Error::NotPendingOwner));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(contract.accept_ownership().is_ok());
+            assert_eq!(contract.owner(), Some(accounts.bob));
+            assert!(contract.set_message("Bob's message".to_string()).is_ok());
+            assert!(contract.reset().is_ok());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(
+                contract.set_message("Alice again".to_string()),
+                Err(Error::NotOwner)
+            );
+            assert_eq!(contract.reset(), Err(Error::NotOwner));
+            assert_eq!(contract.get_message(), "Bob's message");
+        }
+
+        #[ink::test]
+        fn transfer_ownership_rejects_non_owner_and_zero_address() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut contract = HelloBelizeChain::default();
+
+            assert_eq!(
+                contract.transfer_ownership(AccountId::from([0u8; 32])),
+                Err(Error::ZeroAddress)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                contract.transfer_ownership(accounts.bob),
+                Err(Error::NotOwner)
+            );
+            assert_eq!(contract.pending_owner(), None);
+        }
     }
 
     #[cfg(all(test, feature = "e2e-tests"))]
@@ -0,0 +1,1138 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+//! # NFT Marketplace
+//!
+//! First-party escrow marketplace for BeliNFT (PSP34) tokens and PSP37
+//! id/amount bundles, priced in DALLA.
+//!
+//! ## Features
+//! - Listed tokens are held in escrow until bought or cancelled
+//! - `buy` pulls DALLA from the buyer with `transfer_from`, paying the
+//!   protocol fee to the treasury, the collection's royalty (when it exposes
+//!   `royalty_info`) and the rest to the seller
+//! - Only collections registered by the owner can be listed, so a contract
+//!   faking its transfers can't take buyers' DALLA
+//!
+//! ## Usage
+//! ```ignore
+//! // Seller: approve the marketplace on the collection, then list
+//! nft.approve(marketplace, token_id)?;
+//! let listing_id = marketplace.list(nft, Id::U32(token_id), 1, price)?;
+//!
+//! // Buyer: approve the price in DALLA, then buy
+//! dalla.approve(marketplace, price)?;
+//! marketplace.buy(listing_id)?;
+//! ```
+
+#[path = "../dex/psp22_trait.rs"]
+pub mod psp22_trait;
+
+#[path = "../beli_nft/psp34_trait.rs"]
+pub mod psp34_trait;
+
+#[ink::contract]
+pub mod nft_marketplace {
+    use crate::psp22_trait::{ErrorPayload, PSP22Error, PSP22};
+    use crate::psp34_trait::{Id, PSP34};
+    use access_control::ownable::OwnableData;
+    use access_control::AccessError;
+    use ink::codegen::TraitCallBuilder;
+    use ink::env::call::{build_call, ExecutionInput, Selector};
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
+
+    /// Highest protocol fee the owner can set (1000 = 10%)
+    pub const MAX_FEE_BPS: u16 = 1_000;
+
+    /// `PSP37::transfer_from(from, to, token_id, value)`
+    pub const PSP37_TRANSFER_FROM: [u8; 4] = [0x0b, 0x39, 0x6f, 0x18];
+
+    /// `PSP37::transfer(to, token_id, value)`
+    pub const PSP37_TRANSFER: [u8; 4] = [0x84, 0xa1, 0x5d, 0xa1];
+
+    /// `royalty_info(id, sale_price) -> (receiver, amount)` as exposed by BeliNFT
+    pub const ROYALTY_INFO: [u8; 4] = [0x1c, 0xd7, 0xb1, 0xc8];
+
+    /// Unique identifier of a listing
+    pub type ListingId = u64;
+
+    // ============================================================================
+    // Types
+    // ============================================================================
+
+    /// Token standard of a registered collection
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum Standard {
+        /// Single NFTs moved with `PSP34::transfer` (e.g. BeliNFT)
+        Psp34,
+        /// Multi-token bundles moved with `transfer_from`/`transfer`
+        Psp37,
+    }
+
+    /// Tokens held in escrow for sale
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Listing {
+        /// Account that listed the tokens and receives the proceeds
+        pub seller: AccountId,
+        /// Collection contract
+        pub collection: AccountId,
+        /// Standard of the collection at listing time
+        pub standard: Standard,
+        /// Token id (`Id::U128` for PSP37 collections)
+        pub id: Id,
+        /// Number of tokens (always 1 for PSP34)
+        pub amount: u128,
+        /// Price in DALLA for the whole listing
+        pub price: Balance,
+    }
+
+    // ============================================================================
+    // Storage
+    // ============================================================================
+
+    #[ink(storage)]
+    pub struct NftMarketplace {
+        // Owner managing collections and the protocol fee
+        ownable: OwnableData,
+
+        // Token listings are priced in (DALLA)
+        payment_token: AccountId,
+
+        // Recipient of the protocol fee
+        treasury: AccountId,
+
+        // Protocol fee in basis points of the sale price
+        fee_bps: u16,
+
+        // Collections that can be listed, by standard
+        collections: Mapping<AccountId, Standard>,
+
+        // Open listings
+        listings: Mapping<ListingId, Listing>,
+
+        // Id of the next listing
+        next_listing_id: ListingId,
+    }
+
+    // ============================================================================
+    // Events
+    // ============================================================================
+
+    #[ink(event)]
+    pub struct Listed {
+        #[ink(topic)]
+        listing_id: ListingId,
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        collection: AccountId,
+        id: Id,
+        amount: u128,
+        price: Balance,
+    }
+
+    #[ink(event)]
+    pub struct ListingCancelled {
+        #[ink(topic)]
+        listing_id: ListingId,
+        #[ink(topic)]
+        seller: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct Sold {
+        #[ink(topic)]
+        listing_id: ListingId,
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        seller: AccountId,
+        price: Balance,
+        royalty: Balance,
+        fee: Balance,
+    }
+
+    #[ink(event)]
+    pub struct CollectionSet {
+        #[ink(topic)]
+        collection: AccountId,
+        standard: Option<Standard>,
+    }
+
+    #[ink(event)]
+    pub struct ProtocolFeeSet {
+        treasury: AccountId,
+        fee_bps: u16,
+    }
+
+    // ============================================================================
+    // Errors
+    // ============================================================================
+
+    #[derive(Debug, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub enum Error {
+        /// Listing does not exist (never created, sold or cancelled)
+        ListingNotFound,
+        /// Caller is not the listing's seller
+        NotSeller,
+        /// Collection is not registered with the marketplace
+        UnsupportedCollection,
+        /// Amount is zero, or not 1 for a PSP34 token
+        InvalidAmount,
+        /// Id variant not usable with the collection's standard
+        InvalidId,
+        /// Protocol fee above MAX_FEE_BPS
+        FeeTooHigh,
+        /// Collection rejected the token transfer
+        CollectionError,
+        /// Payment token rejected the transfer
+        TokenError(PSP22Error),
+        /// Cross-contract call failed
+        CallFailed,
+        /// Caller is not the owner
+        NotOwner,
+        /// Caller is not the pending owner
+        NotPendingOwner,
+        /// Zero address provided
+        ZeroAddress,
+        /// Caller does not own the PSP34 token being listed
+        NotTokenOwner,
+    }
+
+    impl From<AccessError> for Error {
+        fn from(error: AccessError) -> Self {
+            match error {
+                AccessError::NotPendingOwner => Error::NotPendingOwner,
+                AccessError::ZeroAddress => Error::ZeroAddress,
+                _ => Error::NotOwner,
+            }
+        }
+    }
+
+    pub type Result<T> = core::result::Result<T, Error>;
+
+    // ============================================================================
+    // Implementation
+    // ============================================================================
+
+    impl NftMarketplace {
+        // ========================================================================
+        // Constructor
+        // ========================================================================
+
+        /// Create a marketplace
+        ///
+        /// # Parameters
+        /// * `payment_token` - PSP22 token listings are priced in (DALLA)
+        /// * `treasury` - Recipient of the protocol fee
+        /// * `fee_bps` - Protocol fee in basis points, at most `MAX_FEE_BPS`
+        #[ink(constructor)]
+        pub fn new(payment_token: AccountId, treasury: AccountId, fee_bps: u16) -> Self {
+            Self {
+                ownable: OwnableData::new(Self::env().caller()),
+                payment_token,
+                treasury,
+                fee_bps: fee_bps.min(MAX_FEE_BPS),
+                collections: Mapping::default(),
+                listings: Mapping::default(),
+                next_listing_id: 0,
+            }
+        }
+
+        // ========================================================================
+        // View Functions
+        // ========================================================================
+
+        /// Get the token listings are priced in
+        #[ink(message)]
+        pub fn payment_token(&self) -> AccountId {
+            self.payment_token
+        }
+
+        /// Get the protocol fee recipient
+        #[ink(message)]
+        pub fn treasury(&self) -> AccountId {
+            self.treasury
+        }
+
+        /// Get the protocol fee in basis points
+        #[ink(message)]
+        pub fn fee_bps(&self) -> u16 {
+            self.fee_bps
+        }
+
+        /// Get the standard of a registered collection
+        #[ink(message)]
+        pub fn collection_standard(&self, collection: AccountId) -> Option<Standard> {
+            self.collections.get(collection)
+        }
+
+        /// Get an open listing
+        #[ink(message)]
+        pub fn listing(&self, listing_id: ListingId) -> Option<Listing> {
+            self.listings.get(listing_id)
+        }
+
+        /// Get the id the next listing will receive
+        #[ink(message)]
+        pub fn next_listing_id(&self) -> ListingId {
+            self.next_listing_id
+        }
+
+        /// Get the current owner
+        #[ink(message)]
+        pub fn owner(&self) -> Option<AccountId> {
+            self.ownable.owner()
+        }
+
+        /// Get the account that may accept ownership, if a transfer is pending
+        #[ink(message)]
+        pub fn pending_owner(&self) -> Option<AccountId> {
+            self.ownable.pending_owner()
+        }
+
+        // ========================================================================
+        // Trading Functions
+        // ========================================================================
+
+        /// List tokens for sale, moving them into escrow
+        ///
+        /// The caller must first approve the marketplace on the collection.
+        /// A PSP34 token can only be listed by its current owner.
+        ///
+        /// # Parameters
+        /// * `collection` - Registered collection contract
+        /// * `id` - Token id (`Id::U128` for PSP37 collections)
+        /// * `amount` - Number of tokens (1 for PSP34)
+        /// * `price` - Price in the payment token for the whole listing
+        ///
+        /// # Returns
+        /// Id of the new listing
+        #[ink(message)]
+        pub fn list(
+            &mut self,
+            collection: AccountId,
+            id: Id,
+            amount: u128,
+            price: Balance,
+        ) -> Result<ListingId> {
+            let seller = self.env().caller();
+            let standard = self
+                .collections
+                .get(collection)
+                .ok_or(Error::UnsupportedCollection)?;
+            Self::_validate(standard, &id, amount)?;
+
+            let listing_id = self.next_listing_id;
+            self.next_listing_id = listing_id.saturating_add(1);
+
+            let listing = Listing {
+                seller,
+                collection,
+                standard,
+                id: id.clone(),
+                amount,
+                price,
+            };
+            self._escrow_in(&listing)?;
+            self.listings.insert(listing_id, &listing);
+
+            self.env().emit_event(Listed {
+                listing_id,
+                seller,
+                collection,
+                id,
+                amount,
+                price,
+            });
+
+            Ok(listing_id)
+        }
+
+        /// Cancel a listing and return the tokens to the seller (seller only)
+        #[ink(message)]
+        pub fn cancel(&mut self, listing_id: ListingId) -> Result<()> {
+            let listing = self
+                .listings
+                .get(listing_id)
+                .ok_or(Error::ListingNotFound)?;
+            if self.env().caller() != listing.seller {
+                return Err(Error::NotSeller);
+            }
+
+            self.listings.remove(listing_id);
+            self._escrow_out(&listing, listing.seller)?;
+
+            self.env().emit_event(ListingCancelled {
+                listing_id,
+                seller: listing.seller,
+            });
+
+            Ok(())
+        }
+
+        /// Buy a listing
+        ///
+        /// The caller must first approve `price` of the payment token to the
+        /// marketplace. The price is split between the treasury (protocol
+        /// fee), the collection's royalty receiver and the seller.
+        #[ink(message)]
+        pub fn buy(&mut self, listing_id: ListingId) -> Result<()> {
+            let buyer = self.env().caller();
+            let listing = self
+                .listings
+                .get(listing_id)
+                .ok_or(Error::ListingNotFound)?;
+            self.listings.remove(listing_id);
+
+            let royalty_info = self._royalty_info(&listing);
+            let (fee, royalty, proceeds) = Self::_split(
+                listing.price,
+                self.fee_bps,
+                royalty_info.map_or(0, |(_, amount)| amount),
+            );
+
+            self._pay(buyer, self.treasury, fee)?;
+            if let Some((receiver, _)) = royalty_info {
+                self._pay(buyer, receiver, royalty)?;
+            }
+            self._pay(buyer, listing.seller, proceeds)?;
+
+            self._escrow_out(&listing, buyer)?;
+
+            self.env().emit_event(Sold {
+                listing_id,
+                buyer,
+                seller: listing.seller,
+                price: listing.price,
+                royalty,
+                fee,
+            });
+
+            Ok(())
+        }
+
+        // ========================================================================
+        // Admin Functions
+        // ========================================================================
+
+        /// Register a collection, or unregister it with `None` (owner only)
+        ///
+        /// Unregistering only blocks new listings; open ones can still be
+        /// bought or cancelled.
+        #[ink(message)]
+        pub fn set_collection(
+            &mut self,
+            collection: AccountId,
+            standard: Option<Standard>,
+        ) -> Result<()> {
+            self.ownable.ensure_owner(self.env().caller())?;
+
+            if let Some(standard) = standard {
+                self.collections.insert(collection, &standard);
+            } else {
+                self.collections.remove(collection);
+            }
+
+            self.env().emit_event(CollectionSet {
+                collection,
+                standard,
+            });
+
+            Ok(())
+        }
+
+        /// Set the protocol fee and its recipient (owner only)
+        #[ink(message)]
+        pub fn set_protocol_fee(&mut self, treasury: AccountId, fee_bps: u16) -> Result<()> {
+            self.ownable.ensure_owner(self.env().caller())?;
+
+            if fee_bps > MAX_FEE_BPS {
+                return Err(Error::FeeTooHigh);
+            }
+            if treasury == AccountId::from([0u8; 32]) {
+                return Err(Error::ZeroAddress);
+            }
+
+            self.treasury = treasury;
+            self.fee_bps = fee_bps;
+
+            self.env().emit_event(ProtocolFeeSet { treasury, fee_bps });
+
+            Ok(())
+        }
+
+        /// Start transferring ownership to `new_owner` (owner only)
+        ///
+        /// Ownership moves once `new_owner` calls `accept_ownership`.
+        #[ink(message)]
+        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            self.ownable
+                .transfer_ownership::<Environment>(caller, new_owner, |event| {
+                    Self::env().emit_event(event);
+                })?;
+            Ok(())
+        }
+
+        /// Accept a pending ownership transfer (pending owner only)
+        #[ink(message)]
+        pub fn accept_ownership(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            self.ownable.accept_ownership(caller, |event| {
+                Self::env().emit_event(event);
+            })?;
+            Ok(())
+        }
+
+        // ========================================================================
+        // Internal Functions
+        // ========================================================================
+
+        /// Check the id and amount fit the collection's standard
+        fn _validate(standard: Standard, id: &Id, amount: u128) -> Result<()> {
+            match standard {
+                Standard::Psp34 if amount != 1 => Err(Error::InvalidAmount),
+                Standard::Psp37 if amount == 0 => Err(Error::InvalidAmount),
+                Standard::Psp37 if !matches!(id, Id::U128(_)) => Err(Error::InvalidId),
+                _ => Ok(()),
+            }
+        }
+
+        /// Split a sale price into (protocol fee, royalty, seller proceeds)
+        ///
+        /// The royalty is capped at what is left after the fee, so a
+        /// collection reporting an oversized royalty can't fail the sale.
+        fn _split(price: Balance, fee_bps: u16, royalty: Balance) -> (Balance, Balance, Balance) {
+            // price * fee_bps / 10_000, split so the product can't overflow
+            let fee_bps = u128::from(fee_bps);
+            let fee = price / 10_000 * fee_bps + price % 10_000 * fee_bps / 10_000;
+            let royalty = royalty.min(price.saturating_sub(fee));
+            let proceeds = price.saturating_sub(fee).saturating_sub(royalty);
+
+            (fee, royalty, proceeds)
+        }
+
+        /// Royalty receiver and amount for a listing, if the collection has one
+        ///
+        /// Collections without a `royalty_info` message (PSP37, third-party
+        /// PSP34) simply pay no royalty.
+        fn _royalty_info(&self, listing: &Listing) -> Option<(AccountId, Balance)> {
+            let Id::U32(token_id) = listing.id else {
+                return None;
+            };
+
+            let result = build_call::<Environment>()
+                .call(listing.collection)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ROYALTY_INFO))
+                        .push_arg(token_id)
+                        .push_arg(listing.price),
+                )
+                .returns::<(AccountId, Balance)>()
+                .try_invoke();
+
+            match result {
+                Ok(Ok((receiver, amount))) if amount > 0 => Some((receiver, amount)),
+                _ => None,
+            }
+        }
+
+        /// Pull `amount` of the payment token from `from` to `to`
+        fn _pay(&self, from: AccountId, to: AccountId, amount: Balance) -> Result<()> {
+            if amount == 0 {
+                return Ok(());
+            }
+
+            let mut token: ink::contract_ref!(PSP22) = self.payment_token.into();
+            match token
+                .call_mut()
+                .transfer_from(from, to, amount)
+                .try_invoke()
+            {
+                Ok(Ok(Ok(()))) => Ok(()),
+                Ok(Ok(Err(e))) => Err(Error::TokenError(e)),
+                _ => Err(Error::CallFailed),
+            }
+        }
+
+        /// Move a listing's tokens from the seller into escrow
+        fn _escrow_in(&self, listing: &Listing) -> Result<()> {
+            let marketplace = self.env().account_id();
+            match listing.standard {
+                // An approved operator's transfer moves the owner's token, so
+                // make sure that owner is the seller and not someone else who
+                // approved the marketplace
+                Standard::Psp34 => {
+                    if self._psp34_owner_of(listing)? != Some(listing.seller) {
+                        return Err(Error::NotTokenOwner);
+                    }
+                    self._psp34_transfer(listing, marketplace)
+                }
+                Standard::Psp37 => self._psp37_call(
+                    listing.collection,
+                    ExecutionInput::new(Selector::new(PSP37_TRANSFER_FROM))
+                        .push_arg(listing.seller)
+                        .push_arg(marketplace)
+                        .push_arg(Self::_psp37_id(&listing.id)?)
+                        .push_arg(listing.amount),
+                ),
+            }
+        }
+
+        /// Release a listing's tokens from escrow to `to`
+        fn _escrow_out(&self, listing: &Listing, to: AccountId) -> Result<()> {
+            match listing.standard {
+                Standard::Psp34 => self._psp34_transfer(listing, to),
+                Standard::Psp37 => self._psp37_call(
+                    listing.collection,
+                    ExecutionInput::new(Selector::new(PSP37_TRANSFER))
+                        .push_arg(to)
+                        .push_arg(Self::_psp37_id(&listing.id)?)
+                        .push_arg(listing.amount),
+                ),
+            }
+        }
+
+        /// `PSP34::owner_of` of a listing's token
+        fn _psp34_owner_of(&self, listing: &Listing) -> Result<Option<AccountId>> {
+            let collection: ink::contract_ref!(PSP34) = listing.collection.into();
+            collection
+                .call()
+                .owner_of(listing.id.clone())
+                .try_invoke()
+                .map_err(|_| Error::CallFailed)?
+                .map_err(|_| Error::CallFailed)
+        }
+
+        /// `PSP34::transfer` of a listing's token to `to`
+        fn _psp34_transfer(&self, listing: &Listing, to: AccountId) -> Result<()> {
+            let mut collection: ink::contract_ref!(PSP34) = listing.collection.into();
+            match collection
+                .call_mut()
+                .transfer(to, listing.id.clone(), Vec::new())
+                .try_invoke()
+            {
+                Ok(Ok(Ok(()))) => Ok(()),
+                Ok(Ok(Err(_))) => Err(Error::CollectionError),
+                _ => Err(Error::CallFailed),
+            }
+        }
+
+        /// Call a PSP37 transfer message
+        ///
+        /// PSP37 collections report their own error enum, so the payload is
+        /// left undecoded.
+        fn _psp37_call<Args: ink::scale::Encode>(
+            &self,
+            collection: AccountId,
+            input: ExecutionInput<Args>,
+        ) -> Result<()> {
+            let result = build_call::<Environment>()
+                .call(collection)
+                .exec_input(input)
+                .returns::<core::result::Result<(), ErrorPayload>>()
+                .try_invoke();
+
+            match result {
+                Ok(Ok(Ok(()))) => Ok(()),
+                Ok(Ok(Err(_))) => Err(Error::CollectionError),
+                _ => Err(Error::CallFailed),
+            }
+        }
+
+        /// PSP37 token id of a listing
+        fn _psp37_id(id: &Id) -> Result<u128> {
+            match id {
+                Id::U128(token_id) => Ok(*token_id),
+                _ => Err(Error::InvalidId),
+            }
+        }
+    }
+
+    // ============================================================================
+    // Tests
+    // ============================================================================
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn default_accounts() -> ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment> {
+            ink::env::test::default_accounts::<ink::env::DefaultEnvironment>()
+        }
+
+        fn set_caller(account: AccountId) {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(account);
+        }
+
+        fn new_marketplace() -> NftMarketplace {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+            NftMarketplace::new(accounts.django, accounts.charlie, 250)
+        }
+
+        #[ink::test]
+        fn new_works() {
+            let accounts = default_accounts();
+            let marketplace = new_marketplace();
+
+            assert_eq!(marketplace.payment_token(), accounts.django);
+            assert_eq!(marketplace.treasury(), accounts.charlie);
+            assert_eq!(marketplace.fee_bps(), 250);
+            assert_eq!(marketplace.owner(), Some(accounts.alice));
+            assert_eq!(marketplace.next_listing_id(), 0);
+        }
+
+        #[ink::test]
+        fn set_collection_works() {
+            let accounts = default_accounts();
+            let mut marketplace = new_marketplace();
+
+            marketplace
+                .set_collection(accounts.eve, Some(Standard::Psp34))
+                .unwrap();
+            assert_eq!(
+                marketplace.collection_standard(accounts.eve),
+                Some(Standard::Psp34)
+            );
+
+            marketplace.set_collection(accounts.eve, None).unwrap();
+            assert_eq!(marketplace.collection_standard(accounts.eve), None);
+
+            set_caller(accounts.bob);
+            assert_eq!(
+                marketplace.set_collection(accounts.eve, Some(Standard::Psp37)),
+                Err(Error::NotOwner)
+            );
+        }
+
+        #[ink::test]
+        fn set_protocol_fee_is_capped() {
+            let accounts = default_accounts();
+            let mut marketplace = new_marketplace();
+
+            assert_eq!(
+                marketplace.set_protocol_fee(accounts.bob, MAX_FEE_BPS + 1),
+                Err(Error::FeeTooHigh)
+            );
+            marketplace
+                .set_protocol_fee(accounts.bob, MAX_FEE_BPS)
+                .unwrap();
+            assert_eq!(marketplace.treasury(), accounts.bob);
+            assert_eq!(marketplace.fee_bps(), MAX_FEE_BPS);
+
+            set_caller(accounts.bob);
+            assert_eq!(
+                marketplace.set_protocol_fee(accounts.bob, 0),
+                Err(Error::NotOwner)
+            );
+        }
+
+        #[ink::test]
+        fn list_rejects_unregistered_collection() {
+            let accounts = default_accounts();
+            let mut marketplace = new_marketplace();
+
+            assert_eq!(
+                marketplace.list(accounts.eve, Id::U32(1), 1, 100),
+                Err(Error::UnsupportedCollection)
+            );
+            assert_eq!(marketplace.next_listing_id(), 0);
+        }
+
+        #[ink::test]
+        fn list_validates_id_and_amount() {
+            let accounts = default_accounts();
+            let mut marketplace = new_marketplace();
+            marketplace
+                .set_collection(accounts.eve, Some(Standard::Psp34))
+                .unwrap();
+            marketplace
+                .set_collection(accounts.frank, Some(Standard::Psp37))
+                .unwrap();
+
+            assert_eq!(
+                marketplace.list(accounts.eve, Id::U32(1), 2, 100),
+                Err(Error::InvalidAmount)
+            );
+            assert_eq!(
+                marketplace.list(accounts.frank, Id::U128(1), 0, 100),
+                Err(Error::InvalidAmount)
+            );
+            assert_eq!(
+                marketplace.list(accounts.frank, Id::U32(1), 5, 100),
+                Err(Error::InvalidId)
+            );
+        }
+
+        #[ink::test]
+        fn missing_listing_is_reported() {
+            let mut marketplace = new_marketplace();
+
+            assert_eq!(marketplace.listing(0), None);
+            assert_eq!(marketplace.cancel(0), Err(Error::ListingNotFound));
+            assert_eq!(marketplace.buy(0), Err(Error::ListingNotFound));
+        }
+
+        #[ink::test]
+        fn split_pays_fee_royalty_and_seller() {
+            // 2.5% fee and 5% royalty on 10_000
+            assert_eq!(NftMarketplace::_split(10_000, 250, 500), (250, 500, 9_250));
+            // No fee, no royalty
+            assert_eq!(NftMarketplace::_split(10_000, 0, 0), (0, 0, 10_000));
+            // Oversized royalties are capped at what is left after the fee
+            assert_eq!(NftMarketplace::_split(10_000, 250, 20_000), (250, 9_750, 0));
+            // Rounds down like the full-precision product
+            assert_eq!(NftMarketplace::_split(10_001, 250, 0), (250, 0, 9_751));
+            assert_eq!(NftMarketplace::_split(39, 250, 0), (0, 0, 39));
+            // Huge prices don't overflow
+            let (fee, royalty, proceeds) = NftMarketplace::_split(u128::MAX, MAX_FEE_BPS, 0);
+            assert_eq!(fee, u128::MAX / 10);
+            assert_eq!(fee + royalty + proceeds, u128::MAX);
+        }
+
+        #[ink::test]
+        fn selectors_match_message_names() {
+            assert_eq!(PSP37_TRANSFER_FROM, ink::selector_bytes!("transfer_from"));
+            assert_eq!(PSP37_TRANSFER, ink::selector_bytes!("transfer"));
+            assert_eq!(ROYALTY_INFO, ink::selector_bytes!("royalty_info"));
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use beli_nft::beli_nft::{BeliNft, BeliNftRef};
+        use dalla_token::dalla_token::{DallaToken, DallaTokenRef};
+        use ink::prelude::string::String;
+        use ink_e2e::ContractsBackend;
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn e2e_sale_splits_fee_and_royalty<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let charlie = ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie);
+            let dave = ink_e2e::account_id(ink_e2e::AccountKeyring::Dave);
+
+            let mut dalla_constructor = DallaTokenRef::new(1_000_000);
+            let dalla = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut dalla_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+            let mut dalla_call = dalla.call_builder::<DallaToken>();
+
+            let mut nft_constructor = BeliNftRef::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+            let nft = client
+                .instantiate("beli_nft", &ink_e2e::alice(), &mut nft_constructor)
+                .submit()
+                .await
+                .expect("beli_nft instantiate failed");
+            let mut nft_call = nft.call_builder::<BeliNft>();
+
+            // 2.5% protocol fee to charlie
+            let mut marketplace_constructor =
+                NftMarketplaceRef::new(dalla.account_id, charlie, 250);
+            let marketplace = client
+                .instantiate(
+                    "nft_marketplace",
+                    &ink_e2e::alice(),
+                    &mut marketplace_constructor,
+                )
+                .submit()
+                .await
+                .expect("nft_marketplace instantiate failed");
+            let mut marketplace_call = marketplace.call_builder::<NftMarketplace>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &marketplace_call.set_collection(nft.account_id, Some(Standard::Psp34)),
+                )
+                .submit()
+                .await
+                .expect("set_collection failed");
+
+            // 5% royalty to dave on a token minted to bob
+            client
+                .call(&ink_e2e::alice(), &nft_call.set_default_royalty(dave, 500))
+                .submit()
+                .await
+                .expect("set_default_royalty failed");
+            let token_id = client
+                .call(&ink_e2e::alice(), &nft_call.mint(bob, String::new()))
+                .submit()
+                .await
+                .expect("mint failed")
+                .return_value()
+                .expect("mint reverted");
+
+            // Bob lists the token; it moves into escrow
+            client
+                .call(
+                    &ink_e2e::bob(),
+                    &nft_call.approve(marketplace.account_id, token_id),
+                )
+                .submit()
+                .await
+                .expect("approve failed");
+            let listing_id = client
+                .call(
+                    &ink_e2e::bob(),
+                    &marketplace_call.list(nft.account_id, Id::U32(token_id), 1, 10_000),
+                )
+                .submit()
+                .await
+                .expect("list failed")
+                .return_value()
+                .expect("list reverted");
+
+            let owner = client
+                .call(&ink_e2e::alice(), &nft_call.owner_of(token_id))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(owner, Some(marketplace.account_id));
+
+            // Alice buys with DALLA
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &dalla_call.approve(marketplace.account_id, 10_000),
+                )
+                .submit()
+                .await
+                .expect("approve failed");
+            client
+                .call(&ink_e2e::alice(), &marketplace_call.buy(listing_id))
+                .submit()
+                .await
+                .expect("buy failed")
+                .return_value()
+                .expect("buy reverted");
+
+            let owner = client
+                .call(&ink_e2e::alice(), &nft_call.owner_of(token_id))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(owner, Some(alice));
+
+            for (account, expected) in [(charlie, 250), (dave, 500), (bob, 9_250)] {
+                let balance = client
+                    .call(&ink_e2e::alice(), &dalla_call.balance_of(account))
+                    .dry_run()
+                    .await?
+                    .return_value();
+                assert_eq!(balance, expected);
+            }
+
+            let listing = client
+                .call(&ink_e2e::alice(), &marketplace_call.listing(listing_id))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(listing, None);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_cancel_returns_token<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let charlie = ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie);
+
+            let mut dalla_constructor = DallaTokenRef::new(1_000_000);
+            let dalla = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut dalla_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+
+            let mut nft_constructor = BeliNftRef::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+            let nft = client
+                .instantiate("beli_nft", &ink_e2e::alice(), &mut nft_constructor)
+                .submit()
+                .await
+                .expect("beli_nft instantiate failed");
+            let mut nft_call = nft.call_builder::<BeliNft>();
+
+            let mut marketplace_constructor =
+                NftMarketplaceRef::new(dalla.account_id, charlie, 250);
+            let marketplace = client
+                .instantiate(
+                    "nft_marketplace",
+                    &ink_e2e::alice(),
+                    &mut marketplace_constructor,
+                )
+                .submit()
+                .await
+                .expect("nft_marketplace instantiate failed");
+            let mut marketplace_call = marketplace.call_builder::<NftMarketplace>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &marketplace_call.set_collection(nft.account_id, Some(Standard::Psp34)),
+                )
+                .submit()
+                .await
+                .expect("set_collection failed");
+            let token_id = client
+                .call(&ink_e2e::alice(), &nft_call.mint(bob, String::new()))
+                .submit()
+                .await
+                .expect("mint failed")
+                .return_value()
+                .expect("mint reverted");
+            client
+                .call(
+                    &ink_e2e::bob(),
+                    &nft_call.approve(marketplace.account_id, token_id),
+                )
+                .submit()
+                .await
+                .expect("approve failed");
+            let listing_id = client
+                .call(
+                    &ink_e2e::bob(),
+                    &marketplace_call.list(nft.account_id, Id::U32(token_id), 1, 10_000),
+                )
+                .submit()
+                .await
+                .expect("list failed")
+                .return_value()
+                .expect("list reverted");
+
+            // Only the seller can cancel
+            let result = client
+                .call(&ink_e2e::alice(), &marketplace_call.cancel(listing_id))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(result, Err(Error::NotSeller));
+
+            client
+                .call(&ink_e2e::bob(), &marketplace_call.cancel(listing_id))
+                .submit()
+                .await
+                .expect("cancel failed")
+                .return_value()
+                .expect("cancel reverted");
+
+            let owner = client
+                .call(&ink_e2e::alice(), &nft_call.owner_of(token_id))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(owner, Some(bob));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_list_rejects_token_of_another_owner<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let charlie = ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie);
+
+            let mut dalla_constructor = DallaTokenRef::new(1_000_000);
+            let dalla = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut dalla_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+
+            let mut nft_constructor = BeliNftRef::new(
+                String::from("Belize NFT"),
+                String::from("BNFT"),
+                None,
+                false,
+            );
+            let nft = client
+                .instantiate("beli_nft", &ink_e2e::alice(), &mut nft_constructor)
+                .submit()
+                .await
+                .expect("beli_nft instantiate failed");
+            let mut nft_call = nft.call_builder::<BeliNft>();
+
+            let mut marketplace_constructor =
+                NftMarketplaceRef::new(dalla.account_id, charlie, 250);
+            let marketplace = client
+                .instantiate(
+                    "nft_marketplace",
+                    &ink_e2e::alice(),
+                    &mut marketplace_constructor,
+                )
+                .submit()
+                .await
+                .expect("nft_marketplace instantiate failed");
+            let mut marketplace_call = marketplace.call_builder::<NftMarketplace>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &marketplace_call.set_collection(nft.account_id, Some(Standard::Psp34)),
+                )
+                .submit()
+                .await
+                .expect("set_collection failed");
+            let token_id = client
+                .call(&ink_e2e::alice(), &nft_call.mint(bob, String::new()))
+                .submit()
+                .await
+                .expect("mint failed")
+                .return_value()
+                .expect("mint reverted");
+            client
+                .call(
+                    &ink_e2e::bob(),
+                    &nft_call.approve(marketplace.account_id, token_id),
+                )
+                .submit()
+                .await
+                .expect("approve failed");
+
+            // Charlie can't list bob's approved token as his own
+            let result = client
+                .call(
+                    &ink_e2e::charlie(),
+                    &marketplace_call.list(nft.account_id, Id::U32(token_id), 1, 1),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(result, Err(Error::NotTokenOwner));
+
+            let owner = client
+                .call(&ink_e2e::alice(), &nft_call.owner_of(token_id))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(owner, Some(bob));
+
+            Ok(())
+        }
+    }
+}
@@ -10,7 +10,8 @@
 //! - Mixed fungible and non-fungible tokens
 //! - Batch transfer operations (gas efficient)
 //! - Approval for operators (delegates)
-//! - Token URI metadata support
+//! - Token URI metadata support, with per-token or contract-wide freezing
+//! - Safe transfers that notify contract recipients through `PSP37Receiver`
 //!
 //! ## Use Cases
 //! - Game items (100 swords, 50 shields, 1 legendary sword)
@@ -18,19 +19,33 @@
 //! - Multi-asset platforms
 //! - Fractionalized NFTs
 
+pub mod psp37_trait;
+
 #[ink::contract]
 mod psp37_multi_token {
+    use crate::psp37_trait::PSP37Receiver;
+    use ink::codegen::TraitCallBuilder;
     use ink::prelude::string::String;
-    use ink::prelude::vec::Vec;
+    use ink::prelude::{vec, vec::Vec};
     use ink::storage::Mapping;
     use scale::{Decode, Encode};
 
     /// Token ID type (u128 allows 2^128 unique token types)
     pub type TokenId = u128;
 
-    /// Amount type for token balances (use u128 for compatibility)
+    /// Amount type for every token balance, supply and transfer value
+    ///
+    /// Fixed at u128 regardless of the chain's native `Balance`, so the
+    /// contract builds under any environment and its amounts always encode
+    /// the same way. Message selectors are derived from names only, and on
+    /// the default environment `Balance` is u128 as well, so the switch from
+    /// `Balance` left both the ABI and the storage layout unchanged.
     pub type TokenBalance = u128;
 
+    // `PSP37Receiver::before_received` takes u128 amounts; this fails to
+    // compile if `TokenBalance` ever drifts from that
+    const _: fn(TokenBalance) -> u128 = |value| value;
+
     // ============================================================================
     // Storage
     // ============================================================================
@@ -38,14 +53,18 @@ mod psp37_multi_token {
     #[ink(storage)]
     pub struct Psp37MultiToken {
         /// Balances mapping: (owner, token_id) => balance
-        balances: Mapping<(AccountId, TokenId), Balance>,
+        balances: Mapping<(AccountId, TokenId), TokenBalance>,
 
         /// Operator approvals: (owner, operator) => approved
         /// Operators can transfer ANY token on behalf of owner
         operator_approvals: Mapping<(AccountId, AccountId), bool>,
 
+        /// Scoped operator approvals: (owner, operator, token_id) => ()
+        /// Operators can transfer only the listed token IDs on behalf of owner
+        id_approvals: Mapping<(AccountId, AccountId, TokenId), ()>,
+
         /// Total supply per token ID
-        total_supply: Mapping<TokenId, Balance>,
+        total_supply: Mapping<TokenId, TokenBalance>,
 
         /// Token URIs for metadata (optional)
         token_uris: Mapping<TokenId, String>,
@@ -55,6 +74,15 @@ mod psp37_multi_token {
 
         /// Next token ID for auto-increment
         next_token_id: TokenId,
+
+        /// Token IDs that have been created (auto-assigned or reserved)
+        created: Mapping<TokenId, ()>,
+
+        /// Token IDs whose URI can no longer change
+        frozen_metadata: Mapping<TokenId, ()>,
+
+        /// Whether every token's URI is frozen
+        all_metadata_frozen: bool,
     }
 
     // ============================================================================
@@ -82,7 +110,7 @@ mod psp37_multi_token {
         #[ink(topic)]
         to: Option<AccountId>,
         token_ids: Vec<TokenId>,
-        values: Vec<Balance>,
+        values: Vec<TokenBalance>,
     }
 
     #[ink(event)]
@@ -94,6 +122,16 @@ mod psp37_multi_token {
         approved: bool,
     }
 
+    #[ink(event)]
+    pub struct ApprovalForIds {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        operator: AccountId,
+        token_ids: Vec<TokenId>,
+        approved: bool,
+    }
+
     #[ink(event)]
     pub struct TokenCreated {
         #[ink(topic)]
@@ -102,6 +140,24 @@ mod psp37_multi_token {
         uri: Option<String>,
     }
 
+    /// Emitted whenever a token's URI is set, so metadata caches can refresh
+    #[ink(event)]
+    pub struct UriUpdated {
+        #[ink(topic)]
+        token_id: TokenId,
+        old_uri: Option<String>,
+        new_uri: String,
+    }
+
+    #[ink(event)]
+    pub struct MetadataFrozen {
+        #[ink(topic)]
+        token_id: TokenId,
+    }
+
+    #[ink(event)]
+    pub struct AllMetadataFrozen {}
+
     // ============================================================================
     // Errors
     // ============================================================================
@@ -123,6 +179,10 @@ mod psp37_multi_token {
         ZeroAddress,
         /// Self-approval not allowed
         SelfApproval,
+        /// Token ID has already been created
+        TokenExists,
+        /// Token metadata is frozen and can no longer change
+        MetadataFrozen,
     }
 
     pub type Result<T> = core::result::Result<T, Error>;
@@ -143,10 +203,14 @@ mod psp37_multi_token {
             Self {
                 balances: Mapping::default(),
                 operator_approvals: Mapping::default(),
+                id_approvals: Mapping::default(),
                 total_supply: Mapping::default(),
                 token_uris: Mapping::default(),
                 owner: Self::env().caller(),
                 next_token_id: 1,
+                created: Mapping::default(),
+                frozen_metadata: Mapping::default(),
+                all_metadata_frozen: false,
             }
         }
 
@@ -156,7 +220,7 @@ mod psp37_multi_token {
 
         /// Get balance of account for specific token
         #[ink(message)]
-        pub fn balance_of(&self, owner: AccountId, token_id: TokenId) -> Balance {
+        pub fn balance_of(&self, owner: AccountId, token_id: TokenId) -> TokenBalance {
             self.balances.get((owner, token_id)).unwrap_or(0)
         }
 
@@ -166,7 +230,7 @@ mod psp37_multi_token {
             &self,
             owners: Vec<AccountId>,
             token_ids: Vec<TokenId>,
-        ) -> Result<Vec<Balance>> {
+        ) -> Result<Vec<TokenBalance>> {
             if owners.len() != token_ids.len() {
                 return Err(Error::ArrayLengthMismatch);
             }
@@ -209,7 +273,7 @@ mod psp37_multi_token {
             &mut self,
             to: AccountId,
             token_ids: Vec<TokenId>,
-            values: Vec<Balance>,
+            values: Vec<TokenBalance>,
         ) -> Result<()> {
             let caller = self.env().caller();
             self._batch_transfer_from(caller, caller, to, token_ids, values)
@@ -222,12 +286,57 @@ mod psp37_multi_token {
             from: AccountId,
             to: AccountId,
             token_ids: Vec<TokenId>,
-            values: Vec<Balance>,
+            values: Vec<TokenBalance>,
         ) -> Result<()> {
             let caller = self.env().caller();
             self._batch_transfer_from(caller, from, to, token_ids, values)
         }
 
+        /// Transfer tokens and, if `to` is a contract, notify it through
+        /// `PSP37Receiver::before_received`
+        ///
+        /// The transfer reverts if the receiver returns an error or does not
+        /// implement the hook. Plain transfers never call the hook.
+        #[ink(message)]
+        pub fn safe_transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            token_id: TokenId,
+            value: TokenBalance,
+            data: Vec<u8>,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            self._transfer_from(caller, from, to, token_id, value)?;
+
+            if self.env().is_contract(&to) {
+                self._notify_receiver(caller, from, to, vec![token_id], vec![value], data)?;
+            }
+
+            Ok(())
+        }
+
+        /// Batch transfer and, if `to` is a contract, notify it once for the
+        /// whole batch through `PSP37Receiver::before_received`
+        #[ink(message)]
+        pub fn safe_batch_transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            token_ids: Vec<TokenId>,
+            values: Vec<TokenBalance>,
+            data: Vec<u8>,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            self._batch_transfer_from(caller, from, to, token_ids.clone(), values.clone())?;
+
+            if self.env().is_contract(&to) {
+                self._notify_receiver(caller, from, to, token_ids, values, data)?;
+            }
+
+            Ok(())
+        }
+
         /// Approve or revoke operator to manage all tokens of caller
         #[ink(message)]
         pub fn set_approval_for_all(&mut self, operator: AccountId, approved: bool) -> Result<()> {
@@ -257,13 +366,62 @@ mod psp37_multi_token {
                 .unwrap_or(false)
         }
 
+        /// Approve or revoke operator to manage only the given token IDs of caller
+        ///
+        /// Sits between `set_approval_for_all` and per-owner transfers: e.g.
+        /// a marketplace can be allowed to move every "weapon" ID and nothing
+        /// else. Blanket approval still covers every ID regardless.
+        #[ink(message)]
+        pub fn set_approval_for_ids(
+            &mut self,
+            operator: AccountId,
+            token_ids: Vec<TokenId>,
+            approved: bool,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+
+            if caller == operator {
+                return Err(Error::SelfApproval);
+            }
+
+            for token_id in token_ids.iter() {
+                if approved {
+                    self.id_approvals.insert((caller, operator, *token_id), &());
+                } else {
+                    self.id_approvals.remove((caller, operator, *token_id));
+                }
+            }
+
+            self.env().emit_event(ApprovalForIds {
+                owner: caller,
+                operator,
+                token_ids,
+                approved,
+            });
+
+            Ok(())
+        }
+
+        /// Check if operator is approved for a single token ID of owner
+        /// (through either a scoped or a blanket approval)
+        #[ink(message)]
+        pub fn is_approved_for_id(
+            &self,
+            owner: AccountId,
+            operator: AccountId,
+            token_id: TokenId,
+        ) -> bool {
+            self.is_approved_for_all(owner, operator)
+                || self.id_approvals.contains((owner, operator, token_id))
+        }
+
         // ========================================================================
         // PSP37 Metadata Extension
         // ========================================================================
 
         /// Get total supply of a specific token
         #[ink(message)]
-        pub fn total_supply(&self, token_id: TokenId) -> Balance {
+        pub fn total_supply(&self, token_id: TokenId) -> TokenBalance {
             self.total_supply.get(token_id).unwrap_or(0)
         }
 
@@ -273,6 +431,12 @@ mod psp37_multi_token {
             self.token_uris.get(token_id)
         }
 
+        /// Check if a token type has been created (even with zero supply)
+        #[ink(message)]
+        pub fn exists(&self, token_id: TokenId) -> bool {
+            self.created.contains(token_id)
+        }
+
         // ========================================================================
         // PSP37 Mintable Extension
         // ========================================================================
@@ -289,29 +453,44 @@ mod psp37_multi_token {
                 return Err(Error::NotAuthorized);
             }
 
-            let token_id = self.next_token_id;
-            self.next_token_id = self.next_token_id.saturating_add(1);
-
-            // Mint initial supply to creator
-            if initial_supply > 0 {
-                self._mint(caller, token_id, initial_supply)?;
+            // Skip over IDs already taken by create_token_with_id
+            let mut token_id = self.next_token_id;
+            while self.exists(token_id) {
+                token_id = token_id.saturating_add(1);
             }
+            self.next_token_id = token_id.saturating_add(1);
 
-            // Set URI if provided
-            if let Some(uri_value) = uri.clone() {
-                self.token_uris.insert(token_id, &uri_value);
+            self._create(caller, token_id, initial_supply, uri)?;
+            Ok(token_id)
+        }
+
+        /// Create a token type under a pre-assigned ID and mint initial supply (owner only)
+        ///
+        /// Lets applications reserve stable ID ranges (e.g. 1000-1999 for
+        /// weapons). Auto-incremented IDs from `create_token` skip over IDs
+        /// created here.
+        #[ink(message)]
+        pub fn create_token_with_id(
+            &mut self,
+            token_id: TokenId,
+            initial_supply: TokenBalance,
+            uri: Option<String>,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            if caller != self.owner {
+                return Err(Error::NotAuthorized);
             }
 
-            self.env().emit_event(TokenCreated {
-                token_id,
-                initial_supply,
-                uri,
-            });
+            if self.exists(token_id) {
+                return Err(Error::TokenExists);
+            }
 
-            Ok(token_id)
+            self._create(caller, token_id, initial_supply, uri)
         }
 
         /// Mint additional tokens of existing type (owner only)
+        ///
+        /// Fails with `TokenNotFound` if the token type was never created
         #[ink(message)]
         pub fn mint(
             &mut self,
@@ -333,7 +512,7 @@ mod psp37_multi_token {
             &mut self,
             to: AccountId,
             token_ids: Vec<TokenId>,
-            amounts: Vec<Balance>,
+            amounts: Vec<TokenBalance>,
         ) -> Result<()> {
             let caller = self.env().caller();
             if caller != self.owner {
@@ -410,10 +589,63 @@ mod psp37_multi_token {
                 return Err(Error::NotAuthorized);
             }
 
-            self.token_uris.insert(token_id, &uri);
+            if self.is_metadata_frozen(token_id) {
+                return Err(Error::MetadataFrozen);
+            }
+
+            self._set_uri(token_id, uri);
             Ok(())
         }
 
+        /// Permanently freeze a token's URI (owner only)
+        ///
+        /// There is no unfreeze: once frozen, `set_token_uri` fails with
+        /// `MetadataFrozen` for this ID forever.
+        #[ink(message)]
+        pub fn freeze_metadata(&mut self, token_id: TokenId) -> Result<()> {
+            let caller = self.env().caller();
+            if caller != self.owner {
+                return Err(Error::NotAuthorized);
+            }
+
+            if !self.exists(token_id) {
+                return Err(Error::TokenNotFound);
+            }
+            if self.is_metadata_frozen(token_id) {
+                return Err(Error::MetadataFrozen);
+            }
+
+            self.frozen_metadata.insert(token_id, &());
+            self.env().emit_event(MetadataFrozen { token_id });
+            Ok(())
+        }
+
+        /// Permanently freeze every token's URI (owner only)
+        ///
+        /// Covers tokens created later too: they keep the URI they are
+        /// created with. Irreversible, like `freeze_metadata`.
+        #[ink(message)]
+        pub fn freeze_all_metadata(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            if caller != self.owner {
+                return Err(Error::NotAuthorized);
+            }
+
+            if self.all_metadata_frozen {
+                return Err(Error::MetadataFrozen);
+            }
+
+            self.all_metadata_frozen = true;
+            self.env().emit_event(AllMetadataFrozen {});
+            Ok(())
+        }
+
+        /// Check if a token's URI is frozen, individually or contract-wide
+        #[ink(message)]
+        pub fn is_metadata_frozen(&self, token_id: TokenId) -> bool {
+            self.all_metadata_frozen || self.frozen_metadata.contains(token_id)
+        }
+
         // ========================================================================
         // Internal Functions
         // ========================================================================
@@ -433,7 +665,7 @@ mod psp37_multi_token {
             }
 
             // Check authorization
-            if operator != from && !self.is_approved_for_all(from, operator) {
+            if operator != from && !self.is_approved_for_id(from, operator, token_id) {
                 return Err(Error::NotAuthorized);
             }
 
@@ -470,7 +702,7 @@ mod psp37_multi_token {
             from: AccountId,
             to: AccountId,
             token_ids: Vec<TokenId>,
-            values: Vec<Balance>,
+            values: Vec<TokenBalance>,
         ) -> Result<()> {
             // Validate inputs
             if token_ids.len() != values.len() {
@@ -481,8 +713,12 @@ mod psp37_multi_token {
                 return Err(Error::ZeroAddress);
             }
 
-            // Check authorization
-            if operator != from && !self.is_approved_for_all(from, operator) {
+            // Check authorization for every ID in the batch
+            if operator != from
+                && !token_ids
+                    .iter()
+                    .all(|token_id| self.is_approved_for_id(from, operator, *token_id))
+            {
                 return Err(Error::NotAuthorized);
             }
 
@@ -513,12 +749,80 @@ mod psp37_multi_token {
             Ok(())
         }
 
+        /// Call `PSP37Receiver::before_received` on a receiving contract
+        fn _notify_receiver(
+            &self,
+            operator: AccountId,
+            from: AccountId,
+            to: AccountId,
+            token_ids: Vec<TokenId>,
+            values: Vec<TokenBalance>,
+            data: Vec<u8>,
+        ) -> Result<()> {
+            let mut receiver: ink::contract_ref!(PSP37Receiver) = to.into();
+
+            let result = receiver
+                .call_mut()
+                .before_received(operator, from, token_ids, values, data)
+                .try_invoke();
+
+            match result {
+                Ok(Ok(Ok(()))) => Ok(()),
+                _ => Err(Error::TransferRejected),
+            }
+        }
+
+        /// Register a new token type, mint its initial supply and set its URI
+        fn _create(
+            &mut self,
+            creator: AccountId,
+            token_id: TokenId,
+            initial_supply: TokenBalance,
+            uri: Option<String>,
+        ) -> Result<()> {
+            self.created.insert(token_id, &());
+
+            // Mint initial supply to creator
+            if initial_supply > 0 {
+                self._mint(creator, token_id, initial_supply)?;
+            }
+
+            self.env().emit_event(TokenCreated {
+                token_id,
+                initial_supply,
+                uri: uri.clone(),
+            });
+
+            // Set URI if provided
+            if let Some(uri_value) = uri {
+                self._set_uri(token_id, uri_value);
+            }
+
+            Ok(())
+        }
+
+        /// Store a token's URI and announce the change
+        fn _set_uri(&mut self, token_id: TokenId, new_uri: String) {
+            let old_uri = self.token_uris.get(token_id);
+            self.token_uris.insert(token_id, &new_uri);
+
+            self.env().emit_event(UriUpdated {
+                token_id,
+                old_uri,
+                new_uri,
+            });
+        }
+
         /// Internal mint implementation
         fn _mint(&mut self, to: AccountId, token_id: TokenId, amount: TokenBalance) -> Result<()> {
             if to == AccountId::from([0u8; 32]) {
                 return Err(Error::ZeroAddress);
             }
 
+            if !self.exists(token_id) {
+                return Err(Error::TokenNotFound);
+            }
+
             // Update balance
             let balance = self.balance_of(to, token_id);
             self.balances
@@ -649,6 +953,82 @@ mod psp37_multi_token {
             assert!(!contract.is_approved_for_all(accounts.alice, accounts.bob));
         }
 
+        #[ink::test]
+        fn approval_for_ids_scopes_operator() {
+            let mut contract = Psp37MultiToken::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            let weapon = contract.create_token(1000, None).unwrap();
+            let shield = contract.create_token(1000, None).unwrap();
+
+            contract
+                .set_approval_for_ids(accounts.bob, vec![weapon], true)
+                .unwrap();
+            assert!(contract.is_approved_for_id(accounts.alice, accounts.bob, weapon));
+            assert!(!contract.is_approved_for_id(accounts.alice, accounts.bob, shield));
+            assert!(!contract.is_approved_for_all(accounts.alice, accounts.bob));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+
+            // The approved ID moves, the other one does not
+            assert!(contract
+                .transfer_from(accounts.alice, accounts.charlie, weapon, 100)
+                .is_ok());
+            assert_eq!(contract.balance_of(accounts.charlie, weapon), 100);
+            assert_eq!(
+                contract.transfer_from(accounts.alice, accounts.charlie, shield, 100),
+                Err(Error::NotAuthorized)
+            );
+
+            // Every ID in a batch must be covered
+            assert_eq!(
+                contract.batch_transfer_from(
+                    accounts.alice,
+                    accounts.charlie,
+                    vec![weapon, shield],
+                    vec![10, 10],
+                ),
+                Err(Error::NotAuthorized)
+            );
+            assert!(contract
+                .batch_transfer_from(accounts.alice, accounts.charlie, vec![weapon], vec![10])
+                .is_ok());
+            assert_eq!(contract.balance_of(accounts.charlie, weapon), 110);
+            assert_eq!(contract.balance_of(accounts.charlie, shield), 0);
+        }
+
+        #[ink::test]
+        fn approval_for_ids_revokes_per_id() {
+            let mut contract = Psp37MultiToken::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            let weapon = contract.create_token(1000, None).unwrap();
+            let shield = contract.create_token(1000, None).unwrap();
+
+            contract
+                .set_approval_for_ids(accounts.bob, vec![weapon, shield], true)
+                .unwrap();
+            contract
+                .set_approval_for_ids(accounts.bob, vec![shield], false)
+                .unwrap();
+            assert!(contract.is_approved_for_id(accounts.alice, accounts.bob, weapon));
+            assert!(!contract.is_approved_for_id(accounts.alice, accounts.bob, shield));
+
+            assert_eq!(
+                contract.set_approval_for_ids(accounts.alice, vec![weapon], true),
+                Err(Error::SelfApproval)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                contract.transfer_from(accounts.alice, accounts.charlie, shield, 1),
+                Err(Error::NotAuthorized)
+            );
+            assert!(contract
+                .transfer_from(accounts.alice, accounts.charlie, weapon, 1)
+                .is_ok());
+        }
+
         #[ink::test]
         fn transfer_from_works() {
             let mut contract = Psp37MultiToken::new();
@@ -739,5 +1119,406 @@ mod psp37_multi_token {
             let balances = contract.balance_of_batch(owners, token_ids).unwrap();
             assert_eq!(balances, vec![1000, 2000]);
         }
+
+        #[ink::test]
+        fn create_token_with_id_works() {
+            let mut contract = Psp37MultiToken::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            assert!(!contract.exists(1000));
+            assert!(contract
+                .create_token_with_id(1000, 0, Some("https://example.com/weapons/1000".into()))
+                .is_ok());
+
+            // Exists with zero supply, and can be minted afterwards
+            assert!(contract.exists(1000));
+            assert_eq!(contract.total_supply(1000), 0);
+            assert!(contract.mint(accounts.bob, 1000, 5).is_ok());
+            assert_eq!(contract.balance_of(accounts.bob, 1000), 5);
+        }
+
+        #[ink::test]
+        fn create_token_with_id_rejects_existing_id() {
+            let mut contract = Psp37MultiToken::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            let token_id = contract.create_token(100, None).unwrap();
+            assert_eq!(
+                contract.create_token_with_id(token_id, 100, None),
+                Err(Error::TokenExists)
+            );
+
+            contract.create_token_with_id(1000, 100, None).unwrap();
+            assert_eq!(
+                contract.create_token_with_id(1000, 100, None),
+                Err(Error::TokenExists)
+            );
+            assert_eq!(contract.balance_of(accounts.alice, 1000), 100);
+
+            // Only the owner creates tokens
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                contract.create_token_with_id(2000, 0, None),
+                Err(Error::NotAuthorized)
+            );
+        }
+
+        #[ink::test]
+        fn create_token_skips_reserved_ids() {
+            let mut contract = Psp37MultiToken::new();
+
+            contract.create_token_with_id(2, 0, None).unwrap();
+            contract.create_token_with_id(3, 0, None).unwrap();
+
+            assert_eq!(contract.create_token(0, None), Ok(1));
+            assert_eq!(contract.create_token(0, None), Ok(4));
+            assert_eq!(contract.create_token(0, None), Ok(5));
+        }
+
+        #[ink::test]
+        fn mint_rejects_uncreated_token() {
+            let mut contract = Psp37MultiToken::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            assert_eq!(
+                contract.mint(accounts.bob, 42, 10),
+                Err(Error::TokenNotFound)
+            );
+            assert_eq!(
+                contract.batch_mint(accounts.bob, vec![42], vec![10]),
+                Err(Error::TokenNotFound)
+            );
+            assert!(!contract.exists(42));
+            assert_eq!(contract.total_supply(42), 0);
+        }
+
+        #[ink::test]
+        fn safe_transfer_to_account_skips_hook() {
+            let mut contract = Psp37MultiToken::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            let token1 = contract.create_token(1000, None).unwrap();
+            let token2 = contract.create_token(500, None).unwrap();
+
+            assert!(contract
+                .safe_transfer_from(accounts.alice, accounts.bob, token1, 100, b"ref".to_vec())
+                .is_ok());
+            assert!(contract
+                .safe_batch_transfer_from(
+                    accounts.alice,
+                    accounts.bob,
+                    vec![token1, token2],
+                    vec![50, 25],
+                    Vec::new(),
+                )
+                .is_ok());
+            assert_eq!(contract.balance_of(accounts.bob, token1), 150);
+            assert_eq!(contract.balance_of(accounts.bob, token2), 25);
+
+            // Authorization still applies
+            assert_eq!(
+                contract.safe_transfer_from(accounts.bob, accounts.charlie, token1, 1, Vec::new()),
+                Err(Error::NotAuthorized)
+            );
+        }
+
+        /// Decodes the most recent event as `UriUpdated`
+        fn last_uri_update() -> UriUpdated {
+            let event = ink::env::test::recorded_events()
+                .last()
+                .expect("no event recorded");
+            <UriUpdated as ink::scale::Decode>::decode(&mut &event.data[..])
+                .expect("UriUpdated decode failed")
+        }
+
+        #[ink::test]
+        fn uri_changes_emit_events() {
+            let mut contract = Psp37MultiToken::new();
+
+            let token_id = contract.create_token(10, Some("ipfs://v1".into())).unwrap();
+            let event = last_uri_update();
+            assert_eq!(event.token_id, token_id);
+            assert_eq!(event.old_uri, None);
+            assert_eq!(event.new_uri, "ipfs://v1");
+
+            contract
+                .set_token_uri(token_id, "ipfs://v2".into())
+                .unwrap();
+            let event = last_uri_update();
+            assert_eq!(event.old_uri, Some("ipfs://v1".into()));
+            assert_eq!(event.new_uri, "ipfs://v2");
+
+            // No URI, no event beyond the mint and creation
+            let before = ink::env::test::recorded_events().count();
+            contract.create_token(10, None).unwrap();
+            assert_eq!(ink::env::test::recorded_events().count(), before + 2);
+        }
+
+        #[ink::test]
+        fn frozen_token_uri_cannot_change() {
+            let mut contract = Psp37MultiToken::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            let sword = contract
+                .create_token(10, Some("ipfs://sword".into()))
+                .unwrap();
+            let shield = contract.create_token(10, None).unwrap();
+
+            assert_eq!(contract.freeze_metadata(42), Err(Error::TokenNotFound));
+            assert!(!contract.is_metadata_frozen(sword));
+            assert!(contract.freeze_metadata(sword).is_ok());
+            assert!(contract.is_metadata_frozen(sword));
+            assert!(!contract.is_metadata_frozen(shield));
+
+            assert_eq!(
+                contract.set_token_uri(sword, "ipfs://rug".into()),
+                Err(Error::MetadataFrozen)
+            );
+            assert_eq!(contract.token_uri(sword), Some("ipfs://sword".into()));
+            assert!(contract
+                .set_token_uri(shield, "ipfs://shield".into())
+                .is_ok());
+
+            // Freezing is one-way and owner-only
+            assert_eq!(contract.freeze_metadata(sword), Err(Error::MetadataFrozen));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.freeze_metadata(shield), Err(Error::NotAuthorized));
+            assert_eq!(contract.freeze_all_metadata(), Err(Error::NotAuthorized));
+        }
+
+        #[ink::test]
+        fn freeze_all_metadata_covers_every_token() {
+            let mut contract = Psp37MultiToken::new();
+
+            let sword = contract
+                .create_token(10, Some("ipfs://sword".into()))
+                .unwrap();
+            assert!(contract.freeze_all_metadata().is_ok());
+            assert!(contract.is_metadata_frozen(sword));
+            assert_eq!(
+                contract.set_token_uri(sword, "ipfs://rug".into()),
+                Err(Error::MetadataFrozen)
+            );
+            assert_eq!(contract.freeze_metadata(sword), Err(Error::MetadataFrozen));
+            assert_eq!(contract.freeze_all_metadata(), Err(Error::MetadataFrozen));
+
+            // Tokens created afterwards keep their initial URI for good
+            let shield = contract
+                .create_token(10, Some("ipfs://shield".into()))
+                .unwrap();
+            assert_eq!(contract.token_uri(shield), Some("ipfs://shield".into()));
+            assert!(contract.is_metadata_frozen(shield));
+            assert_eq!(
+                contract.set_token_uri(shield, "ipfs://rug".into()),
+                Err(Error::MetadataFrozen)
+            );
+        }
+
+        #[ink::test]
+        fn amounts_use_full_token_balance_range() {
+            let mut contract = Psp37MultiToken::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            // Well past u64::MAX, so nothing truncates to a narrower type
+            let large: TokenBalance = u128::from(u64::MAX) * 4;
+            let token1 = contract.create_token(large, None).unwrap();
+            let token2 = contract.create_token(0, None).unwrap();
+            assert!(contract
+                .batch_mint(accounts.alice, vec![token2], vec![large])
+                .is_ok());
+            assert_eq!(contract.total_supply(token2), large);
+
+            assert!(contract.transfer(accounts.bob, token1, large / 2).is_ok());
+            assert!(contract
+                .batch_transfer(accounts.bob, vec![token1, token2], vec![1, large])
+                .is_ok());
+
+            let balances = contract
+                .balance_of_batch(
+                    vec![accounts.alice, accounts.bob, accounts.bob],
+                    vec![token1, token1, token2],
+                )
+                .unwrap();
+            assert_eq!(balances, vec![large / 2 - 1, large / 2 + 1, large]);
+
+            let event = ink::env::test::recorded_events()
+                .last()
+                .expect("no event recorded");
+            let decoded = <TransferBatch as ink::scale::Decode>::decode(&mut &event.data[..])
+                .expect("TransferBatch decode failed");
+            assert_eq!(decoded.token_ids, vec![token1, token2]);
+            assert_eq!(decoded.values, vec![1, large]);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(contract.burn(token2, large).is_ok());
+            assert_eq!(contract.total_supply(token2), 0);
+        }
+
+        #[ink::test]
+        fn amount_message_selectors_are_stable() {
+            // Selectors depend only on message names, so moving amounts to
+            // `TokenBalance` must not change them; integrators such as the
+            // NFT marketplace pin these values
+            macro_rules! selector {
+                ($name:literal) => {
+                    <Psp37MultiToken as ink::reflect::DispatchableMessageInfo<
+                        { ink::selector_id!($name) },
+                    >>::SELECTOR
+                };
+            }
+
+            assert_eq!(selector!("transfer"), [0x84, 0xa1, 0x5d, 0xa1]);
+            assert_eq!(selector!("transfer_from"), [0x0b, 0x39, 0x6f, 0x18]);
+            assert_eq!(selector!("balance_of"), [0x0f, 0x75, 0x5a, 0x56]);
+            assert_eq!(selector!("balance_of_batch"), [0x0e, 0xf0, 0xe2, 0xa2]);
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use ink_e2e::ContractsBackend;
+        use token_receiver_mock::token_receiver_mock::{TokenReceiverMock, TokenReceiverMockRef};
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn e2e_safe_transfer_calls_receiver<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+
+            let mut token_constructor = Psp37MultiTokenRef::new();
+            let token = client
+                .instantiate(
+                    "psp37_multi_token",
+                    &ink_e2e::alice(),
+                    &mut token_constructor,
+                )
+                .submit()
+                .await
+                .expect("psp37_multi_token instantiate failed");
+            let mut token_call = token.call_builder::<Psp37MultiToken>();
+
+            let mut receiver_constructor = TokenReceiverMockRef::new(true);
+            let receiver = client
+                .instantiate(
+                    "token_receiver_mock",
+                    &ink_e2e::alice(),
+                    &mut receiver_constructor,
+                )
+                .submit()
+                .await
+                .expect("token_receiver_mock instantiate failed");
+            let receiver_call = receiver.call_builder::<TokenReceiverMock>();
+
+            let token_id = client
+                .call(&ink_e2e::alice(), &token_call.create_token(1000, None))
+                .submit()
+                .await
+                .expect("create_token failed")
+                .return_value()
+                .expect("create_token reverted");
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &token_call.safe_transfer_from(
+                        alice,
+                        receiver.account_id,
+                        token_id,
+                        250,
+                        b"hi".to_vec(),
+                    ),
+                )
+                .submit()
+                .await
+                .expect("safe_transfer_from failed");
+
+            let balance = client
+                .call(
+                    &ink_e2e::alice(),
+                    &token_call.balance_of(receiver.account_id, token_id),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(balance, 250);
+
+            let last = client
+                .call(&ink_e2e::alice(), &receiver_call.last_psp37_received())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(
+                last,
+                Some((alice, alice, vec![token_id], vec![250], b"hi".to_vec()))
+            );
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_safe_transfer_rejected_by_receiver<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+
+            let mut token_constructor = Psp37MultiTokenRef::new();
+            let token = client
+                .instantiate(
+                    "psp37_multi_token",
+                    &ink_e2e::alice(),
+                    &mut token_constructor,
+                )
+                .submit()
+                .await
+                .expect("psp37_multi_token instantiate failed");
+            let mut token_call = token.call_builder::<Psp37MultiToken>();
+
+            let mut receiver_constructor = TokenReceiverMockRef::new(false);
+            let receiver = client
+                .instantiate(
+                    "token_receiver_mock",
+                    &ink_e2e::alice(),
+                    &mut receiver_constructor,
+                )
+                .submit()
+                .await
+                .expect("token_receiver_mock instantiate failed");
+
+            let token_id = client
+                .call(&ink_e2e::alice(), &token_call.create_token(1000, None))
+                .submit()
+                .await
+                .expect("create_token failed")
+                .return_value()
+                .expect("create_token reverted");
+
+            let result = client
+                .call(
+                    &ink_e2e::alice(),
+                    &token_call.safe_batch_transfer_from(
+                        alice,
+                        receiver.account_id,
+                        vec![token_id],
+                        vec![250],
+                        Vec::new(),
+                    ),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(result, Err(Error::TransferRejected));
+
+            let balance = client
+                .call(&ink_e2e::alice(), &token_call.balance_of(alice, token_id))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(balance, 1000);
+
+            Ok(())
+        }
     }
 }
@@ -0,0 +1,36 @@
+//! PSP37 Trait Reference for Cross-Contract Calls
+//!
+//! Defines the receiver hook PSP37 (and single-id NFT) transfers call on a
+//! contract recipient. The selector is pinned so any compliant receiver can be
+//! notified without depending on the implementing crate.
+
+use ink::prelude::{string::String, vec::Vec};
+use ink::primitives::AccountId;
+
+/// PSP37 Receiver error types
+#[derive(Debug, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub enum PSP37ReceiverError {
+    /// The receiver refuses the incoming tokens
+    TransferRejected(String),
+}
+
+/// PSP37 Receiver hook
+///
+/// Implemented by contracts that want to be notified of (and able to refuse)
+/// incoming tokens sent with a safe transfer
+#[ink::trait_definition]
+pub trait PSP37Receiver {
+    /// Called by the token after `values` of `ids` have moved from `from` to
+    /// this contract on behalf of `operator`; returning an error reverts the
+    /// transfer
+    #[ink(message, selector = 0x11e16fea)]
+    fn before_received(
+        &mut self,
+        operator: AccountId,
+        from: AccountId,
+        ids: Vec<u128>,
+        values: Vec<u128>,
+        data: Vec<u8>,
+    ) -> Result<(), PSP37ReceiverError>;
+}
@@ -0,0 +1,98 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// # Token Receiver Mock
+///
+/// Test-only contract implementing `PSP22Receiver` and `PSP37Receiver` that
+/// either accepts every incoming transfer (recording the last one) or rejects
+/// them all. Used by the DALLA, PSP37 and BeliNFT end-to-end tests for their
+/// safe-transfer paths.
+
+#[path = "../dex/psp22_trait.rs"]
+pub mod psp22_trait;
+
+#[path = "../psp37_multi_token/psp37_trait.rs"]
+pub mod psp37_trait;
+
+#[ink::contract]
+pub mod token_receiver_mock {
+    use crate::psp22_trait::{self, PSP22Error, PSP22Receiver};
+    use crate::psp37_trait::{PSP37Receiver, PSP37ReceiverError};
+    use ink::prelude::{string::String, vec::Vec};
+
+    /// (operator, from, ids, values, data) of an accepted PSP37 transfer
+    pub type Psp37Received = (AccountId, AccountId, Vec<u128>, Vec<u128>, Vec<u8>);
+
+    /// Receiver storage
+    #[ink(storage)]
+    pub struct TokenReceiverMock {
+        /// Whether incoming transfers are accepted
+        accept: bool,
+        /// (operator, from, value, data) of the last accepted PSP22 transfer
+        last_received: Option<(AccountId, AccountId, u128, Vec<u8>)>,
+        /// The last accepted PSP37 transfer
+        last_psp37_received: Option<Psp37Received>,
+    }
+
+    impl TokenReceiverMock {
+        /// Creates a receiver that accepts or rejects every transfer
+        #[ink(constructor)]
+        pub fn new(accept: bool) -> Self {
+            Self {
+                accept,
+                last_received: None,
+                last_psp37_received: None,
+            }
+        }
+
+        /// Returns the last accepted PSP22 transfer
+        #[ink(message)]
+        pub fn last_received(&self) -> Option<(AccountId, AccountId, u128, Vec<u8>)> {
+            self.last_received.clone()
+        }
+
+        /// Returns the last accepted PSP37 transfer
+        #[ink(message)]
+        pub fn last_psp37_received(&self) -> Option<Psp37Received> {
+            self.last_psp37_received.clone()
+        }
+    }
+
+    impl PSP22Receiver for TokenReceiverMock {
+        #[ink(message)]
+        fn on_psp22_received(
+            &mut self,
+            operator: AccountId,
+            from: AccountId,
+            value: u128,
+            data: Vec<u8>,
+        ) -> psp22_trait::Result<()> {
+            if !self.accept {
+                return Err(PSP22Error::Custom(String::from("Rejected")));
+            }
+
+            self.last_received = Some((operator, from, value, data));
+            Ok(())
+        }
+    }
+
+    impl PSP37Receiver for TokenReceiverMock {
+        #[ink(message)]
+        fn before_received(
+            &mut self,
+            operator: AccountId,
+            from: AccountId,
+            ids: Vec<u128>,
+            values: Vec<u128>,
+            data: Vec<u8>,
+        ) -> Result<(), PSP37ReceiverError> {
+            if !self.accept {
+                return Err(PSP37ReceiverError::TransferRejected(String::from(
+                    "Rejected",
+                )));
+            }
+
+            self.last_psp37_received = Some((operator, from, ids, values, data));
+            Ok(())
+        }
+    }
+}
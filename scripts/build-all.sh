@@ -51,9 +51,13 @@ declare -a CONTRACTS=(
     "Faucet:faucet"
     "Access Control:access_control"
     "PSP37 Multi-Token:psp37_multi_token"
+    "NFT Marketplace:nft_marketplace"
     "BelizeX Factory:dex/factory"
     "BelizeX Pair:dex/pair"
     "BelizeX Router:dex/router"
+    "Wrapped BZC:dex/wbzc"
+    "BelizeX LP Staking:dex/lp_staking"
+    "BelizeX Liquidity Locker:dex/liquidity_locker"
 )
 
 # Track success/failure
@@ -34,6 +34,25 @@ export interface RemoveLiquidityParams {
   deadline: number;
 }
 
+export interface HopSimulation {
+  pair: string;
+  tokenIn: string;
+  tokenOut: string;
+  amountSent: string;
+  transferFee: string;
+  amountIn: string;
+  lpFee: string;
+  amountOut: string;
+  shallow: boolean;
+}
+
+export interface SwapSimulation {
+  amountOut: string;
+  outputTransferFee: string;
+  hops: HopSimulation[];
+  totalFeeBps: number;
+}
+
 export interface SwapParams {
   amountIn?: string;
   amountOut?: string;
@@ -191,6 +210,14 @@ export declare class BelizeXSDK {
    */
   getAmountsIn(amountOut: string, path: string[]): Promise<string[]>;
 
+  /**
+   * Estimate a swap including token transfer fees (query only)
+   * @param amountIn - Input amount
+   * @param path - Token path
+   * @returns Expected output, per-hop breakdown and total fee
+   */
+  simulateSwap(amountIn: string, path: string[]): Promise<SwapSimulation>;
+
   // Utility Methods
   /**
    * Calculate price impact
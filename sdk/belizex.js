@@ -436,6 +436,31 @@ class BelizeXSDK {
     return output.toHuman();
   }
 
+  /**
+   * Estimate a swap of exactly `amountIn` along `path`, including the
+   * transfer fees of fee-on-transfer tokens. An estimate only: nothing is
+   * executed and reserves may change before a real swap.
+   * @param {string} amountIn - Input amount
+   * @param {string[]} path - Token path
+   * @returns {Promise<Object>} Expected output, per-hop amounts and fees,
+   *   shallow-pool flags and the total fee in basis points
+   */
+  async simulateSwap(amountIn, path) {
+    const gasLimit = this.api.registry.createType('WeightV2', {
+      refTime: 5000000000,
+      proofSize: 262144,
+    });
+
+    const { output } = await this.router.query.simulateSwap(
+      this.routerAddress,
+      { gasLimit },
+      amountIn,
+      path
+    );
+
+    return output.toHuman();
+  }
+
   // ============================================================================
   // Utility Methods
   // ============================================================================
@@ -20,12 +20,80 @@
 
 #[ink::contract]
 mod simple_dao {
+    use access_control::access_control::{AccessControlData, DEFAULT_ADMIN_ROLE};
+    use access_control::{AccessError, RoleType};
+    use gem_math::sqrt;
+    use ink::env::call::{build_call, ExecutionInput, Selector};
+    use ink::env::hash::Blake2x256;
     use ink::prelude::string::String;
+    use ink::prelude::{vec, vec::Vec};
     use ink::storage::Mapping;
 
     /// Proposal ID type
     pub type ProposalId = u32;
 
+    /// Maximum number of actions attached to one proposal
+    pub const MAX_ACTIONS: u32 = 10;
+
+    /// Maximum encoded argument size of a single action, in bytes
+    pub const MAX_ACTION_INPUT: u32 = 1024;
+
+    /// Selector of the treasury payout action
+    ///
+    /// An action targeting the DAO itself with this selector and input
+    /// `(token: Option<AccountId>, to: AccountId, amount: Balance)` pays out
+    /// from the treasury. `None` as token means native funds.
+    pub const TREASURY_TRANSFER_SELECTOR: [u8; 4] = [0x4b, 0xb6, 0x15, 0x35];
+
+    /// Selector of the timelock update action
+    ///
+    /// An action targeting the DAO itself with this selector and input
+    /// `(execution_delay: u32, expiration_window: u32)` updates the timelock.
+    pub const SET_TIMELOCK_SELECTOR: [u8; 4] = [0xf8, 0x3d, 0x16, 0x2e];
+
+    /// Selector of the voting strategy update action
+    ///
+    /// An action targeting the DAO itself with this selector and an encoded
+    /// `VotingStrategy` as input changes the strategy for new proposals.
+    pub const SET_VOTING_STRATEGY_SELECTOR: [u8; 4] = [0xee, 0xd2, 0xf2, 0x10];
+
+    /// Selector of the guardian removal action
+    ///
+    /// An action targeting the DAO itself with this selector and empty input
+    /// removes the guardian.
+    pub const REMOVE_GUARDIAN_SELECTOR: [u8; 4] = [0x26, 0xc9, 0xe6, 0xb9];
+
+    /// Selector of the vote reward update action
+    ///
+    /// An action targeting the DAO itself with this selector and input
+    /// `amount: Balance` sets the DALLA vote reward (see `set_vote_reward`).
+    pub const SET_VOTE_REWARD_SELECTOR: [u8; 4] = [0x41, 0xbb, 0x5f, 0x8e];
+
+    /// Default number of blocks a passed proposal stays executable
+    pub const DEFAULT_EXPIRATION_WINDOW: u32 = 100_800;
+
+    /// Default share of yes votes among yes and no votes a proposal must
+    /// exceed; 5000 keeps the simple majority rule
+    pub const DEFAULT_APPROVAL_THRESHOLD_BPS: u32 = 5000;
+
+    /// Minimum number of options on a multi-choice proposal
+    pub const MIN_OPTIONS: u32 = 2;
+
+    /// Maximum number of options on a multi-choice proposal
+    pub const MAX_OPTIONS: u32 = 10;
+
+    /// Role allowed to adjust voting period, quorum and approval settings
+    pub const CONFIG_ROLE: RoleType = 5;
+
+    /// Maximum number of accounts delegating to a single delegate
+    pub const MAX_DELEGATORS: u32 = 50;
+
+    /// Maximum length of a proposal title, in bytes
+    pub const MAX_TITLE_LENGTH: u32 = 100;
+
+    /// Maximum number of proposals in a single vote reward claim
+    pub const MAX_REWARD_CLAIMS: u32 = 20;
+
     /// Proposal status
     #[derive(Debug, PartialEq, Eq, Clone, Copy)]
     #[ink::scale_derive(Encode, Decode, TypeInfo)]
@@ -35,6 +103,222 @@ mod simple_dao {
         Passed,
         Rejected,
         Executed,
+        ExecutionFailed,
+        Cancelled,
+        Vetoed,
+    }
+
+    /// Effective proposal state, derived from the block number and tallies
+    ///
+    /// Unlike `ProposalStatus`, this does not wait for `finalize_proposal`.
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub enum ProposalState {
+        /// Voting is open
+        Active,
+        /// Passed and within its execution window
+        Succeeded,
+        /// Voting ended without passing
+        Defeated,
+        /// Passed, waiting for the execution delay
+        Queued,
+        /// Executed successfully
+        Executed,
+        /// Execution was attempted and an action failed
+        ExecutionFailed,
+        /// Passed but never executed within its window
+        Expired,
+        /// Cancelled by the proposer or admin
+        Cancelled,
+        /// Vetoed by the guardian after passing
+        Vetoed,
+    }
+
+    /// Membership gating and vote weight source, derived from configuration
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub enum MembershipMode {
+        /// Anyone may propose and vote
+        Open,
+        /// A membership NFT is required; weight comes from DALLA balances
+        NftGated,
+        /// A membership NFT is required; each NFT held is one vote
+        NftWeighted,
+    }
+
+    /// How token balances translate into vote weight
+    ///
+    /// The strategy only shapes the tallies. Quorum is measured on the raw
+    /// balance behind the votes, so it means the same share of the snapshot
+    /// total under every strategy.
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum VotingStrategy {
+        /// One token, one vote
+        Linear,
+        /// Weight is the integer square root of the balance, rounded down
+        ///
+        /// Weights are in units of sqrt(smallest token unit), so 1 DALLA
+        /// (10^12 units) counts as 10^6.
+        Quadratic,
+        /// Weight is the balance clamped to the given maximum per voter
+        Capped(u128),
+    }
+
+    impl VotingStrategy {
+        /// Effective vote weight of a single account's balance
+        pub fn effective_weight(&self, balance: u128) -> u128 {
+            match self {
+                VotingStrategy::Linear => balance,
+                VotingStrategy::Quadratic => sqrt(balance),
+                VotingStrategy::Capped(cap) => balance.min(*cap),
+            }
+        }
+    }
+
+    /// How a vote is cast
+    ///
+    /// Abstentions count towards quorum but not towards the outcome.
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum VoteType {
+        Yes,
+        No,
+        Abstain,
+    }
+
+    /// What a voter supported on a proposal
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum Support {
+        /// Yes, no or abstain on a binary proposal
+        Vote(VoteType),
+        /// Option index on a multi-choice proposal
+        Choice(u8),
+    }
+
+    /// A voter's current vote on a proposal
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct VoterRecord {
+        pub support: Support,
+        pub weight: u128,
+        /// Block of the most recent cast or change
+        pub block: u32,
+        /// Raw balance behind the vote, delegators included, before the
+        /// voting strategy
+        pub power: u128,
+    }
+
+    /// A vote signed off-chain and submitted by a relayer
+    ///
+    /// The signature is an ECDSA signature over `vote_hash`.
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub struct SignedVote {
+        pub voter: AccountId,
+        pub support: Support,
+        /// Must equal `vote_nonce(voter)`
+        pub nonce: u64,
+        pub signature: Vec<u8>,
+    }
+
+    /// Tally evaluation of a proposal
+    struct Outcome {
+        total_votes: u128,
+        quorum_required: u128,
+        quorum_reached: bool,
+        passed: bool,
+    }
+
+    /// A call dispatched by the DAO when a proposal is executed
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Action {
+        /// Contract to call
+        pub target: AccountId,
+        /// Message selector on the target
+        pub selector: [u8; 4],
+        /// SCALE-encoded message arguments
+        pub input: Vec<u8>,
+        /// Native value sent along with the call
+        pub transferred_value: Balance,
+    }
+
+    impl Action {
+        /// Builds an action calling `selector` on `target` with `args`
+        ///
+        /// `args` is SCALE-encoded as the message would receive it: a single
+        /// argument as itself, several as a tuple in declaration order. For
+        /// example, governing a BelizeX factory:
+        ///
+        /// ```ignore
+        /// let action = Action::call(
+        ///     factory,
+        ///     ink::selector_bytes!("set_fee_to"),
+        ///     &Some(treasury),
+        /// );
+        /// ```
+        pub fn call<Args: ink::scale::Encode>(
+            target: AccountId,
+            selector: [u8; 4],
+            args: &Args,
+        ) -> Self {
+            Self {
+                target,
+                selector,
+                input: args.encode(),
+                transferred_value: 0,
+            }
+        }
+    }
+
+    /// Per-proposal overrides of the global voting parameters
+    ///
+    /// `None` fields fall back to the DAO defaults. Overrides must lie within
+    /// the admin-set `ConfigBounds`.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub struct ProposalConfig {
+        pub voting_period: Option<u32>,
+        pub quorum_bps: Option<u32>,
+        pub approval_threshold_bps: Option<u32>,
+    }
+
+    /// Inclusive `(min, max)` ranges allowed for `ProposalConfig` overrides
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct ConfigBounds {
+        pub voting_period: (u32, u32),
+        pub quorum_bps: (u32, u32),
+        pub approval_threshold_bps: (u32, u32),
+    }
+
+    impl Default for ConfigBounds {
+        fn default() -> Self {
+            Self {
+                voting_period: (1, u32::MAX),
+                quorum_bps: (0, 10000),
+                approval_threshold_bps: (DEFAULT_APPROVAL_THRESHOLD_BPS, 10000),
+            }
+        }
+    }
+
+    impl ConfigBounds {
+        /// Resolves an override against its range, or returns `default`
+        fn resolve(value: Option<u32>, default: u32, (min, max): (u32, u32)) -> Result<u32> {
+            match value {
+                None => Ok(default),
+                Some(value) if value >= min && value <= max => Ok(value),
+                Some(_) => Err(Error::ConfigOutOfRange),
+            }
+        }
     }
 
     /// Proposal structure
@@ -43,13 +327,53 @@ mod simple_dao {
     #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
     pub struct Proposal {
         pub proposer: AccountId,
-        pub description: String,
+        /// Blake2x256 hash of the full description, published off-chain
+        pub description_hash: [u8; 32],
+        /// Optional short title (at most `MAX_TITLE_LENGTH` bytes)
+        pub title: Option<String>,
         pub yes_votes: u128,
         pub no_votes: u128,
         pub start_block: u32,
         pub end_block: u32,
         pub status: ProposalStatus,
         pub executed: bool,
+        /// Block whose closing balances determine voting weight
+        pub snapshot_block: u32,
+        /// Total voting power at the snapshot (used for quorum)
+        pub snapshot_total_power: u128,
+        /// Calls dispatched in order on execution
+        pub actions: Vec<Action>,
+        /// Weight of abstaining votes
+        ///
+        /// Appended after the original fields so their encoding is unchanged.
+        pub abstain_votes: u128,
+        /// First block at which a passed proposal may be executed
+        pub executable_after: u32,
+        /// Last block at which a passed proposal may be executed
+        pub expires_at: u32,
+        /// Strategy in force when the proposal was created
+        pub voting_strategy: VotingStrategy,
+        /// Option labels; empty for binary (yes/no/abstain) proposals
+        pub options: Vec<String>,
+        /// Vote weight per option, parallel to `options`
+        pub option_votes: Vec<u128>,
+        /// Option with the most votes, set when a multi-choice proposal passes
+        pub winning_option: Option<u8>,
+        /// Quorum in basis points of the snapshot total power
+        pub quorum_bps: u32,
+        /// Share of yes votes among yes and no votes that must be exceeded
+        pub approval_threshold_bps: u32,
+        /// Raw balance behind the current votes, before the voting strategy
+        ///
+        /// Quorum compares this against the snapshot total power.
+        pub turnout: u128,
+    }
+
+    impl Proposal {
+        /// Whether this is a multi-choice rather than a binary proposal
+        pub fn is_multi_choice(&self) -> bool {
+            !self.options.is_empty()
+        }
     }
 
     /// The DAO error types
@@ -72,6 +396,82 @@ mod simple_dao {
         NotMember,
         /// Invalid voting period
         InvalidVotingPeriod,
+        /// Caller holds no voting tokens
+        NoVotingPower,
+        /// Weight must come from the configured DALLA token or membership NFT
+        ManualWeightDisabled,
+        /// No DALLA token or membership NFT configured to read voting weight from
+        TokenNotConfigured,
+        /// Proposal carries more than `MAX_ACTIONS` actions
+        TooManyActions,
+        /// Action input exceeds `MAX_ACTION_INPUT` bytes
+        ActionTooLarge,
+        /// Token transfer into or out of the treasury failed
+        TransferFailed,
+        /// Treasury holds less than the requested amount
+        InsufficientTreasury,
+        /// Deposit amount must be non-zero
+        ZeroAmount,
+        /// Cannot delegate to yourself
+        SelfDelegation,
+        /// Delegation is limited to one level
+        DelegationChain,
+        /// Caller has not delegated
+        NotDelegating,
+        /// Delegate already has `MAX_DELEGATORS` delegators
+        TooManyDelegators,
+        /// Caller delegated their vote and cannot vote directly
+        VoteDelegated,
+        /// Proposal is no longer active
+        ProposalNotActive,
+        /// Caller may not cancel this proposal
+        CannotCancel,
+        /// Caller's DALLA balance is below the proposal threshold
+        BelowProposalThreshold,
+        /// Caller is not on the proposer allowlist
+        NotProposer,
+        /// Proposal is still in its execution delay
+        TimelockNotElapsed,
+        /// Proposal's execution window has passed
+        ProposalExpired,
+        /// Action targets the DAO with an unknown selector or malformed input
+        InvalidAction,
+        /// Caller has no vote on this proposal
+        NotVoted,
+        /// Multi-choice proposals need between `MIN_OPTIONS` and `MAX_OPTIONS` options
+        InvalidOptionCount,
+        /// Option index out of range
+        InvalidOption,
+        /// Binary vote on a multi-choice proposal or vice versa
+        WrongProposalKind,
+        /// Caller is not the guardian
+        NotGuardian,
+        /// Proposal config override or bound outside the allowed range
+        ConfigOutOfRange,
+        /// Vote signature is malformed or not from the voter
+        InvalidSignature,
+        /// Signed vote nonce does not match the voter's next nonce
+        InvalidNonce,
+        /// Caller lacks the required role
+        NotAuthorized,
+        /// Proposal title exceeds `MAX_TITLE_LENGTH` bytes
+        TitleTooLong,
+        /// Proposal has not been finalized
+        NotFinalized,
+        /// Vote reward for this proposal was already claimed
+        RewardClaimed,
+        /// Claim covers more than `MAX_REWARD_CLAIMS` proposals
+        TooManyClaims,
+        /// Proposal did not reach quorum and pays no vote reward
+        QuorumNotReached,
+        /// Voter's share of the snapshot voting power rounds to no reward
+        RewardTooSmall,
+    }
+
+    impl From<AccessError> for Error {
+        fn from(_: AccessError) -> Self {
+            Error::NotAuthorized
+        }
     }
 
     /// Result type
@@ -82,22 +482,68 @@ mod simple_dao {
     pub struct SimpleDao {
         /// Mapping from proposal ID to proposal
         proposals: Mapping<ProposalId, Proposal>,
-        /// Mapping from (proposal_id, voter) to vote weight
-        votes: Mapping<(ProposalId, AccountId), u128>,
+        /// Mapping from (proposal_id, voter) to their current vote
+        votes: Mapping<(ProposalId, AccountId), VoterRecord>,
         /// Next proposal ID
         next_proposal_id: ProposalId,
         /// Voting period in blocks
         voting_period: u32,
         /// Quorum threshold (basis points, e.g., 2000 = 20%)
         quorum_bps: u32,
+        /// Blocks between the end of voting and the earliest execution
+        execution_delay: u32,
+        /// Blocks a passed proposal stays executable after its delay
+        expiration_window: u32,
+        /// Strategy applied to proposals created from now on
+        voting_strategy: VotingStrategy,
         /// Total voting power (for quorum calculation)
         total_voting_power: u128,
-        /// Admin account
+        /// Primary admin; always holds DEFAULT_ADMIN_ROLE
         admin: AccountId,
         /// DALLA token contract address (optional)
         dalla_token: Option<AccountId>,
         /// NFT membership contract address (optional)
         nft_membership: Option<AccountId>,
+        /// Mapping from delegator to the account voting on their behalf
+        delegates: Mapping<AccountId, AccountId>,
+        /// Mapping from delegate to the accounts delegating to them
+        delegators: Mapping<AccountId, Vec<AccountId>>,
+        /// Accounts whose balance has been counted on a proposal, directly
+        /// or through their delegate
+        counted: Mapping<(ProposalId, AccountId), ()>,
+        /// Delegators counted through each voter's vote on a proposal
+        counted_by: Mapping<(ProposalId, AccountId), Vec<AccountId>>,
+        /// Active proposal at each index of the active set
+        active_proposals: Mapping<u32, ProposalId>,
+        /// Index of each proposal in the active set
+        active_index: Mapping<ProposalId, u32>,
+        /// Number of proposals in the active set
+        active_count: u32,
+        /// DALLA bond required to create a proposal
+        proposal_bond: Balance,
+        /// Bond held for each proposal until finalization or cancellation
+        bonds: Mapping<ProposalId, Balance>,
+        /// Sum of all held bonds, which the treasury may not spend
+        total_bonded: Balance,
+        /// DALLA balance required to create a proposal (0 = anyone)
+        proposal_threshold: u128,
+        /// Accounts allowed to propose when no DALLA token is configured
+        proposers: Mapping<AccountId, ()>,
+        /// Account allowed to veto passed proposals before execution
+        guardian: Option<AccountId>,
+        /// Default approval threshold in basis points
+        approval_threshold_bps: u32,
+        /// Allowed ranges for per-proposal overrides
+        config_bounds: ConfigBounds,
+        /// Next expected signed vote nonce per voter
+        vote_nonces: Mapping<AccountId, u64>,
+        /// DALLA vote reward per finalized proposal, split among its voters
+        /// by voting power and only paid once quorum was reached
+        vote_reward: Balance,
+        /// Vote rewards already paid out
+        reward_claims: Mapping<(ProposalId, AccountId), ()>,
+        /// Role assignments for admin and config functions
+        access_control: AccessControlData,
     }
 
     /// Event emitted when a proposal is created
@@ -107,7 +553,8 @@ mod simple_dao {
         id: ProposalId,
         #[ink(topic)]
         proposer: AccountId,
-        description: String,
+        title: Option<String>,
+        description_hash: [u8; 32],
         end_block: u32,
     }
 
@@ -118,7 +565,43 @@ mod simple_dao {
         proposal_id: ProposalId,
         #[ink(topic)]
         voter: AccountId,
-        support: bool,
+        vote_type: VoteType,
+        weight: u128,
+    }
+
+    /// Event emitted when a voter changes an existing vote
+    #[ink(event)]
+    pub struct VoteChanged {
+        #[ink(topic)]
+        proposal_id: ProposalId,
+        #[ink(topic)]
+        voter: AccountId,
+        old_vote_type: VoteType,
+        old_weight: u128,
+        new_vote_type: VoteType,
+        new_weight: u128,
+    }
+
+    /// Event emitted when a voter picks an option on a multi-choice proposal
+    ///
+    /// Also emitted when a voter switches to a different option.
+    #[ink(event)]
+    pub struct ChoiceVoteCast {
+        #[ink(topic)]
+        proposal_id: ProposalId,
+        #[ink(topic)]
+        voter: AccountId,
+        option_index: u8,
+        weight: u128,
+    }
+
+    /// Event emitted when a voter retracts their vote
+    #[ink(event)]
+    pub struct VoteRetracted {
+        #[ink(topic)]
+        proposal_id: ProposalId,
+        #[ink(topic)]
+        voter: AccountId,
         weight: u128,
     }
 
@@ -129,16 +612,154 @@ mod simple_dao {
         proposal_id: ProposalId,
     }
 
+    /// Event emitted when one of a proposal's actions fails
+    #[ink(event)]
+    pub struct ProposalExecutionFailed {
+        #[ink(topic)]
+        proposal_id: ProposalId,
+        action_index: u32,
+    }
+
+    /// Event emitted when an account changes its delegate
+    #[ink(event)]
+    pub struct DelegateChanged {
+        #[ink(topic)]
+        delegator: AccountId,
+        from_delegate: Option<AccountId>,
+        to_delegate: Option<AccountId>,
+    }
+
+    /// Event emitted when voting on a proposal is finalized
+    #[ink(event)]
+    pub struct ProposalFinalized {
+        #[ink(topic)]
+        proposal_id: ProposalId,
+        status: ProposalStatus,
+        yes_votes: u128,
+        no_votes: u128,
+        total_votes: u128,
+        quorum_required: u128,
+    }
+
+    /// Event emitted when a proposal is cancelled
+    #[ink(event)]
+    pub struct ProposalCancelled {
+        #[ink(topic)]
+        proposal_id: ProposalId,
+        #[ink(topic)]
+        cancelled_by: AccountId,
+    }
+
+    /// Event emitted when the guardian vetoes a passed proposal
+    #[ink(event)]
+    pub struct ProposalVetoed {
+        #[ink(topic)]
+        proposal_id: ProposalId,
+        #[ink(topic)]
+        guardian: AccountId,
+    }
+
+    /// Event emitted when the guardian renounces or is removed by governance
+    #[ink(event)]
+    pub struct GuardianRemoved {
+        #[ink(topic)]
+        guardian: AccountId,
+    }
+
+    /// Event emitted when funds are deposited into the treasury
+    #[ink(event)]
+    pub struct TreasuryDeposit {
+        #[ink(topic)]
+        from: AccountId,
+        /// `None` for native funds
+        #[ink(topic)]
+        token: Option<AccountId>,
+        amount: Balance,
+    }
+
+    /// Event emitted when a proposal pays out from the treasury
+    #[ink(event)]
+    pub struct TreasuryWithdrawal {
+        #[ink(topic)]
+        to: AccountId,
+        /// `None` for native funds
+        #[ink(topic)]
+        token: Option<AccountId>,
+        amount: Balance,
+    }
+
+    /// Event emitted when a voter claims their reward for a proposal
+    #[ink(event)]
+    pub struct VoteRewardClaimed {
+        #[ink(topic)]
+        proposal_id: ProposalId,
+        #[ink(topic)]
+        voter: AccountId,
+        amount: Balance,
+    }
+
+    /// Pre-encoded message arguments, written to the call input verbatim
+    struct CallInput<'a>(&'a [u8]);
+
+    impl ink::scale::Encode for CallInput<'_> {
+        fn encode_to<T: ink::scale::Output + ?Sized>(&self, dest: &mut T) {
+            dest.write(self.0);
+        }
+    }
+
+    /// Undecoded reply of an action call
+    ///
+    /// Captures whatever the target returned, so actions can call messages
+    /// of any return type without the reply failing to decode.
+    struct CallOutput(Vec<u8>);
+
+    impl ink::scale::Decode for CallOutput {
+        fn decode<I: ink::scale::Input>(
+            input: &mut I,
+        ) -> core::result::Result<Self, ink::scale::Error> {
+            let len = input.remaining_len()?.unwrap_or(0);
+            let mut bytes = vec![0u8; len];
+            input.read(&mut bytes)?;
+            Ok(Self(bytes))
+        }
+    }
+
+    impl CallOutput {
+        /// Whether the reply reports success
+        ///
+        /// Action targets are expected to return `()` or a `Result`, as
+        /// setters do: an empty reply or one starting with the `Ok` tag
+        /// succeeded, one starting with the `Err` tag (the target reverted)
+        /// did not.
+        fn is_ok(&self) -> bool {
+            self.0.first().map_or(true, |tag| *tag == 0)
+        }
+    }
+
     impl SimpleDao {
         /// Creates a new Simple DAO
+        ///
+        /// The deployer becomes the admin and holds both DEFAULT_ADMIN_ROLE
+        /// and CONFIG_ROLE. `guardian` may veto passed proposals until it
+        /// renounces the role or a passed proposal removes it.
         #[ink(constructor)]
         pub fn new(
             voting_period: u32,
             quorum_bps: u32,
+            execution_delay: u32,
+            voting_strategy: VotingStrategy,
             dalla_token: Option<AccountId>,
             nft_membership: Option<AccountId>,
+            guardian: Option<AccountId>,
         ) -> Self {
             let caller = Self::env().caller();
+            let now = Self::env().block_timestamp();
+
+            // Cannot fail: the deployer already holds DEFAULT_ADMIN_ROLE
+            let mut access_control = AccessControlData::new(caller);
+            let _ = access_control.grant_role(caller, CONFIG_ROLE, caller, now, |event| {
+                Self::env().emit_event(event);
+            });
 
             Self {
                 proposals: Mapping::default(),
@@ -146,44 +767,337 @@ mod simple_dao {
                 next_proposal_id: 1,
                 voting_period,
                 quorum_bps,
+                execution_delay,
+                expiration_window: DEFAULT_EXPIRATION_WINDOW,
+                voting_strategy,
                 total_voting_power: 0,
                 admin: caller,
                 dalla_token,
                 nft_membership,
+                delegates: Mapping::default(),
+                delegators: Mapping::default(),
+                counted: Mapping::default(),
+                counted_by: Mapping::default(),
+                active_proposals: Mapping::default(),
+                active_index: Mapping::default(),
+                active_count: 0,
+                proposal_bond: 0,
+                bonds: Mapping::default(),
+                total_bonded: 0,
+                proposal_threshold: 0,
+                proposers: Mapping::default(),
+                guardian,
+                approval_threshold_bps: DEFAULT_APPROVAL_THRESHOLD_BPS,
+                config_bounds: ConfigBounds::default(),
+                vote_nonces: Mapping::default(),
+                vote_reward: 0,
+                reward_claims: Mapping::default(),
+                access_control,
             }
         }
 
         /// Creates a new proposal
+        ///
+        /// Only the Blake2x256 hash of `description` is stored; publish the
+        /// full text off-chain so anyone can check it with
+        /// `verify_description`. Prefer `create_proposal_with_hash`, which
+        /// keeps long descriptions out of the transaction as well.
+        ///
+        /// `actions` are dispatched in order by `execute_proposal` once the
+        /// proposal has passed. Pass an empty list for a signalling proposal.
+        ///
+        /// `config` overrides the voting period, quorum or approval threshold
+        /// for this proposal only, within `config_bounds`.
+        ///
+        /// The caller must pass `can_propose`. When a DALLA token is
+        /// configured, the current proposal bond is pulled from the caller
+        /// via `transfer_from`; the DAO must have been approved for it
+        /// beforehand. Only what the DAO receives after any transfer fee is
+        /// held as the bond and refunded.
+        #[ink(message)]
+        pub fn create_proposal(
+            &mut self,
+            description: String,
+            actions: Vec<Action>,
+            config: Option<ProposalConfig>,
+        ) -> Result<ProposalId> {
+            let description_hash = Self::hash_description(&description);
+            self.submit_proposal(None, description_hash, actions, Vec::new(), config)
+        }
+
+        /// Creates a new proposal from the hash of its off-chain description
+        ///
+        /// `description_hash` is the Blake2x256 hash of the full text and
+        /// `title` an optional short label of at most `MAX_TITLE_LENGTH`
+        /// bytes. Actions, config, threshold and bond work as in
+        /// `create_proposal`.
+        #[ink(message)]
+        pub fn create_proposal_with_hash(
+            &mut self,
+            title: Option<String>,
+            description_hash: [u8; 32],
+            actions: Vec<Action>,
+            config: Option<ProposalConfig>,
+        ) -> Result<ProposalId> {
+            self.submit_proposal(title, description_hash, actions, Vec::new(), config)
+        }
+
+        /// Checks `full_text` against a proposal's description hash
+        #[ink(message)]
+        pub fn verify_description(&self, proposal_id: ProposalId, full_text: String) -> bool {
+            self.proposals.get(proposal_id).is_some_and(|proposal| {
+                proposal.description_hash == Self::hash_description(&full_text)
+            })
+        }
+
+        /// Creates a multi-choice proposal
+        ///
+        /// Voters pick one of 2 to 10 `options` with `vote_choice`. The
+        /// proposal passes when quorum is reached and a single option has the
+        /// most votes; ties are rejected. Same threshold and bond rules as
+        /// `create_proposal`.
+        #[ink(message)]
+        pub fn create_multi_choice_proposal(
+            &mut self,
+            description: String,
+            options: Vec<String>,
+        ) -> Result<ProposalId> {
+            if options.len() < MIN_OPTIONS as usize || options.len() > MAX_OPTIONS as usize {
+                return Err(Error::InvalidOptionCount);
+            }
+
+            let description_hash = Self::hash_description(&description);
+            self.submit_proposal(None, description_hash, Vec::new(), options, None)
+        }
+
+        /// Casts a token-weighted vote for one option of a multi-choice proposal
+        ///
+        /// Weight is resolved as in `vote`. Voting again before `end_block`
+        /// moves the recorded weight to the new option.
+        #[ink(message)]
+        pub fn vote_choice(&mut self, proposal_id: ProposalId, option_index: u8) -> Result<()> {
+            let caller = self.env().caller();
+            if self.dalla_token.is_none() && self.nft_membership.is_none() {
+                return Err(Error::TokenNotConfigured);
+            }
+            let mut proposal = self.open_choice_proposal(proposal_id, option_index)?;
+
+            let support = Support::Choice(option_index);
+            if let Some(previous) = self.votes.get((proposal_id, caller)) {
+                let (weight, power) = (previous.weight, previous.power);
+                self.change_vote(
+                    proposal_id,
+                    &mut proposal,
+                    caller,
+                    previous,
+                    support,
+                    weight,
+                    power,
+                );
+                return Ok(());
+            }
+
+            self.ensure_can_vote(proposal_id, caller)?;
+            self.ensure_member(caller)?;
+
+            let (weight, power) = self.resolve_weight(proposal_id, &proposal, caller)?;
+            self.record_vote(proposal_id, &mut proposal, caller, support, weight, power);
+            Ok(())
+        }
+
+        /// Votes for an option with a caller-supplied weight
+        ///
+        /// Only available when neither a DALLA token nor a membership NFT is
+        /// configured; see `vote_with_weight`.
+        #[ink(message)]
+        pub fn vote_choice_with_weight(
+            &mut self,
+            proposal_id: ProposalId,
+            option_index: u8,
+            weight: u128,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            if self.dalla_token.is_some() || self.nft_membership.is_some() {
+                return Err(Error::ManualWeightDisabled);
+            }
+            let mut proposal = self.open_choice_proposal(proposal_id, option_index)?;
+            let power = weight;
+            let weight = proposal.voting_strategy.effective_weight(power);
+
+            let support = Support::Choice(option_index);
+            if let Some(previous) = self.votes.get((proposal_id, caller)) {
+                self.change_vote(
+                    proposal_id,
+                    &mut proposal,
+                    caller,
+                    previous,
+                    support,
+                    weight,
+                    power,
+                );
+                return Ok(());
+            }
+
+            self.ensure_can_vote(proposal_id, caller)?;
+            self.ensure_member(caller)?;
+
+            self.record_vote(proposal_id, &mut proposal, caller, support, weight, power);
+            Ok(())
+        }
+
+        /// Gets the option labels and their tallies for a proposal
+        #[ink(message)]
+        pub fn get_options(&self, proposal_id: ProposalId) -> Vec<(String, u128)> {
+            self.proposals
+                .get(proposal_id)
+                .map(|proposal| {
+                    proposal
+                        .options
+                        .into_iter()
+                        .zip(proposal.option_votes)
+                        .collect()
+                })
+                .unwrap_or_default()
+        }
+
+        /// Gets the option an account voted for on a multi-choice proposal
         #[ink(message)]
-        pub fn create_proposal(&mut self, description: String) -> Result<ProposalId> {
+        pub fn get_choice(&self, proposal_id: ProposalId, voter: AccountId) -> Option<u8> {
+            match self.votes.get((proposal_id, voter))?.support {
+                Support::Choice(option_index) => Some(option_index),
+                Support::Vote(_) => None,
+            }
+        }
+
+        /// Blake2x256 hash of a proposal description
+        fn hash_description(text: &str) -> [u8; 32] {
+            let mut hash = [0u8; 32];
+            ink::env::hash_bytes::<Blake2x256>(text.as_bytes(), &mut hash);
+            hash
+        }
+
+        /// Creates a binary or multi-choice proposal
+        fn submit_proposal(
+            &mut self,
+            title: Option<String>,
+            description_hash: [u8; 32],
+            actions: Vec<Action>,
+            options: Vec<String>,
+            config: Option<ProposalConfig>,
+        ) -> Result<ProposalId> {
             let caller = self.env().caller();
             let current_block = self.env().block_number();
 
-            if self.voting_period == 0 {
+            if title
+                .as_ref()
+                .is_some_and(|title| title.len() > MAX_TITLE_LENGTH as usize)
+            {
+                return Err(Error::TitleTooLong);
+            }
+
+            let config = config.unwrap_or_default();
+            let bounds = self.config_bounds;
+            let voting_period = ConfigBounds::resolve(
+                config.voting_period,
+                self.voting_period,
+                bounds.voting_period,
+            )?;
+            let quorum_bps =
+                ConfigBounds::resolve(config.quorum_bps, self.quorum_bps, bounds.quorum_bps)?;
+            let approval_threshold_bps = ConfigBounds::resolve(
+                config.approval_threshold_bps,
+                self.approval_threshold_bps,
+                bounds.approval_threshold_bps,
+            )?;
+
+            if voting_period == 0 {
                 return Err(Error::InvalidVotingPeriod);
             }
 
+            self.ensure_member(caller)?;
+
+            if self.proposal_threshold > 0 {
+                match self.dalla_token {
+                    Some(token) => {
+                        if self.token_balance_of(token, caller) < self.proposal_threshold {
+                            return Err(Error::BelowProposalThreshold);
+                        }
+                    }
+                    None => {
+                        if !self.proposers.contains(caller) {
+                            return Err(Error::NotProposer);
+                        }
+                    }
+                }
+            }
+
+            if actions.len() > MAX_ACTIONS as usize {
+                return Err(Error::TooManyActions);
+            }
+            if actions
+                .iter()
+                .any(|action| action.input.len() > MAX_ACTION_INPUT as usize)
+            {
+                return Err(Error::ActionTooLarge);
+            }
+
             let proposal_id = self.next_proposal_id;
-            let end_block = current_block.saturating_add(self.voting_period);
+            let end_block = current_block.saturating_add(voting_period);
+
+            if let Some(token) = self.dalla_token {
+                if self.proposal_bond > 0 {
+                    let bond = self.token_pull(token, caller, self.proposal_bond)?;
+                    self.bonds.insert(proposal_id, &bond);
+                    self.total_bonded = self.total_bonded.saturating_add(bond);
+                }
+            }
+
+            // Snapshot balances as of the previous block so tokens bought
+            // after the proposal appears carry no weight
+            let snapshot_block = current_block.saturating_sub(1);
+            let snapshot_total_power = match self.dalla_token {
+                // Tokens without checkpoints report no historical supply
+                Some(token) => match self.token_total_supply_at(token, snapshot_block) {
+                    0 => self.token_total_supply(token),
+                    supply => supply,
+                },
+                None => self.total_voting_power,
+            };
 
             let proposal = Proposal {
                 proposer: caller,
-                description: description.clone(),
+                description_hash,
+                title: title.clone(),
                 yes_votes: 0,
                 no_votes: 0,
                 start_block: current_block,
                 end_block,
                 status: ProposalStatus::Active,
                 executed: false,
+                snapshot_block,
+                snapshot_total_power,
+                actions,
+                abstain_votes: 0,
+                executable_after: 0,
+                expires_at: 0,
+                voting_strategy: self.voting_strategy,
+                option_votes: vec![0; options.len()],
+                options,
+                winning_option: None,
+                quorum_bps,
+                approval_threshold_bps,
+                turnout: 0,
             };
 
             self.proposals.insert(proposal_id, &proposal);
+            self.add_active(proposal_id);
             self.next_proposal_id = self.next_proposal_id.saturating_add(1);
 
             self.env().emit_event(ProposalCreated {
                 id: proposal_id,
                 proposer: caller,
-                description,
+                title,
+                description_hash,
                 end_block,
             });
 
@@ -191,83 +1105,353 @@ mod simple_dao {
         }
 
         /// Casts a vote on a proposal
+        ///
+        /// The vote weight is the caller's DALLA balance at the proposal's
+        /// snapshot block, read from the configured token contract, plus the
+        /// snapshot balances of everyone delegating to the caller who has not
+        /// already been counted on this proposal. If an NFT membership
+        /// contract is set, the caller must also hold at least one membership
+        /// NFT; without a DALLA token, each NFT held counts as one vote.
+        ///
+        /// Voting again before `end_block` changes the caller's vote type and
+        /// keeps the weight recorded with the original vote.
         #[ink(message)]
-        pub fn vote(&mut self, proposal_id: ProposalId, support: bool, weight: u128) -> Result<()> {
+        pub fn vote(&mut self, proposal_id: ProposalId, vote_type: VoteType) -> Result<()> {
             let caller = self.env().caller();
-            let current_block = self.env().block_number();
-
-            // Check if already voted
-            if self.votes.contains((proposal_id, caller)) {
-                return Err(Error::AlreadyVoted);
+            if self.dalla_token.is_none() && self.nft_membership.is_none() {
+                return Err(Error::TokenNotConfigured);
+            }
+            let mut proposal = self.open_proposal(proposal_id)?;
+            if proposal.is_multi_choice() {
+                return Err(Error::WrongProposalKind);
             }
 
-            // Get proposal
-            let mut proposal = self
-                .proposals
-                .get(proposal_id)
-                .ok_or(Error::ProposalNotFound)?;
-
-            // Check if voting is still active
-            if current_block > proposal.end_block {
-                return Err(Error::VotingEnded);
+            let support = Support::Vote(vote_type);
+            if let Some(previous) = self.votes.get((proposal_id, caller)) {
+                let (weight, power) = (previous.weight, previous.power);
+                self.change_vote(
+                    proposal_id,
+                    &mut proposal,
+                    caller,
+                    previous,
+                    support,
+                    weight,
+                    power,
+                );
+                return Ok(());
             }
 
-            // Record vote
-            self.votes.insert((proposal_id, caller), &weight);
+            self.ensure_can_vote(proposal_id, caller)?;
+            self.ensure_member(caller)?;
 
-            // Update vote counts
-            if support {
-                proposal.yes_votes = proposal.yes_votes.saturating_add(weight);
-            } else {
-                proposal.no_votes = proposal.no_votes.saturating_add(weight);
+            let (weight, power) = self.resolve_weight(proposal_id, &proposal, caller)?;
+            self.record_vote(proposal_id, &mut proposal, caller, support, weight, power);
+            Ok(())
+        }
+
+        /// Casts a vote with a caller-supplied weight
+        ///
+        /// Only available when neither a DALLA token nor a membership NFT is
+        /// configured. `weight` is
+        /// treated as the caller's balance and passed through the voting
+        /// strategy. Voting again before `end_block` replaces the caller's
+        /// previous vote.
+        #[ink(message)]
+        pub fn vote_with_weight(
+            &mut self,
+            proposal_id: ProposalId,
+            vote_type: VoteType,
+            weight: u128,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            if self.dalla_token.is_some() || self.nft_membership.is_some() {
+                return Err(Error::ManualWeightDisabled);
+            }
+            let mut proposal = self.open_proposal(proposal_id)?;
+            if proposal.is_multi_choice() {
+                return Err(Error::WrongProposalKind);
             }
+            let power = weight;
+            let weight = proposal.voting_strategy.effective_weight(power);
 
-            self.proposals.insert(proposal_id, &proposal);
+            let support = Support::Vote(vote_type);
+            if let Some(previous) = self.votes.get((proposal_id, caller)) {
+                self.change_vote(
+                    proposal_id,
+                    &mut proposal,
+                    caller,
+                    previous,
+                    support,
+                    weight,
+                    power,
+                );
+                return Ok(());
+            }
 
-            self.env().emit_event(VoteCast {
-                proposal_id,
-                voter: caller,
-                support,
-                weight,
-            });
+            self.ensure_can_vote(proposal_id, caller)?;
+            self.ensure_member(caller)?;
 
+            self.record_vote(proposal_id, &mut proposal, caller, support, weight, power);
             Ok(())
         }
 
-        /// Finalizes a proposal after voting period ends
+        /// Retracts the caller's vote before `end_block`
+        ///
+        /// Delegators counted through the vote become free to vote again.
         #[ink(message)]
-        pub fn finalize_proposal(&mut self, proposal_id: ProposalId) -> Result<()> {
-            let current_block = self.env().block_number();
+        pub fn retract_vote(&mut self, proposal_id: ProposalId) -> Result<()> {
+            let caller = self.env().caller();
+            let mut proposal = self.open_proposal(proposal_id)?;
 
-            let mut proposal = self
+            let VoterRecord {
+                support,
+                weight,
+                power,
+                ..
+            } = self
+                .votes
+                .take((proposal_id, caller))
+                .ok_or(Error::NotVoted)?;
+
+            Self::remove_tally(&mut proposal, support, weight);
+            proposal.turnout = proposal.turnout.saturating_sub(power);
+            self.proposals.insert(proposal_id, &proposal);
+
+            self.counted.remove((proposal_id, caller));
+            for delegator in self
+                .counted_by
+                .take((proposal_id, caller))
+                .unwrap_or_default()
+            {
+                self.counted.remove((proposal_id, delegator));
+            }
+
+            self.env().emit_event(VoteRetracted {
+                proposal_id,
+                voter: caller,
+                weight,
+            });
+
+            Ok(())
+        }
+
+        /// Casts a batch of votes signed off-chain
+        ///
+        /// Anyone may relay the batch and pay for it. Each vote is weighted
+        /// like `vote` and must carry the voter's next nonce. Invalid or
+        /// duplicate votes are skipped; the returned vector holds the outcome
+        /// of each vote in order.
+        #[ink(message)]
+        pub fn submit_votes(
+            &mut self,
+            proposal_id: ProposalId,
+            votes: Vec<SignedVote>,
+        ) -> Result<Vec<Result<()>>> {
+            if self.dalla_token.is_none() && self.nft_membership.is_none() {
+                return Err(Error::TokenNotConfigured);
+            }
+            let mut proposal = self.open_proposal(proposal_id)?;
+
+            Ok(votes
+                .iter()
+                .map(|vote| self.cast_signed_vote(proposal_id, &mut proposal, vote))
+                .collect())
+        }
+
+        /// Returns the digest a voter signs to authorize a `SignedVote`
+        ///
+        /// The DAO address and proposal id are included so signatures cannot
+        /// be replayed on another DAO or proposal.
+        #[ink(message)]
+        pub fn vote_hash(
+            &self,
+            proposal_id: ProposalId,
+            voter: AccountId,
+            support: Support,
+            nonce: u64,
+        ) -> [u8; 32] {
+            let mut hash = [0u8; 32];
+            ink::env::hash_encoded::<Blake2x256, _>(
+                &(self.env().account_id(), proposal_id, voter, support, nonce),
+                &mut hash,
+            );
+            hash
+        }
+
+        /// Gets the nonce the next signed vote of `voter` must carry
+        #[ink(message)]
+        pub fn vote_nonce(&self, voter: AccountId) -> u64 {
+            self.vote_nonces.get(voter).unwrap_or(0)
+        }
+
+        /// Finalizes a proposal after voting period ends
+        ///
+        /// The proposer's bond is refunded if the proposal reached quorum and
+        /// kept by the treasury otherwise.
+        #[ink(message)]
+        pub fn finalize_proposal(&mut self, proposal_id: ProposalId) -> Result<()> {
+            let current_block = self.env().block_number();
+
+            let mut proposal = self
                 .proposals
                 .get(proposal_id)
                 .ok_or(Error::ProposalNotFound)?;
 
+            if proposal.status != ProposalStatus::Active {
+                return Err(Error::ProposalNotActive);
+            }
+
             // Check if voting period ended
             if current_block <= proposal.end_block {
                 return Err(Error::VotingActive);
             }
 
-            // Update status based on votes
-            let total_votes = proposal.yes_votes.saturating_add(proposal.no_votes);
-            let quorum_required = self
-                .total_voting_power
-                .saturating_mul(self.quorum_bps as u128)
-                .saturating_div(10000);
-
-            if total_votes >= quorum_required && proposal.yes_votes > proposal.no_votes {
+            let outcome = self.outcome(&proposal);
+            if outcome.passed {
                 proposal.status = ProposalStatus::Passed;
+                proposal.winning_option = Self::plurality(&proposal.option_votes);
+                (proposal.executable_after, proposal.expires_at) =
+                    self.execution_window(proposal.end_block);
             } else {
                 proposal.status = ProposalStatus::Rejected;
             }
 
             self.proposals.insert(proposal_id, &proposal);
+            self.remove_active(proposal_id);
+
+            if outcome.quorum_reached {
+                self.refund_bond(proposal_id, proposal.proposer)?;
+            } else {
+                self.release_bond(proposal_id);
+            }
+
+            self.env().emit_event(ProposalFinalized {
+                proposal_id,
+                status: proposal.status,
+                yes_votes: proposal.yes_votes,
+                no_votes: proposal.no_votes,
+                total_votes: outcome.total_votes,
+                quorum_required: outcome.quorum_required,
+            });
+
+            Ok(())
+        }
+
+        /// Cancels a proposal
+        ///
+        /// The proposer may cancel while the proposal is active and has no
+        /// votes, and gets the bond back. The admin may cancel any active or
+        /// passed proposal before execution; the bond then goes to the
+        /// treasury.
+        #[ink(message)]
+        pub fn cancel_proposal(&mut self, proposal_id: ProposalId) -> Result<()> {
+            let caller = self.env().caller();
+
+            let mut proposal = self
+                .proposals
+                .get(proposal_id)
+                .ok_or(Error::ProposalNotFound)?;
+
+            if proposal.executed {
+                return Err(Error::AlreadyExecuted);
+            }
+
+            if self.access_control.has_role(DEFAULT_ADMIN_ROLE, caller) {
+                if !matches!(
+                    proposal.status,
+                    ProposalStatus::Active | ProposalStatus::Passed
+                ) {
+                    return Err(Error::ProposalNotActive);
+                }
+            } else {
+                let has_votes = proposal.yes_votes > 0
+                    || proposal.no_votes > 0
+                    || proposal.abstain_votes > 0
+                    || proposal.option_votes.iter().any(|votes| *votes > 0);
+                if caller != proposal.proposer
+                    || proposal.status != ProposalStatus::Active
+                    || has_votes
+                {
+                    return Err(Error::CannotCancel);
+                }
+            }
+
+            proposal.status = ProposalStatus::Cancelled;
+            self.proposals.insert(proposal_id, &proposal);
+            self.remove_active(proposal_id);
+
+            if caller == proposal.proposer {
+                self.refund_bond(proposal_id, proposal.proposer)?;
+            } else {
+                self.release_bond(proposal_id);
+            }
+
+            self.env().emit_event(ProposalCancelled {
+                proposal_id,
+                cancelled_by: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Vetoes a passed proposal before it is executed (guardian only)
+        ///
+        /// The guardian has no other powers; it cannot create proposals or
+        /// affect votes through this role.
+        #[ink(message)]
+        pub fn veto_proposal(&mut self, proposal_id: ProposalId) -> Result<()> {
+            let caller = self.env().caller();
+            if self.guardian != Some(caller) {
+                return Err(Error::NotGuardian);
+            }
+
+            let mut proposal = self
+                .proposals
+                .get(proposal_id)
+                .ok_or(Error::ProposalNotFound)?;
+
+            if proposal.executed || proposal.status == ProposalStatus::ExecutionFailed {
+                return Err(Error::AlreadyExecuted);
+            }
+            if proposal.status != ProposalStatus::Passed {
+                return Err(Error::ProposalFailed);
+            }
+
+            proposal.status = ProposalStatus::Vetoed;
+            self.proposals.insert(proposal_id, &proposal);
+
+            self.env().emit_event(ProposalVetoed {
+                proposal_id,
+                guardian: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Gives up the guardian role permanently (guardian only)
+        #[ink(message)]
+        pub fn renounce_guardian(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            if self.guardian != Some(caller) {
+                return Err(Error::NotGuardian);
+            }
 
+            self.remove_guardian();
             Ok(())
         }
 
+        /// Gets the guardian, if any
+        #[ink(message)]
+        pub fn guardian(&self) -> Option<AccountId> {
+            self.guardian
+        }
+
         /// Executes a passed proposal
+        ///
+        /// Only possible from `executable_after` up to and including
+        /// `expires_at`. Dispatches each action in order. If any call fails, execution stops
+        /// and the proposal is marked `ExecutionFailed`; actions that already
+        /// ran are not rolled back.
         #[ink(message)]
         pub fn execute_proposal(&mut self, proposal_id: ProposalId) -> Result<()> {
             let mut proposal = self
@@ -283,6 +1467,28 @@ mod simple_dao {
                 return Err(Error::ProposalFailed);
             }
 
+            let current_block = self.env().block_number();
+            if current_block < proposal.executable_after {
+                return Err(Error::TimelockNotElapsed);
+            }
+            if current_block > proposal.expires_at {
+                return Err(Error::ProposalExpired);
+            }
+
+            for (index, action) in proposal.actions.iter().enumerate() {
+                if !self.dispatch(action) {
+                    proposal.status = ProposalStatus::ExecutionFailed;
+                    self.proposals.insert(proposal_id, &proposal);
+
+                    self.env().emit_event(ProposalExecutionFailed {
+                        proposal_id,
+                        action_index: index as u32,
+                    });
+
+                    return Ok(());
+                }
+            }
+
             // Mark as executed
             proposal.executed = true;
             proposal.status = ProposalStatus::Executed;
@@ -293,15 +1499,235 @@ mod simple_dao {
             Ok(())
         }
 
+        /// Delegates the caller's voting power to `to`
+        ///
+        /// Replaces any existing delegation. Delegation is one level deep: an
+        /// account that delegates cannot receive delegations and vice versa.
+        #[ink(message)]
+        pub fn delegate(&mut self, to: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+
+            if to == caller {
+                return Err(Error::SelfDelegation);
+            }
+            if self.delegates.contains(to)
+                || !self.delegators.get(caller).unwrap_or_default().is_empty()
+            {
+                return Err(Error::DelegationChain);
+            }
+
+            let mut new_delegators = self.delegators.get(to).unwrap_or_default();
+            if new_delegators.len() >= MAX_DELEGATORS as usize {
+                return Err(Error::TooManyDelegators);
+            }
+
+            let previous = self.delegates.get(caller);
+            if previous == Some(to) {
+                return Ok(());
+            }
+            if let Some(previous) = previous {
+                self.remove_delegator(previous, caller);
+            }
+
+            new_delegators.push(caller);
+            self.delegators.insert(to, &new_delegators);
+            self.delegates.insert(caller, &to);
+
+            self.env().emit_event(DelegateChanged {
+                delegator: caller,
+                from_delegate: previous,
+                to_delegate: Some(to),
+            });
+
+            Ok(())
+        }
+
+        /// Removes the caller's delegation
+        #[ink(message)]
+        pub fn undelegate(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            let previous = self.delegates.get(caller).ok_or(Error::NotDelegating)?;
+
+            self.remove_delegator(previous, caller);
+            self.delegates.remove(caller);
+
+            self.env().emit_event(DelegateChanged {
+                delegator: caller,
+                from_delegate: Some(previous),
+                to_delegate: None,
+            });
+
+            Ok(())
+        }
+
+        /// Gets the account `account` delegates to
+        #[ink(message)]
+        pub fn delegate_of(&self, account: AccountId) -> Option<AccountId> {
+            self.delegates.get(account)
+        }
+
+        /// Gets the accounts delegating to `account`
+        #[ink(message)]
+        pub fn delegators_of(&self, account: AccountId) -> Vec<AccountId> {
+            self.delegators.get(account).unwrap_or_default()
+        }
+
+        /// Gets the current voting power of `account`
+        ///
+        /// Own DALLA balance (or membership NFT count when no token is set)
+        /// plus that of its delegators, or zero if the account has delegated.
+        /// Token votes use the snapshot balances instead.
+        #[ink(message)]
+        pub fn voting_power(&self, account: AccountId) -> Balance {
+            if self.delegates.contains(account) {
+                return 0;
+            }
+
+            self.delegators
+                .get(account)
+                .unwrap_or_default()
+                .into_iter()
+                .fold(self.power_of(account, None), |power, delegator| {
+                    power.saturating_add(self.power_of(delegator, None))
+                })
+        }
+
+        /// Deposits native funds into the treasury
+        #[ink(message, payable)]
+        pub fn deposit(&mut self) -> Result<()> {
+            let amount = self.env().transferred_value();
+            if amount == 0 {
+                return Err(Error::ZeroAmount);
+            }
+
+            self.env().emit_event(TreasuryDeposit {
+                from: self.env().caller(),
+                token: None,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Deposits PSP22 tokens into the treasury
+        ///
+        /// The caller must have approved the DAO for at least `amount`. The
+        /// event reports the amount received, net of any transfer fee.
+        #[ink(message)]
+        pub fn deposit_token(&mut self, token: AccountId, amount: Balance) -> Result<()> {
+            if amount == 0 {
+                return Err(Error::ZeroAmount);
+            }
+
+            let caller = self.env().caller();
+            let amount = self.token_pull(token, caller, amount)?;
+
+            self.env().emit_event(TreasuryDeposit {
+                from: caller,
+                token: Some(token),
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Gets the native treasury balance
+        #[ink(message)]
+        pub fn treasury_balance(&self) -> Balance {
+            self.env().balance()
+        }
+
+        /// Gets the treasury balance of a PSP22 token
+        ///
+        /// DALLA held as proposal bonds is not part of the treasury and is
+        /// left out.
+        #[ink(message)]
+        pub fn treasury_token_balance(&self, token: AccountId) -> Balance {
+            self.spendable_token_balance(token)
+        }
+
         /// Gets a proposal by ID
         #[ink(message)]
         pub fn get_proposal(&self, proposal_id: ProposalId) -> Option<Proposal> {
             self.proposals.get(proposal_id)
         }
 
+        /// Gets up to `limit` proposals, starting at the `offset`-th proposal
+        #[ink(message)]
+        pub fn get_proposals(&self, offset: u32, limit: u32) -> Vec<(ProposalId, Proposal)> {
+            let end = offset.saturating_add(limit).min(self.proposal_count());
+
+            (offset..end)
+                .filter_map(|index| {
+                    let proposal_id = index.saturating_add(1);
+                    self.proposals
+                        .get(proposal_id)
+                        .map(|proposal| (proposal_id, proposal))
+                })
+                .collect()
+        }
+
+        /// Gets the IDs of proposals that have not been finalized or cancelled
+        ///
+        /// Order is not stable: removals move the last entry into the gap.
+        #[ink(message)]
+        pub fn get_active_proposals(&self) -> Vec<ProposalId> {
+            (0..self.active_count)
+                .filter_map(|index| self.active_proposals.get(index))
+                .collect()
+        }
+
+        /// Computes the effective state of a proposal
+        ///
+        /// Proposals past `end_block` are evaluated as if finalized now, so
+        /// the result does not depend on anyone calling `finalize_proposal`.
+        #[ink(message)]
+        pub fn proposal_state(&self, proposal_id: ProposalId) -> Option<ProposalState> {
+            let proposal = self.proposals.get(proposal_id)?;
+            let current_block = self.env().block_number();
+
+            let (executable_after, expires_at) = match proposal.status {
+                ProposalStatus::Executed => return Some(ProposalState::Executed),
+                ProposalStatus::ExecutionFailed => return Some(ProposalState::ExecutionFailed),
+                ProposalStatus::Cancelled => return Some(ProposalState::Cancelled),
+                ProposalStatus::Vetoed => return Some(ProposalState::Vetoed),
+                ProposalStatus::Rejected => return Some(ProposalState::Defeated),
+                ProposalStatus::Passed => (proposal.executable_after, proposal.expires_at),
+                ProposalStatus::Active => {
+                    if current_block <= proposal.end_block {
+                        return Some(ProposalState::Active);
+                    }
+                    if !self.outcome(&proposal).passed {
+                        return Some(ProposalState::Defeated);
+                    }
+                    self.execution_window(proposal.end_block)
+                }
+            };
+
+            Some(if current_block < executable_after {
+                ProposalState::Queued
+            } else if current_block > expires_at {
+                ProposalState::Expired
+            } else {
+                ProposalState::Succeeded
+            })
+        }
+
         /// Gets the vote weight for an account on a proposal
         #[ink(message)]
         pub fn get_vote(&self, proposal_id: ProposalId, voter: AccountId) -> Option<u128> {
+            self.votes
+                .get((proposal_id, voter))
+                .map(|record| record.weight)
+        }
+
+        /// Gets how an account voted on a proposal, with weight and block
+        #[ink(message)]
+        pub fn get_voter_record(
+            &self,
+            proposal_id: ProposalId,
+            voter: AccountId,
+        ) -> Option<VoterRecord> {
             self.votes.get((proposal_id, voter))
         }
 
@@ -323,217 +1749,4542 @@ mod simple_dao {
             self.quorum_bps
         }
 
-        /// Sets the total voting power (admin only)
+        /// Sets the default voting period for new proposals (config role only)
         #[ink(message)]
-        pub fn set_total_voting_power(&mut self, power: u128) -> Result<()> {
-            let caller = self.env().caller();
-            if caller != self.admin {
-                return Err(Error::NotMember);
+        pub fn set_voting_period(&mut self, voting_period: u32) -> Result<()> {
+            self.ensure_role(CONFIG_ROLE)?;
+            if voting_period == 0 {
+                return Err(Error::InvalidVotingPeriod);
             }
 
-            self.total_voting_power = power;
+            self.voting_period = voting_period;
             Ok(())
         }
 
-        /// Gets the total voting power
+        /// Sets the default quorum for new proposals (config role only)
         #[ink(message)]
-        pub fn total_voting_power(&self) -> u128 {
-            self.total_voting_power
-        }
+        pub fn set_quorum_threshold(&mut self, quorum_bps: u32) -> Result<()> {
+            self.ensure_role(CONFIG_ROLE)?;
+            if quorum_bps > 10000 {
+                return Err(Error::ConfigOutOfRange);
+            }
 
-        /// Gets the DALLA token address
-        #[ink(message)]
-        pub fn dalla_token_address(&self) -> Option<AccountId> {
-            self.dalla_token
+            self.quorum_bps = quorum_bps;
+            Ok(())
         }
 
-        /// Gets the NFT membership address
+        /// Gets the default approval threshold in basis points
         #[ink(message)]
-        pub fn nft_membership_address(&self) -> Option<AccountId> {
-            self.nft_membership
+        pub fn approval_threshold(&self) -> u32 {
+            self.approval_threshold_bps
         }
 
-        /// Transfers admin rights
+        /// Sets the default approval threshold (config role only)
+        ///
+        /// A binary proposal passes when its yes votes exceed this share of
+        /// yes and no votes combined.
         #[ink(message)]
-        pub fn transfer_admin(&mut self, new_admin: AccountId) -> Result<()> {
-            let caller = self.env().caller();
-            if caller != self.admin {
-                return Err(Error::NotMember);
+        pub fn set_approval_threshold(&mut self, approval_threshold_bps: u32) -> Result<()> {
+            self.ensure_role(CONFIG_ROLE)?;
+            if approval_threshold_bps > 10000 {
+                return Err(Error::ConfigOutOfRange);
             }
 
-            self.admin = new_admin;
+            self.approval_threshold_bps = approval_threshold_bps;
             Ok(())
         }
 
-        /// Gets the admin address
+        /// Gets the allowed ranges for per-proposal overrides
         #[ink(message)]
-        pub fn admin(&self) -> AccountId {
-            self.admin
+        pub fn config_bounds(&self) -> ConfigBounds {
+            self.config_bounds
         }
-    }
 
-    #[cfg(test)]
-    mod tests {
-        use super::*;
+        /// Sets the allowed ranges for per-proposal overrides (config role only)
+        #[ink(message)]
+        pub fn set_config_bounds(&mut self, bounds: ConfigBounds) -> Result<()> {
+            self.ensure_role(CONFIG_ROLE)?;
 
-        fn default_accounts() -> ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment> {
-            ink::env::test::default_accounts::<ink::env::DefaultEnvironment>()
-        }
+            let (min_period, max_period) = bounds.voting_period;
+            let (min_quorum, max_quorum) = bounds.quorum_bps;
+            let (min_approval, max_approval) = bounds.approval_threshold_bps;
+            if min_period == 0
+                || min_period > max_period
+                || min_quorum > max_quorum
+                || max_quorum > 10000
+                || min_approval > max_approval
+                || max_approval > 10000
+            {
+                return Err(Error::ConfigOutOfRange);
+            }
 
-        fn set_caller(account: AccountId) {
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(account);
+            self.config_bounds = bounds;
+            Ok(())
         }
 
-        fn advance_block(blocks: u32) {
-            let current = ink::env::block_number::<ink::env::DefaultEnvironment>();
-            ink::env::test::set_block_number::<ink::env::DefaultEnvironment>(
-                current.saturating_add(blocks),
-            );
+        /// Gets the voting strategy applied to new proposals
+        #[ink(message)]
+        pub fn voting_strategy(&self) -> VotingStrategy {
+            self.voting_strategy
         }
 
-        #[ink::test]
-        fn new_works() {
-            let accounts = default_accounts();
-            set_caller(accounts.alice);
-
-            let dao = SimpleDao::new(100, 2000, None, None);
+        /// Gets the execution delay in blocks
+        #[ink(message)]
+        pub fn execution_delay(&self) -> u32 {
+            self.execution_delay
+        }
 
-            assert_eq!(dao.voting_period(), 100);
-            assert_eq!(dao.quorum_threshold(), 2000);
-            assert_eq!(dao.proposal_count(), 0);
-            assert_eq!(dao.admin(), accounts.alice);
+        /// Gets the execution window in blocks
+        #[ink(message)]
+        pub fn expiration_window(&self) -> u32 {
+            self.expiration_window
         }
 
-        #[ink::test]
-        fn create_proposal_works() {
-            let accounts = default_accounts();
-            set_caller(accounts.alice);
+        /// Sets the total voting power (admin only)
+        ///
+        /// Only used without a DALLA token; with one, proposals snapshot the
+        /// token's total supply instead.
+        #[ink(message)]
+        pub fn set_total_voting_power(&mut self, power: u128) -> Result<()> {
+            self.ensure_role(DEFAULT_ADMIN_ROLE)?;
+            if self.dalla_token.is_some() {
+                return Err(Error::ManualWeightDisabled);
+            }
 
-            let mut dao = SimpleDao::new(100, 2000, None, None);
+            self.total_voting_power = power;
+            Ok(())
+        }
+
+        /// Gets the total voting power
+        #[ink(message)]
+        pub fn total_voting_power(&self) -> u128 {
+            self.total_voting_power
+        }
+
+        /// Gets the total voting power a proposal's quorum is computed from
+        ///
+        /// This is the raw snapshot total whatever the voting strategy, as
+        /// quorum is measured against the raw balance behind the votes.
+        #[ink(message)]
+        pub fn effective_total_power(&self, proposal_id: ProposalId) -> Option<u128> {
+            self.proposals
+                .get(proposal_id)
+                .map(|proposal| proposal.snapshot_total_power)
+        }
+
+        /// Sets the DALLA bond required to create a proposal (admin only)
+        #[ink(message)]
+        pub fn set_proposal_bond(&mut self, bond: Balance) -> Result<()> {
+            self.ensure_role(DEFAULT_ADMIN_ROLE)?;
+
+            self.proposal_bond = bond;
+            Ok(())
+        }
+
+        /// Gets the DALLA bond required to create a proposal
+        #[ink(message)]
+        pub fn proposal_bond(&self) -> Balance {
+            self.proposal_bond
+        }
+
+        /// Gets the bond held for a proposal
+        #[ink(message)]
+        pub fn bond_of(&self, proposal_id: ProposalId) -> Balance {
+            self.bonds.get(proposal_id).unwrap_or(0)
+        }
+
+        /// Sets the DALLA vote reward per finalized proposal (admin only)
+        ///
+        /// This is not a flat amount per voter. A voter on a proposal that
+        /// reached quorum is paid
+        /// `amount * min(power * 10000 / snapshot_total_power, 10000) / 10000`,
+        /// where `power` is their raw voting power behind the vote, delegated
+        /// balances included. A voter whose share rounds to zero gets
+        /// `RewardTooSmall`, and proposals that missed quorum pay nothing
+        /// (`QuorumNotReached`). Weighting by power keeps the payout from
+        /// growing when a balance is split over many accounts, and the quorum
+        /// gate keeps cheap ignored proposals from being farmed.
+        ///
+        /// Governance can change it through `SET_VOTE_REWARD_SELECTOR`.
+        #[ink(message)]
+        pub fn set_vote_reward(&mut self, amount: Balance) -> Result<()> {
+            self.ensure_role(DEFAULT_ADMIN_ROLE)?;
+
+            self.vote_reward = amount;
+            Ok(())
+        }
+
+        /// Gets the DALLA vote reward per finalized proposal
+        ///
+        /// Split among voters by voting power once quorum is reached; see
+        /// `set_vote_reward`.
+        #[ink(message)]
+        pub fn vote_reward(&self) -> Balance {
+            self.vote_reward
+        }
+
+        /// Checks whether `voter` has claimed their reward for a proposal
+        #[ink(message)]
+        pub fn has_claimed_vote_reward(&self, proposal_id: ProposalId, voter: AccountId) -> bool {
+            self.reward_claims.contains((proposal_id, voter))
+        }
+
+        /// Claims the vote reward for each listed proposal
+        ///
+        /// Pays the caller's share of the current reward in DALLA from the
+        /// treasury for every finalized proposal that reached quorum, which
+        /// the caller voted on and has not claimed yet. The share is the
+        /// caller's voting power out of the proposal's snapshot total, as
+        /// described on `set_vote_reward`, so it is not a flat amount.
+        /// Ineligible proposals (including `QuorumNotReached` and
+        /// `RewardTooSmall`), and payouts the treasury cannot cover, are
+        /// reported in the per-proposal result without aborting the claim.
+        #[ink(message)]
+        pub fn claim_vote_rewards(
+            &mut self,
+            proposal_ids: Vec<ProposalId>,
+        ) -> Result<Vec<(ProposalId, Result<Balance>)>> {
+            if proposal_ids.len() > MAX_REWARD_CLAIMS as usize {
+                return Err(Error::TooManyClaims);
+            }
+            let token = self.dalla_token.ok_or(Error::TokenNotConfigured)?;
+            if self.vote_reward == 0 {
+                return Err(Error::ZeroAmount);
+            }
+
+            let caller = self.env().caller();
+            let results = proposal_ids
+                .into_iter()
+                .map(|proposal_id| {
+                    let result =
+                        self.vote_reward_eligibility(proposal_id, caller)
+                            .and_then(|reward| {
+                                self.pay_vote_reward(token, proposal_id, caller, reward)
+                            });
+                    (proposal_id, result)
+                })
+                .collect();
+
+            Ok(results)
+        }
+
+        /// Sets the proposal threshold (admin only)
+        ///
+        /// With a DALLA token configured, proposers need at least this balance.
+        /// Without one, any non-zero threshold restricts proposing to the
+        /// allowlist.
+        #[ink(message)]
+        pub fn set_proposal_threshold(&mut self, threshold: u128) -> Result<()> {
+            self.ensure_role(DEFAULT_ADMIN_ROLE)?;
+
+            self.proposal_threshold = threshold;
+            Ok(())
+        }
+
+        /// Gets the proposal threshold
+        #[ink(message)]
+        pub fn proposal_threshold(&self) -> u128 {
+            self.proposal_threshold
+        }
+
+        /// Adds an account to the proposer allowlist (admin only)
+        #[ink(message)]
+        pub fn add_proposer(&mut self, account: AccountId) -> Result<()> {
+            self.ensure_role(DEFAULT_ADMIN_ROLE)?;
+
+            self.proposers.insert(account, &());
+            Ok(())
+        }
+
+        /// Removes an account from the proposer allowlist (admin only)
+        #[ink(message)]
+        pub fn remove_proposer(&mut self, account: AccountId) -> Result<()> {
+            self.ensure_role(DEFAULT_ADMIN_ROLE)?;
+
+            self.proposers.remove(account);
+            Ok(())
+        }
+
+        /// Checks whether `account` may create proposals
+        #[ink(message)]
+        pub fn can_propose(&self, account: AccountId) -> bool {
+            if self.ensure_member(account).is_err() {
+                return false;
+            }
+            if self.proposal_threshold == 0 {
+                return true;
+            }
+            match self.dalla_token {
+                Some(token) => self.token_balance_of(token, account) >= self.proposal_threshold,
+                None => self.proposers.contains(account),
+            }
+        }
+
+        /// Gets the DALLA token address
+        #[ink(message)]
+        pub fn dalla_token_address(&self) -> Option<AccountId> {
+            self.dalla_token
+        }
+
+        /// Gets the NFT membership address
+        #[ink(message)]
+        pub fn nft_membership_address(&self) -> Option<AccountId> {
+            self.nft_membership
+        }
+
+        /// Describes how membership is gated and where vote weight comes from
+        #[ink(message)]
+        pub fn membership_mode(&self) -> MembershipMode {
+            match (self.nft_membership, self.dalla_token) {
+                (None, _) => MembershipMode::Open,
+                (Some(_), Some(_)) => MembershipMode::NftGated,
+                (Some(_), None) => MembershipMode::NftWeighted,
+            }
+        }
+
+        /// Transfers admin rights
+        ///
+        /// Moves every role the caller holds to `new_admin`.
+        #[ink(message)]
+        pub fn transfer_admin(&mut self, new_admin: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            let now = self.env().block_timestamp();
+            if caller != self.admin {
+                return Err(Error::NotAuthorized);
+            }
+
+            if new_admin == caller {
+                return Ok(());
+            }
+
+            // DEFAULT_ADMIN_ROLE goes last so the caller can still revoke the others
+            for role in [CONFIG_ROLE, DEFAULT_ADMIN_ROLE] {
+                if self.access_control.has_role(role, caller) {
+                    self.access_control
+                        .grant_role(caller, role, new_admin, now, |event| {
+                            Self::env().emit_event(event);
+                        })?;
+                    self.access_control
+                        .revoke_role(caller, role, caller, now, |event| {
+                            Self::env().emit_event(event);
+                        })?;
+                }
+            }
+
+            self.admin = new_admin;
+            Ok(())
+        }
+
+        /// Gets the admin address
+        #[ink(message)]
+        pub fn admin(&self) -> AccountId {
+            self.admin
+        }
+
+        /// Grants `role` to `account` (role admin only)
+        #[ink(message)]
+        pub fn grant_role(&mut self, role: RoleType, account: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            let now = self.env().block_timestamp();
+            self.access_control
+                .grant_role(caller, role, account, now, |event| {
+                    Self::env().emit_event(event);
+                })?;
+
+            Ok(())
+        }
+
+        /// Revokes `role` from `account` (role admin only)
+        ///
+        /// The admin's DEFAULT_ADMIN_ROLE can only move through `transfer_admin`.
+        #[ink(message)]
+        pub fn revoke_role(&mut self, role: RoleType, account: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            let now = self.env().block_timestamp();
+            if role == DEFAULT_ADMIN_ROLE && account == self.admin {
+                return Err(Error::NotAuthorized);
+            }
+
+            self.access_control
+                .revoke_role(caller, role, account, now, |event| {
+                    Self::env().emit_event(event);
+                })?;
+
+            Ok(())
+        }
+
+        /// Returns whether `account` holds `role`
+        #[ink(message)]
+        pub fn has_role(&self, role: RoleType, account: AccountId) -> bool {
+            self.access_control.has_role(role, account)
+        }
+
+        // ========================================================================
+        // Internal Functions
+        // ========================================================================
+
+        /// Requires the caller to hold `role`
+        fn ensure_role(&self, role: RoleType) -> Result<()> {
+            self.access_control.ensure_role(self.env().caller(), role)?;
+            Ok(())
+        }
+
+        /// Loads a proposal that is still open for voting
+        fn open_proposal(&self, proposal_id: ProposalId) -> Result<Proposal> {
+            let proposal = self
+                .proposals
+                .get(proposal_id)
+                .ok_or(Error::ProposalNotFound)?;
+
+            if proposal.status != ProposalStatus::Active {
+                return Err(Error::ProposalNotActive);
+            }
+
+            // Check if voting is still active
+            if self.env().block_number() > proposal.end_block {
+                return Err(Error::VotingEnded);
+            }
+
+            Ok(proposal)
+        }
+
+        /// Loads an open multi-choice proposal and checks `option_index`
+        fn open_choice_proposal(
+            &self,
+            proposal_id: ProposalId,
+            option_index: u8,
+        ) -> Result<Proposal> {
+            let proposal = self.open_proposal(proposal_id)?;
+            if !proposal.is_multi_choice() {
+                return Err(Error::WrongProposalKind);
+            }
+            if option_index as usize >= proposal.options.len() {
+                return Err(Error::InvalidOption);
+            }
+            Ok(proposal)
+        }
+
+        /// Resolves the snapshot voting power of `voter` plus their uncounted
+        /// delegators, marking those delegators as counted
+        fn resolve_weight(
+            &mut self,
+            proposal_id: ProposalId,
+            proposal: &Proposal,
+            voter: AccountId,
+        ) -> Result<(u128, u128)> {
+            // The strategy applies to each holder's balance separately
+            let snapshot_block = Some(proposal.snapshot_block);
+            let strategy = proposal.voting_strategy;
+            let mut total_power = self.power_of(voter, snapshot_block);
+            let mut weight = strategy.effective_weight(total_power);
+            let mut counted = Vec::new();
+            for delegator in self.delegators.get(voter).unwrap_or_default() {
+                if self.counted.contains((proposal_id, delegator)) {
+                    continue;
+                }
+                let power = self.power_of(delegator, snapshot_block);
+                weight = weight.saturating_add(strategy.effective_weight(power));
+                total_power = total_power.saturating_add(power);
+                counted.push(delegator);
+            }
+
+            if weight == 0 {
+                return Err(Error::NoVotingPower);
+            }
+
+            for delegator in &counted {
+                self.counted.insert((proposal_id, *delegator), &());
+            }
+            if !counted.is_empty() {
+                self.counted_by.insert((proposal_id, voter), &counted);
+            }
+            Ok((weight, total_power))
+        }
+
+        /// Voting power of `account` from the configured weight source
+        ///
+        /// DALLA balances and NFT counts are read at `block` when given, live
+        /// otherwise, so tokens moved after the snapshot cannot vote twice.
+        fn power_of(&self, account: AccountId, block: Option<u32>) -> u128 {
+            match (self.dalla_token, self.nft_membership) {
+                (Some(token), _) => match block {
+                    Some(block) => self.token_balance_of_at(token, account, block),
+                    None => self.token_balance_of(token, account),
+                },
+                (None, Some(nft)) => match block {
+                    Some(block) => self.nft_balance_of_at(nft, account, block) as u128,
+                    None => self.nft_balance_of(nft, account) as u128,
+                },
+                (None, None) => 0,
+            }
+        }
+
+        /// Evaluates a proposal's tallies against its quorum and threshold
+        fn outcome(&self, proposal: &Proposal) -> Outcome {
+            // Abstentions count towards quorum, not towards the outcome.
+            // Quorum uses raw balances, which the strategy does not reshape.
+            let total_votes = proposal.turnout;
+            let quorum_required = proposal
+                .snapshot_total_power
+                .saturating_mul(proposal.quorum_bps as u128)
+                .saturating_div(10000);
+
+            let quorum_reached = total_votes >= quorum_required;
+            let passed = if proposal.is_multi_choice() {
+                Self::plurality(&proposal.option_votes).is_some()
+            } else {
+                let decisive = proposal.yes_votes.saturating_add(proposal.no_votes);
+                proposal.yes_votes.saturating_mul(10000)
+                    > decisive.saturating_mul(proposal.approval_threshold_bps as u128)
+            };
+            Outcome {
+                total_votes,
+                quorum_required,
+                quorum_reached,
+                passed: quorum_reached && passed,
+            }
+        }
+
+        /// Index of the option with strictly the most votes, if any
+        fn plurality(option_votes: &[u128]) -> Option<u8> {
+            let max = option_votes.iter().copied().max().filter(|max| *max > 0)?;
+            let mut leaders = option_votes
+                .iter()
+                .enumerate()
+                .filter(|(_, votes)| **votes == max);
+            let (winner, _) = leaders.next()?;
+            if leaders.next().is_some() {
+                return None;
+            }
+            Some(winner as u8)
+        }
+
+        /// Execution window `(executable_after, expires_at)` for a proposal
+        /// whose voting ends at `end_block`
+        fn execution_window(&self, end_block: u32) -> (u32, u32) {
+            let executable_after = end_block.saturating_add(self.execution_delay);
+            (
+                executable_after,
+                executable_after.saturating_add(self.expiration_window),
+            )
+        }
+
+        /// Appends a proposal to the active set
+        fn add_active(&mut self, proposal_id: ProposalId) {
+            let count = self.active_count;
+            self.active_proposals.insert(count, &proposal_id);
+            self.active_index.insert(proposal_id, &count);
+            self.active_count = count.saturating_add(1);
+        }
+
+        /// Removes a proposal from the active set (swap-and-pop)
+        fn remove_active(&mut self, proposal_id: ProposalId) {
+            let Some(index) = self.active_index.take(proposal_id) else {
+                return;
+            };
+            let last = self.active_count.saturating_sub(1);
+
+            if index != last {
+                if let Some(moved) = self.active_proposals.get(last) {
+                    self.active_proposals.insert(index, &moved);
+                    self.active_index.insert(moved, &index);
+                }
+            }
+
+            self.active_proposals.remove(last);
+            self.active_count = last;
+        }
+
+        /// Verifies and records one vote of a `submit_votes` batch
+        fn cast_signed_vote(
+            &mut self,
+            proposal_id: ProposalId,
+            proposal: &mut Proposal,
+            vote: &SignedVote,
+        ) -> Result<()> {
+            if matches!(vote.support, Support::Choice(_)) != proposal.is_multi_choice() {
+                return Err(Error::WrongProposalKind);
+            }
+            if let Support::Choice(option_index) = vote.support {
+                if option_index as usize >= proposal.options.len() {
+                    return Err(Error::InvalidOption);
+                }
+            }
+
+            if self.votes.contains((proposal_id, vote.voter)) {
+                return Err(Error::AlreadyVoted);
+            }
+            let nonce = self.vote_nonce(vote.voter);
+            if vote.nonce != nonce {
+                return Err(Error::InvalidNonce);
+            }
+            let hash = self.vote_hash(proposal_id, vote.voter, vote.support, vote.nonce);
+            if self.recover_signer(&hash, &vote.signature)? != vote.voter {
+                return Err(Error::InvalidSignature);
+            }
+
+            self.ensure_can_vote(proposal_id, vote.voter)?;
+            self.ensure_member(vote.voter)?;
+            let (weight, power) = self.resolve_weight(proposal_id, proposal, vote.voter)?;
+
+            self.vote_nonces
+                .insert(vote.voter, &nonce.saturating_add(1));
+            self.record_vote(
+                proposal_id,
+                proposal,
+                vote.voter,
+                vote.support,
+                weight,
+                power,
+            );
+            Ok(())
+        }
+
+        /// Recovers the account that signed `hash`
+        ///
+        /// The account id is `blake2_256` of the compressed public key, matching
+        /// Substrate's ECDSA account derivation.
+        fn recover_signer(&self, hash: &[u8; 32], signature: &[u8]) -> Result<AccountId> {
+            let signature: [u8; 65] = signature.try_into().map_err(|_| Error::InvalidSignature)?;
+
+            let mut public_key = [0u8; 33];
+            self.env()
+                .ecdsa_recover(&signature, hash, &mut public_key)
+                .map_err(|_| Error::InvalidSignature)?;
+
+            let mut account = [0u8; 32];
+            ink::env::hash_bytes::<Blake2x256>(&public_key, &mut account);
+
+            Ok(AccountId::from(account))
+        }
+
+        /// Requires that `voter` may cast a first vote on a proposal
+        fn ensure_can_vote(&self, proposal_id: ProposalId, voter: AccountId) -> Result<()> {
+            if self.delegates.contains(voter) {
+                return Err(Error::VoteDelegated);
+            }
+
+            // Check if already counted through a delegate
+            if self.counted.contains((proposal_id, voter)) {
+                return Err(Error::AlreadyVoted);
+            }
+
+            Ok(())
+        }
+
+        /// Records a vote and updates the proposal tallies
+        fn record_vote(
+            &mut self,
+            proposal_id: ProposalId,
+            proposal: &mut Proposal,
+            voter: AccountId,
+            support: Support,
+            weight: u128,
+            power: u128,
+        ) {
+            let record = VoterRecord {
+                support,
+                weight,
+                block: self.env().block_number(),
+                power,
+            };
+            self.votes.insert((proposal_id, voter), &record);
+            self.counted.insert((proposal_id, voter), &());
+
+            Self::add_tally(proposal, support, weight);
+            proposal.turnout = proposal.turnout.saturating_add(power);
+            self.proposals.insert(proposal_id, proposal);
+
+            match support {
+                Support::Vote(vote_type) => self.env().emit_event(VoteCast {
+                    proposal_id,
+                    voter,
+                    vote_type,
+                    weight,
+                }),
+                Support::Choice(option_index) => self.env().emit_event(ChoiceVoteCast {
+                    proposal_id,
+                    voter,
+                    option_index,
+                    weight,
+                }),
+            }
+        }
+
+        /// Replaces an existing vote, moving its weight between tallies
+        fn change_vote(
+            &mut self,
+            proposal_id: ProposalId,
+            proposal: &mut Proposal,
+            voter: AccountId,
+            previous: VoterRecord,
+            support: Support,
+            weight: u128,
+            power: u128,
+        ) {
+            Self::remove_tally(proposal, previous.support, previous.weight);
+            Self::add_tally(proposal, support, weight);
+            proposal.turnout = proposal
+                .turnout
+                .saturating_sub(previous.power)
+                .saturating_add(power);
+            self.proposals.insert(proposal_id, proposal);
+            let record = VoterRecord {
+                support,
+                weight,
+                block: self.env().block_number(),
+                power,
+            };
+            self.votes.insert((proposal_id, voter), &record);
+
+            match (previous.support, support) {
+                (Support::Vote(old_vote_type), Support::Vote(new_vote_type)) => {
+                    self.env().emit_event(VoteChanged {
+                        proposal_id,
+                        voter,
+                        old_vote_type,
+                        old_weight: previous.weight,
+                        new_vote_type,
+                        new_weight: weight,
+                    })
+                }
+                (_, Support::Choice(option_index)) => self.env().emit_event(ChoiceVoteCast {
+                    proposal_id,
+                    voter,
+                    option_index,
+                    weight,
+                }),
+                // The proposal kind is fixed, so a vote never changes kind
+                (Support::Choice(_), Support::Vote(_)) => {}
+            }
+        }
+
+        /// Returns the tally `support` counts towards
+        fn tally_mut(proposal: &mut Proposal, support: Support) -> &mut u128 {
+            match support {
+                Support::Vote(VoteType::Yes) => &mut proposal.yes_votes,
+                Support::Vote(VoteType::No) => &mut proposal.no_votes,
+                Support::Vote(VoteType::Abstain) => &mut proposal.abstain_votes,
+                Support::Choice(option_index) => &mut proposal.option_votes[option_index as usize],
+            }
+        }
+
+        /// Adds `weight` to the tally for `support`
+        fn add_tally(proposal: &mut Proposal, support: Support, weight: u128) {
+            let tally = Self::tally_mut(proposal, support);
+            *tally = tally.saturating_add(weight);
+        }
+
+        /// Removes `weight` from the tally for `support`
+        fn remove_tally(proposal: &mut Proposal, support: Support, weight: u128) {
+            let tally = Self::tally_mut(proposal, support);
+            *tally = tally.saturating_sub(weight);
+        }
+
+        /// Returns the bond held for a proposal to its proposer
+        fn refund_bond(&mut self, proposal_id: ProposalId, proposer: AccountId) -> Result<()> {
+            let bond = self.release_bond(proposal_id);
+            match self.dalla_token {
+                Some(token) if bond > 0 => self.token_transfer(token, proposer, bond),
+                _ => Ok(()),
+            }
+        }
+
+        /// Stops holding a proposal's bond, leaving it to the treasury unless
+        /// refunded, and returns its amount
+        fn release_bond(&mut self, proposal_id: ProposalId) -> Balance {
+            let bond = self.bonds.take(proposal_id).unwrap_or(0);
+            self.total_bonded = self.total_bonded.saturating_sub(bond);
+            bond
+        }
+
+        /// Treasury balance of a PSP22 token, excluding held DALLA bonds
+        fn spendable_token_balance(&self, token: AccountId) -> Balance {
+            let balance = self.token_balance_of(token, self.env().account_id());
+            if Some(token) == self.dalla_token {
+                balance.saturating_sub(self.total_bonded)
+            } else {
+                balance
+            }
+        }
+
+        /// Removes `delegator` from the delegator list of `delegate`
+        fn remove_delegator(&mut self, delegate: AccountId, delegator: AccountId) {
+            let mut delegators = self.delegators.get(delegate).unwrap_or_default();
+            delegators.retain(|account| *account != delegator);
+            if delegators.is_empty() {
+                self.delegators.remove(delegate);
+            } else {
+                self.delegators.insert(delegate, &delegators);
+            }
+        }
+
+        /// Dispatches a proposal action, returning whether the call succeeded
+        ///
+        /// Actions targeting the DAO itself are handled in place rather than
+        /// as a call back into the DAO; they are the only way to move treasury
+        /// funds or change governance parameters.
+        fn dispatch(&mut self, action: &Action) -> bool {
+            if action.target == self.env().account_id() {
+                return self.dispatch_internal(action).is_ok();
+            }
+
+            let result = build_call::<Environment>()
+                .call(action.target)
+                .transferred_value(action.transferred_value)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(action.selector))
+                        .push_arg(CallInput(&action.input)),
+                )
+                .returns::<CallOutput>()
+                .try_invoke();
+
+            matches!(result, Ok(Ok(output)) if output.is_ok())
+        }
+
+        /// Applies an action targeting the DAO itself
+        fn dispatch_internal(&mut self, action: &Action) -> Result<()> {
+            let mut input = &action.input[..];
+            match action.selector {
+                TREASURY_TRANSFER_SELECTOR => {
+                    let (token, to, amount) =
+                        <(Option<AccountId>, AccountId, Balance) as ink::scale::Decode>::decode(
+                            &mut input,
+                        )
+                        .map_err(|_| Error::InvalidAction)?;
+                    self._treasury_transfer(token, to, amount)
+                }
+                SET_TIMELOCK_SELECTOR => {
+                    let (execution_delay, expiration_window) =
+                        <(u32, u32) as ink::scale::Decode>::decode(&mut input)
+                            .map_err(|_| Error::InvalidAction)?;
+                    self.execution_delay = execution_delay;
+                    self.expiration_window = expiration_window;
+                    Ok(())
+                }
+                SET_VOTING_STRATEGY_SELECTOR => {
+                    self.voting_strategy =
+                        <VotingStrategy as ink::scale::Decode>::decode(&mut input)
+                            .map_err(|_| Error::InvalidAction)?;
+                    Ok(())
+                }
+                REMOVE_GUARDIAN_SELECTOR => {
+                    self.remove_guardian();
+                    Ok(())
+                }
+                SET_VOTE_REWARD_SELECTOR => {
+                    self.vote_reward = <Balance as ink::scale::Decode>::decode(&mut input)
+                        .map_err(|_| Error::InvalidAction)?;
+                    Ok(())
+                }
+                _ => Err(Error::InvalidAction),
+            }
+        }
+
+        /// Clears the guardian role
+        fn remove_guardian(&mut self) {
+            if let Some(guardian) = self.guardian.take() {
+                self.env().emit_event(GuardianRemoved { guardian });
+            }
+        }
+
+        /// Pays out native funds or PSP22 tokens from the treasury
+        fn _treasury_transfer(
+            &self,
+            token: Option<AccountId>,
+            to: AccountId,
+            amount: Balance,
+        ) -> Result<()> {
+            match token {
+                None => {
+                    if self.env().balance() < amount {
+                        return Err(Error::InsufficientTreasury);
+                    }
+                    self.env()
+                        .transfer(to, amount)
+                        .map_err(|_| Error::TransferFailed)?;
+                }
+                Some(token) => {
+                    if self.spendable_token_balance(token) < amount {
+                        return Err(Error::InsufficientTreasury);
+                    }
+                    self.token_transfer(token, to, amount)?;
+                }
+            }
+
+            self.env()
+                .emit_event(TreasuryWithdrawal { to, token, amount });
+
+            Ok(())
+        }
+
+        /// Checks that `voter` may claim the vote reward for a proposal and
+        /// returns the amount owed
+        ///
+        /// The proposal must have been finalized with quorum reached, whatever
+        /// happened to it afterwards; cancelled proposals never pay out. The
+        /// reward is split by share of the snapshot voting power, so splitting
+        /// a balance across accounts or voting with dust earns nothing extra.
+        fn vote_reward_eligibility(
+            &self,
+            proposal_id: ProposalId,
+            voter: AccountId,
+        ) -> Result<Balance> {
+            let proposal = self
+                .proposals
+                .get(proposal_id)
+                .ok_or(Error::ProposalNotFound)?;
+            if matches!(
+                proposal.status,
+                ProposalStatus::Active | ProposalStatus::Cancelled
+            ) {
+                return Err(Error::NotFinalized);
+            }
+            if !self.outcome(&proposal).quorum_reached {
+                return Err(Error::QuorumNotReached);
+            }
+            let record = self
+                .votes
+                .get((proposal_id, voter))
+                .ok_or(Error::NotVoted)?;
+            if self.reward_claims.contains((proposal_id, voter)) {
+                return Err(Error::RewardClaimed);
+            }
+
+            // Share in basis points first, so the product can't overflow
+            let share_bps = record
+                .power
+                .saturating_mul(10000)
+                .checked_div(proposal.snapshot_total_power)
+                .unwrap_or(0)
+                .min(10000);
+            match self.vote_reward.saturating_mul(share_bps) / 10000 {
+                0 => Err(Error::RewardTooSmall),
+                reward => Ok(reward),
+            }
+        }
+
+        /// Pays one vote reward from the treasury and records the claim
+        ///
+        /// The claim is recorded before the transfer and rolled back if the
+        /// treasury cannot cover it, so it can be retried once funded.
+        fn pay_vote_reward(
+            &mut self,
+            token: AccountId,
+            proposal_id: ProposalId,
+            voter: AccountId,
+            reward: Balance,
+        ) -> Result<Balance> {
+            if self.spendable_token_balance(token) < reward {
+                return Err(Error::InsufficientTreasury);
+            }
+
+            self.reward_claims.insert((proposal_id, voter), &());
+            if let Err(error) = self._treasury_transfer(Some(token), voter, reward) {
+                self.reward_claims.remove((proposal_id, voter));
+                return Err(error);
+            }
+
+            self.env().emit_event(VoteRewardClaimed {
+                proposal_id,
+                voter,
+                amount: reward,
+            });
+
+            Ok(reward)
+        }
+
+        /// Requires `account` to hold a membership NFT, if membership is configured
+        fn ensure_member(&self, account: AccountId) -> Result<()> {
+            if let Some(nft) = self.nft_membership {
+                if self.nft_balance_of(nft, account) == 0 {
+                    return Err(Error::NotMember);
+                }
+            }
+            Ok(())
+        }
+
+        /// Get token balance via PSP22 cross-contract call
+        fn token_balance_of(&self, token: AccountId, account: AccountId) -> Balance {
+            // PSP22::balance_of selector is 0x6568382f
+            let selector = [0x65, 0x68, 0x38, 0x2f];
+
+            let result = build_call::<Environment>()
+                .call(token)
+                .exec_input(ExecutionInput::new(Selector::new(selector)).push_arg(account))
+                .returns::<Balance>()
+                .try_invoke();
+
+            match result {
+                Ok(Ok(balance)) => balance,
+                _ => 0,
+            }
+        }
+
+        /// Transfer tokens via PSP22 cross-contract call
+        fn token_transfer(&self, token: AccountId, to: AccountId, amount: Balance) -> Result<()> {
+            // PSP22::transfer selector is 0xdb20f9f5
+            let selector = [0xdb, 0x20, 0xf9, 0xf5];
+
+            let result = build_call::<Environment>()
+                .call(token)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(selector))
+                        .push_arg(to)
+                        .push_arg(amount),
+                )
+                .returns::<core::result::Result<(), Vec<u8>>>()
+                .try_invoke();
+
+            match result {
+                Ok(Ok(Ok(()))) => Ok(()),
+                _ => Err(Error::TransferFailed),
+            }
+        }
+
+        /// Transfer tokens on behalf of `from` via PSP22 cross-contract call
+        fn token_transfer_from(
+            &self,
+            token: AccountId,
+            from: AccountId,
+            to: AccountId,
+            amount: Balance,
+        ) -> Result<()> {
+            // PSP22::transfer_from selector is 0x54b3c76e
+            let selector = [0x54, 0xb3, 0xc7, 0x6e];
+
+            let result = build_call::<Environment>()
+                .call(token)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(selector))
+                        .push_arg(from)
+                        .push_arg(to)
+                        .push_arg(amount),
+                )
+                .returns::<core::result::Result<(), Vec<u8>>>()
+                .try_invoke();
+
+            match result {
+                Ok(Ok(Ok(()))) => Ok(()),
+                _ => Err(Error::TransferFailed),
+            }
+        }
+
+        /// Pull `amount` of `token` from `from` into the DAO
+        ///
+        /// Returns the increase in the DAO's balance, which is less than
+        /// `amount` when the token charges a transfer fee.
+        fn token_pull(
+            &self,
+            token: AccountId,
+            from: AccountId,
+            amount: Balance,
+        ) -> Result<Balance> {
+            let dao = self.env().account_id();
+            let before = self.token_balance_of(token, dao);
+            self.token_transfer_from(token, from, dao, amount)?;
+            Ok(self.token_balance_of(token, dao).saturating_sub(before))
+        }
+
+        /// Get a historical token balance via cross-contract call
+        ///
+        /// Calls the checkpointed `balance_of_at` method on the DALLA token
+        fn token_balance_of_at(&self, token: AccountId, account: AccountId, block: u32) -> Balance {
+            // DallaToken::balance_of_at selector is 0x5a2f8344
+            let selector = [0x5a, 0x2f, 0x83, 0x44];
+
+            let result = build_call::<Environment>()
+                .call(token)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(selector))
+                        .push_arg(account)
+                        .push_arg(block),
+                )
+                .returns::<Balance>()
+                .try_invoke();
+
+            match result {
+                Ok(Ok(balance)) => balance,
+                _ => 0,
+            }
+        }
+
+        /// Get the historical token supply via cross-contract call
+        ///
+        /// Calls the checkpointed `total_supply_at` method on the DALLA token
+        fn token_total_supply_at(&self, token: AccountId, block: u32) -> Balance {
+            // DallaToken::total_supply_at selector is 0x3727369d
+            let selector = [0x37, 0x27, 0x36, 0x9d];
+
+            let result = build_call::<Environment>()
+                .call(token)
+                .exec_input(ExecutionInput::new(Selector::new(selector)).push_arg(block))
+                .returns::<Balance>()
+                .try_invoke();
+
+            match result {
+                Ok(Ok(supply)) => supply,
+                _ => 0,
+            }
+        }
+
+        /// Get the current token supply via cross-contract call
+        fn token_total_supply(&self, token: AccountId) -> Balance {
+            // DallaToken::total_supply selector is 0xdb6375a8
+            let selector = [0xdb, 0x63, 0x75, 0xa8];
+
+            let result = build_call::<Environment>()
+                .call(token)
+                .exec_input(ExecutionInput::new(Selector::new(selector)))
+                .returns::<Balance>()
+                .try_invoke();
+
+            match result {
+                Ok(Ok(supply)) => supply,
+                _ => 0,
+            }
+        }
+
+        /// Get the historical membership NFT count via cross-contract call
+        fn nft_balance_of_at(&self, nft: AccountId, account: AccountId, block: u32) -> u32 {
+            // BeliNft::balance_of_at selector is 0x5a2f8344
+            let selector = [0x5a, 0x2f, 0x83, 0x44];
+
+            let result = build_call::<Environment>()
+                .call(nft)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(selector))
+                        .push_arg(account)
+                        .push_arg(block),
+                )
+                .returns::<u32>()
+                .try_invoke();
+
+            match result {
+                Ok(Ok(count)) => count,
+                _ => 0,
+            }
+        }
+
+        /// Get membership NFT count via cross-contract call
+        fn nft_balance_of(&self, nft: AccountId, account: AccountId) -> u32 {
+            // BeliNft::balance_of selector is 0x0f755a56
+            let selector = [0x0f, 0x75, 0x5a, 0x56];
+
+            let result = build_call::<Environment>()
+                .call(nft)
+                .exec_input(ExecutionInput::new(Selector::new(selector)).push_arg(account))
+                .returns::<u32>()
+                .try_invoke();
+
+            match result {
+                Ok(Ok(count)) => count,
+                _ => 0,
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn default_accounts() -> ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment> {
+            ink::env::test::default_accounts::<ink::env::DefaultEnvironment>()
+        }
+
+        fn set_caller(account: AccountId) {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(account);
+        }
+
+        fn advance_block(blocks: u32) {
+            let current = ink::env::block_number::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_block_number::<ink::env::DefaultEnvironment>(
+                current.saturating_add(blocks),
+            );
+        }
+
+        #[ink::test]
+        fn new_works() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+
+            assert_eq!(dao.voting_period(), 100);
+            assert_eq!(dao.quorum_threshold(), 2000);
+            assert_eq!(dao.execution_delay(), 0);
+            assert_eq!(dao.voting_strategy(), VotingStrategy::Linear);
+            assert_eq!(dao.expiration_window(), DEFAULT_EXPIRATION_WINDOW);
+            assert_eq!(dao.proposal_count(), 0);
+            assert_eq!(dao.admin(), accounts.alice);
+        }
+
+        #[ink::test]
+        fn create_proposal_works() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
 
             let description = String::from("Increase treasury allocation");
-            let result = dao.create_proposal(description.clone());
+            let result = dao.create_proposal(description.clone(), Vec::new(), None);
+            assert!(result.is_ok());
+
+            let proposal_id = result.unwrap();
+            assert_eq!(proposal_id, 1);
+            assert_eq!(dao.proposal_count(), 1);
+
+            let proposal = dao.get_proposal(proposal_id).unwrap();
+            assert_eq!(proposal.proposer, accounts.alice);
+            assert_eq!(
+                proposal.description_hash,
+                SimpleDao::hash_description(&description)
+            );
+            assert_eq!(proposal.title, None);
+            assert_eq!(proposal.status, ProposalStatus::Active);
+
+            // The legacy path stores only the hash, which the text still verifies against
+            assert!(dao.verify_description(proposal_id, description));
+            assert!(!dao.verify_description(proposal_id, String::from("Drain the treasury")));
+        }
+
+        #[ink::test]
+        fn create_proposal_with_hash_works() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+
+            let full_text = "# Treasury allocation\n\n".repeat(500);
+            let description_hash = SimpleDao::hash_description(&full_text);
+            let proposal_id = dao
+                .create_proposal_with_hash(
+                    Some(String::from("Treasury allocation")),
+                    description_hash,
+                    Vec::new(),
+                    None,
+                )
+                .unwrap();
+
+            let proposal = dao.get_proposal(proposal_id).unwrap();
+            assert_eq!(proposal.title, Some(String::from("Treasury allocation")));
+            assert_eq!(proposal.description_hash, description_hash);
+            assert!(dao.verify_description(proposal_id, full_text.clone()));
+            assert!(!dao.verify_description(proposal_id, full_text[1..].into()));
+            assert!(!dao.verify_description(99, full_text));
+        }
+
+        #[ink::test]
+        fn proposal_title_is_capped() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+
+            let max_title = "a".repeat(MAX_TITLE_LENGTH as usize);
+            assert!(dao
+                .create_proposal_with_hash(Some(max_title), [1; 32], Vec::new(), None)
+                .is_ok());
+
+            let long_title = "a".repeat(MAX_TITLE_LENGTH as usize + 1);
+            assert_eq!(
+                dao.create_proposal_with_hash(Some(long_title), [1; 32], Vec::new(), None),
+                Err(Error::TitleTooLong)
+            );
+            assert_eq!(dao.proposal_count(), 1);
+        }
+
+        #[ink::test]
+        fn vote_works() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            dao.set_total_voting_power(1000).unwrap();
+
+            let proposal_id = dao
+                .create_proposal(String::from("Test proposal"), Vec::new(), None)
+                .unwrap();
+
+            // Alice votes yes with weight 100
+            set_caller(accounts.alice);
+            let result = dao.vote_with_weight(proposal_id, VoteType::Yes, 100);
+            assert!(result.is_ok());
+
+            let proposal = dao.get_proposal(proposal_id).unwrap();
+            assert_eq!(proposal.yes_votes, 100);
+            assert_eq!(proposal.no_votes, 0);
+
+            // Bob votes no with weight 50
+            set_caller(accounts.bob);
+            let result = dao.vote_with_weight(proposal_id, VoteType::No, 50);
+            assert!(result.is_ok());
+
+            let proposal = dao.get_proposal(proposal_id).unwrap();
+            assert_eq!(proposal.yes_votes, 100);
+            assert_eq!(proposal.no_votes, 50);
+        }
+
+        #[ink::test]
+        fn change_vote_works() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+
+            dao.vote_with_weight(proposal_id, VoteType::Yes, 100)
+                .unwrap();
+            set_caller(accounts.bob);
+            dao.vote_with_weight(proposal_id, VoteType::Yes, 40)
+                .unwrap();
+
+            // Alice flips yes -> no
+            set_caller(accounts.alice);
+            dao.vote_with_weight(proposal_id, VoteType::No, 100)
+                .unwrap();
+
+            let proposal = dao.get_proposal(proposal_id).unwrap();
+            assert_eq!(proposal.yes_votes, 40);
+            assert_eq!(proposal.no_votes, 100);
+
+            // Voting the same way again does not double count
+            dao.vote_with_weight(proposal_id, VoteType::No, 100)
+                .unwrap();
+            let proposal = dao.get_proposal(proposal_id).unwrap();
+            assert_eq!(proposal.yes_votes, 40);
+            assert_eq!(proposal.no_votes, 100);
+        }
+
+        fn last_event() -> ink::env::test::EmittedEvent {
+            ink::env::test::recorded_events()
+                .last()
+                .expect("no event recorded")
+        }
+
+        #[ink::test]
+        fn finalize_emits_event() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            dao.set_total_voting_power(1000).unwrap();
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+            dao.vote_with_weight(proposal_id, VoteType::Yes, 300)
+                .unwrap();
+            set_caller(accounts.bob);
+            dao.vote_with_weight(proposal_id, VoteType::Abstain, 50)
+                .unwrap();
+
+            advance_block(101);
+            dao.finalize_proposal(proposal_id).unwrap();
+
+            let event =
+                <ProposalFinalized as ink::scale::Decode>::decode(&mut &last_event().data[..])
+                    .unwrap();
+            assert_eq!(event.proposal_id, proposal_id);
+            assert_eq!(event.status, ProposalStatus::Passed);
+            assert_eq!(event.yes_votes, 300);
+            assert_eq!(event.no_votes, 0);
+            assert_eq!(event.total_votes, 350);
+            assert_eq!(event.quorum_required, 200);
+        }
+
+        #[ink::test]
+        fn cancel_emits_event() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+            dao.cancel_proposal(proposal_id).unwrap();
+
+            let event =
+                <ProposalCancelled as ink::scale::Decode>::decode(&mut &last_event().data[..])
+                    .unwrap();
+            assert_eq!(event.proposal_id, proposal_id);
+            assert_eq!(event.cancelled_by, accounts.alice);
+        }
+
+        #[ink::test]
+        fn voter_record_tracks_latest_vote() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+            assert_eq!(dao.get_voter_record(proposal_id, accounts.alice), None);
+
+            let cast_block = ink::env::block_number::<ink::env::DefaultEnvironment>();
+            dao.vote_with_weight(proposal_id, VoteType::Yes, 100)
+                .unwrap();
+            assert_eq!(
+                dao.get_voter_record(proposal_id, accounts.alice),
+                Some(VoterRecord {
+                    support: Support::Vote(VoteType::Yes),
+                    weight: 100,
+                    block: cast_block,
+                    power: 100,
+                })
+            );
+
+            advance_block(5);
+            dao.vote_with_weight(proposal_id, VoteType::No, 80).unwrap();
+            assert_eq!(
+                dao.get_voter_record(proposal_id, accounts.alice),
+                Some(VoterRecord {
+                    support: Support::Vote(VoteType::No),
+                    weight: 80,
+                    block: cast_block + 5,
+                    power: 80,
+                })
+            );
+
+            dao.retract_vote(proposal_id).unwrap();
+            assert_eq!(dao.get_voter_record(proposal_id, accounts.alice), None);
+        }
+
+        #[ink::test]
+        fn retract_vote_works() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+
+            assert_eq!(dao.retract_vote(proposal_id), Err(Error::NotVoted));
+
+            dao.vote_with_weight(proposal_id, VoteType::Abstain, 70)
+                .unwrap();
+            dao.retract_vote(proposal_id).unwrap();
+
+            let proposal = dao.get_proposal(proposal_id).unwrap();
+            assert_eq!(proposal.abstain_votes, 0);
+            assert_eq!(dao.get_vote(proposal_id, accounts.alice), None);
+
+            // A fresh vote is possible after retracting
+            dao.vote_with_weight(proposal_id, VoteType::Yes, 70)
+                .unwrap();
+            assert_eq!(dao.get_proposal(proposal_id).unwrap().yes_votes, 70);
+        }
+
+        #[ink::test]
+        fn change_after_deadline_fails() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+            dao.vote_with_weight(proposal_id, VoteType::Yes, 100)
+                .unwrap();
+
+            advance_block(101);
+            assert_eq!(
+                dao.vote_with_weight(proposal_id, VoteType::No, 100),
+                Err(Error::VotingEnded)
+            );
+            assert_eq!(dao.retract_vote(proposal_id), Err(Error::VotingEnded));
+            assert_eq!(dao.get_proposal(proposal_id).unwrap().yes_votes, 100);
+        }
+
+        #[ink::test]
+        fn finalize_proposal_works() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            dao.set_total_voting_power(1000).unwrap();
+
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+
+            // Vote
+            dao.vote_with_weight(proposal_id, VoteType::Yes, 300)
+                .unwrap();
+
+            // Advance blocks past voting period
+            advance_block(101);
+
+            // Finalize
+            let result = dao.finalize_proposal(proposal_id);
+            assert!(result.is_ok());
+
+            let proposal = dao.get_proposal(proposal_id).unwrap();
+            assert_eq!(proposal.status, ProposalStatus::Passed);
+        }
+
+        #[ink::test]
+        fn execute_proposal_works() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            dao.set_total_voting_power(1000).unwrap();
+
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+            dao.vote_with_weight(proposal_id, VoteType::Yes, 300)
+                .unwrap();
+
+            advance_block(101);
+            dao.finalize_proposal(proposal_id).unwrap();
+
+            // Execute
+            let result = dao.execute_proposal(proposal_id);
             assert!(result.is_ok());
 
-            let proposal_id = result.unwrap();
-            assert_eq!(proposal_id, 1);
-            assert_eq!(dao.proposal_count(), 1);
+            let proposal = dao.get_proposal(proposal_id).unwrap();
+            assert_eq!(proposal.status, ProposalStatus::Executed);
+            assert!(proposal.executed);
+        }
+
+        #[ink::test]
+        fn quorum_not_met_fails() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            dao.set_total_voting_power(1000).unwrap();
+
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+
+            // Vote with only 100 (quorum needs 200 = 20% of 1000)
+            dao.vote_with_weight(proposal_id, VoteType::Yes, 100)
+                .unwrap();
+
+            advance_block(101);
+            dao.finalize_proposal(proposal_id).unwrap();
+
+            let proposal = dao.get_proposal(proposal_id).unwrap();
+            assert_eq!(proposal.status, ProposalStatus::Rejected);
+        }
+
+        #[ink::test]
+        fn proposal_snapshots_voting_power() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            dao.set_total_voting_power(1000).unwrap();
+
+            advance_block(10);
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+
+            let proposal = dao.get_proposal(proposal_id).unwrap();
+            assert_eq!(proposal.snapshot_block, 9);
+            assert_eq!(proposal.snapshot_total_power, 1000);
+
+            // Raising total power afterwards must not move this proposal's quorum
+            dao.set_total_voting_power(10_000).unwrap();
+            dao.vote_with_weight(proposal_id, VoteType::Yes, 300)
+                .unwrap();
+
+            advance_block(101);
+            dao.finalize_proposal(proposal_id).unwrap();
+
+            let proposal = dao.get_proposal(proposal_id).unwrap();
+            assert_eq!(proposal.status, ProposalStatus::Passed);
+        }
+
+        #[ink::test]
+        fn action_limits_enforced() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            let action = Action {
+                target: accounts.django,
+                selector: [0x1f, 0xe7, 0x42, 0x6f],
+                input: Vec::new(),
+                transferred_value: 0,
+            };
+
+            let too_many = vec![action.clone(); MAX_ACTIONS as usize + 1];
+            assert_eq!(
+                dao.create_proposal(String::from("Test"), too_many, None),
+                Err(Error::TooManyActions)
+            );
+
+            let oversized = Action {
+                input: vec![0; MAX_ACTION_INPUT as usize + 1],
+                ..action.clone()
+            };
+            assert_eq!(
+                dao.create_proposal(String::from("Test"), vec![oversized], None),
+                Err(Error::ActionTooLarge)
+            );
+            assert_eq!(dao.proposal_count(), 0);
+
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), vec![action.clone()], None)
+                .unwrap();
+            assert_eq!(dao.get_proposal(proposal_id).unwrap().actions, vec![action]);
+        }
+
+        #[ink::test]
+        fn action_call_encodes_args() {
+            let accounts = default_accounts();
+
+            let action = Action::call(
+                accounts.django,
+                [0x1f, 0xe7, 0x42, 0x6f],
+                &(Some(accounts.bob), 7u32),
+            );
+            assert_eq!(action.target, accounts.django);
+            assert_eq!(action.selector, [0x1f, 0xe7, 0x42, 0x6f]);
+            assert_eq!(
+                action.input,
+                ink::scale::Encode::encode(&(Some(accounts.bob), 7u32))
+            );
+            assert_eq!(action.transferred_value, 0);
+
+            // Same bytes as a hand-built action
+            assert_eq!(
+                Action::call(
+                    contract_id(),
+                    TREASURY_TRANSFER_SELECTOR,
+                    &(None::<AccountId>, accounts.bob, 5u128)
+                ),
+                payout_action(accounts.bob, 5)
+            );
+        }
+
+        #[ink::test]
+        fn call_output_reports_result_replies() {
+            use ink::scale::{Decode, Encode};
+
+            let decode = |bytes: Vec<u8>| CallOutput::decode(&mut &bytes[..]).unwrap();
+
+            // `()` and `Ok(())` replies
+            assert!(decode(Vec::new()).is_ok());
+            assert!(decode(Ok::<(), Error>(()).encode()).is_ok());
+            // `Err(..)` replies, whatever the error type
+            assert!(!decode(Err::<(), Error>(Error::ProposalNotFound).encode()).is_ok());
+            assert!(!decode(Err::<(), u8>(0).encode()).is_ok());
+        }
+
+        fn set_value(value: Balance) {
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(value);
+        }
+
+        fn set_balance(account: AccountId, balance: Balance) {
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(account, balance);
+        }
+
+        fn get_balance(account: AccountId) -> Balance {
+            ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(account)
+                .expect("account balance")
+        }
+
+        fn contract_id() -> AccountId {
+            ink::env::test::callee::<ink::env::DefaultEnvironment>()
+        }
+
+        fn payout_action(to: AccountId, amount: Balance) -> Action {
+            Action {
+                target: contract_id(),
+                selector: TREASURY_TRANSFER_SELECTOR,
+                input: ink::scale::Encode::encode(&(None::<AccountId>, to, amount)),
+                transferred_value: 0,
+            }
+        }
+
+        #[ink::test]
+        fn deposit_works() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+
+            set_value(0);
+            assert_eq!(dao.deposit(), Err(Error::ZeroAmount));
+
+            set_value(500);
+            assert!(dao.deposit().is_ok());
+
+            let events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            assert_eq!(events.len(), 1);
+        }
+
+        #[ink::test]
+        fn treasury_payout_via_proposal() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            dao.set_total_voting_power(1000).unwrap();
+            set_balance(contract_id(), 1_000);
+            let bob_before = get_balance(accounts.bob);
+
+            let proposal_id = dao
+                .create_proposal(
+                    String::from("Grant"),
+                    vec![payout_action(accounts.bob, 400)],
+                    None,
+                )
+                .unwrap();
+            dao.vote_with_weight(proposal_id, VoteType::Yes, 300)
+                .unwrap();
+
+            // Cannot pay out before the proposal has passed
+            assert_eq!(
+                dao.execute_proposal(proposal_id),
+                Err(Error::ProposalFailed)
+            );
+            assert_eq!(dao.treasury_balance(), 1_000);
+
+            advance_block(101);
+            dao.finalize_proposal(proposal_id).unwrap();
+            dao.execute_proposal(proposal_id).unwrap();
+
+            assert_eq!(dao.treasury_balance(), 600);
+            assert_eq!(get_balance(accounts.bob), bob_before + 400);
+            assert_eq!(
+                dao.get_proposal(proposal_id).unwrap().status,
+                ProposalStatus::Executed
+            );
+        }
+
+        #[ink::test]
+        fn treasury_overdraw_marks_execution_failed() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            dao.set_total_voting_power(1000).unwrap();
+            set_balance(contract_id(), 100);
+
+            let proposal_id = dao
+                .create_proposal(
+                    String::from("Grant"),
+                    vec![payout_action(accounts.bob, 400)],
+                    None,
+                )
+                .unwrap();
+            dao.vote_with_weight(proposal_id, VoteType::Yes, 300)
+                .unwrap();
+            advance_block(101);
+            dao.finalize_proposal(proposal_id).unwrap();
+
+            assert!(dao.execute_proposal(proposal_id).is_ok());
+            assert_eq!(
+                dao.get_proposal(proposal_id).unwrap().status,
+                ProposalStatus::ExecutionFailed
+            );
+            assert_eq!(dao.treasury_balance(), 100);
+        }
+
+        #[ink::test]
+        fn delegate_works() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+
+            set_caller(accounts.bob);
+            assert_eq!(dao.delegate(accounts.bob), Err(Error::SelfDelegation));
+            assert_eq!(dao.undelegate(), Err(Error::NotDelegating));
+
+            dao.delegate(accounts.alice).unwrap();
+            assert_eq!(dao.delegate_of(accounts.bob), Some(accounts.alice));
+            assert_eq!(dao.delegators_of(accounts.alice), vec![accounts.bob]);
+
+            // Re-delegation moves Bob to Charlie
+            dao.delegate(accounts.charlie).unwrap();
+            assert_eq!(dao.delegators_of(accounts.alice), Vec::<AccountId>::new());
+            assert_eq!(dao.delegators_of(accounts.charlie), vec![accounts.bob]);
+
+            dao.undelegate().unwrap();
+            assert_eq!(dao.delegate_of(accounts.bob), None);
+            assert_eq!(dao.delegators_of(accounts.charlie), Vec::<AccountId>::new());
+        }
+
+        #[ink::test]
+        fn delegation_is_one_level() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+
+            set_caller(accounts.bob);
+            dao.delegate(accounts.alice).unwrap();
+
+            // Alice has delegators, so she cannot delegate onward
+            set_caller(accounts.alice);
+            assert_eq!(dao.delegate(accounts.charlie), Err(Error::DelegationChain));
+
+            // Nobody can delegate to Bob while he delegates
+            set_caller(accounts.charlie);
+            assert_eq!(dao.delegate(accounts.bob), Err(Error::DelegationChain));
+        }
+
+        #[ink::test]
+        fn delegator_cannot_vote() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+
+            set_caller(accounts.bob);
+            dao.delegate(accounts.alice).unwrap();
+            assert_eq!(
+                dao.vote_with_weight(proposal_id, VoteType::Yes, 10),
+                Err(Error::VoteDelegated)
+            );
+
+            dao.undelegate().unwrap();
+            assert!(dao.vote_with_weight(proposal_id, VoteType::Yes, 10).is_ok());
+        }
+
+        #[ink::test]
+        fn abstain_counts_for_quorum_only() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            dao.set_total_voting_power(1000).unwrap();
+
+            // Quorum reached purely through abstentions, but yes <= no
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+            dao.vote_with_weight(proposal_id, VoteType::Abstain, 250)
+                .unwrap();
+            set_caller(accounts.bob);
+            dao.vote_with_weight(proposal_id, VoteType::Yes, 10)
+                .unwrap();
+            set_caller(accounts.charlie);
+            dao.vote_with_weight(proposal_id, VoteType::No, 10).unwrap();
+
+            let proposal = dao.get_proposal(proposal_id).unwrap();
+            assert_eq!(proposal.abstain_votes, 250);
+
+            advance_block(101);
+            dao.finalize_proposal(proposal_id).unwrap();
+            assert_eq!(
+                dao.get_proposal(proposal_id).unwrap().status,
+                ProposalStatus::Rejected
+            );
+        }
+
+        #[ink::test]
+        fn abstain_pushes_over_quorum() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            dao.set_total_voting_power(1000).unwrap();
+
+            // 150 yes alone misses the 200 quorum
+            let under = dao
+                .create_proposal(String::from("Under"), Vec::new(), None)
+                .unwrap();
+            let over = dao
+                .create_proposal(String::from("Over"), Vec::new(), None)
+                .unwrap();
+            dao.vote_with_weight(under, VoteType::Yes, 150).unwrap();
+            dao.vote_with_weight(over, VoteType::Yes, 150).unwrap();
+            set_caller(accounts.bob);
+            dao.vote_with_weight(over, VoteType::Abstain, 50).unwrap();
+
+            advance_block(101);
+            dao.finalize_proposal(under).unwrap();
+            dao.finalize_proposal(over).unwrap();
+
+            assert_eq!(
+                dao.get_proposal(under).unwrap().status,
+                ProposalStatus::Rejected
+            );
+            assert_eq!(
+                dao.get_proposal(over).unwrap().status,
+                ProposalStatus::Passed
+            );
+        }
+
+        #[ink::test]
+        fn cancel_by_proposer_works() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+
+            set_caller(accounts.bob);
+            let proposal_id = dao
+                .create_proposal(String::from("Oops"), Vec::new(), None)
+                .unwrap();
+
+            set_caller(accounts.charlie);
+            assert_eq!(dao.cancel_proposal(proposal_id), Err(Error::CannotCancel));
+
+            set_caller(accounts.bob);
+            assert!(dao.cancel_proposal(proposal_id).is_ok());
+            assert_eq!(
+                dao.get_proposal(proposal_id).unwrap().status,
+                ProposalStatus::Cancelled
+            );
+
+            // Cancelled proposals can no longer be voted on or finalized
+            assert_eq!(
+                dao.vote_with_weight(proposal_id, VoteType::Yes, 10),
+                Err(Error::ProposalNotActive)
+            );
+            advance_block(101);
+            assert_eq!(
+                dao.finalize_proposal(proposal_id),
+                Err(Error::ProposalNotActive)
+            );
+        }
+
+        #[ink::test]
+        fn cancel_blocked_after_votes() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            dao.set_total_voting_power(1000).unwrap();
+
+            set_caller(accounts.bob);
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+            set_caller(accounts.charlie);
+            dao.vote_with_weight(proposal_id, VoteType::Yes, 300)
+                .unwrap();
+
+            set_caller(accounts.bob);
+            assert_eq!(dao.cancel_proposal(proposal_id), Err(Error::CannotCancel));
+
+            // The admin can still cancel, even once it has passed
+            advance_block(101);
+            dao.finalize_proposal(proposal_id).unwrap();
+            set_caller(accounts.alice);
+            assert!(dao.cancel_proposal(proposal_id).is_ok());
+            assert_eq!(
+                dao.execute_proposal(proposal_id),
+                Err(Error::ProposalFailed)
+            );
+        }
+
+        #[ink::test]
+        fn admin_cannot_cancel_executed() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            dao.set_total_voting_power(1000).unwrap();
+
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+            dao.vote_with_weight(proposal_id, VoteType::Yes, 300)
+                .unwrap();
+            advance_block(101);
+            dao.finalize_proposal(proposal_id).unwrap();
+            dao.execute_proposal(proposal_id).unwrap();
+
+            assert_eq!(
+                dao.cancel_proposal(proposal_id),
+                Err(Error::AlreadyExecuted)
+            );
+        }
+
+        #[ink::test]
+        fn set_proposal_bond_admin_only() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            dao.set_proposal_bond(1_000).unwrap();
+            assert_eq!(dao.proposal_bond(), 1_000);
+
+            set_caller(accounts.bob);
+            assert_eq!(dao.set_proposal_bond(0), Err(Error::NotAuthorized));
+        }
+
+        #[ink::test]
+        fn proposer_allowlist_without_token() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            assert!(dao.can_propose(accounts.charlie));
+
+            dao.set_proposal_threshold(1).unwrap();
+            dao.add_proposer(accounts.bob).unwrap();
+            assert!(dao.can_propose(accounts.bob));
+            assert!(!dao.can_propose(accounts.charlie));
+
+            set_caller(accounts.bob);
+            assert!(dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .is_ok());
+            assert_eq!(
+                dao.add_proposer(accounts.charlie),
+                Err(Error::NotAuthorized)
+            );
+
+            set_caller(accounts.charlie);
+            assert_eq!(
+                dao.create_proposal(String::from("Test"), Vec::new(), None),
+                Err(Error::NotProposer)
+            );
+
+            set_caller(accounts.alice);
+            dao.remove_proposer(accounts.bob).unwrap();
+            assert!(!dao.can_propose(accounts.bob));
+        }
+
+        fn passed_proposal(dao: &mut SimpleDao, actions: Vec<Action>) -> ProposalId {
+            dao.set_total_voting_power(1000).unwrap();
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), actions, None)
+                .unwrap();
+            dao.vote_with_weight(proposal_id, VoteType::Yes, 300)
+                .unwrap();
+            advance_block(101);
+            dao.finalize_proposal(proposal_id).unwrap();
+            proposal_id
+        }
+
+        #[ink::test]
+        fn execute_too_early_fails() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 50, VotingStrategy::Linear, None, None, None);
+            let proposal_id = passed_proposal(&mut dao, Vec::new());
+
+            let proposal = dao.get_proposal(proposal_id).unwrap();
+            assert_eq!(proposal.executable_after, proposal.end_block + 50);
+            assert_eq!(
+                proposal.expires_at,
+                proposal.executable_after + DEFAULT_EXPIRATION_WINDOW
+            );
+
+            assert_eq!(
+                dao.execute_proposal(proposal_id),
+                Err(Error::TimelockNotElapsed)
+            );
+
+            advance_block(49);
+            assert!(dao.execute_proposal(proposal_id).is_ok());
+        }
+
+        #[ink::test]
+        fn execute_after_window_fails() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 50, VotingStrategy::Linear, None, None, None);
+            let proposal_id = passed_proposal(&mut dao, Vec::new());
+
+            advance_block(49 + DEFAULT_EXPIRATION_WINDOW + 1);
+            assert_eq!(
+                dao.execute_proposal(proposal_id),
+                Err(Error::ProposalExpired)
+            );
+        }
+
+        #[ink::test]
+        fn timelock_set_by_governance() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            let action = Action {
+                target: contract_id(),
+                selector: SET_TIMELOCK_SELECTOR,
+                input: ink::scale::Encode::encode(&(10u32, 500u32)),
+                transferred_value: 0,
+            };
+            let proposal_id = passed_proposal(&mut dao, vec![action]);
+            dao.execute_proposal(proposal_id).unwrap();
+
+            assert_eq!(dao.execution_delay(), 10);
+            assert_eq!(dao.expiration_window(), 500);
+        }
+
+        #[ink::test]
+        fn get_proposals_paginates() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            for description in ["One", "Two", "Three"] {
+                dao.create_proposal(String::from(description), Vec::new(), None)
+                    .unwrap();
+            }
+
+            let page = dao.get_proposals(1, 5);
+            assert_eq!(page.len(), 2);
+            assert_eq!(page[0].0, 2);
+            assert!(dao.verify_description(page[0].0, String::from("Two")));
+            assert_eq!(page[1].0, 3);
+            assert!(dao.get_proposals(3, 5).is_empty());
+        }
+
+        #[ink::test]
+        fn active_set_tracks_lifecycle() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            let first = dao
+                .create_proposal(String::from("One"), Vec::new(), None)
+                .unwrap();
+            let second = dao
+                .create_proposal(String::from("Two"), Vec::new(), None)
+                .unwrap();
+            let third = dao
+                .create_proposal(String::from("Three"), Vec::new(), None)
+                .unwrap();
+            assert_eq!(dao.get_active_proposals(), vec![first, second, third]);
+
+            dao.cancel_proposal(first).unwrap();
+            assert_eq!(dao.get_active_proposals(), vec![third, second]);
+
+            advance_block(101);
+            dao.finalize_proposal(second).unwrap();
+            assert_eq!(dao.get_active_proposals(), vec![third]);
+        }
+
+        #[ink::test]
+        fn proposal_state_across_lifecycle() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 10, VotingStrategy::Linear, None, None, None);
+            dao.set_total_voting_power(1000).unwrap();
+            assert_eq!(dao.proposal_state(1), None);
+
+            let passing = dao
+                .create_proposal(String::from("Pass"), Vec::new(), None)
+                .unwrap();
+            let failing = dao
+                .create_proposal(String::from("Fail"), Vec::new(), None)
+                .unwrap();
+            let cancelled = dao
+                .create_proposal(String::from("Drop"), Vec::new(), None)
+                .unwrap();
+            dao.vote_with_weight(passing, VoteType::Yes, 300).unwrap();
+            dao.vote_with_weight(failing, VoteType::No, 300).unwrap();
+            dao.cancel_proposal(cancelled).unwrap();
+            assert_eq!(dao.proposal_state(passing), Some(ProposalState::Active));
+            assert_eq!(
+                dao.proposal_state(cancelled),
+                Some(ProposalState::Cancelled)
+            );
+
+            // Voting ended but nobody has finalized yet
+            advance_block(101);
+            assert_eq!(dao.proposal_state(passing), Some(ProposalState::Queued));
+            assert_eq!(dao.proposal_state(failing), Some(ProposalState::Defeated));
+
+            dao.finalize_proposal(passing).unwrap();
+            assert_eq!(dao.proposal_state(passing), Some(ProposalState::Queued));
+
+            advance_block(10);
+            assert_eq!(dao.proposal_state(passing), Some(ProposalState::Succeeded));
+
+            dao.execute_proposal(passing).unwrap();
+            assert_eq!(dao.proposal_state(passing), Some(ProposalState::Executed));
+        }
+
+        #[ink::test]
+        fn proposal_state_expires_without_finalize() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            dao.set_total_voting_power(1000).unwrap();
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+            dao.vote_with_weight(proposal_id, VoteType::Yes, 300)
+                .unwrap();
+
+            advance_block(101);
+            assert_eq!(
+                dao.proposal_state(proposal_id),
+                Some(ProposalState::Succeeded)
+            );
+
+            advance_block(DEFAULT_EXPIRATION_WINDOW);
+            assert_eq!(
+                dao.proposal_state(proposal_id),
+                Some(ProposalState::Expired)
+            );
+        }
+
+        #[ink::test]
+        fn sqrt_works() {
+            assert_eq!(sqrt(0), 0);
+            assert_eq!(sqrt(1), 1);
+            assert_eq!(sqrt(3), 1);
+            assert_eq!(sqrt(99), 9);
+            assert_eq!(sqrt(100), 10);
+            assert_eq!(sqrt(1_000_000_000_000), 1_000_000);
+        }
+
+        /// Runs one proposal where Alice (900) votes yes against four
+        /// holders of 100 each voting no
+        fn whale_vote(strategy: VotingStrategy) -> Proposal {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, strategy, None, None, None);
+            dao.set_total_voting_power(1300).unwrap();
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+
+            dao.vote_with_weight(proposal_id, VoteType::Yes, 900)
+                .unwrap();
+            for voter in [
+                accounts.bob,
+                accounts.charlie,
+                accounts.django,
+                accounts.eve,
+            ] {
+                set_caller(voter);
+                dao.vote_with_weight(proposal_id, VoteType::No, 100)
+                    .unwrap();
+            }
+
+            advance_block(101);
+            dao.finalize_proposal(proposal_id).unwrap();
+            dao.get_proposal(proposal_id).unwrap()
+        }
+
+        #[ink::test]
+        fn linear_strategy_favours_whale() {
+            let proposal = whale_vote(VotingStrategy::Linear);
+            assert_eq!(proposal.yes_votes, 900);
+            assert_eq!(proposal.no_votes, 400);
+            assert_eq!(proposal.status, ProposalStatus::Passed);
+        }
+
+        #[ink::test]
+        fn quadratic_strategy_favours_many() {
+            let proposal = whale_vote(VotingStrategy::Quadratic);
+            assert_eq!(proposal.yes_votes, 30);
+            assert_eq!(proposal.no_votes, 40);
+            assert_eq!(proposal.status, ProposalStatus::Rejected);
+        }
+
+        #[ink::test]
+        fn capped_strategy_clamps_whale() {
+            let proposal = whale_vote(VotingStrategy::Capped(200));
+            assert_eq!(proposal.yes_votes, 200);
+            assert_eq!(proposal.no_votes, 400);
+            assert_eq!(proposal.status, ProposalStatus::Rejected);
+        }
+
+        /// Statuses of two proposals voted on with 1_999 and 2_000 out of a
+        /// 10_000 total, against a 20% quorum
+        fn quorum_statuses(strategy: VotingStrategy) -> (ProposalStatus, ProposalStatus) {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, strategy, None, None, None);
+            dao.set_total_voting_power(10_000).unwrap();
+            let short = dao
+                .create_proposal(String::from("Short"), Vec::new(), None)
+                .unwrap();
+            let reached = dao
+                .create_proposal(String::from("Reached"), Vec::new(), None)
+                .unwrap();
+            dao.vote_with_weight(short, VoteType::Yes, 1_999).unwrap();
+            dao.vote_with_weight(reached, VoteType::Yes, 2_000).unwrap();
+
+            advance_block(101);
+            dao.finalize_proposal(short).unwrap();
+            dao.finalize_proposal(reached).unwrap();
+            (
+                dao.get_proposal(short).unwrap().status,
+                dao.get_proposal(reached).unwrap().status,
+            )
+        }
+
+        #[ink::test]
+        fn linear_quorum_uses_raw_balance() {
+            assert_eq!(
+                quorum_statuses(VotingStrategy::Linear),
+                (ProposalStatus::Rejected, ProposalStatus::Passed)
+            );
+        }
+
+        #[ink::test]
+        fn quadratic_quorum_uses_raw_balance() {
+            // sqrt(1_999) would clear 20% of a square-rooted total
+            assert_eq!(
+                quorum_statuses(VotingStrategy::Quadratic),
+                (ProposalStatus::Rejected, ProposalStatus::Passed)
+            );
+        }
+
+        #[ink::test]
+        fn capped_quorum_uses_raw_balance() {
+            // A weight capped at 50 could never reach 20% of the raw total
+            assert_eq!(
+                quorum_statuses(VotingStrategy::Capped(50)),
+                (ProposalStatus::Rejected, ProposalStatus::Passed)
+            );
+        }
+
+        #[ink::test]
+        fn retracted_vote_leaves_turnout() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Quadratic, None, None, None);
+            dao.set_total_voting_power(10_000).unwrap();
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+            dao.vote_with_weight(proposal_id, VoteType::Yes, 2_500)
+                .unwrap();
+            dao.vote_with_weight(proposal_id, VoteType::No, 900)
+                .unwrap();
+            let proposal = dao.get_proposal(proposal_id).unwrap();
+            assert_eq!((proposal.no_votes, proposal.turnout), (30, 900));
+
+            dao.retract_vote(proposal_id).unwrap();
+            assert_eq!(dao.get_proposal(proposal_id).unwrap().turnout, 0);
+        }
+
+        #[ink::test]
+        fn voting_strategy_set_by_governance() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            let action = Action {
+                target: contract_id(),
+                selector: SET_VOTING_STRATEGY_SELECTOR,
+                input: ink::scale::Encode::encode(&VotingStrategy::Capped(50)),
+                transferred_value: 0,
+            };
+            let proposal_id = passed_proposal(&mut dao, vec![action]);
+            dao.execute_proposal(proposal_id).unwrap();
+
+            assert_eq!(dao.voting_strategy(), VotingStrategy::Capped(50));
+            let next = dao
+                .create_proposal(String::from("Next"), Vec::new(), None)
+                .unwrap();
+            assert_eq!(
+                dao.get_proposal(next).unwrap().voting_strategy,
+                VotingStrategy::Capped(50)
+            );
+        }
+
+        #[ink::test]
+        fn set_vote_reward_admin_only() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            assert_eq!(dao.vote_reward(), 0);
+            dao.set_vote_reward(10).unwrap();
+            assert_eq!(dao.vote_reward(), 10);
+
+            set_caller(accounts.bob);
+            assert_eq!(dao.set_vote_reward(0), Err(Error::NotAuthorized));
+        }
+
+        #[ink::test]
+        fn vote_reward_set_by_governance() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            let action = Action {
+                target: contract_id(),
+                selector: SET_VOTE_REWARD_SELECTOR,
+                input: ink::scale::Encode::encode(&25u128),
+                transferred_value: 0,
+            };
+            let proposal_id = passed_proposal(&mut dao, vec![action]);
+            dao.execute_proposal(proposal_id).unwrap();
+
+            assert_eq!(dao.vote_reward(), 25);
+        }
+
+        #[ink::test]
+        fn vote_reward_eligibility() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            dao.set_vote_reward(1_000).unwrap();
+            let active = dao
+                .create_proposal(String::from("Open"), Vec::new(), None)
+                .unwrap();
+            dao.vote_with_weight(active, VoteType::Yes, 100).unwrap();
+            let finalized = passed_proposal(&mut dao, Vec::new());
+
+            assert_eq!(
+                dao.vote_reward_eligibility(active, accounts.alice),
+                Err(Error::NotFinalized)
+            );
+            // 300 of the 1000 snapshot power earns 30% of the reward
+            assert_eq!(
+                dao.vote_reward_eligibility(finalized, accounts.alice),
+                Ok(300)
+            );
+            assert_eq!(
+                dao.vote_reward_eligibility(finalized, accounts.bob),
+                Err(Error::NotVoted)
+            );
+            assert_eq!(
+                dao.vote_reward_eligibility(99, accounts.alice),
+                Err(Error::ProposalNotFound)
+            );
+
+            dao.reward_claims.insert((finalized, accounts.alice), &());
+            assert!(dao.has_claimed_vote_reward(finalized, accounts.alice));
+            assert_eq!(
+                dao.vote_reward_eligibility(finalized, accounts.alice),
+                Err(Error::RewardClaimed)
+            );
+        }
+
+        #[ink::test]
+        fn vote_reward_cannot_be_farmed() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            dao.set_vote_reward(1_000).unwrap();
+            dao.set_total_voting_power(10_000).unwrap();
+
+            // Rejected below quorum: alice's 100 is short of the 2_000 needed
+            let unnoticed = dao
+                .create_proposal(String::from("Unnoticed"), Vec::new(), None)
+                .unwrap();
+            dao.vote_with_weight(unnoticed, VoteType::Yes, 100).unwrap();
+
+            // Reached quorum, with bob voting dust alongside alice
+            let contested = dao
+                .create_proposal(String::from("Contested"), Vec::new(), None)
+                .unwrap();
+            dao.vote_with_weight(contested, VoteType::No, 2_000)
+                .unwrap();
+            set_caller(accounts.bob);
+            dao.vote_with_weight(contested, VoteType::Yes, 1).unwrap();
+
+            advance_block(101);
+            dao.finalize_proposal(unnoticed).unwrap();
+            dao.finalize_proposal(contested).unwrap();
+            assert_eq!(
+                dao.get_proposal(contested).unwrap().status,
+                ProposalStatus::Rejected
+            );
+
+            assert_eq!(
+                dao.vote_reward_eligibility(unnoticed, accounts.alice),
+                Err(Error::QuorumNotReached)
+            );
+            assert_eq!(
+                dao.vote_reward_eligibility(contested, accounts.bob),
+                Err(Error::RewardTooSmall)
+            );
+            assert_eq!(
+                dao.vote_reward_eligibility(contested, accounts.alice),
+                Ok(200)
+            );
+        }
+
+        #[ink::test]
+        fn cancelled_proposal_pays_no_vote_reward() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+            dao.vote_with_weight(proposal_id, VoteType::Yes, 100)
+                .unwrap();
+            dao.cancel_proposal(proposal_id).unwrap();
+
+            assert_eq!(
+                dao.vote_reward_eligibility(proposal_id, accounts.alice),
+                Err(Error::NotFinalized)
+            );
+        }
+
+        #[ink::test]
+        fn claim_vote_rewards_validates() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            assert_eq!(
+                dao.claim_vote_rewards(vec![1; MAX_REWARD_CLAIMS as usize + 1]),
+                Err(Error::TooManyClaims)
+            );
+            assert_eq!(
+                dao.claim_vote_rewards(vec![1]),
+                Err(Error::TokenNotConfigured)
+            );
+        }
+
+        #[ink::test]
+        fn vote_requires_token() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+
+            assert_eq!(
+                dao.vote(proposal_id, VoteType::Yes),
+                Err(Error::TokenNotConfigured)
+            );
+        }
+
+        #[ink::test]
+        fn manual_weight_disabled_with_token() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(
+                100,
+                2000,
+                0,
+                VotingStrategy::Linear,
+                Some(accounts.django),
+                None,
+                None,
+            );
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+
+            assert_eq!(
+                dao.vote_with_weight(proposal_id, VoteType::Yes, 1_000_000),
+                Err(Error::ManualWeightDisabled)
+            );
+            assert_eq!(dao.get_vote(proposal_id, accounts.alice), None);
+        }
+
+        #[ink::test]
+        fn membership_mode_reflects_config() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let open = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            assert_eq!(open.membership_mode(), MembershipMode::Open);
+
+            let gated = SimpleDao::new(
+                100,
+                2000,
+                0,
+                VotingStrategy::Linear,
+                Some(accounts.django),
+                Some(accounts.eve),
+                None,
+            );
+            assert_eq!(gated.membership_mode(), MembershipMode::NftGated);
+
+            let weighted = SimpleDao::new(
+                100,
+                2000,
+                0,
+                VotingStrategy::Linear,
+                None,
+                Some(accounts.eve),
+                None,
+            );
+            assert_eq!(weighted.membership_mode(), MembershipMode::NftWeighted);
+        }
+
+        #[ink::test]
+        fn manual_weight_disabled_with_nft() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(
+                100,
+                2000,
+                0,
+                VotingStrategy::Linear,
+                None,
+                Some(accounts.eve),
+                None,
+            );
+            assert_eq!(
+                dao.vote_with_weight(1, VoteType::Yes, 100),
+                Err(Error::ManualWeightDisabled)
+            );
+        }
+
+        #[ink::test]
+        fn proposal_config_defaults() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+
+            let proposal = dao.get_proposal(proposal_id).unwrap();
+            assert_eq!(proposal.end_block, proposal.start_block + 100);
+            assert_eq!(proposal.quorum_bps, 2000);
+            assert_eq!(
+                proposal.approval_threshold_bps,
+                DEFAULT_APPROVAL_THRESHOLD_BPS
+            );
+        }
+
+        #[ink::test]
+        fn proposal_config_overrides_within_bounds() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            dao.set_total_voting_power(1000).unwrap();
+            let config = ProposalConfig {
+                voting_period: Some(10),
+                quorum_bps: Some(5000),
+                approval_threshold_bps: Some(6000),
+            };
+            let proposal_id = dao
+                .create_proposal(String::from("Constitutional"), Vec::new(), Some(config))
+                .unwrap();
+
+            let proposal = dao.get_proposal(proposal_id).unwrap();
+            assert_eq!(proposal.end_block, proposal.start_block + 10);
+            assert_eq!(proposal.quorum_bps, 5000);
+            assert_eq!(proposal.approval_threshold_bps, 6000);
+
+            // 55% yes would pass a simple majority but not a 60% threshold
+            dao.vote_with_weight(proposal_id, VoteType::Yes, 550)
+                .unwrap();
+            set_caller(accounts.bob);
+            dao.vote_with_weight(proposal_id, VoteType::No, 450)
+                .unwrap();
+
+            advance_block(11);
+            dao.finalize_proposal(proposal_id).unwrap();
+            assert_eq!(
+                dao.get_proposal(proposal_id).unwrap().status,
+                ProposalStatus::Rejected
+            );
+        }
+
+        #[ink::test]
+        fn proposal_config_overrides_outside_bounds() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            dao.set_config_bounds(ConfigBounds {
+                voting_period: (10, 50),
+                quorum_bps: (1000, 5000),
+                approval_threshold_bps: (5000, 7500),
+            })
+            .unwrap();
+
+            for config in [
+                ProposalConfig {
+                    voting_period: Some(5),
+                    ..Default::default()
+                },
+                ProposalConfig {
+                    voting_period: Some(51),
+                    ..Default::default()
+                },
+                ProposalConfig {
+                    quorum_bps: Some(6000),
+                    ..Default::default()
+                },
+                ProposalConfig {
+                    approval_threshold_bps: Some(4000),
+                    ..Default::default()
+                },
+            ] {
+                assert_eq!(
+                    dao.create_proposal(String::from("Test"), Vec::new(), Some(config)),
+                    Err(Error::ConfigOutOfRange)
+                );
+            }
+            assert_eq!(dao.proposal_count(), 0);
+
+            // Bounds only apply to overrides, not to the defaults
+            let edge = ProposalConfig {
+                voting_period: Some(50),
+                quorum_bps: Some(1000),
+                approval_threshold_bps: Some(7500),
+            };
+            assert!(dao
+                .create_proposal(String::from("Edge"), Vec::new(), Some(edge))
+                .is_ok());
+            assert!(dao
+                .create_proposal(String::from("Default"), Vec::new(), None)
+                .is_ok());
+        }
+
+        #[ink::test]
+        fn set_config_bounds_validates() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            let inverted = ConfigBounds {
+                voting_period: (50, 10),
+                ..ConfigBounds::default()
+            };
+            assert_eq!(
+                dao.set_config_bounds(inverted),
+                Err(Error::ConfigOutOfRange)
+            );
+            let over = ConfigBounds {
+                quorum_bps: (0, 10001),
+                ..ConfigBounds::default()
+            };
+            assert_eq!(dao.set_config_bounds(over), Err(Error::ConfigOutOfRange));
+            assert_eq!(dao.config_bounds(), ConfigBounds::default());
+
+            set_caller(accounts.bob);
+            assert_eq!(
+                dao.set_config_bounds(ConfigBounds::default()),
+                Err(Error::NotAuthorized)
+            );
+            assert_eq!(dao.set_approval_threshold(6000), Err(Error::NotAuthorized));
+        }
+
+        #[ink::test]
+        fn effective_total_power_works() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Quadratic, None, None, None);
+            assert_eq!(dao.effective_total_power(1), None);
+
+            dao.set_total_voting_power(10_000).unwrap();
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+            dao.set_total_voting_power(40_000).unwrap();
+
+            // Snapshotted at creation; the strategy does not reshape it
+            assert_eq!(dao.effective_total_power(proposal_id), Some(10_000));
+        }
+
+        #[ink::test]
+        fn set_total_voting_power_disabled_with_token() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(
+                100,
+                2000,
+                0,
+                VotingStrategy::Linear,
+                Some(accounts.django),
+                None,
+                None,
+            );
+            assert_eq!(
+                dao.set_total_voting_power(1000),
+                Err(Error::ManualWeightDisabled)
+            );
+        }
+
+        #[ink::test]
+        fn submit_votes_requires_weight_source() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+
+            assert_eq!(
+                dao.submit_votes(proposal_id, Vec::new()),
+                Err(Error::TokenNotConfigured)
+            );
+            assert_eq!(dao.vote_nonce(accounts.bob), 0);
+        }
+
+        #[ink::test]
+        fn vote_hash_binds_all_fields() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            let yes = Support::Vote(VoteType::Yes);
+            let hash = dao.vote_hash(1, accounts.bob, yes, 0);
+
+            assert_eq!(hash, dao.vote_hash(1, accounts.bob, yes, 0));
+            assert_ne!(hash, dao.vote_hash(2, accounts.bob, yes, 0));
+            assert_ne!(hash, dao.vote_hash(1, accounts.charlie, yes, 0));
+            assert_ne!(
+                hash,
+                dao.vote_hash(1, accounts.bob, Support::Vote(VoteType::No), 0)
+            );
+            assert_ne!(hash, dao.vote_hash(1, accounts.bob, yes, 1));
+        }
+
+        #[ink::test]
+        fn deployer_holds_admin_roles() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            assert!(dao.has_role(DEFAULT_ADMIN_ROLE, accounts.alice));
+            assert!(dao.has_role(CONFIG_ROLE, accounts.alice));
+            assert!(!dao.has_role(DEFAULT_ADMIN_ROLE, accounts.bob));
+        }
+
+        #[ink::test]
+        fn admin_functions_require_roles() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+
+            set_caller(accounts.bob);
+            assert_eq!(dao.set_total_voting_power(1000), Err(Error::NotAuthorized));
+            assert_eq!(dao.set_voting_period(50), Err(Error::NotAuthorized));
+            assert_eq!(dao.set_quorum_threshold(3000), Err(Error::NotAuthorized));
+            assert_eq!(dao.transfer_admin(accounts.bob), Err(Error::NotAuthorized));
+            assert_eq!(
+                dao.grant_role(CONFIG_ROLE, accounts.bob),
+                Err(Error::NotAuthorized)
+            );
+
+            set_caller(accounts.alice);
+            dao.set_total_voting_power(1000).unwrap();
+            dao.set_voting_period(50).unwrap();
+            dao.set_quorum_threshold(3000).unwrap();
+            assert_eq!(dao.voting_period(), 50);
+            assert_eq!(dao.quorum_threshold(), 3000);
+            assert_eq!(dao.set_voting_period(0), Err(Error::InvalidVotingPeriod));
+            assert_eq!(
+                dao.set_quorum_threshold(10001),
+                Err(Error::ConfigOutOfRange)
+            );
+        }
+
+        #[ink::test]
+        fn config_role_limited_to_voting_settings() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            dao.grant_role(CONFIG_ROLE, accounts.bob).unwrap();
+
+            set_caller(accounts.bob);
+            dao.set_voting_period(200).unwrap();
+            dao.set_approval_threshold(6000).unwrap();
+            assert_eq!(dao.set_proposal_bond(10), Err(Error::NotAuthorized));
+
+            set_caller(accounts.alice);
+            dao.revoke_role(CONFIG_ROLE, accounts.bob).unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(dao.set_voting_period(300), Err(Error::NotAuthorized));
+        }
+
+        #[ink::test]
+        fn transfer_admin_moves_roles() {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            assert_eq!(
+                dao.revoke_role(DEFAULT_ADMIN_ROLE, accounts.alice),
+                Err(Error::NotAuthorized)
+            );
+
+            dao.transfer_admin(accounts.bob).unwrap();
+            assert_eq!(dao.admin(), accounts.bob);
+            assert!(dao.has_role(DEFAULT_ADMIN_ROLE, accounts.bob));
+            assert!(dao.has_role(CONFIG_ROLE, accounts.bob));
+            assert!(!dao.has_role(DEFAULT_ADMIN_ROLE, accounts.alice));
+            assert!(!dao.has_role(CONFIG_ROLE, accounts.alice));
+
+            // The old admin lost every privilege
+            assert_eq!(dao.set_total_voting_power(1000), Err(Error::NotAuthorized));
+            set_caller(accounts.bob);
+            dao.set_total_voting_power(1000).unwrap();
+        }
+
+        fn guarded_dao() -> SimpleDao {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+            SimpleDao::new(
+                100,
+                2000,
+                0,
+                VotingStrategy::Linear,
+                None,
+                None,
+                Some(accounts.eve),
+            )
+        }
+
+        #[ink::test]
+        fn veto_blocks_execution() {
+            let accounts = default_accounts();
+            let mut dao = guarded_dao();
+            let proposal_id = passed_proposal(&mut dao, Vec::new());
+
+            assert_eq!(dao.veto_proposal(proposal_id), Err(Error::NotGuardian));
+
+            set_caller(accounts.eve);
+            dao.veto_proposal(proposal_id).unwrap();
+            assert_eq!(
+                dao.get_proposal(proposal_id).unwrap().status,
+                ProposalStatus::Vetoed
+            );
+            assert_eq!(dao.proposal_state(proposal_id), Some(ProposalState::Vetoed));
+            assert_eq!(
+                dao.execute_proposal(proposal_id),
+                Err(Error::ProposalFailed)
+            );
+        }
+
+        #[ink::test]
+        fn veto_after_execution_fails() {
+            let accounts = default_accounts();
+            let mut dao = guarded_dao();
+            let proposal_id = passed_proposal(&mut dao, Vec::new());
+            dao.execute_proposal(proposal_id).unwrap();
+
+            set_caller(accounts.eve);
+            assert_eq!(dao.veto_proposal(proposal_id), Err(Error::AlreadyExecuted));
+        }
+
+        #[ink::test]
+        fn veto_requires_passed_proposal() {
+            let accounts = default_accounts();
+            let mut dao = guarded_dao();
+            let proposal_id = dao
+                .create_proposal(String::from("Test"), Vec::new(), None)
+                .unwrap();
+
+            set_caller(accounts.eve);
+            assert_eq!(dao.veto_proposal(proposal_id), Err(Error::ProposalFailed));
+        }
+
+        #[ink::test]
+        fn renounced_guardian_cannot_veto() {
+            let accounts = default_accounts();
+            let mut dao = guarded_dao();
+            let proposal_id = passed_proposal(&mut dao, Vec::new());
+
+            set_caller(accounts.eve);
+            dao.renounce_guardian().unwrap();
+            assert_eq!(dao.guardian(), None);
+            assert_eq!(dao.veto_proposal(proposal_id), Err(Error::NotGuardian));
+            assert_eq!(dao.renounce_guardian(), Err(Error::NotGuardian));
+        }
+
+        #[ink::test]
+        fn guardian_removed_by_governance() {
+            let mut dao = guarded_dao();
+            let action = Action {
+                target: contract_id(),
+                selector: REMOVE_GUARDIAN_SELECTOR,
+                input: Vec::new(),
+                transferred_value: 0,
+            };
+            let proposal_id = passed_proposal(&mut dao, vec![action]);
+            dao.execute_proposal(proposal_id).unwrap();
+
+            assert_eq!(dao.guardian(), None);
+        }
 
-            let proposal = dao.get_proposal(proposal_id).unwrap();
-            assert_eq!(proposal.proposer, accounts.alice);
-            assert_eq!(proposal.description, description);
-            assert_eq!(proposal.status, ProposalStatus::Active);
+        fn three_options() -> Vec<String> {
+            vec![
+                String::from("Red"),
+                String::from("Green"),
+                String::from("Blue"),
+            ]
         }
 
-        #[ink::test]
-        fn vote_works() {
+        /// Creates a three-option proposal out of 1000 total voting power
+        /// and casts `(voter, option, weight)` choices before finalizing
+        fn choice_vote(choices: &[(AccountId, u8, u128)]) -> (SimpleDao, ProposalId) {
             let accounts = default_accounts();
             set_caller(accounts.alice);
 
-            let mut dao = SimpleDao::new(100, 2000, None, None);
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
             dao.set_total_voting_power(1000).unwrap();
+            let proposal_id = dao
+                .create_multi_choice_proposal(String::from("Colour"), three_options())
+                .unwrap();
 
-            let proposal_id = dao.create_proposal(String::from("Test proposal")).unwrap();
+            for (voter, option_index, weight) in choices {
+                set_caller(*voter);
+                dao.vote_choice_with_weight(proposal_id, *option_index, *weight)
+                    .unwrap();
+            }
 
-            // Alice votes yes with weight 100
-            set_caller(accounts.alice);
-            let result = dao.vote(proposal_id, true, 100);
-            assert!(result.is_ok());
+            advance_block(101);
+            dao.finalize_proposal(proposal_id).unwrap();
+            (dao, proposal_id)
+        }
+
+        #[ink::test]
+        fn multi_choice_clear_winner() {
+            let accounts = default_accounts();
+            let (dao, proposal_id) = choice_vote(&[
+                (accounts.alice, 0, 100),
+                (accounts.bob, 1, 250),
+                (accounts.charlie, 2, 50),
+            ]);
 
             let proposal = dao.get_proposal(proposal_id).unwrap();
-            assert_eq!(proposal.yes_votes, 100);
-            assert_eq!(proposal.no_votes, 0);
+            assert_eq!(proposal.status, ProposalStatus::Passed);
+            assert_eq!(proposal.winning_option, Some(1));
+            assert_eq!(
+                dao.get_options(proposal_id),
+                vec![
+                    (String::from("Red"), 100),
+                    (String::from("Green"), 250),
+                    (String::from("Blue"), 50),
+                ]
+            );
+            assert_eq!(dao.get_choice(proposal_id, accounts.bob), Some(1));
+        }
 
-            // Bob votes no with weight 50
-            set_caller(accounts.bob);
-            let result = dao.vote(proposal_id, false, 50);
-            assert!(result.is_ok());
+        #[ink::test]
+        fn multi_choice_tie_rejected() {
+            let accounts = default_accounts();
+            let (dao, proposal_id) = choice_vote(&[
+                (accounts.alice, 0, 150),
+                (accounts.bob, 2, 150),
+                (accounts.charlie, 1, 100),
+            ]);
 
             let proposal = dao.get_proposal(proposal_id).unwrap();
-            assert_eq!(proposal.yes_votes, 100);
-            assert_eq!(proposal.no_votes, 50);
+            assert_eq!(proposal.status, ProposalStatus::Rejected);
+            assert_eq!(proposal.winning_option, None);
+        }
+
+        #[ink::test]
+        fn multi_choice_quorum_failure() {
+            let accounts = default_accounts();
+            // 20% of 1000 = 200 needed
+            let (dao, proposal_id) =
+                choice_vote(&[(accounts.alice, 0, 150), (accounts.bob, 1, 40)]);
+
+            let proposal = dao.get_proposal(proposal_id).unwrap();
+            assert_eq!(proposal.status, ProposalStatus::Rejected);
+            assert_eq!(proposal.winning_option, None);
         }
 
         #[ink::test]
-        fn already_voted_fails() {
+        fn multi_choice_change_and_retract() {
             let accounts = default_accounts();
             set_caller(accounts.alice);
 
-            let mut dao = SimpleDao::new(100, 2000, None, None);
-            let proposal_id = dao.create_proposal(String::from("Test")).unwrap();
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            let proposal_id = dao
+                .create_multi_choice_proposal(String::from("Colour"), three_options())
+                .unwrap();
 
-            dao.vote(proposal_id, true, 100).unwrap();
+            dao.vote_choice_with_weight(proposal_id, 0, 100).unwrap();
+            dao.vote_choice_with_weight(proposal_id, 2, 100).unwrap();
+            assert_eq!(
+                dao.get_proposal(proposal_id).unwrap().option_votes,
+                vec![0, 0, 100]
+            );
+            assert_eq!(dao.get_choice(proposal_id, accounts.alice), Some(2));
 
-            // Try to vote again
-            let result = dao.vote(proposal_id, false, 50);
-            assert_eq!(result, Err(Error::AlreadyVoted));
+            dao.retract_vote(proposal_id).unwrap();
+            assert_eq!(
+                dao.get_proposal(proposal_id).unwrap().option_votes,
+                vec![0, 0, 0]
+            );
+            assert_eq!(dao.get_choice(proposal_id, accounts.alice), None);
         }
 
         #[ink::test]
-        fn finalize_proposal_works() {
+        fn multi_choice_validates_options() {
             let accounts = default_accounts();
             set_caller(accounts.alice);
 
-            let mut dao = SimpleDao::new(100, 2000, None, None);
-            dao.set_total_voting_power(1000).unwrap();
+            let mut dao = SimpleDao::new(100, 2000, 0, VotingStrategy::Linear, None, None, None);
+            assert_eq!(
+                dao.create_multi_choice_proposal(String::from("One"), vec![String::from("Only")]),
+                Err(Error::InvalidOptionCount)
+            );
+            let too_many = vec![String::from("Option"); MAX_OPTIONS as usize + 1];
+            assert_eq!(
+                dao.create_multi_choice_proposal(String::from("Many"), too_many),
+                Err(Error::InvalidOptionCount)
+            );
 
-            let proposal_id = dao.create_proposal(String::from("Test")).unwrap();
+            let proposal_id = dao
+                .create_multi_choice_proposal(String::from("Colour"), three_options())
+                .unwrap();
+            assert_eq!(
+                dao.vote_choice_with_weight(proposal_id, 3, 100),
+                Err(Error::InvalidOption)
+            );
+            assert_eq!(
+                dao.vote_with_weight(proposal_id, VoteType::Yes, 100),
+                Err(Error::WrongProposalKind)
+            );
 
-            // Vote
-            dao.vote(proposal_id, true, 300).unwrap();
+            let binary = dao
+                .create_proposal(String::from("Binary"), Vec::new(), None)
+                .unwrap();
+            assert_eq!(
+                dao.vote_choice_with_weight(binary, 0, 100),
+                Err(Error::WrongProposalKind)
+            );
+        }
+    }
 
-            // Advance blocks past voting period
-            advance_block(101);
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use beli_nft::beli_nft::{BeliNft, BeliNftRef};
+        use belizex_factory::factory::{Factory, FactoryRef};
+        use dalla_token::dalla_token::{DallaToken, DallaTokenRef};
+        use hello_belizechain::hello_belizechain::{HelloBelizeChain, HelloBelizeChainRef};
+        use ink_e2e::ContractsBackend;
 
-            // Finalize
-            let result = dao.finalize_proposal(proposal_id);
-            assert!(result.is_ok());
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
-            let proposal = dao.get_proposal(proposal_id).unwrap();
-            assert_eq!(proposal.status, ProposalStatus::Passed);
+        #[ink_e2e::test]
+        async fn e2e_execute_dispatches_actions<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // Deploy a target contract for the proposal to act on
+            let mut target_constructor = HelloBelizeChainRef::new(String::from("Before"));
+            let target = client
+                .instantiate(
+                    "hello_belizechain",
+                    &ink_e2e::alice(),
+                    &mut target_constructor,
+                )
+                .submit()
+                .await
+                .expect("hello_belizechain instantiate failed");
+            let mut target_call = target.call_builder::<HelloBelizeChain>();
+
+            let mut dao_constructor =
+                SimpleDaoRef::new(1, 2000, 0, VotingStrategy::Linear, None, None, None);
+            let dao = client
+                .instantiate("simple_dao", &ink_e2e::alice(), &mut dao_constructor)
+                .submit()
+                .await
+                .expect("simple_dao instantiate failed");
+            let mut dao_call = dao.call_builder::<SimpleDao>();
+
+            // The target is owner-gated: offer ownership to the DAO, which
+            // accepts it in the proposal's first action
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &target_call.transfer_ownership(dao.account_id),
+                )
+                .submit()
+                .await
+                .expect("transfer_ownership failed");
+
+            let actions = vec![
+                Action::call(
+                    target.account_id,
+                    ink::selector_bytes!("accept_ownership"),
+                    &(),
+                ),
+                // set_message("Governed")
+                Action::call(
+                    target.account_id,
+                    [0x1f, 0xe7, 0x42, 0x6f],
+                    &String::from("Governed"),
+                ),
+            ];
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &dao_call.create_proposal(String::from("Rename"), actions, None),
+                )
+                .submit()
+                .await
+                .expect("create_proposal failed");
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &dao_call.vote_with_weight(1, VoteType::Yes, 100),
+                )
+                .submit()
+                .await
+                .expect("vote failed");
+            client
+                .call(&ink_e2e::alice(), &dao_call.finalize_proposal(1))
+                .submit()
+                .await
+                .expect("finalize failed");
+
+            // Nothing happens until the proposal is executed
+            let message = client
+                .call(&ink_e2e::alice(), &target_call.get_message())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(message, "Before");
+
+            client
+                .call(&ink_e2e::bob(), &dao_call.execute_proposal(1))
+                .submit()
+                .await
+                .expect("execute failed");
+
+            let message = client
+                .call(&ink_e2e::alice(), &target_call.get_message())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(message, "Governed");
+
+            let proposal = client
+                .call(&ink_e2e::alice(), &dao_call.get_proposal(1))
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("proposal missing");
+            assert_eq!(proposal.status, ProposalStatus::Executed);
+
+            Ok(())
         }
 
-        #[ink::test]
-        fn execute_proposal_works() {
-            let accounts = default_accounts();
-            set_caller(accounts.alice);
+        #[ink_e2e::test]
+        async fn e2e_dao_governs_factory_fee_to<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
 
-            let mut dao = SimpleDao::new(100, 2000, None, None);
-            dao.set_total_voting_power(1000).unwrap();
+            // DALLA holders vote; Alice holds the whole supply
+            let mut token_constructor = DallaTokenRef::new(1_000_000);
+            let token = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut token_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
 
-            let proposal_id = dao.create_proposal(String::from("Test")).unwrap();
-            dao.vote(proposal_id, true, 300).unwrap();
+            let mut factory_constructor = FactoryRef::new(alice, Hash::from([0x42; 32]));
+            let factory = client
+                .instantiate(
+                    "belizex_factory",
+                    &ink_e2e::alice(),
+                    &mut factory_constructor,
+                )
+                .submit()
+                .await
+                .expect("belizex_factory instantiate failed");
+            let mut factory_call = factory.call_builder::<Factory>();
 
-            advance_block(101);
-            dao.finalize_proposal(proposal_id).unwrap();
+            let mut dao_constructor = SimpleDaoRef::new(
+                1,
+                2000,
+                0,
+                VotingStrategy::Linear,
+                Some(token.account_id),
+                None,
+                None,
+            );
+            let dao = client
+                .instantiate("simple_dao", &ink_e2e::alice(), &mut dao_constructor)
+                .submit()
+                .await
+                .expect("simple_dao instantiate failed");
+            let mut dao_call = dao.call_builder::<SimpleDao>();
 
-            // Execute
-            let result = dao.execute_proposal(proposal_id);
-            assert!(result.is_ok());
+            // Hand the factory's fee settings over to the DAO
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &factory_call.set_fee_to_setter(dao.account_id),
+                )
+                .submit()
+                .await
+                .expect("set_fee_to_setter failed");
 
-            let proposal = dao.get_proposal(proposal_id).unwrap();
+            // Alice can no longer change fee_to herself
+            let result = client
+                .call(&ink_e2e::alice(), &factory_call.set_fee_to(Some(alice)))
+                .dry_run()
+                .await?
+                .return_value();
+            assert!(result.is_err());
+
+            let action = Action::call(
+                factory.account_id,
+                ink::selector_bytes!("set_fee_to"),
+                &Some(bob),
+            );
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &dao_call.create_proposal(
+                        String::from("Send protocol fees to Bob"),
+                        vec![action],
+                        None,
+                    ),
+                )
+                .submit()
+                .await
+                .expect("create_proposal failed");
+            client
+                .call(&ink_e2e::alice(), &dao_call.vote(1, VoteType::Yes))
+                .submit()
+                .await
+                .expect("vote failed");
+            client
+                .call(&ink_e2e::alice(), &dao_call.finalize_proposal(1))
+                .submit()
+                .await
+                .expect("finalize failed");
+
+            let fee_to = client
+                .call(&ink_e2e::alice(), &factory_call.fee_to())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(fee_to, None);
+
+            client
+                .call(&ink_e2e::bob(), &dao_call.execute_proposal(1))
+                .submit()
+                .await
+                .expect("execute failed");
+
+            let fee_to = client
+                .call(&ink_e2e::alice(), &factory_call.fee_to())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(fee_to, Some(bob));
+
+            let proposal = client
+                .call(&ink_e2e::alice(), &dao_call.get_proposal(1))
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("proposal missing");
             assert_eq!(proposal.status, ProposalStatus::Executed);
-            assert!(proposal.executed);
+
+            Ok(())
         }
 
-        #[ink::test]
-        fn quorum_not_met_fails() {
-            let accounts = default_accounts();
-            set_caller(accounts.alice);
+        #[ink_e2e::test]
+        async fn e2e_vote_weight_matches_balance<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // Deploy DALLA and give Bob some tokens
+            let mut token_constructor = DallaTokenRef::new(1_000_000);
+            let token = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut token_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+            let mut token_call = token.call_builder::<DallaToken>();
 
-            let mut dao = SimpleDao::new(100, 2000, None, None);
-            dao.set_total_voting_power(1000).unwrap();
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            client
+                .call(&ink_e2e::alice(), &token_call.transfer(bob, 250_000))
+                .submit()
+                .await
+                .expect("transfer failed");
 
-            let proposal_id = dao.create_proposal(String::from("Test")).unwrap();
+            // Deploy the DAO pointing at DALLA
+            let mut dao_constructor = SimpleDaoRef::new(
+                100,
+                2000,
+                0,
+                VotingStrategy::Linear,
+                Some(token.account_id),
+                None,
+                None,
+            );
+            let dao = client
+                .instantiate("simple_dao", &ink_e2e::alice(), &mut dao_constructor)
+                .submit()
+                .await
+                .expect("simple_dao instantiate failed");
+            let mut dao_call = dao.call_builder::<SimpleDao>();
 
-            // Vote with only 100 (quorum needs 200 = 20% of 1000)
-            dao.vote(proposal_id, true, 100).unwrap();
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &dao_call.create_proposal(String::from("Test"), Vec::new(), None),
+                )
+                .submit()
+                .await
+                .expect("create_proposal failed");
 
-            advance_block(101);
-            dao.finalize_proposal(proposal_id).unwrap();
+            client
+                .call(&ink_e2e::alice(), &dao_call.vote(1, VoteType::Yes))
+                .submit()
+                .await
+                .expect("alice vote failed");
+            client
+                .call(&ink_e2e::bob(), &dao_call.vote(1, VoteType::No))
+                .submit()
+                .await
+                .expect("bob vote failed");
 
-            let proposal = dao.get_proposal(proposal_id).unwrap();
-            assert_eq!(proposal.status, ProposalStatus::Rejected);
+            let proposal = client
+                .call(&ink_e2e::alice(), &dao_call.get_proposal(1))
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("proposal missing");
+            assert_eq!(proposal.yes_votes, 750_000);
+            assert_eq!(proposal.no_votes, 250_000);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_transfer_after_snapshot_ignored<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let mut token_constructor = DallaTokenRef::new(1_000_000);
+            let token = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut token_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+            let mut token_call = token.call_builder::<DallaToken>();
+
+            let mut dao_constructor = SimpleDaoRef::new(
+                100,
+                2000,
+                0,
+                VotingStrategy::Linear,
+                Some(token.account_id),
+                None,
+                None,
+            );
+            let dao = client
+                .instantiate("simple_dao", &ink_e2e::alice(), &mut dao_constructor)
+                .submit()
+                .await
+                .expect("simple_dao instantiate failed");
+            let mut dao_call = dao.call_builder::<SimpleDao>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &dao_call.create_proposal(String::from("Test"), Vec::new(), None),
+                )
+                .submit()
+                .await
+                .expect("create_proposal failed");
+
+            // Alice moves half her tokens to Bob after the snapshot
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            client
+                .call(&ink_e2e::alice(), &token_call.transfer(bob, 500_000))
+                .submit()
+                .await
+                .expect("transfer failed");
+
+            // Bob had nothing at the snapshot
+            let result = client
+                .call(&ink_e2e::bob(), &dao_call.vote(1, VoteType::Yes))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(result, Err(Error::NoVotingPower));
+
+            // Alice still votes with her full snapshot balance
+            client
+                .call(&ink_e2e::alice(), &dao_call.vote(1, VoteType::Yes))
+                .submit()
+                .await
+                .expect("alice vote failed");
+
+            let proposal = client
+                .call(&ink_e2e::alice(), &dao_call.get_proposal(1))
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("proposal missing");
+            assert_eq!(proposal.yes_votes, 1_000_000);
+            assert_eq!(proposal.snapshot_total_power, 1_000_000);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_delegated_power_counts_once<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let mut token_constructor = DallaTokenRef::new(1_000_000);
+            let token = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut token_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+            let mut token_call = token.call_builder::<DallaToken>();
+
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let charlie = ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie);
+            client
+                .call(&ink_e2e::alice(), &token_call.transfer(bob, 300_000))
+                .submit()
+                .await
+                .expect("transfer failed");
+
+            let mut dao_constructor = SimpleDaoRef::new(
+                100,
+                2000,
+                0,
+                VotingStrategy::Linear,
+                Some(token.account_id),
+                None,
+                None,
+            );
+            let dao = client
+                .instantiate("simple_dao", &ink_e2e::alice(), &mut dao_constructor)
+                .submit()
+                .await
+                .expect("simple_dao instantiate failed");
+            let mut dao_call = dao.call_builder::<SimpleDao>();
+
+            // Bob delegates to Charlie, who holds nothing himself
+            client
+                .call(&ink_e2e::bob(), &dao_call.delegate(charlie))
+                .submit()
+                .await
+                .expect("delegate failed");
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &dao_call.create_proposal(String::from("Test"), Vec::new(), None),
+                )
+                .submit()
+                .await
+                .expect("create_proposal failed");
+
+            client
+                .call(&ink_e2e::charlie(), &dao_call.vote(1, VoteType::Yes))
+                .submit()
+                .await
+                .expect("charlie vote failed");
+
+            // Bob cannot vote while delegating, nor after undelegating
+            let result = client
+                .call(&ink_e2e::bob(), &dao_call.vote(1, VoteType::No))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(result, Err(Error::VoteDelegated));
+
+            client
+                .call(&ink_e2e::bob(), &dao_call.undelegate())
+                .submit()
+                .await
+                .expect("undelegate failed");
+            let result = client
+                .call(&ink_e2e::bob(), &dao_call.vote(1, VoteType::No))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(result, Err(Error::AlreadyVoted));
+
+            let proposal = client
+                .call(&ink_e2e::alice(), &dao_call.get_proposal(1))
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("proposal missing");
+            assert_eq!(proposal.yes_votes, 300_000);
+            assert_eq!(proposal.no_votes, 0);
+
+            // Undelegating restores Bob's own voting power
+            let power = client
+                .call(&ink_e2e::alice(), &dao_call.voting_power(bob))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(power, 300_000);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_bond_refunded_and_slashed<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let mut token_constructor = DallaTokenRef::new(1_000_000);
+            let token = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut token_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+            let mut token_call = token.call_builder::<DallaToken>();
+
+            let mut dao_constructor = SimpleDaoRef::new(
+                1,
+                2000,
+                0,
+                VotingStrategy::Linear,
+                Some(token.account_id),
+                None,
+                None,
+            );
+            let dao = client
+                .instantiate("simple_dao", &ink_e2e::alice(), &mut dao_constructor)
+                .submit()
+                .await
+                .expect("simple_dao instantiate failed");
+            let mut dao_call = dao.call_builder::<SimpleDao>();
+
+            let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+            client
+                .call(&ink_e2e::alice(), &dao_call.set_proposal_bond(1_000))
+                .submit()
+                .await
+                .expect("set_proposal_bond failed");
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &token_call.approve(dao.account_id, 2_000),
+                )
+                .submit()
+                .await
+                .expect("approve failed");
+
+            // Proposal 1 gets no votes, proposal 2 reaches quorum
+            for description in ["Ignored", "Funded"] {
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &dao_call.create_proposal(String::from(description), Vec::new(), None),
+                    )
+                    .submit()
+                    .await
+                    .expect("create_proposal failed");
+            }
+            let balance = client
+                .call(&ink_e2e::alice(), &token_call.balance_of(alice))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(balance, 998_000);
+
+            client
+                .call(&ink_e2e::alice(), &dao_call.vote(2, VoteType::Yes))
+                .submit()
+                .await
+                .expect("vote failed");
+            for proposal_id in [1, 2] {
+                client
+                    .call(&ink_e2e::alice(), &dao_call.finalize_proposal(proposal_id))
+                    .submit()
+                    .await
+                    .expect("finalize failed");
+            }
+
+            let balance = client
+                .call(&ink_e2e::alice(), &token_call.balance_of(alice))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(balance, 999_000);
+
+            let treasury = client
+                .call(&ink_e2e::alice(), &token_call.balance_of(dao.account_id))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(treasury, 1_000);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_treasury_cannot_spend_bonds<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+
+            let mut token_constructor = DallaTokenRef::new(1_000_000);
+            let token = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut token_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+            let mut token_call = token.call_builder::<DallaToken>();
+
+            let mut dao_constructor = SimpleDaoRef::new(
+                1,
+                2000,
+                0,
+                VotingStrategy::Linear,
+                Some(token.account_id),
+                None,
+                None,
+            );
+            let dao = client
+                .instantiate("simple_dao", &ink_e2e::alice(), &mut dao_constructor)
+                .submit()
+                .await
+                .expect("simple_dao instantiate failed");
+            let mut dao_call = dao.call_builder::<SimpleDao>();
+
+            client
+                .call(&ink_e2e::alice(), &dao_call.set_proposal_bond(1_000))
+                .submit()
+                .await
+                .expect("set_proposal_bond failed");
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &token_call.approve(dao.account_id, 2_000),
+                )
+                .submit()
+                .await
+                .expect("approve failed");
+
+            // Proposal 1 spends 1_000 DALLA from a treasury holding nothing
+            // but proposal 2's bond
+            let spend = vec![Action::call(
+                dao.account_id,
+                TREASURY_TRANSFER_SELECTOR,
+                &(Some(token.account_id), bob, 1_000u128),
+            )];
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &dao_call.create_proposal(String::from("Spend"), spend, None),
+                )
+                .submit()
+                .await
+                .expect("create_proposal failed");
+            client
+                .call(&ink_e2e::alice(), &dao_call.vote(1, VoteType::Yes))
+                .submit()
+                .await
+                .expect("vote failed");
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &dao_call.create_proposal(String::from("Bonded"), Vec::new(), None),
+                )
+                .submit()
+                .await
+                .expect("create_proposal failed");
+            client
+                .call(&ink_e2e::alice(), &dao_call.vote(2, VoteType::Yes))
+                .submit()
+                .await
+                .expect("vote failed");
+            client
+                .call(&ink_e2e::alice(), &dao_call.finalize_proposal(1))
+                .submit()
+                .await
+                .expect("finalize failed");
+
+            let treasury = client
+                .call(
+                    &ink_e2e::alice(),
+                    &dao_call.treasury_token_balance(token.account_id),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(treasury, 0);
+
+            client
+                .call(&ink_e2e::bob(), &dao_call.execute_proposal(1))
+                .submit()
+                .await
+                .expect("execute failed");
+            let proposal = client
+                .call(&ink_e2e::alice(), &dao_call.get_proposal(1))
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("proposal missing");
+            assert_eq!(proposal.status, ProposalStatus::ExecutionFailed);
+
+            // The bond is still there to refund
+            client
+                .call(&ink_e2e::alice(), &dao_call.finalize_proposal(2))
+                .submit()
+                .await
+                .expect("finalize failed");
+            for (account, expected) in [(alice, 1_000_000), (bob, 0), (dao.account_id, 0)] {
+                let balance = client
+                    .call(&ink_e2e::alice(), &token_call.balance_of(account))
+                    .dry_run()
+                    .await?
+                    .return_value();
+                assert_eq!(balance, expected);
+            }
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_bond_and_deposit_net_of_transfer_fee<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let mut token_constructor = DallaTokenRef::new(1_000_000);
+            let token = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut token_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+            let mut token_call = token.call_builder::<DallaToken>();
+
+            let mut dao_constructor = SimpleDaoRef::new(
+                1,
+                2000,
+                0,
+                VotingStrategy::Linear,
+                Some(token.account_id),
+                None,
+                None,
+            );
+            let dao = client
+                .instantiate("simple_dao", &ink_e2e::alice(), &mut dao_constructor)
+                .submit()
+                .await
+                .expect("simple_dao instantiate failed");
+            let mut dao_call = dao.call_builder::<SimpleDao>();
+
+            // 1% of every transfer to or from the DAO goes to charlie
+            let charlie = ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie);
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &token_call.set_transfer_fee(100, charlie),
+                )
+                .submit()
+                .await
+                .expect("set_transfer_fee failed");
+            client
+                .call(&ink_e2e::alice(), &dao_call.set_proposal_bond(1_000))
+                .submit()
+                .await
+                .expect("set_proposal_bond failed");
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &token_call.approve(dao.account_id, 11_000),
+                )
+                .submit()
+                .await
+                .expect("approve failed");
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &dao_call.create_proposal(String::from("Funded"), Vec::new(), None),
+                )
+                .submit()
+                .await
+                .expect("create_proposal failed");
+            let bond = client
+                .call(&ink_e2e::alice(), &dao_call.bond_of(1))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(bond, 990);
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &dao_call.deposit_token(token.account_id, 10_000),
+                )
+                .submit()
+                .await
+                .expect("deposit_token failed");
+
+            // Refunding the bond only pays out what the bond brought in
+            client
+                .call(&ink_e2e::alice(), &dao_call.vote(1, VoteType::Yes))
+                .submit()
+                .await
+                .expect("vote failed");
+            client
+                .call(&ink_e2e::alice(), &dao_call.finalize_proposal(1))
+                .submit()
+                .await
+                .expect("finalize failed");
+
+            let treasury = client
+                .call(&ink_e2e::alice(), &token_call.balance_of(dao.account_id))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(treasury, 9_900);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_vote_rewards_claimed_once<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let mut token_constructor = DallaTokenRef::new(1_000_000);
+            let token = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut token_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+            let mut token_call = token.call_builder::<DallaToken>();
+
+            let mut dao_constructor = SimpleDaoRef::new(
+                1,
+                2000,
+                0,
+                VotingStrategy::Linear,
+                Some(token.account_id),
+                None,
+                None,
+            );
+            let dao = client
+                .instantiate("simple_dao", &ink_e2e::alice(), &mut dao_constructor)
+                .submit()
+                .await
+                .expect("simple_dao instantiate failed");
+            let mut dao_call = dao.call_builder::<SimpleDao>();
+
+            let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+            let claim = client
+                .call(&ink_e2e::alice(), &dao_call.claim_vote_rewards(vec![1]))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(claim, Err(Error::ZeroAmount));
+            client
+                .call(&ink_e2e::alice(), &dao_call.set_vote_reward(10))
+                .submit()
+                .await
+                .expect("set_vote_reward failed");
+
+            // Proposal 1 is voted on and finalized, proposal 2 stays open
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &dao_call.create_proposal(String::from("Rewarded"), Vec::new(), None),
+                )
+                .submit()
+                .await
+                .expect("create_proposal failed");
+            client
+                .call(&ink_e2e::alice(), &dao_call.vote(1, VoteType::Yes))
+                .submit()
+                .await
+                .expect("vote failed");
+            client
+                .call(&ink_e2e::alice(), &dao_call.finalize_proposal(1))
+                .submit()
+                .await
+                .expect("finalize failed");
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &dao_call.create_proposal(String::from("Open"), Vec::new(), None),
+                )
+                .submit()
+                .await
+                .expect("create_proposal failed");
+
+            // An empty treasury fails the payout without recording the claim
+            let claim = client
+                .call(&ink_e2e::alice(), &dao_call.claim_vote_rewards(vec![1]))
+                .submit()
+                .await
+                .expect("claim_vote_rewards failed")
+                .return_value();
+            assert_eq!(claim, Ok(vec![(1, Err(Error::InsufficientTreasury))]));
+            let claimed = client
+                .call(
+                    &ink_e2e::alice(),
+                    &dao_call.has_claimed_vote_reward(1, alice),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert!(!claimed);
+
+            client
+                .call(&ink_e2e::alice(), &token_call.approve(dao.account_id, 100))
+                .submit()
+                .await
+                .expect("approve failed");
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &dao_call.deposit_token(token.account_id, 100),
+                )
+                .submit()
+                .await
+                .expect("deposit_token failed");
+
+            let claim = client
+                .call(
+                    &ink_e2e::alice(),
+                    &dao_call.claim_vote_rewards(vec![1, 1, 2, 3]),
+                )
+                .submit()
+                .await
+                .expect("claim_vote_rewards failed")
+                .return_value();
+            assert_eq!(
+                claim,
+                Ok(vec![
+                    (1, Ok(10)),
+                    (1, Err(Error::RewardClaimed)),
+                    (2, Err(Error::NotFinalized)),
+                    (3, Err(Error::ProposalNotFound)),
+                ])
+            );
+
+            let balance = client
+                .call(&ink_e2e::alice(), &token_call.balance_of(alice))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(balance, 999_910);
+            let treasury = client
+                .call(&ink_e2e::alice(), &token_call.balance_of(dao.account_id))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(treasury, 90);
+
+            let claim = client
+                .call(&ink_e2e::alice(), &dao_call.claim_vote_rewards(vec![1]))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(claim, Ok(vec![(1, Err(Error::RewardClaimed))]));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_proposal_threshold<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+            let mut token_constructor = DallaTokenRef::new(1_000_000);
+            let token = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut token_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+            let mut token_call = token.call_builder::<DallaToken>();
+
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            client
+                .call(&ink_e2e::alice(), &token_call.transfer(bob, 999))
+                .submit()
+                .await
+                .expect("transfer failed");
+
+            let mut dao_constructor = SimpleDaoRef::new(
+                100,
+                2000,
+                0,
+                VotingStrategy::Linear,
+                Some(token.account_id),
+                None,
+                None,
+            );
+            let dao = client
+                .instantiate("simple_dao", &ink_e2e::alice(), &mut dao_constructor)
+                .submit()
+                .await
+                .expect("simple_dao instantiate failed");
+            let mut dao_call = dao.call_builder::<SimpleDao>();
+
+            client
+                .call(&ink_e2e::alice(), &dao_call.set_proposal_threshold(1_000))
+                .submit()
+                .await
+                .expect("set_proposal_threshold failed");
+
+            // Bob is one token short
+            let result = client
+                .call(
+                    &ink_e2e::bob(),
+                    &dao_call.create_proposal(String::from("Test"), Vec::new(), None),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(result, Err(Error::BelowProposalThreshold));
+
+            client
+                .call(&ink_e2e::alice(), &token_call.transfer(bob, 1))
+                .submit()
+                .await
+                .expect("transfer failed");
+            let result = client
+                .call(
+                    &ink_e2e::bob(),
+                    &dao_call.create_proposal(String::from("Test"), Vec::new(), None),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(result, Ok(1));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_non_holder_rejected<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+            let mut token_constructor = DallaTokenRef::new(1_000_000);
+            let token = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut token_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+
+            let mut dao_constructor = SimpleDaoRef::new(
+                100,
+                2000,
+                0,
+                VotingStrategy::Linear,
+                Some(token.account_id),
+                None,
+                None,
+            );
+            let dao = client
+                .instantiate("simple_dao", &ink_e2e::alice(), &mut dao_constructor)
+                .submit()
+                .await
+                .expect("simple_dao instantiate failed");
+            let mut dao_call = dao.call_builder::<SimpleDao>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &dao_call.create_proposal(String::from("Test"), Vec::new(), None),
+                )
+                .submit()
+                .await
+                .expect("create_proposal failed");
+
+            // Charlie holds no DALLA
+            let result = client
+                .call(&ink_e2e::charlie(), &dao_call.vote(1, VoteType::Yes))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(result, Err(Error::NoVotingPower));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_nft_membership_gates_and_weights<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let mut nft_constructor =
+                BeliNftRef::new(String::from("Members"), String::from("MEM"), None, false);
+            let nft = client
+                .instantiate("beli_nft", &ink_e2e::alice(), &mut nft_constructor)
+                .submit()
+                .await
+                .expect("beli_nft instantiate failed");
+            let mut nft_call = nft.call_builder::<BeliNft>();
+
+            // Bob holds two membership NFTs, Charlie none
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            for uri in ["ipfs://member/1", "ipfs://member/2"] {
+                client
+                    .call(&ink_e2e::alice(), &nft_call.mint(bob, String::from(uri)))
+                    .submit()
+                    .await
+                    .expect("mint failed");
+            }
+
+            let mut dao_constructor = SimpleDaoRef::new(
+                100,
+                2000,
+                0,
+                VotingStrategy::Linear,
+                None,
+                Some(nft.account_id),
+                None,
+            );
+            let dao = client
+                .instantiate("simple_dao", &ink_e2e::alice(), &mut dao_constructor)
+                .submit()
+                .await
+                .expect("simple_dao instantiate failed");
+            let mut dao_call = dao.call_builder::<SimpleDao>();
+
+            let mode = client
+                .call(&ink_e2e::alice(), &dao_call.membership_mode())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(mode, MembershipMode::NftWeighted);
+
+            let result = client
+                .call(
+                    &ink_e2e::charlie(),
+                    &dao_call.create_proposal(String::from("Outsider"), Vec::new(), None),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(result, Err(Error::NotMember));
+
+            client
+                .call(
+                    &ink_e2e::bob(),
+                    &dao_call.create_proposal(String::from("Member"), Vec::new(), None),
+                )
+                .submit()
+                .await
+                .expect("create_proposal failed");
+
+            let result = client
+                .call(&ink_e2e::charlie(), &dao_call.vote(1, VoteType::Yes))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(result, Err(Error::NotMember));
+
+            client
+                .call(&ink_e2e::bob(), &dao_call.vote(1, VoteType::Yes))
+                .submit()
+                .await
+                .expect("vote failed");
+
+            // One NFT, one vote
+            let weight = client
+                .call(&ink_e2e::alice(), &dao_call.get_vote(1, bob))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(weight, Some(2));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_nft_moved_after_snapshot_votes_once<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let mut nft_constructor =
+                BeliNftRef::new(String::from("Members"), String::from("MEM"), None, false);
+            let nft = client
+                .instantiate("beli_nft", &ink_e2e::alice(), &mut nft_constructor)
+                .submit()
+                .await
+                .expect("beli_nft instantiate failed");
+            let mut nft_call = nft.call_builder::<BeliNft>();
+
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let charlie = ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie);
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &nft_call.mint(bob, String::from("ipfs://member/1")),
+                )
+                .submit()
+                .await
+                .expect("mint failed");
+
+            let mut dao_constructor = SimpleDaoRef::new(
+                100,
+                2000,
+                0,
+                VotingStrategy::Linear,
+                None,
+                Some(nft.account_id),
+                None,
+            );
+            let dao = client
+                .instantiate("simple_dao", &ink_e2e::alice(), &mut dao_constructor)
+                .submit()
+                .await
+                .expect("simple_dao instantiate failed");
+            let mut dao_call = dao.call_builder::<SimpleDao>();
+
+            client
+                .call(
+                    &ink_e2e::bob(),
+                    &dao_call.create_proposal(String::from("Member"), Vec::new(), None),
+                )
+                .submit()
+                .await
+                .expect("create_proposal failed");
+            client
+                .call(&ink_e2e::bob(), &dao_call.vote(1, VoteType::Yes))
+                .submit()
+                .await
+                .expect("vote failed");
+
+            // The NFT moves on after voting; Charlie is now a member but held
+            // nothing at the snapshot
+            client
+                .call(&ink_e2e::bob(), &nft_call.transfer(charlie, 1))
+                .submit()
+                .await
+                .expect("transfer failed");
+            let result = client
+                .call(&ink_e2e::charlie(), &dao_call.vote(1, VoteType::Yes))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(result, Err(Error::NoVotingPower));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_total_power_tracks_supply<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let mut token_constructor = DallaTokenRef::new(1_000_000);
+            let token = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut token_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+            let mut token_call = token.call_builder::<DallaToken>();
+
+            let mut dao_constructor = SimpleDaoRef::new(
+                100,
+                2000,
+                0,
+                VotingStrategy::Linear,
+                Some(token.account_id),
+                None,
+                None,
+            );
+            let dao = client
+                .instantiate("simple_dao", &ink_e2e::alice(), &mut dao_constructor)
+                .submit()
+                .await
+                .expect("simple_dao instantiate failed");
+            let mut dao_call = dao.call_builder::<SimpleDao>();
+
+            // The admin can no longer override the total
+            let result = client
+                .call(&ink_e2e::alice(), &dao_call.set_total_voting_power(1))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(result, Err(Error::ManualWeightDisabled));
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &dao_call.create_proposal(String::from("First"), Vec::new(), None),
+                )
+                .submit()
+                .await
+                .expect("create_proposal failed");
+
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            client
+                .call(&ink_e2e::alice(), &token_call.mint(bob, 500_000))
+                .submit()
+                .await
+                .expect("mint failed");
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &dao_call.create_proposal(String::from("Second"), Vec::new(), None),
+                )
+                .submit()
+                .await
+                .expect("create_proposal failed");
+
+            let first = client
+                .call(&ink_e2e::alice(), &dao_call.effective_total_power(1))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(first, Some(1_000_000));
+
+            let second = client
+                .call(&ink_e2e::alice(), &dao_call.effective_total_power(2))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(second, Some(1_500_000));
+
+            Ok(())
+        }
+
+        /// Account id of the ECDSA key derived from `secret`
+        fn ecdsa_account(secret: [u8; 32]) -> AccountId {
+            use secp256k1::{PublicKey, Secp256k1, SecretKey};
+
+            let secp = Secp256k1::new();
+            let secret = SecretKey::from_slice(&secret).unwrap();
+            let public_key = PublicKey::from_secret_key(&secp, &secret).serialize();
+
+            let mut account = [0u8; 32];
+            ink::env::hash_bytes::<Blake2x256>(&public_key, &mut account);
+            AccountId::from(account)
+        }
+
+        /// Signs `hash` with `secret` as a recoverable ECDSA signature
+        fn sign(hash: [u8; 32], secret: [u8; 32]) -> Vec<u8> {
+            use secp256k1::{Message, Secp256k1, SecretKey};
+
+            let secp = Secp256k1::new();
+            let secret = SecretKey::from_slice(&secret).unwrap();
+            let (recovery_id, compact) = secp
+                .sign_ecdsa_recoverable(&Message::from_digest(hash), &secret)
+                .serialize_compact();
+
+            let mut signature = compact.to_vec();
+            signature.push(recovery_id.to_i32() as u8);
+            signature
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_submit_signed_votes<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+            let mut token_constructor = DallaTokenRef::new(1_000_000);
+            let token = client
+                .instantiate("dalla_token", &ink_e2e::alice(), &mut token_constructor)
+                .submit()
+                .await
+                .expect("dalla_token instantiate failed");
+            let mut token_call = token.call_builder::<DallaToken>();
+
+            // Voters are plain ECDSA keys that never pay for a transaction
+            let (first, second, third) = ([1u8; 32], [2u8; 32], [3u8; 32]);
+            for (secret, amount) in [(first, 100), (second, 300), (third, 50)] {
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &token_call.transfer(ecdsa_account(secret), amount),
+                    )
+                    .submit()
+                    .await
+                    .expect("transfer failed");
+            }
+
+            let mut dao_constructor = SimpleDaoRef::new(
+                100,
+                2000,
+                0,
+                VotingStrategy::Linear,
+                Some(token.account_id),
+                None,
+                None,
+            );
+            let dao = client
+                .instantiate("simple_dao", &ink_e2e::alice(), &mut dao_constructor)
+                .submit()
+                .await
+                .expect("simple_dao instantiate failed");
+            let mut dao_call = dao.call_builder::<SimpleDao>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &dao_call.create_proposal(String::from("Gasless"), Vec::new(), None),
+                )
+                .submit()
+                .await
+                .expect("create_proposal failed");
+
+            let mut votes = Vec::new();
+            for (secret, vote_type) in [
+                (first, VoteType::Yes),
+                (second, VoteType::No),
+                (first, VoteType::Yes),
+                (third, VoteType::Yes),
+            ] {
+                let voter = ecdsa_account(secret);
+                let support = Support::Vote(vote_type);
+                let hash = client
+                    .call(&ink_e2e::alice(), &dao_call.vote_hash(1, voter, support, 0))
+                    .dry_run()
+                    .await?
+                    .return_value();
+                votes.push(SignedVote {
+                    voter,
+                    support,
+                    nonce: 0,
+                    signature: sign(hash, secret),
+                });
+            }
+            // Flip the third voter's choice after signing
+            votes[3].support = Support::Vote(VoteType::No);
+
+            let results = client
+                .call(&ink_e2e::charlie(), &dao_call.submit_votes(1, votes))
+                .submit()
+                .await
+                .expect("submit_votes failed")
+                .return_value();
+            assert_eq!(
+                results,
+                Ok(vec![
+                    Ok(()),
+                    Ok(()),
+                    Err(Error::AlreadyVoted),
+                    Err(Error::InvalidSignature),
+                ])
+            );
+
+            let proposal = client
+                .call(&ink_e2e::alice(), &dao_call.get_proposal(1))
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("proposal missing");
+            assert_eq!(proposal.yes_votes, 100);
+            assert_eq!(proposal.no_votes, 300);
+
+            let nonce = client
+                .call(
+                    &ink_e2e::alice(),
+                    &dao_call.vote_nonce(ecdsa_account(first)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(nonce, 1);
+
+            Ok(())
         }
     }
 }